@@ -27,7 +27,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let destination = db_config.connection_string();
     let client = Client::connect(destination.clone(), ClientOptions::default())
         .await
-        .expect(format!("Failed to connect to {}", &destination).as_str());
+        .unwrap_or_else(|_| panic!("Failed to connect to {}", &destination));
 
     print_utils::dbg_print(vrb, "Build import config");
     let config =
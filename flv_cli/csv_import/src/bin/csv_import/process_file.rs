@@ -5,6 +5,9 @@ use klickhouse::Client;
 use std::error::Error;
 use std::path::PathBuf;
 
+/// The columns every CSV file of trades starts with.
+const TRADE_COLUMNS: [&str; 3] = ["timestamp", "price", "volume"];
+
 /// Process a CSV file for import into TimePlus Proton.
 ///
 /// # Arguments
@@ -20,6 +23,7 @@ use std::path::PathBuf;
 /// * Extracts the filename and path from the input file path
 /// * Generates a SQL query to count the number of rows in the CSV file
 /// * Executes the count query and saves the number of rows
+/// * Reads the header row of the CSV file to check whether it has a side column
 /// * Generates a SQL CREATE TABLE statement for a new table to hold the CSV data
 /// * Executes the CREATE TABLE statement
 /// * Generates a SQL INSERT statement to populate the new table from the CSV
@@ -38,6 +42,7 @@ use std::path::PathBuf;
 /// - Failed to get file name
 /// - Failed to convert file name to string
 /// - Failed to convert file path to string
+/// - Failed to read a valid header row from the CSV file
 /// - Failed to count rows in CSV file
 /// - Failed to create trade table in QuestDB
 /// - Failed to insert trade data into QuestDB
@@ -69,18 +74,21 @@ pub(crate) async fn process(
     let table_name = format!("KRAKEN_{}", file).to_lowercase();
     let symbol = file.to_lowercase();
 
+    print_utils::dbg_print(vrb, "Check if the trades have a side column");
+    let with_side = has_side_column(file_path)?;
+
     print_utils::dbg_print(vrb, "Create the trade data table if it doesn't exist");
-    query_utils::create_trade_data_table(&client, &table_name)
+    query_utils::create_trade_data_table(client, &table_name, with_side)
         .await
         .expect("Failed to create trade table");
 
     print_utils::dbg_print(vrb, "Insert trade data into the trade table");
-    query_utils::insert_trade_data(&client, &file, path)
+    query_utils::insert_trade_data(client, &file, path, with_side)
         .await
         .expect("Failed to insert trade data");
 
     print_utils::dbg_print(vrb, "Count number of rows imported");
-    let number_of_rows: u64 = query_utils::count_rows(&client, &table_name)
+    let number_of_rows: u64 = query_utils::count_rows(client, &table_name)
         .await
         .expect("Failed to count imported data");
     if vrb {
@@ -89,9 +97,58 @@ pub(crate) async fn process(
 
     print_utils::dbg_print(vrb, "Insert meta data into meta data table");
     let meta_data = MetaData::new(table_name, symbol, symbol_id, number_of_rows);
-    query_utils::insert_meta_data(&client, &meta_data, meta_data_table)
+    query_utils::insert_meta_data(client, &meta_data, meta_data_table)
         .await
         .expect("Failed to insert meta data");
 
     Ok(())
 }
+
+/// Checks whether a CSV file of trades has a side column.
+///
+/// The file starts with a header row naming its columns: timestamp, price, and volume,
+/// and optionally side, with 'b' for buy and 's' for sell trades.
+///
+/// # Arguments
+///
+/// * `file_path` - Path to CSV file
+///
+/// # Returns
+///
+/// Returns `true` if the header row names a side column, `false` otherwise.
+///
+/// # Errors
+///
+/// Will return an error if:
+///
+/// - Failed to read the header row of the file
+/// - The header row names other columns
+///
+fn has_side_column(file_path: &PathBuf) -> Result<bool, Box<dyn Error>> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_path(file_path)?;
+
+    let header: Vec<String> = csv_reader
+        .headers()?
+        .iter()
+        .map(|column| column.to_lowercase())
+        .collect();
+
+    match header.as_slice() {
+        [timestamp, price, volume] if [timestamp, price, volume] == TRADE_COLUMNS => Ok(false),
+        [timestamp, price, volume, side]
+            if [timestamp, price, volume] == TRADE_COLUMNS && side == "side" =>
+        {
+            Ok(true)
+        }
+        _ => Err(format!(
+            "Invalid header row in {}: expected {} with an optional side column, found {}",
+            file_path.display(),
+            TRADE_COLUMNS.join(","),
+            header.join(",")
+        )
+        .into()),
+    }
+}
@@ -1,6 +1,13 @@
 use crate::types::MetaData;
 
-pub(crate) fn generate_trade_table_ddl(table_name: &str) -> String {
+pub(crate) fn generate_trade_table_ddl(table_name: &str, with_side: bool) -> String {
+    // Side of the aggressor of each trade, 'b' for buy and 's' for sell.
+    let side_column = if with_side {
+        "`side` LowCardinality(String),"
+    } else {
+        ""
+    };
+
     format!(
         r"
         CREATE TABLE IF NOT EXISTS default.{table_name}
@@ -8,6 +15,7 @@ pub(crate) fn generate_trade_table_ddl(table_name: &str) -> String {
            `timestamp` Datetime64(3) CODEC(DoubleDelta, LZ4),
            `price`  Float64 CODEC(Delta, LZ4),
            `volume` Float64 CODEC(Delta, LZ4),
+           {side_column}
         )
      ENGINE = MergeTree()
      PRIMARY KEY toStartOfHour(timestamp)
@@ -15,14 +23,26 @@ pub(crate) fn generate_trade_table_ddl(table_name: &str) -> String {
     )
 }
 
-pub(crate) fn generate_insert_query(file: &str, path: &str) -> String {
+pub(crate) fn generate_insert_query(file: &str, path: &str, with_side: bool) -> String {
     let table_name = format!("KRAKEN_{}", file).to_lowercase();
+
+    if with_side {
+        return format!(
+            r"
+        INSERT INTO {table_name} (timestamp, price, volume, side)
+        SELECT timestamp, price, volume, side
+        FROM
+        file('{path}', 'CSVWithNames', 'timestamp Datetime64(3), price Float64, volume Float64, side String')
+        "
+        );
+    }
+
     format!(
         r"
         INSERT INTO {table_name} (timestamp, price, volume)
         SELECT timestamp, price, volume
         FROM
-        file('{path}', 'CSV', 'timestamp Datetime64(3), price Float64, volume Float64')
+        file('{path}', 'CSVWithNames', 'timestamp Datetime64(3), price Float64, volume Float64')
         "
     )
 }
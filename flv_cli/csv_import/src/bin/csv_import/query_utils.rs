@@ -19,8 +19,9 @@ pub(crate) async fn count_rows(client: &Client, path: &str) -> Result<u64, Box<d
 pub(crate) async fn create_trade_data_table(
     client: &Client,
     table_name: &str,
+    with_side: bool,
 ) -> Result<(), Box<dyn Error>> {
-    let query = query_gen::generate_trade_table_ddl(table_name);
+    let query = query_gen::generate_trade_table_ddl(table_name, with_side);
     client
         .execute(&query)
         .await
@@ -33,8 +34,9 @@ pub(crate) async fn insert_trade_data(
     client: &Client,
     file: &str,
     path: &str,
+    with_side: bool,
 ) -> Result<(), Box<dyn Error>> {
-    let query = query_gen::generate_insert_query(&file, &path);
+    let query = query_gen::generate_insert_query(file, path, with_side);
 
     client
         .execute(&query)
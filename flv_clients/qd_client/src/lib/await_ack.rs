@@ -0,0 +1,215 @@
+use std::error::Error;
+
+use iggy::client::MessageClient;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use tokio::time::{sleep, Instant};
+
+use common::prelude::{IggyConfig, MessageProcessingError};
+use sbe_messages::prelude::{
+    validate_frame, ClientErrorMessage, ClientLoginAckMessage, ClientLogoutAckMessage,
+    DataErrorMessage, MessageType,
+};
+
+use crate::{QDClient, ACK_POLL_INTERVAL, ACK_TIMEOUT};
+
+impl QDClient {
+    /// Waits for the gateway to acknowledge the login of this client.
+    ///
+    /// The gateway sends the login ack with an access token on the data channel of this client,
+    /// and a rejected login to the login reject topic, see `check_login_reject`.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - The timestamp in microseconds at which the login message was sent.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the `ClientLoginAckMessage` on success, or an `Error`
+    /// if the gateway rejected the login or did not answer within `ACK_TIMEOUT`.
+    ///
+    pub(crate) async fn await_login_ack(
+        &self,
+        since: u64,
+    ) -> Result<ClientLoginAckMessage, Box<dyn Error + Send>> {
+        let buffer = match self.await_ack(MessageType::ClientLoginAck, since).await {
+            Ok(buffer) => buffer,
+            Err(e) => return Err(e),
+        };
+
+        match ClientLoginAckMessage::try_from(buffer.as_slice()) {
+            Ok(login_ack) => Ok(login_ack),
+            Err(e) => Err(Box::new(MessageProcessingError(e.to_string()))),
+        }
+    }
+
+    /// Waits for the gateway to acknowledge the logout of this client.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - The timestamp in microseconds at which the logout message was sent.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the `ClientLogoutAckMessage` on success, or an `Error`
+    /// if the gateway rejected the logout or did not answer within `ACK_TIMEOUT`.
+    ///
+    pub(crate) async fn await_logout_ack(
+        &self,
+        since: u64,
+    ) -> Result<ClientLogoutAckMessage, Box<dyn Error + Send>> {
+        let buffer = match self.await_ack(MessageType::ClientLogoutAck, since).await {
+            Ok(buffer) => buffer,
+            Err(e) => return Err(e),
+        };
+
+        Ok(ClientLogoutAckMessage::from(buffer.as_slice()))
+    }
+
+    /// Polls the data channel of this client for an ack of the given type.
+    ///
+    /// The gateway sends acks and client errors on the data channel,
+    /// which only the iggy user of this client can read.
+    /// Only messages sent after `since` are considered so that acks
+    /// from earlier sessions with the same client ID are ignored.
+    /// A client error for this client ends the wait with an error,
+    /// and so does a rejected login while waiting for the login ack.
+    ///
+    async fn await_ack(
+        &self,
+        ack_type: MessageType,
+        since: u64,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send>> {
+        let poll_command = poll_command_since(self.consumer_config(), since);
+
+        let deadline = Instant::now() + ACK_TIMEOUT;
+
+        while Instant::now() < deadline {
+            // A login rejected because the client ID is already logged in never reaches the data channel.
+            if ack_type == MessageType::ClientLoginAck {
+                match self.check_login_reject(since).await {
+                    Ok(_) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+
+            let polled_messages = match self.consumer().poll_messages(&poll_command).await {
+                Ok(polled_messages) => polled_messages.messages,
+                Err(e) => return Err(Box::new(MessageProcessingError(e.to_string()))),
+            };
+
+            for polled_message in polled_messages {
+                let buffer = polled_message.payload.as_ref();
+
+                // Skip messages that cannot be decoded safely. A valid frame holds
+                // all fields of the message in its schema version, so decoding cannot fail.
+                let message_type = match validate_frame(buffer) {
+                    Ok(message_type) => message_type,
+                    Err(_) => continue,
+                };
+
+                // Skip messages addressed to other clients.
+                match client_id_of(buffer) {
+                    Some(client_id) if client_id == self.client_id => {}
+                    _ => continue,
+                }
+
+                if message_type == ack_type {
+                    return Ok(buffer.to_vec());
+                }
+
+                if message_type == MessageType::ClientError {
+                    let client_error = ClientErrorMessage::from(buffer);
+                    return Err(Box::new(MessageProcessingError(format!(
+                        "[QDClient/await_ack]: Gateway rejected {}: {}",
+                        ack_type,
+                        client_error.client_error_type()
+                    ))));
+                }
+            }
+
+            sleep(ACK_POLL_INTERVAL).await;
+        }
+
+        Err(Box::new(MessageProcessingError(format!(
+            "[QDClient/await_ack]: No {} received within {:?}",
+            ack_type, ACK_TIMEOUT
+        ))))
+    }
+}
+
+impl QDClient {
+    /// Polls the login reject topic of the gateway for an error of the login of this client.
+    ///
+    /// A client that is not logged in has no data channel, so the gateway sends the error of
+    /// a rejected login to the login reject topic, which the bootstrap user may poll.
+    /// Must be called while the producer is still logged in with the bootstrap user.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - The timestamp in microseconds at which the login message was sent.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if the login has not been rejected (yet). Otherwise, an `Error`
+    /// with the decoded `ClientErrorMessage`, i.e. for a wrong auth token, or the decoded
+    /// `DataErrorMessage` with `RateLimited` and the retry-after hint.
+    ///
+    pub(crate) async fn check_login_reject(&self, since: u64) -> Result<(), Box<dyn Error + Send>> {
+        let reject_config = iggy_utils::login_reject_config(self.producer_config());
+        let poll_command = poll_command_since(&reject_config, since);
+
+        let polled_messages = match self.producer().poll_messages(&poll_command).await {
+            Ok(polled_messages) => polled_messages.messages,
+            Err(e) => return Err(Box::new(MessageProcessingError(e.to_string()))),
+        };
+
+        for polled_message in polled_messages {
+            let buffer = polled_message.payload.as_ref();
+
+            // Skip messages that cannot be decoded safely.
+            let message_type = match validate_frame(buffer) {
+                Ok(message_type) => message_type,
+                Err(_) => continue,
+            };
+
+            // Skip rejected logins of other clients.
+            match client_id_of(buffer) {
+                Some(client_id) if client_id == self.client_id => {}
+                _ => continue,
+            }
+
+            match message_type {
+                MessageType::ClientError => {
+                    return Err(Box::new(ClientErrorMessage::from(buffer)));
+                }
+                MessageType::DataError => {
+                    return Err(Box::new(DataErrorMessage::from(buffer)));
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the client id of an ack or client error, which encode it right after the message type,
+/// or `None` if the buffer is too short to hold it.
+fn client_id_of(buffer: &[u8]) -> Option<u16> {
+    buffer
+        .get(10..12)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Builds a poll command for all messages sent to the channel of the config after `since`.
+fn poll_command_since(iggy_config: &IggyConfig, since: u64) -> PollMessages {
+    PollMessages {
+        consumer: Default::default(),
+        stream_id: iggy_config.stream_id(),
+        topic_id: iggy_config.topic_id(),
+        partition_id: Option::from(iggy_config.partition_id()),
+        strategy: PollingStrategy::timestamp(since),
+        count: iggy_config.messages_per_batch(),
+        auto_commit: false,
+    }
+}
@@ -1,8 +1,12 @@
+use std::sync::MutexGuard;
+
 use iggy::clients::client::IggyClient;
 use iggy::messages::poll_messages::PollMessages;
 
 use common::prelude::IggyConfig;
+use sbe_messages::prelude::{Capabilities, ClientLoginAckMessage, DataType, StartDataMessage};
 
+use crate::sequence_tracker::SequenceTracker;
 use crate::QDClient;
 
 impl QDClient {
@@ -24,4 +28,67 @@ impl QDClient {
     pub fn producer_config(&self) -> &IggyConfig {
         &self.producer_config
     }
+    /// Returns the login ack with the data channel the gateway assigned to this client.
+    pub fn login_ack(&self) -> &ClientLoginAckMessage {
+        &self.login_ack
+    }
+    /// Returns the protocol version negotiated with the gateway.
+    pub fn protocol_version(&self) -> u16 {
+        self.login_ack.protocol_version()
+    }
+    /// Returns the capabilities both this client and the gateway support.
+    pub fn capabilities(&self) -> Capabilities {
+        self.login_ack.capabilities()
+    }
+
+    /// Returns the start request of the data stream of the symbol and data type, if any.
+    pub(crate) fn data_request(
+        &self,
+        symbol_id: u16,
+        data_type: DataType,
+    ) -> Option<StartDataMessage> {
+        self.data_requests
+            .lock()
+            .expect("[QDClient/data_request]: Failed to lock data requests")
+            .get(&(symbol_id, data_type))
+            .cloned()
+    }
+    /// Returns the data type of the start request that streams bars of the given data type, if any.
+    ///
+    /// Tick, volume, and dollar bars arrive as OHLCV bars, so a stream of OHLCV bars
+    /// may have been requested with any of these data types.
+    pub(crate) fn data_request_type(
+        &self,
+        symbol_id: u16,
+        data_type: DataType,
+    ) -> Option<DataType> {
+        let data_types = match data_type {
+            DataType::OHLCVData => vec![
+                DataType::OHLCVData,
+                DataType::TickBarData,
+                DataType::VolumeBarData,
+                DataType::DollarBarData,
+            ],
+            _ => vec![data_type],
+        };
+
+        data_types
+            .into_iter()
+            .find(|data_type| self.data_request(symbol_id, *data_type).is_some())
+    }
+    /// Records the start request of a data stream so that the stream can be resumed.
+    pub(crate) fn record_data_request(&self, start_data_msg: &StartDataMessage) {
+        self.data_requests
+            .lock()
+            .expect("[QDClient/record_data_request]: Failed to lock data requests")
+            .insert(
+                (*start_data_msg.symbol_id(), *start_data_msg.data_type_id()),
+                start_data_msg.clone(),
+            );
+    }
+    pub(crate) fn sequence_tracker(&self) -> MutexGuard<'_, SequenceTracker> {
+        self.sequence_tracker
+            .lock()
+            .expect("[QDClient/sequence_tracker]: Failed to lock sequence tracker")
+    }
 }
@@ -0,0 +1,107 @@
+use std::error::Error;
+
+use common::prelude::MessageProcessingError;
+use sbe_messages::prelude::{
+    validate_frame, DataType, FirstOHLCVBar, FirstTradeBar, LastOHLCVBar, LastTradeBar,
+    MessageType, SbeOHLCVBar, SbeTradeBar,
+};
+
+use crate::sequence_tracker::SequenceCheck;
+use crate::QDClient;
+
+impl QDClient {
+    /// Checks a message from the client data channel for gaps in the data stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_message` - The raw SBE message polled from the client data channel.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `true` if the message should be delivered to the application,
+    /// `false` if it should be dropped, or an `Error` if the message cannot be decoded.
+    ///
+    /// Trade and OHLCV bars carry a sequence number that increases by one per bar
+    /// and symbol. A bar that has already been received is dropped. When bars are
+    /// missing, the client sends a ResumeDataMessage once to restart the stream from
+    /// the first missing bar, and drops all bars until that bar arrives.
+    /// Bars of streams of several symbols cannot be resumed, so their gaps are only reported.
+    /// Tick, volume, and dollar bars arrive as OHLCV bars and are tracked as such.
+    ///
+    pub async fn handle_data_message(
+        &self,
+        raw_message: &[u8],
+    ) -> Result<bool, Box<dyn Error + Send>> {
+        let message_type = match validate_frame(raw_message) {
+            Ok(message_type) => message_type,
+            Err(e) => return Err(Box::new(MessageProcessingError(e.to_string()))),
+        };
+
+        let (symbol_id, data_type, sequence_number) = match message_type {
+            MessageType::FirstTradeBar => {
+                let first_bar = FirstTradeBar::from(raw_message);
+                self.sequence_tracker()
+                    .start(first_bar.symbol_id(), DataType::TradeData);
+                return Ok(true);
+            }
+            MessageType::FirstOHLCVBar => {
+                let first_bar = FirstOHLCVBar::from(raw_message);
+                self.sequence_tracker()
+                    .start(first_bar.symbol_id(), DataType::OHLCVData);
+                return Ok(true);
+            }
+            MessageType::LastTradeBar => {
+                let last_bar = LastTradeBar::from(raw_message);
+                self.sequence_tracker()
+                    .end(last_bar.symbol_id(), DataType::TradeData);
+                return Ok(true);
+            }
+            MessageType::LastOHLCVBar => {
+                let last_bar = LastOHLCVBar::from(raw_message);
+                self.sequence_tracker()
+                    .end(last_bar.symbol_id(), DataType::OHLCVData);
+                return Ok(true);
+            }
+            MessageType::TradeBar => match SbeTradeBar::decode(raw_message) {
+                Ok(bar) => (bar.symbol_id(), DataType::TradeData, bar.sequence_number()),
+                Err(e) => return Err(Box::new(MessageProcessingError(e.to_string()))),
+            },
+            MessageType::OHLCVBar => match SbeOHLCVBar::decode(raw_message) {
+                Ok(bar) => (bar.symbol_id(), DataType::OHLCVData, bar.sequence_number()),
+                Err(e) => return Err(Box::new(MessageProcessingError(e.to_string()))),
+            },
+            _ => return Ok(true),
+        };
+
+        let check = self
+            .sequence_tracker()
+            .check(symbol_id, data_type, sequence_number);
+
+        match check {
+            SequenceCheck::Next => Ok(true),
+            SequenceCheck::Duplicate | SequenceCheck::Resuming => Ok(false),
+            SequenceCheck::Gap(expected) => {
+                let request_type = match self.data_request_type(symbol_id, data_type) {
+                    Some(request_type) => request_type,
+                    None => {
+                        println!(
+                            "[QDClient/handle_data_message]: Missing bars {}..{} of symbol {} cannot be resumed",
+                            expected, sequence_number, symbol_id
+                        );
+                        self.sequence_tracker()
+                            .skip_to(symbol_id, data_type, sequence_number);
+                        return Ok(true);
+                    }
+                };
+
+                match self
+                    .resume_data(symbol_id, request_type, Some(expected), None)
+                    .await
+                {
+                    Ok(_) => Ok(false),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    }
+}
@@ -1,37 +1,88 @@
+use std::collections::HashMap;
 use std::error::Error;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use iggy::client::Client;
 use iggy::clients::client::IggyClient;
 use iggy::messages::poll_messages::PollMessages;
-use tokio::time::sleep;
+use iggy::utils::timestamp::IggyTimestamp;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Instant};
 
-use common::prelude::{IggyConfig, IggyUser, ServiceID};
+use crate::sequence_tracker::SequenceTracker;
+use common::prelude::{IggyConfig, IggyUser, MessageProcessingError, ServiceID};
+use sbe_messages::prelude::{
+    ClientLoginAckMessage, DataType, StartDataMessage, MIN_PROTOCOL_VERSION,
+};
 
+mod await_ack;
 mod getters;
+mod handle_data;
+mod send_heartbeat;
 mod send_login;
 mod send_logout;
+mod send_pause_data;
+mod send_resume_data;
 mod send_start_data;
+mod send_start_multi_data;
+mod send_stop_data;
+mod sequence_tracker;
 mod shared;
 
+/// The maximum time to wait for the gateway to acknowledge a login or logout.
+const ACK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The time between two polls of the data channel while waiting for an ack.
+const ACK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The interval at which the client sends a heartbeat to the gateway.
+/// The gateway evicts the session after three missed heartbeats.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
 /// The QDClient struct.
 #[derive(Debug)]
 pub struct QDClient {
     client_id: u16,
-    producer: IggyClient,
+    producer: Arc<IggyClient>,
     consumer: IggyClient,
     poll_command: PollMessages,
     consumer_config: IggyConfig,
     producer_config: IggyConfig,
+    login_ack: ClientLoginAckMessage,
+    heartbeat: Option<JoinHandle<()>>,
+    // The start requests of the data streams, to resume a stream after a gap.
+    data_requests: Mutex<HashMap<(u16, DataType), StartDataMessage>>,
+    sequence_tracker: Mutex<SequenceTracker>,
 }
 
 impl QDClient {
     /// Creates a new QDClient instance.
+    ///
+    /// Logs in to the QD gateway and waits up to `ACK_TIMEOUT` for the login ack.
+    /// Returns an error if the gateway rejects the login or does not answer in time.
+    /// A rejected login returns the decoded `ClientErrorMessage` of the gateway as error, i.e. for
+    /// a wrong auth token, or the decoded `DataErrorMessage` if the login attempts are rate limited.
+    /// The login ack carries the protocol version and capabilities negotiated with the gateway.
+    /// Returns an error if the gateway only speaks a protocol version older than `MIN_PROTOCOL_VERSION`.
+    /// The client sends the login with the bootstrap user, which may only send logins to the gateway.
+    /// The gateway authenticates the client with its `auth_token` and creates an iggy user
+    /// with the `auth_token` as password that may only use the data stream of this client.
+    /// The client waits for that user to log in and reads the login ack from its data channel.
+    /// Meanwhile, it polls the login reject topic of the gateway with the bootstrap user.
+    /// The login ack carries the personal access token of the client user,
+    /// with which the client then reconnects to iggy and sends all further requests
+    /// to the control topic of its data stream, which only the client user can write to.
+    /// The user in `iggy_config` is ignored; only its data channel settings are used.
+    /// Once logged in, the client sends a heartbeat every `HEARTBEAT_INTERVAL`.
+    ///
     pub async fn new(
         client_id: u16,
+        auth_token: &str,
         iggy_config: IggyConfig,
     ) -> Result<Self, Box<dyn Error + Send>> {
         // Producer is configured to send messages to the  QD gateway channel
-        let producer_user = IggyUser::default();
+        let producer_user = iggy_utils::bootstrap_user();
         let producer_id = ServiceID::QDGW.id() as u32;
         let producer_config = IggyConfig::from_client_id(producer_user, producer_id, 50000, false);
         let producer = iggy_utils::get_client_producer(&producer_config)
@@ -41,29 +92,152 @@ impl QDClient {
         // The poll command is using the producer config for polling for messages from the QD gateway
         let poll_command = shared::get_poll_command(&producer_config);
 
-        // Consumer is configured for listing for incoming messages on the client channel
-        let consumer = iggy_utils::get_consumer(&iggy_config)
+        // Consumer is configured for listing for incoming messages on the client channel.
+        // It logs in once the gateway has created the iggy user of the client.
+        let consumer = iggy_utils::get_iggy_client(iggy_config.tcp_server_addr())
             .await
             .expect("Failed to create iggy consumer");
 
+        consumer
+            .connect()
+            .await
+            .expect("Failed to connect iggy consumer");
+
         // Create client.
-        let client = Self {
+        let mut client = Self {
             client_id,
-            producer,
+            producer: Arc::new(producer),
             consumer,
             poll_command,
             consumer_config: iggy_config,
             producer_config,
+            login_ack: ClientLoginAckMessage::default(),
+            heartbeat: None,
+            data_requests: Mutex::new(HashMap::new()),
+            sequence_tracker: Mutex::new(SequenceTracker::default()),
         };
 
         // Login to the QD gateway and register the clients data channel
+        let since = IggyTimestamp::now().to_micros();
         client
-            .login()
+            .login(auth_token)
             .await
             .expect("[QDClient/new]: Failed to log in to the QD Gateway");
 
+        // Wait until the gateway has created the client user to read the data channel
+        match client.login_client_user(auth_token, since).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Wait until the gateway confirms the login and assigns the data channel
+        client.login_ack = match client.await_login_ack(since).await {
+            Ok(login_ack) => login_ack,
+            Err(e) => return Err(e),
+        };
+
+        // An old gateway answers with the schema version of its ack
+        let protocol_version = client.login_ack.protocol_version();
+        if protocol_version < MIN_PROTOCOL_VERSION {
+            return Err(Box::new(MessageProcessingError(format!(
+                "[QDClient/new]: Unsupported gateway protocol version: {}",
+                protocol_version
+            ))));
+        }
+
+        // Replace the bootstrap connections with connections of the client user,
+        // and send further requests to the control topic of the client
+        let access_token = client.login_ack.access_token().to_string();
+        if !access_token.is_empty() {
+            match client.reconnect_with_token(&access_token).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+
+            client.producer_config = iggy_utils::client_control_config(&client.consumer_config);
+        }
+
+        // Keep the session alive with periodic heartbeats
+        client.heartbeat = Some(client.spawn_heartbeat());
+
         Ok(client)
     }
+
+    /// Logs in the consumer with the iggy user the gateway creates for this client at login.
+    ///
+    /// Retries until `ACK_TIMEOUT`, as the user only exists once the gateway
+    /// has accepted the login. Returns the error of the gateway if it rejected the login,
+    /// or an error if the user cannot log in in time.
+    ///
+    async fn login_client_user(
+        &self,
+        auth_token: &str,
+        since: u64,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        let user = IggyUser::new(&iggy_utils::client_user_name(self.client_id), auth_token);
+        let deadline = Instant::now() + ACK_TIMEOUT;
+
+        while Instant::now() < deadline {
+            match self.check_login_reject(since).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+
+            if iggy_utils::login_user(self.consumer(), &user).await.is_ok() {
+                return Ok(());
+            }
+
+            sleep(ACK_POLL_INTERVAL).await;
+        }
+
+        Err(Box::new(MessageProcessingError(format!(
+            "[QDClient/login_client_user]: Gateway did not accept the login within {:?}",
+            ACK_TIMEOUT
+        ))))
+    }
+
+    /// Replaces the producer and the consumer with clients logged in with the access token.
+    ///
+    /// Must be called before the heartbeat starts, which shares the producer.
+    ///
+    async fn reconnect_with_token(
+        &mut self,
+        access_token: &str,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        let tcp_server_addr = self.consumer_config.tcp_server_addr();
+
+        let producer = match iggy_utils::get_token_client(tcp_server_addr.clone(), access_token)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(producer) => producer,
+            Err(e) => return Err(Box::new(MessageProcessingError(e))),
+        };
+
+        let consumer = match iggy_utils::get_token_client(tcp_server_addr, access_token)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(consumer) => consumer,
+            Err(e) => return Err(Box::new(MessageProcessingError(e))),
+        };
+
+        // Shut down the bootstrap connections
+        for client in [self.producer(), self.consumer()] {
+            match iggy_utils::shutdown(client)
+                .await
+                .map_err(|e| e.to_string())
+            {
+                Ok(_) => {}
+                Err(e) => return Err(Box::new(MessageProcessingError(e))),
+            }
+        }
+
+        self.producer = Arc::new(producer);
+        self.consumer = consumer;
+
+        Ok(())
+    }
 }
 
 impl QDClient {
@@ -73,22 +247,40 @@ impl QDClient {
     ///
     /// Returns a `Result` with `()` on success, or an `Error` on failure.
     ///
-    /// This logs the client out of the gateway and then deletes all client topics.
+    /// This logs the client out of the gateway, waits for the logout ack,
+    /// deletes all client topics, and revokes the access token of the client.
     ///
     pub async fn close(&self) -> Result<(), Box<dyn Error + Send>> {
+        // Stop sending heartbeats.
+        if let Some(heartbeat) = &self.heartbeat {
+            heartbeat.abort();
+        }
+
         // Logs out of the gateway.
+        let since = IggyTimestamp::now().to_micros();
         self.logout()
             .await
             .expect("[QDClient/close]: Failed to log out");
 
-        // Wait a moment for the logout to complete.
-        sleep(Duration::from_millis(25)).await;
+        // Wait until the gateway confirms the logout.
+        match self.await_logout_ack(since).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
 
         // Delete stream and topic before shutting down.
         iggy_utils::cleanup(&self.consumer(), &self.consumer_config())
             .await
             .expect("Failed to clean up iggy consumer");
 
+        // Revoke the access token this client logged in with.
+        if !self.login_ack.access_token().is_empty() {
+            let token_name = iggy_utils::client_token_name(self.client_id);
+            iggy_utils::delete_token(self.consumer(), token_name)
+                .await
+                .expect("Failed to delete access token");
+        }
+
         // Logout user. Call it just once as consumer and producer use the same user.
         iggy_utils::logout_user(&self.consumer())
             .await
@@ -107,3 +299,13 @@ impl QDClient {
         Ok(())
     }
 }
+
+impl Drop for QDClient {
+    /// Stops the heartbeat so that the gateway evicts the session
+    /// of a client that got dropped without calling close.
+    fn drop(&mut self) {
+        if let Some(heartbeat) = &self.heartbeat {
+            heartbeat.abort();
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use bytes::Bytes;
+use iggy::client::MessageClient;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use tokio::task::JoinHandle;
+use tokio::time::interval;
+
+use sbe_messages::prelude::ClientHeartbeatMessage;
+
+use crate::{QDClient, HEARTBEAT_INTERVAL};
+
+impl QDClient {
+    /// Spawns a task that sends a ClientHeartbeatMessage to the gateway every `HEARTBEAT_INTERVAL`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the join handle of the heartbeat task.
+    ///
+    /// The gateway evicts the session of a client that stops sending heartbeats,
+    /// so a crashed client does not block the next login with the same client ID.
+    /// The task runs until the client gets closed or dropped.
+    ///
+    pub(crate) fn spawn_heartbeat(&self) -> JoinHandle<()> {
+        let producer = self.producer.clone();
+        let producer_config = self.producer_config.clone();
+
+        // Encode the message once as it never changes
+        let (_, buffer) = ClientHeartbeatMessage::new(self.client_id)
+            .encode()
+            .expect("[QDClient/spawn_heartbeat]: Failed to encode message");
+
+        tokio::spawn(async move {
+            let mut ticker = interval(HEARTBEAT_INTERVAL);
+
+            loop {
+                ticker.tick().await;
+
+                // Build iggy message wrapper
+                let message = Message::new(None, Bytes::from(buffer.clone()), None);
+
+                // Send message to the gateway
+                let res = producer
+                    .send_messages(&mut SendMessages {
+                        stream_id: producer_config.stream_id(),
+                        topic_id: producer_config.topic_id(),
+                        partitioning: Partitioning::partition_id(producer_config.partition_id()),
+                        messages: vec![message],
+                    })
+                    .await;
+
+                if let Err(e) = res {
+                    eprintln!("[QDClient/heartbeat]: Failed to send heartbeat: {}", e);
+                }
+            }
+        })
+    }
+}
@@ -10,22 +10,27 @@ use crate::QDClient;
 impl QDClient {
     /// Logs in the client by sending a login message to the gateway.
     ///
+    /// # Arguments
+    ///
+    /// * `auth_token` - The auth token the gateway authenticates this client with.
+    ///
     /// # Returns
     ///
     /// Returns a `Result` with `()` on success, or an `Error` on failure.
     ///
-    /// This constructs a `ClientLoginMessage` with the client ID.
+    /// This constructs a `ClientLoginMessage` with the client ID and the auth token.
     /// It encodes the message to bytes and sends it to the gateway
     /// using the `send_message` method.
     ///
-    pub(crate) async fn login(&self) -> Result<(), Box<dyn Error + Send>> {
+    pub(crate) async fn login(&self, auth_token: &str) -> Result<(), Box<dyn Error + Send>> {
         // Construct login message
-        let message = ClientLoginMessage::new(self.client_id);
+        let message = ClientLoginMessage::new(self.client_id).with_auth_token(auth_token);
 
         // Encode message
-        let (_, buffer) = message
-            .encode()
-            .expect("[QDClient/login]: Failed to encode message");
+        let (_, buffer) = match message.encode() {
+            Ok(enc) => enc,
+            Err(e) => return Err(Box::new(e)),
+        };
 
         // Build iggy message wrapper
         let message = Message::new(None, Bytes::from(buffer), None);
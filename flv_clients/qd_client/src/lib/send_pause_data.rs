@@ -0,0 +1,91 @@
+use std::error::Error;
+
+use bytes::Bytes;
+use iggy::messages::send_messages::Message;
+
+use common::prelude::ExchangeID;
+use sbe_messages::prelude::{ContinueDataMessage, DataType, PauseDataMessage};
+
+use crate::QDClient;
+
+impl QDClient {
+    /// Sends a PauseDataMessage to pause a running data stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The exchange ID for the symbol.
+    /// * `symbol_id` - The symbol ID of the data stream.
+    /// * `data_type` - The data type of the data stream.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` on failure.
+    ///
+    /// The gateway holds back the next bar until the stream
+    /// gets continued with `continue_data` or stopped with `stop_data`.
+    ///
+    pub async fn pause_data(
+        &self,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        data_type: DataType,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Create message
+        let message = PauseDataMessage::new(self.client_id, exchange_id, symbol_id, data_type);
+
+        // Encode message
+        let (_, buffer) = message
+            .encode()
+            .expect("[QDClient/pause_data]: Failed to encode message");
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message)
+            .await
+            .expect("[QDClient/pause_data]: Failed to send PauseDataMessage message!");
+
+        Ok(())
+    }
+
+    /// Sends a ContinueDataMessage to resume a paused data stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The exchange ID for the symbol.
+    /// * `symbol_id` - The symbol ID of the data stream.
+    /// * `data_type` - The data type of the data stream.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` on failure.
+    ///
+    /// The gateway resumes the data stream with the bar after the last bar sent.
+    /// Paced streams continue at the requested replay speed from that bar on.
+    ///
+    pub async fn continue_data(
+        &self,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        data_type: DataType,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Create message
+        let message = ContinueDataMessage::new(self.client_id, exchange_id, symbol_id, data_type);
+
+        // Encode message
+        let (_, buffer) = message
+            .encode()
+            .expect("[QDClient/continue_data]: Failed to encode message");
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message)
+            .await
+            .expect("[QDClient/continue_data]: Failed to send ContinueDataMessage message!");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use std::error::Error;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use iggy::messages::send_messages::Message;
+
+use common::prelude::MessageProcessingError;
+use sbe_messages::prelude::{DataType, ResumeDataMessage};
+
+use crate::QDClient;
+
+impl QDClient {
+    /// Sends a ResumeDataMessage to restart a data stream from a sequence number or timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - The symbol ID of the stream to resume.
+    /// * `data_type` - The data type of the stream to resume.
+    /// * `resume_sequence_number` - Optional sequence number of the first bar to send again.
+    /// * `resume_time` - Optional timestamp of the first bar to send again. Ignored if a sequence number is given.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` if no data stream
+    /// has been started for the symbol and data type.
+    ///
+    /// This repeats the start request of the stream with the given resume point.
+    /// The gateway replaces a running stream with the resumed stream, which keeps
+    /// the sequence numbers of the original stream. Without a resume point,
+    /// the stream resumes from the first bar.
+    ///
+    pub async fn resume_data(
+        &self,
+        symbol_id: u16,
+        data_type: DataType,
+        resume_sequence_number: Option<u64>,
+        resume_time: Option<DateTime<Utc>>,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Look up the start request of the stream
+        let start_data_msg = match self.data_request(symbol_id, data_type) {
+            Some(start_data_msg) => start_data_msg,
+            None => {
+                return Err(Box::new(MessageProcessingError(format!(
+                    "[QDClient/resume_data]: No data stream started for symbol {} and data type {}",
+                    symbol_id, data_type
+                ))))
+            }
+        };
+
+        // Create message
+        let message = ResumeDataMessage::new(
+            self.client_id,
+            *start_data_msg.exchange_id(),
+            symbol_id,
+            *start_data_msg.time_resolution(),
+            data_type,
+            start_data_msg.start_time(),
+            start_data_msg.end_time(),
+            *start_data_msg.replay_mode(),
+            *start_data_msg.replay_speed(),
+            resume_sequence_number,
+            resume_time,
+        )
+        .with_threshold(start_data_msg.threshold());
+
+        // Encode message
+        let (_, buffer) = message
+            .encode()
+            .expect("[QDClient/resume_data]: Failed to encode message");
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message)
+            .await
+            .expect("[QDClient/resume_data]: Failed to send ResumeDataMessage message!");
+
+        Ok(())
+    }
+}
@@ -1,10 +1,11 @@
 use std::error::Error;
 
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use iggy::messages::send_messages::Message;
 
 use common::prelude::{ExchangeID, TimeResolution};
-use sbe_messages::prelude::{DataType, StartDataMessage};
+use sbe_messages::prelude::{DataType, ReplayMode, StartDataMessage};
 
 use crate::QDClient;
 
@@ -15,6 +16,10 @@ impl QDClient {
     ///
     /// * `exchange_id` - The exchange ID for the symbol.
     /// * `symbol_id` - The symbol ID to request data for.
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first record.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last record.
+    /// * `replay_mode` - The pacing of the data stream i.e. unthrottled, real-time, or scaled.
+    /// * `replay_speed` - The speed multiplier for `ReplayMode::Scaled` i.e. 10 or 100. Ignored otherwise.
     ///
     /// # Returns
     ///
@@ -22,11 +27,16 @@ impl QDClient {
     ///
     /// This creates a `StartDataMessage` requesting `TradeData` for the
     /// given symbol. It encodes the message and sends it to the gateway.
+    /// The request is kept to resume the stream after a gap, see `handle_data_message`.
     ///
     pub async fn start_trade_data(
         &self,
         exchange_id: ExchangeID,
         symbol_id: u16,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
     ) -> Result<(), Box<dyn Error + Send>> {
         // Create message
         let data_type = DataType::TradeData;
@@ -37,8 +47,15 @@ impl QDClient {
             symbol_id,
             time_resolution,
             data_type,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
         );
 
+        // Record the request to resume the stream after a gap
+        self.record_data_request(&message);
+
         // Encode message
         let (_, buffer) = message
             .encode()
@@ -62,6 +79,10 @@ impl QDClient {
     /// * `exchange_id` - The exchange ID for the symbol.
     /// * `symbol_id` - The symbol ID to request data for.
     /// * `time_resolution` - The time resolution for the OHLCV data.
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first record.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last record.
+    /// * `replay_mode` - The pacing of the data stream i.e. unthrottled, real-time, or scaled.
+    /// * `replay_speed` - The speed multiplier for `ReplayMode::Scaled` i.e. 10 or 100. Ignored otherwise.
     ///
     /// # Returns
     ///
@@ -69,13 +90,19 @@ impl QDClient {
     ///
     /// This creates a `StartDataMessage` requesting `OHLCVData` for the given
     /// symbol and time resolution. It encodes the message and sends it to
-    /// the gateway.
+    /// the gateway. The request is kept to resume the stream after a gap,
+    /// see `handle_data_message`.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub async fn start_ohlcv_data(
         &self,
         exchange_id: ExchangeID,
         symbol_id: u16,
         time_resolution: TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
     ) -> Result<(), Box<dyn Error + Send>> {
         // Create message
         let data_type = DataType::OHLCVData;
@@ -85,8 +112,15 @@ impl QDClient {
             symbol_id,
             time_resolution,
             data_type,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
         );
 
+        // Record the request to resume the stream after a gap
+        self.record_data_request(&message);
+
         // Encode message
         let (_, buffer) = message
             .encode()
@@ -102,4 +136,72 @@ impl QDClient {
 
         Ok(())
     }
+
+    /// Sends a StartDataMessage to request tick, volume, or dollar bars.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The exchange ID for the symbol.
+    /// * `symbol_id` - The symbol ID to request data for.
+    /// * `data_type` - The bar type i.e. TickBarData, VolumeBarData, or DollarBarData.
+    /// * `threshold` - The number of trades, traded volume, or traded dollar value that closes a bar.
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first record.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last record.
+    /// * `replay_mode` - The pacing of the data stream i.e. unthrottled, real-time, or scaled.
+    /// * `replay_speed` - The speed multiplier for `ReplayMode::Scaled` i.e. 10 or 100. Ignored otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` on failure.
+    ///
+    /// This creates a `StartDataMessage` requesting the bar type with the given threshold
+    /// for the given symbol. It encodes the message and sends it to the gateway.
+    /// The gateway samples the bars from the trades of the symbol and sends them as OHLCV bars.
+    /// The request is kept to resume the stream after a gap, see `handle_data_message`.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub async fn start_bar_data(
+        &self,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        data_type: DataType,
+        threshold: f64,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Create message
+        let time_resolution = TimeResolution::NoValue; // Time resolution will be ignored for information bars hence NoValue.
+        let message = StartDataMessage::new(
+            self.client_id,
+            exchange_id,
+            symbol_id,
+            time_resolution,
+            data_type,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
+        )
+        .with_threshold(threshold);
+
+        // Record the request to resume the stream after a gap
+        self.record_data_request(&message);
+
+        // Encode message
+        let (_, buffer) = message
+            .encode()
+            .expect("[QDClient/start_bar_data]: Failed to encode message");
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message)
+            .await
+            .expect("[QDClient/start_bar_data]: Failed to send StartDataMessage message!");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,134 @@
+use std::error::Error;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use iggy::messages::send_messages::Message;
+
+use common::prelude::{ExchangeID, TimeResolution};
+use sbe_messages::prelude::{DataType, ReplayMode, StartMultiDataMessage};
+
+use crate::QDClient;
+
+impl QDClient {
+    /// Sends a StartMultiDataMessage to request trade data of several symbols as one stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The exchange ID for the symbols.
+    /// * `symbol_ids` - The symbol IDs to request data for.
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first record.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last record.
+    /// * `replay_mode` - The pacing of the data stream i.e. unthrottled, real-time, or scaled.
+    /// * `replay_speed` - The speed multiplier for `ReplayMode::Scaled` i.e. 10 or 100. Ignored otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` if the message cannot be encoded.
+    ///
+    /// This creates a `StartMultiDataMessage` requesting `TradeData` for the
+    /// given symbols. The gateway merges the trade bars of all symbols by timestamp
+    /// into one stream, and each trade bar carries the symbol ID of its symbol.
+    ///
+    pub async fn start_multi_trade_data(
+        &self,
+        exchange_id: ExchangeID,
+        symbol_ids: Vec<u16>,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Create message
+        let data_type = DataType::TradeData;
+        let time_resolution = TimeResolution::NoValue; // Time resolution will be ignored for TradeData hence NoValue.
+        let message = StartMultiDataMessage::new(
+            self.client_id,
+            exchange_id,
+            symbol_ids,
+            time_resolution,
+            data_type,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
+        );
+
+        // Encode message; fails if there are more symbols than the message can hold
+        let (_, buffer) = match message.encode() {
+            Ok(encoded) => encoded,
+            Err(e) => return Err(Box::new(e)),
+        };
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message).await.expect(
+            "[QDClient/start_multi_trade_data]: Failed to send StartMultiDataMessage message!",
+        );
+
+        Ok(())
+    }
+
+    /// Sends a StartMultiDataMessage to request OHLCV data of several symbols as one stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The exchange ID for the symbols.
+    /// * `symbol_ids` - The symbol IDs to request data for.
+    /// * `time_resolution` - The time resolution for the OHLCV data.
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first record.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last record.
+    /// * `replay_mode` - The pacing of the data stream i.e. unthrottled, real-time, or scaled.
+    /// * `replay_speed` - The speed multiplier for `ReplayMode::Scaled` i.e. 10 or 100. Ignored otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` if the message cannot be encoded.
+    ///
+    /// This creates a `StartMultiDataMessage` requesting `OHLCVData` for the given
+    /// symbols and time resolution. The gateway merges the OHLCV bars of all symbols
+    /// by timestamp into one stream, and each OHLCV bar carries the symbol ID of its symbol.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub async fn start_multi_ohlcv_data(
+        &self,
+        exchange_id: ExchangeID,
+        symbol_ids: Vec<u16>,
+        time_resolution: TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Create message
+        let data_type = DataType::OHLCVData;
+        let message = StartMultiDataMessage::new(
+            self.client_id,
+            exchange_id,
+            symbol_ids,
+            time_resolution,
+            data_type,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
+        );
+
+        // Encode message; fails if there are more symbols than the message can hold
+        let (_, buffer) = match message.encode() {
+            Ok(encoded) => encoded,
+            Err(e) => return Err(Box::new(e)),
+        };
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message).await.expect(
+            "[QDClient/start_multi_ohlcv_data]: Failed to send StartMultiDataMessage message!",
+        );
+
+        Ok(())
+    }
+}
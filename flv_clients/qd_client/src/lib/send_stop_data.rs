@@ -0,0 +1,87 @@
+use std::error::Error;
+
+use bytes::Bytes;
+use iggy::messages::send_messages::Message;
+
+use common::prelude::ExchangeID;
+use sbe_messages::prelude::{DataType, StopAllDataMessage, StopDataMessage};
+
+use crate::QDClient;
+
+impl QDClient {
+    /// Sends a StopDataMessage to cancel a running data stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The exchange ID for the symbol.
+    /// * `symbol_id` - The symbol ID of the data stream.
+    /// * `data_type` - The data type of the data stream.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` on failure.
+    ///
+    /// The gateway stops the data stream at the next bar and
+    /// sends the last bar message to indicate the end of the stream.
+    ///
+    pub async fn stop_data(
+        &self,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        data_type: DataType,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Create message
+        let message = StopDataMessage::new(self.client_id, exchange_id, symbol_id, data_type);
+
+        // Encode message
+        let (_, buffer) = message
+            .encode()
+            .expect("[QDClient/stop_data]: Failed to encode message");
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message)
+            .await
+            .expect("[QDClient/stop_data]: Failed to send StopDataMessage message!");
+
+        Ok(())
+    }
+
+    /// Sends a StopAllDataMessage to cancel all running data streams of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The exchange ID of the client.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` on success, or an `Error` on failure.
+    ///
+    /// The gateway stops each data stream at the next bar and
+    /// sends the last bar message to indicate the end of each stream.
+    ///
+    pub async fn stop_all_data(
+        &self,
+        exchange_id: ExchangeID,
+    ) -> Result<(), Box<dyn Error + Send>> {
+        // Create message
+        let message = StopAllDataMessage::new(self.client_id, exchange_id);
+
+        // Encode message
+        let (_, buffer) = message
+            .encode()
+            .expect("[QDClient/stop_all_data]: Failed to encode message");
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        // Send message to the gateway
+        self.send_message(message)
+            .await
+            .expect("[QDClient/stop_all_data]: Failed to send StopAllDataMessage message!");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use sbe_messages::prelude::DataType;
+
+/// The outcome of checking the sequence number of a received bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SequenceCheck {
+    /// The bar is the next bar of the stream.
+    Next,
+    /// The bar has already been received.
+    Duplicate,
+    /// Bars are missing before this bar; the stream must resume from the expected sequence number.
+    Gap(u64),
+    /// Bars are missing and the stream has already been asked to resume.
+    Resuming,
+}
+
+/// The sequence state of one data stream.
+#[derive(Debug, Clone, Copy)]
+struct SequenceState {
+    expected: u64,
+    resuming: bool,
+}
+
+impl Default for SequenceState {
+    fn default() -> Self {
+        // Sequence numbers of a stream start at 1.
+        Self {
+            expected: 1,
+            resuming: false,
+        }
+    }
+}
+
+/// Tracks the sequence numbers of the bars of each data stream to detect gaps.
+///
+/// Streams are keyed by symbol ID and data type.
+#[derive(Debug, Default)]
+pub(crate) struct SequenceTracker {
+    streams: HashMap<(u16, DataType), SequenceState>,
+}
+
+impl SequenceTracker {
+    /// Starts tracking a data stream, i.e. when its first bar message arrives.
+    pub(crate) fn start(&mut self, symbol_id: u16, data_type: DataType) {
+        self.streams
+            .insert((symbol_id, data_type), SequenceState::default());
+    }
+
+    /// Stops tracking a data stream, i.e. when its last bar message arrives.
+    pub(crate) fn end(&mut self, symbol_id: u16, data_type: DataType) {
+        self.streams.remove(&(symbol_id, data_type));
+    }
+
+    /// Continues tracking a data stream after a gap that cannot be resumed.
+    ///
+    /// The bar with the given sequence number counts as received,
+    /// and the stream expects the bar after it next.
+    pub(crate) fn skip_to(&mut self, symbol_id: u16, data_type: DataType, sequence_number: u64) {
+        self.streams.insert(
+            (symbol_id, data_type),
+            SequenceState {
+                expected: sequence_number + 1,
+                resuming: false,
+            },
+        );
+    }
+
+    /// Checks the sequence number of a received bar against the expected sequence number.
+    ///
+    /// A gap gets reported once; until the missing bar arrives, all following bars
+    /// are reported as `Resuming`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - The symbol ID of the bar.
+    /// * `data_type` - The data type of the stream of the bar.
+    /// * `sequence_number` - The sequence number of the bar.
+    ///
+    pub(crate) fn check(
+        &mut self,
+        symbol_id: u16,
+        data_type: DataType,
+        sequence_number: u64,
+    ) -> SequenceCheck {
+        let state = self.streams.entry((symbol_id, data_type)).or_default();
+
+        if sequence_number < state.expected {
+            return SequenceCheck::Duplicate;
+        }
+
+        if sequence_number == state.expected {
+            state.expected += 1;
+            state.resuming = false;
+            return SequenceCheck::Next;
+        }
+
+        if state.resuming {
+            return SequenceCheck::Resuming;
+        }
+
+        state.resuming = true;
+        SequenceCheck::Gap(state.expected)
+    }
+}
@@ -3,6 +3,9 @@ use qd_client::QDClient;
 
 const CLIENT_ID: u16 = 77;
 
+// Must match the auth token of the client in qdgw_credentials.toml
+const AUTH_TOKEN: &str = "qd-client-test-token";
+
 //
 // Requires that the QDGW server is running on localhost
 // Start the server with:
@@ -16,7 +19,7 @@ async fn test_new() {
     let client_config = IggyConfig::from_client_id(user, CLIENT_ID as u32, 50000, false);
 
     // Happy path
-    let client = QDClient::new(CLIENT_ID, client_config).await;
+    let client = QDClient::new(CLIENT_ID, AUTH_TOKEN, client_config).await;
     assert!(client.is_ok());
 
     let client = client.unwrap();
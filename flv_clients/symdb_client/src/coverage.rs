@@ -0,0 +1,82 @@
+use crate::error::SymdbClientError;
+use crate::{utils_proto, SymdbClient};
+use common::prelude::ExchangeID;
+use proto::binding::SymbolCoverage;
+use std::time::Duration;
+
+const FN_NAME: &str = "[SymdbClient]: ";
+
+impl SymdbClient {
+    /// Gets the time coverage of the trades of the given symbols.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The ID of the exchange of the symbols
+    /// * `symbol_ids` - The IDs of the symbols. An empty slice requests all symbols of the exchange.
+    /// * `min_gap` - Only gaps between consecutive trades longer than this threshold are returned
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result with the coverage of each symbol, otherwise a SymdbClientError.
+    /// Timestamps are in milliseconds since the Unix epoch.
+    ///
+    /// # Example
+    ///
+    /// let coverages = get_symbol_coverage(ExchangeID::Kraken, &[1, 2], Duration::from_secs(3600)).await?;
+    ///
+    pub async fn get_symbol_coverage(
+        &mut self,
+        exchange_id: ExchangeID,
+        symbol_ids: &[u16],
+        min_gap: Duration,
+    ) -> Result<Vec<SymbolCoverage>, SymdbClientError> {
+        let request = utils_proto::get_symbol_coverage_request(exchange_id, symbol_ids, min_gap);
+
+        let res = self.client.get_symbol_coverage(request).await;
+
+        match res {
+            Ok(res) => Ok(res.into_inner().coverages),
+            Err(err) => Err(get_error(
+                format!("Error getting coverage for symbols {:?}", symbol_ids).as_str(),
+                &err.to_string(),
+            )),
+        }
+    }
+
+    /// Refreshes the time coverage of the given symbols with the trades imported
+    /// after their last trade. Call this after an import of new trades.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_id` - The ID of the exchange of the symbols
+    /// * `symbol_ids` - The IDs of the symbols. An empty slice refreshes all symbols of the exchange.
+    /// * `min_gap` - Only gaps between consecutive trades longer than this threshold are returned
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result with the refreshed coverage of each symbol, otherwise a SymdbClientError.
+    ///
+    pub async fn refresh_symbol_coverage(
+        &mut self,
+        exchange_id: ExchangeID,
+        symbol_ids: &[u16],
+        min_gap: Duration,
+    ) -> Result<Vec<SymbolCoverage>, SymdbClientError> {
+        let request = utils_proto::get_symbol_coverage_request(exchange_id, symbol_ids, min_gap);
+
+        let res = self.client.refresh_symbol_coverage(request).await;
+
+        match res {
+            Ok(res) => Ok(res.into_inner().coverages),
+            Err(err) => Err(get_error(
+                format!("Error refreshing coverage for symbols {:?}", symbol_ids).as_str(),
+                &err.to_string(),
+            )),
+        }
+    }
+}
+
+/// Creates a SymdbClientError with a formatted error message.
+fn get_error(msg: &str, err: &str) -> SymdbClientError {
+    SymdbClientError(format!("{} {} because of Error {}", FN_NAME, msg, err))
+}
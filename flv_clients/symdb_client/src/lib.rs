@@ -1,3 +1,4 @@
+mod coverage;
 mod error;
 mod lookup;
 mod utils_proto;
@@ -10,7 +11,8 @@ use tonic::transport::{Channel, Uri};
 /// Client for interacting with the SymdbService.
 ///
 /// Wraps a SymdbServiceClient and provides methods to
-/// lookup symbols, symbol IDs, and exchange names.
+/// lookup symbols, symbol IDs, and exchange names,
+/// and to get the time coverage of the trades of symbols.
 ///
 #[derive(Debug, Clone)]
 pub struct SymdbClient {
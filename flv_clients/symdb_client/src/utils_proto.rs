@@ -1,5 +1,8 @@
 use common::prelude::ExchangeID;
-use proto::binding::{LookupExchangeNameRequest, LookupSymbolIdRequest, LookupSymbolRequest};
+use proto::binding::{
+    LookupExchangeNameRequest, LookupSymbolIdRequest, LookupSymbolRequest, SymbolCoverageRequest,
+};
+use std::time::Duration;
 
 /// Creates a tonic::Request for the GetExchange RPC method.
 ///
@@ -59,3 +62,32 @@ pub(crate) fn get_symbol_id_request(
         symbol,
     }
 }
+
+/// Creates a tonic::Request for the GetSymbolCoverage and RefreshSymbolCoverage RPC methods.
+///
+/// # Arguments
+///
+/// * `exchange_id` - The ID of the exchange of the symbols
+/// * `symbol_ids` - The IDs of the symbols
+/// * `min_gap` - The gap threshold, truncated to seconds
+///
+/// # Returns
+///
+/// Returns a tonic::Request with the SymbolCoverage RPC request populated
+/// with the provided exchange ID, symbol IDs, and gap threshold.
+///
+pub(crate) fn get_symbol_coverage_request(
+    exchange_id: ExchangeID,
+    symbol_ids: &[u16],
+    min_gap: Duration,
+) -> SymbolCoverageRequest {
+    let exchange_id = exchange_id as i32;
+    let symbol_ids = symbol_ids.iter().map(|id| *id as i32).collect();
+    let min_gap_secs = min_gap.as_secs() as i64;
+
+    SymbolCoverageRequest {
+        exchange_id,
+        symbol_ids,
+        min_gap_secs,
+    }
+}
@@ -67,3 +67,39 @@ async fn test_lookup_symbol_id_invalid() {
     let result = client.lookup_symbol_id(ExchangeID::Kraken, symbol).await;
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_get_symbol_coverage() {
+    let config = get_config();
+    let mut client = SymdbClient::new(config).await.unwrap();
+
+    let min_gap = std::time::Duration::from_secs(3600);
+    let result = client
+        .get_symbol_coverage(ExchangeID::Kraken, &[42], min_gap)
+        .await;
+    assert!(result.is_ok());
+
+    let coverages = result.unwrap();
+    assert_eq!(coverages.len(), 1);
+    assert_eq!(coverages[0].symbol_id, 42);
+    assert!(coverages[0].first_trade <= coverages[0].last_trade);
+}
+
+#[tokio::test]
+async fn test_refresh_symbol_coverage() {
+    let config = get_config();
+    let mut client = SymdbClient::new(config).await.unwrap();
+
+    let min_gap = std::time::Duration::from_secs(3600);
+    let coverages = client
+        .get_symbol_coverage(ExchangeID::Kraken, &[42], min_gap)
+        .await
+        .unwrap();
+
+    let refreshed = client
+        .refresh_symbol_coverage(ExchangeID::Kraken, &[42], min_gap)
+        .await
+        .unwrap();
+    assert_eq!(refreshed.len(), 1);
+    assert!(refreshed[0].row_count >= coverages[0].row_count);
+}
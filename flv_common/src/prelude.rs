@@ -7,6 +7,7 @@ pub use crate::types::error_types::ValidationError;
 // Config types
 pub use crate::types::config_types::click_house_config::ClickHouseConfig;
 pub use crate::types::config_types::client_channel::ClientChannel;
+pub use crate::types::config_types::db_backend::DBBackend;
 pub use crate::types::config_types::db_config::DBConfig;
 pub use crate::types::config_types::environment_types::EnvironmentType;
 pub use crate::types::config_types::host_endpoint::HostEndpoint;
@@ -17,6 +18,8 @@ pub use crate::types::config_types::metric_config::MetricConfig;
 pub use crate::types::config_types::service_config::ServiceConfig;
 pub use crate::types::config_types::service_id::ServiceID;
 // Data Types
+pub use crate::types::data_types::bar_threshold::BarThreshold;
+pub use crate::types::data_types::information_bar_builder::InformationBarBuilder;
 pub use crate::types::data_types::ohlcv_bar::OHLCVBar;
 pub use crate::types::data_types::sampled_bars::SampledDataBars;
 pub use crate::types::data_types::time_resolution::TimeResolution;
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// DBBackend enum definition.
+///
+/// Selects the database that serves the trade and OHLCV queries.
+///
+/// # Variants
+///
+/// `ClickHouse` - ClickHouse via its native protocol, ID = 0x0
+/// `QuestDB` - QuestDB via the PostgreSQL wire protocol, ID = 0x1
+///
+#[derive(Serialize, Deserialize, Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[repr(u8)]
+pub enum DBBackend {
+    #[default]
+    ClickHouse = 0x0_u8,
+    QuestDB = 0x1_u8,
+}
+
+impl DBBackend {
+    /// Converts a string to a DBBackend, ignoring case.
+    ///
+    /// Matches on the string value:
+    ///
+    /// "clickhouse" -> ClickHouse
+    /// "questdb" -> QuestDB
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - String to convert
+    ///
+    /// # Returns
+    ///
+    /// `Result<DBBackend, &'static str>` - Ok(variant) on match, Err on invalid string
+    ///
+    #[inline]
+    pub fn from_string(s: &str) -> Result<Self, &'static str> {
+        match s.to_lowercase().as_str() {
+            "clickhouse" => Ok(Self::ClickHouse),
+            "questdb" => Ok(Self::QuestDB),
+            _ => Err("Invalid DB backend string"),
+        }
+    }
+}
+
+impl fmt::Display for DBBackend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DBBackend::ClickHouse => write!(f, "ClickHouse"),
+            DBBackend::QuestDB => write!(f, "QuestDB"),
+        }
+    }
+}
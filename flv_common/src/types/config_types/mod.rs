@@ -5,6 +5,7 @@ pub mod click_house_config;
 /// application. It contains the following modules:
 ///
 /// - `client_channel`: Configuration for client channels
+/// - `db_backend`: Selection of the database backend
 /// - `db_config`: Database configuration
 /// - `environment_types`: Configuration for environments like development or production
 /// - `host_endpoint`: Configuration for host endpoints
@@ -19,6 +20,7 @@ pub mod click_house_config;
 /// data structures.
 ///
 pub mod client_channel;
+pub mod db_backend;
 pub mod db_config;
 pub mod environment_types;
 pub mod host_endpoint;
@@ -29,6 +29,13 @@ pub struct ServiceConfig {
     dependencies: Option<Vec<ServiceID>>,
     /// Service metrics configuration for Prometheus.
     metrics: MetricConfig,
+    /// Number of worker tasks for background jobs i.e. data streams.
+    #[serde(default = "default_worker_count")]
+    worker_count: u16,
+}
+
+fn default_worker_count() -> u16 {
+    1
 }
 
 impl ServiceConfig {
@@ -51,7 +58,7 @@ impl ServiceConfig {
     ///
     /// # Returns
     ///
-    /// ServiceConfig instance
+    /// ServiceConfig instance with a single worker task
     ///
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -81,8 +88,15 @@ impl ServiceConfig {
             health_check_uri,
             dependencies,
             metrics,
+            worker_count: default_worker_count(),
         }
     }
+
+    /// Sets the number of worker tasks for background jobs i.e. data streams.
+    pub fn with_worker_count(mut self, worker_count: u16) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
 }
 
 impl ServiceConfig {
@@ -122,11 +136,14 @@ impl ServiceConfig {
     pub fn metrics(&self) -> &MetricConfig {
         &self.metrics
     }
+    pub fn worker_count(&self) -> u16 {
+        self.worker_count
+    }
 }
 
 impl Display for ServiceConfig {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "ServiceConfig[id: {}, name: {}, version: {}, online: {}, description: {}, local_host: {}, local_port: {:?}, cluster_host: {}, cluster_port: {:?}, health_check_uri: {}, dependencies: {:?}, metrics: {}]",
+        write!(f, "ServiceConfig[id: {}, name: {}, version: {}, online: {}, description: {}, local_host: {}, local_port: {:?}, cluster_host: {}, cluster_port: {:?}, health_check_uri: {}, dependencies: {:?}, metrics: {}, worker_count: {}]",
                self.svc_id(),
                self.name(),
                self.version(),
@@ -138,6 +155,7 @@ impl Display for ServiceConfig {
                self.cluster_port(),
                self.health_check_uri(),
                self.dependencies(),
-               self.metrics())
+               self.metrics(),
+               self.worker_count())
     }
 }
@@ -0,0 +1,57 @@
+use crate::prelude::ValidationError;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The BarThreshold enum represents the threshold that closes an information-driven bar.
+///
+/// It has the following variants:
+///
+/// - `Ticks`: The bar closes after the given number of trades.
+/// - `Volume`: The bar closes once the traded volume reaches the given amount.
+/// - `Dollar`: The bar closes once the traded notional value, price times volume,
+///   reaches the given amount.
+///
+/// Unlike time bars, information-driven bars sample the trade stream at a
+/// constant amount of market activity instead of a constant time interval.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BarThreshold {
+    Ticks(u64),
+    Volume(Decimal),
+    Dollar(Decimal),
+}
+
+impl BarThreshold {
+    /// Validates that the threshold can close a bar.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the threshold is valid, or a `ValidationError` if it is not positive.
+    ///
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let valid = match self {
+            BarThreshold::Ticks(ticks) => *ticks > 0,
+            BarThreshold::Volume(volume) => volume.is_sign_positive() && !volume.is_zero(),
+            BarThreshold::Dollar(dollar) => dollar.is_sign_positive() && !dollar.is_zero(),
+        };
+
+        if !valid {
+            return Err(ValidationError::new(format!(
+                "Bar threshold must be positive: {}",
+                self
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for BarThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BarThreshold::Ticks(ticks) => write!(f, "{} ticks", ticks),
+            BarThreshold::Volume(volume) => write!(f, "{} volume", volume),
+            BarThreshold::Dollar(dollar) => write!(f, "{} dollar", dollar),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::prelude::{BarThreshold, OHLCVBar};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+/// Builds information-driven OHLCV bars, i.e. tick, volume, or dollar bars, from a trade stream.
+///
+/// Each trade gets added to the open bar, which closes once the tick count, volume,
+/// or notional value of its trades reaches the threshold. A trade is never split
+/// across bars, so a bar may exceed the volume or dollar threshold by its last trade.
+/// The date time of a bar is the date time of its first trade.
+/// Each bar carries the volume weighted average price and the number of its trades.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Utc;
+/// use common::prelude::{BarThreshold, InformationBarBuilder};
+/// use rust_decimal::Decimal;
+///
+/// let mut builder = InformationBarBuilder::new(1, BarThreshold::Ticks(2));
+///
+/// assert!(builder.update(Utc::now(), Decimal::from(100), Decimal::from(1)).is_none());
+/// let bar = builder.update(Utc::now(), Decimal::from(101), Decimal::from(2)).unwrap();
+///
+/// assert_eq!(bar.open(), Decimal::from(100));
+/// assert_eq!(bar.close(), Decimal::from(101));
+/// assert_eq!(bar.volume(), Decimal::from(3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InformationBarBuilder {
+    symbol_id: u16,
+    threshold: BarThreshold,
+    bar: Option<OpenBar>,
+}
+
+/// The trades of the bar that has not reached the threshold yet.
+#[derive(Debug, Clone)]
+struct OpenBar {
+    date_time: DateTime<Utc>,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+    close: Decimal,
+    volume: Decimal,
+    dollar: Decimal,
+    ticks: u64,
+}
+
+impl InformationBarBuilder {
+    /// Creates a new InformationBarBuilder.
+    ///
+    /// # Parameters
+    ///
+    /// - `symbol_id` - The symbol ID of the bars
+    /// - `threshold` - The validated threshold that closes a bar
+    ///
+    pub fn new(symbol_id: u16, threshold: BarThreshold) -> Self {
+        Self {
+            symbol_id,
+            threshold,
+            bar: None,
+        }
+    }
+
+    /// Adds a trade to the open bar.
+    ///
+    /// # Parameters
+    ///
+    /// - `date_time` - The date/time of the trade
+    /// - `price` - The price of the trade
+    /// - `volume` - The volume of the trade
+    ///
+    /// # Returns
+    ///
+    /// The closed bar if the trade made the open bar reach the threshold, otherwise `None`.
+    ///
+    pub fn update(
+        &mut self,
+        date_time: DateTime<Utc>,
+        price: Decimal,
+        volume: Decimal,
+    ) -> Option<OHLCVBar> {
+        let bar = self.bar.get_or_insert(OpenBar {
+            date_time,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: Decimal::ZERO,
+            dollar: Decimal::ZERO,
+            ticks: 0,
+        });
+
+        bar.high = bar.high.max(price);
+        bar.low = bar.low.min(price);
+        bar.close = price;
+        bar.volume += volume;
+        bar.dollar += price * volume;
+        bar.ticks += 1;
+
+        let closed = match self.threshold {
+            BarThreshold::Ticks(ticks) => bar.ticks >= ticks,
+            BarThreshold::Volume(volume) => bar.volume >= volume,
+            BarThreshold::Dollar(dollar) => bar.dollar >= dollar,
+        };
+
+        if closed {
+            self.flush()
+        } else {
+            None
+        }
+    }
+
+    /// Closes the open bar regardless of the threshold, i.e. at the end of the trade stream.
+    ///
+    /// # Returns
+    ///
+    /// The closed bar, or `None` if no trade has been added since the last bar closed.
+    ///
+    pub fn flush(&mut self) -> Option<OHLCVBar> {
+        self.bar.take().map(|bar| {
+            // A bar of zero volume trades has no volume weighted price; fall back to the close
+            let vwap = if bar.volume.is_zero() {
+                bar.close
+            } else {
+                bar.dollar / bar.volume
+            };
+
+            OHLCVBar::new(
+                self.symbol_id,
+                bar.date_time,
+                bar.open,
+                bar.high,
+                bar.low,
+                bar.close,
+                bar.volume,
+            )
+            .with_vwap(vwap)
+            .with_trade_count(bar.ticks)
+        })
+    }
+}
+
+impl InformationBarBuilder {
+    pub fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+    pub fn threshold(&self) -> BarThreshold {
+        self.threshold
+    }
+}
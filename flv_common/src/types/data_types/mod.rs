@@ -3,6 +3,8 @@
 /// This module defines core data types used throughout the application,
 /// organized into submodules:
 ///
+/// - `bar_threshold`: Threshold that closes a tick, volume, or dollar bar.
+/// - `information_bar_builder`: Builds tick, volume, or dollar bars from trades.
 /// - `ohlcv_bar`: OHLCV (open-high-low-close-volume) bar data.
 /// - `sampled_bars`: Grouped OHLCV bars for a time period.
 /// - `time_resolution`: Resolution enum for OHLCV bars.
@@ -16,6 +18,8 @@
 ///
 /// By centralizing the data type definitions, they can be reused
 /// consistently across the system.
+pub mod bar_threshold;
+pub mod information_bar_builder;
 pub mod ohlcv_bar;
 pub mod sampled_bars;
 pub mod time_resolution;
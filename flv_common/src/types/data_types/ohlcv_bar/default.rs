@@ -12,6 +12,11 @@ impl Default for OHLCVBar {
             low: Decimal::default(),
             close: Decimal::default(),
             volume: Decimal::default(),
+            sequence_number: 0,
+            vwap: None,
+            trade_count: None,
+            buy_volume: None,
+            sell_volume: None,
         }
     }
 }
@@ -41,4 +41,24 @@ impl OHLCVBar {
     pub fn symbol_id(&self) -> u16 {
         self.symbol_id
     }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    pub fn vwap(&self) -> Option<Decimal> {
+        self.vwap
+    }
+
+    pub fn trade_count(&self) -> Option<u64> {
+        self.trade_count
+    }
+
+    pub fn buy_volume(&self) -> Option<Decimal> {
+        self.buy_volume
+    }
+
+    pub fn sell_volume(&self) -> Option<Decimal> {
+        self.sell_volume
+    }
 }
@@ -16,6 +16,20 @@ pub struct OHLCVBar {
     low: Decimal,
     close: Decimal,
     volume: Decimal,
+    // Position of the bar in its data stream; 0 if the bar was not sent in a data stream.
+    #[serde(default)]
+    sequence_number: u64,
+    // Volume weighted average price of the trades in the bar; None if not computed.
+    #[serde(default)]
+    vwap: Option<Decimal>,
+    // Number of trades in the bar; None if not computed.
+    #[serde(default)]
+    trade_count: Option<u64>,
+    // Volume of the trades initiated by buyers and sellers; None if the trades carry no side.
+    #[serde(default)]
+    buy_volume: Option<Decimal>,
+    #[serde(default)]
+    sell_volume: Option<Decimal>,
 }
 
 impl OHLCVBar {
@@ -51,6 +65,59 @@ impl OHLCVBar {
             low,
             close,
             volume,
+            sequence_number: 0,
+            vwap: None,
+            trade_count: None,
+            buy_volume: None,
+            sell_volume: None,
         }
     }
+
+    /// Returns the OHLCVBar with the given sequence number.
+    ///
+    /// # Parameters
+    ///
+    /// - `sequence_number` - The position of the bar in its data stream, starting at 1
+    ///
+    pub fn with_sequence_number(mut self, sequence_number: u64) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    /// Returns the OHLCVBar with the given volume weighted average price.
+    ///
+    /// # Parameters
+    ///
+    /// - `vwap` - The volume weighted average price of the trades in the bar
+    ///
+    pub fn with_vwap(mut self, vwap: Decimal) -> Self {
+        self.vwap = Some(vwap);
+        self
+    }
+
+    /// Returns the OHLCVBar with the given number of trades.
+    ///
+    /// # Parameters
+    ///
+    /// - `trade_count` - The number of trades in the bar
+    ///
+    pub fn with_trade_count(mut self, trade_count: u64) -> Self {
+        self.trade_count = Some(trade_count);
+        self
+    }
+
+    /// Returns the OHLCVBar with the given buy and sell volume.
+    ///
+    /// Only applies to trades that carry the side of the aggressor.
+    ///
+    /// # Parameters
+    ///
+    /// - `buy_volume` - The volume of the trades initiated by buyers
+    /// - `sell_volume` - The volume of the trades initiated by sellers
+    ///
+    pub fn with_buy_sell_volume(mut self, buy_volume: Decimal, sell_volume: Decimal) -> Self {
+        self.buy_volume = Some(buy_volume);
+        self.sell_volume = Some(sell_volume);
+        self
+    }
 }
@@ -1,3 +1,4 @@
+use crate::prelude::{TimeScale, ValidationError};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -14,6 +15,12 @@ use std::fmt;
 /// - `OneDay`: 1 day time resolution.
 /// - `OneMonth`: 1 month time resolution.
 /// - `OneYear`: 1 year time resolution.
+/// - `Custom`: Any other time resolution as count of time scale units, i.e. 3 minutes,
+///   4 hours, or 100 milliseconds.
+///
+/// Every time resolution is a count of time scale units. Create custom time resolutions
+/// with `TimeResolution::new`, which validates the count and time scale and returns the
+/// named variant if there is one, so equal time resolutions compare equal.
 ///
 /// The enum is decorated with various attributes like `Serialize`, `Deserialize`, etc.
 /// to control how it is (de)serialized. It is also represented as a `u8` under the hood.
@@ -31,6 +38,141 @@ pub enum TimeResolution {
     OneWeek = 7_u8,
     OneMonth = 8_u8,
     OneYear = 9_u8,
+    Custom {
+        count: u32,
+        scale: TimeScale,
+    } = 10_u8,
+}
+
+impl TimeResolution {
+    /// Creates a validated time resolution of `count` time scale units.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of time scale units per bar
+    /// * `scale` - The time scale unit
+    ///
+    /// # Returns
+    ///
+    /// The named TimeResolution variant if there is one, i.e. `OneMin` for 1 minute,
+    /// otherwise `Custom`, or a `ValidationError` if the time resolution is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use common::prelude::{TimeResolution, TimeScale};
+    ///
+    /// let three_min = TimeResolution::new(3, TimeScale::Minute).unwrap();
+    /// assert_eq!(three_min.to_string(), "3 minute");
+    ///
+    /// let one_min = TimeResolution::new(1, TimeScale::Minute).unwrap();
+    /// assert_eq!(one_min, TimeResolution::OneMin);
+    ///
+    /// assert!(TimeResolution::new(0, TimeScale::Minute).is_err());
+    /// ```
+    pub fn new(count: u32, scale: TimeScale) -> Result<Self, ValidationError> {
+        let time_resolution = Self::from_parts(count, scale);
+
+        match time_resolution.validate() {
+            Ok(_) => Ok(time_resolution),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates a time resolution of `count` time scale units without validation.
+    ///
+    /// Used to decode a time resolution received over the wire, which
+    /// must be validated before it is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of time scale units per bar
+    /// * `scale` - The time scale unit
+    ///
+    /// # Returns
+    ///
+    /// The named TimeResolution variant if there is one, otherwise `Custom`.
+    ///
+    pub fn from_parts(count: u32, scale: TimeScale) -> Self {
+        match (count, scale) {
+            (0, TimeScale::NoScale) => TimeResolution::NoValue,
+            (1, TimeScale::Minute) => TimeResolution::OneMin,
+            (5, TimeScale::Minute) => TimeResolution::FiveMin,
+            (15, TimeScale::Minute) => TimeResolution::FifteenMin,
+            (30, TimeScale::Minute) => TimeResolution::ThirtyMin,
+            (1, TimeScale::Hour) => TimeResolution::OneHour,
+            (1, TimeScale::Day) => TimeResolution::OneDay,
+            (1, TimeScale::Week) => TimeResolution::OneWeek,
+            (1, TimeScale::Month) => TimeResolution::OneMonth,
+            (1, TimeScale::Year) => TimeResolution::OneYear,
+            (count, scale) => TimeResolution::Custom { count, scale },
+        }
+    }
+
+    /// Returns the number of time scale units per bar.
+    pub fn count(&self) -> u32 {
+        match self {
+            TimeResolution::NoValue => 0,
+            TimeResolution::OneMin => 1,
+            TimeResolution::FiveMin => 5,
+            TimeResolution::FifteenMin => 15,
+            TimeResolution::ThirtyMin => 30,
+            TimeResolution::OneHour => 1,
+            TimeResolution::OneDay => 1,
+            TimeResolution::OneWeek => 1,
+            TimeResolution::OneMonth => 1,
+            TimeResolution::OneYear => 1,
+            TimeResolution::Custom { count, .. } => *count,
+        }
+    }
+
+    /// Returns the time scale unit.
+    pub fn scale(&self) -> TimeScale {
+        match self {
+            TimeResolution::NoValue => TimeScale::NoScale,
+            TimeResolution::OneMin
+            | TimeResolution::FiveMin
+            | TimeResolution::FifteenMin
+            | TimeResolution::ThirtyMin => TimeScale::Minute,
+            TimeResolution::OneHour => TimeScale::Hour,
+            TimeResolution::OneDay => TimeScale::Day,
+            TimeResolution::OneWeek => TimeScale::Week,
+            TimeResolution::OneMonth => TimeScale::Month,
+            TimeResolution::OneYear => TimeScale::Year,
+            TimeResolution::Custom { scale, .. } => *scale,
+        }
+    }
+
+    /// Validates that the time resolution can be used to resample data.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the time resolution is valid, or a `ValidationError` if
+    /// it has no value, a count of zero, or no time scale.
+    ///
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if *self == TimeResolution::NoValue {
+            return Err(ValidationError::new(
+                "Time resolution has no value".to_string(),
+            ));
+        }
+
+        if self.count() == 0 {
+            return Err(ValidationError::new(format!(
+                "Time resolution count must be at least 1: {}",
+                self
+            )));
+        }
+
+        if self.scale() == TimeScale::NoScale {
+            return Err(ValidationError::new(format!(
+                "Time resolution has no time scale: {}",
+                self
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl From<u8> for TimeResolution {
@@ -43,6 +185,8 @@ impl From<u8> for TimeResolution {
     /// 2 -> FiveMin
     /// etc.
     ///
+    /// Custom time resolutions have no u8 value, use `TimeResolution::new` instead.
+    ///
     /// # Arguments
     ///
     /// * `v` - u8 value to convert
@@ -81,6 +225,19 @@ impl fmt::Display for TimeResolution {
             TimeResolution::OneWeek => write!(f, "1 week"),
             TimeResolution::OneMonth => write!(f, "1 month"),
             TimeResolution::OneYear => write!(f, "1 year"),
+            // The unit names match the interval units of ClickHouse.
+            TimeResolution::Custom { count, scale } => match scale {
+                TimeScale::NoScale => write!(f, "{} NoScale", count),
+                TimeScale::Millisecond => write!(f, "{} millisecond", count),
+                TimeScale::Second => write!(f, "{} second", count),
+                TimeScale::Minute => write!(f, "{} minute", count),
+                TimeScale::Hour => write!(f, "{} hour", count),
+                TimeScale::Day => write!(f, "{} day", count),
+                TimeScale::Week => write!(f, "{} week", count),
+                TimeScale::Month => write!(f, "{} month", count),
+                TimeScale::Quarter => write!(f, "{} quarter", count),
+                TimeScale::Year => write!(f, "{} year", count),
+            },
         }
     }
 }
@@ -10,6 +10,7 @@ impl Default for TradeBar {
             date_time: Utc::now(),
             price: Decimal::zero(),
             volume: Decimal::zero(),
+            sequence_number: 0,
         }
     }
 }
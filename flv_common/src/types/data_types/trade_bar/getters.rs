@@ -16,4 +16,7 @@ impl TradeBar {
     pub fn symbol_id(&self) -> u16 {
         self.symbol_id
     }
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
 }
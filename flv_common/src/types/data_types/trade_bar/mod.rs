@@ -12,6 +12,9 @@ pub struct TradeBar {
     date_time: DateTime<Utc>,
     price: Decimal,
     volume: Decimal,
+    // Position of the bar in its data stream; 0 if the bar was not sent in a data stream.
+    #[serde(default)]
+    sequence_number: u64,
 }
 
 impl TradeBar {
@@ -32,6 +35,18 @@ impl TradeBar {
             date_time,
             price,
             volume,
+            sequence_number: 0,
         }
     }
+
+    /// Returns the TradeBar with the given sequence number.
+    ///
+    /// # Parameters
+    ///
+    /// * `sequence_number` - The position of the bar in its data stream, starting at 1
+    ///
+    pub fn with_sequence_number(mut self, sequence_number: u64) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
 }
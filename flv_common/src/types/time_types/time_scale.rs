@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
 #[repr(u8)]
 pub enum TimeScale {
     #[default]
@@ -13,6 +14,7 @@ pub enum TimeScale {
     Month = 6,
     Quarter = 7,
     Year = 8,
+    Millisecond = 9,
 }
 
 impl From<u8> for TimeScale {
@@ -27,6 +29,7 @@ impl From<u8> for TimeScale {
             6 => TimeScale::Month,
             7 => TimeScale::Quarter,
             8 => TimeScale::Year,
+            9 => TimeScale::Millisecond,
             _ => TimeScale::NoScale,
         }
     }
@@ -0,0 +1,24 @@
+use common::prelude::DBBackend;
+
+#[test]
+fn test_default() {
+    assert_eq!(DBBackend::default(), DBBackend::ClickHouse);
+}
+
+#[test]
+fn test_from_string() {
+    let result = DBBackend::from_string("clickhouse");
+    assert_eq!(result, Ok(DBBackend::ClickHouse));
+
+    let result = DBBackend::from_string("QuestDB");
+    assert_eq!(result, Ok(DBBackend::QuestDB));
+
+    let result = DBBackend::from_string("postgres");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", DBBackend::ClickHouse), "ClickHouse");
+    assert_eq!(format!("{}", DBBackend::QuestDB), "QuestDB");
+}
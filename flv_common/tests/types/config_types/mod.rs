@@ -3,6 +3,8 @@ mod click_house_config_tests;
 #[cfg(test)]
 mod client_channel_tests;
 #[cfg(test)]
+mod db_backend_tests;
+#[cfg(test)]
 mod db_config_tests;
 #[cfg(test)]
 mod message_client_config_tests;
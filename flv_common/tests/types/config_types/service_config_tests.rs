@@ -84,3 +84,25 @@ fn test_online() {
     );
     assert!(config.online());
 }
+
+#[test]
+fn test_worker_count() {
+    let config = ServiceConfig::new(
+        ServiceID::QDGW,
+        "My Service".to_string(),
+        1,
+        true,
+        "Desc".to_string(),
+        "localhost".to_string(),
+        vec![8080],
+        "cluster".to_string(),
+        vec![8081],
+        "/health".to_string(),
+        None,
+        get_metric_config(),
+    );
+    assert_eq!(config.worker_count(), 1);
+
+    let config = config.with_worker_count(4);
+    assert_eq!(config.worker_count(), 4);
+}
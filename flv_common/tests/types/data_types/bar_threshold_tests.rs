@@ -0,0 +1,31 @@
+use common::prelude::BarThreshold;
+use rust_decimal::Decimal;
+
+#[test]
+fn test_validate() {
+    assert!(BarThreshold::Ticks(100).validate().is_ok());
+    assert!(BarThreshold::Volume(Decimal::new(5, 1)).validate().is_ok());
+    assert!(BarThreshold::Dollar(Decimal::from(1_000_000))
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn test_validate_invalid() {
+    assert!(BarThreshold::Ticks(0).validate().is_err());
+    assert!(BarThreshold::Volume(Decimal::ZERO).validate().is_err());
+    assert!(BarThreshold::Dollar(Decimal::from(-1)).validate().is_err());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(BarThreshold::Ticks(100).to_string(), "100 ticks");
+    assert_eq!(
+        BarThreshold::Volume(Decimal::new(5, 1)).to_string(),
+        "0.5 volume"
+    );
+    assert_eq!(
+        BarThreshold::Dollar(Decimal::from(1000)).to_string(),
+        "1000 dollar"
+    );
+}
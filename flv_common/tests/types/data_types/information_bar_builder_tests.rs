@@ -0,0 +1,108 @@
+use chrono::{Duration, TimeZone, Utc};
+use common::prelude::{BarThreshold, InformationBarBuilder};
+use rust_decimal::Decimal;
+
+fn price(value: i64) -> Decimal {
+    Decimal::from(value)
+}
+
+#[test]
+fn test_tick_bars() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut builder = InformationBarBuilder::new(7, BarThreshold::Ticks(3));
+
+    assert!(builder.update(start, price(10), price(1)).is_none());
+    assert!(builder
+        .update(start + Duration::seconds(1), price(12), price(2))
+        .is_none());
+
+    let bar = builder
+        .update(start + Duration::seconds(2), price(9), price(3))
+        .expect("Third trade closes the bar");
+
+    assert_eq!(bar.symbol_id(), 7);
+    assert_eq!(bar.date_time(), start);
+    assert_eq!(bar.open(), price(10));
+    assert_eq!(bar.high(), price(12));
+    assert_eq!(bar.low(), price(9));
+    assert_eq!(bar.close(), price(9));
+    assert_eq!(bar.volume(), price(6));
+
+    // The next trade opens a new bar
+    assert!(builder
+        .update(start + Duration::seconds(3), price(11), price(1))
+        .is_none());
+    let bar = builder.flush().expect("Flush closes the open bar");
+    assert_eq!(bar.date_time(), start + Duration::seconds(3));
+    assert_eq!(bar.open(), price(11));
+}
+
+#[test]
+fn test_volume_bars() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut builder = InformationBarBuilder::new(1, BarThreshold::Volume(price(5)));
+
+    assert!(builder.update(start, price(10), price(2)).is_none());
+
+    // The trade that crosses the threshold is not split
+    let bar = builder
+        .update(start, price(11), price(4))
+        .expect("Volume reached the threshold");
+    assert_eq!(bar.volume(), price(6));
+    assert_eq!(bar.close(), price(11));
+}
+
+#[test]
+fn test_dollar_bars() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut builder = InformationBarBuilder::new(1, BarThreshold::Dollar(price(100)));
+
+    // 10 * 5 = 50
+    assert!(builder.update(start, price(10), price(5)).is_none());
+    // 20 * 2 = 40, 90 in total
+    assert!(builder.update(start, price(20), price(2)).is_none());
+    // 10 * 1 = 10, 100 in total
+    let bar = builder
+        .update(start, price(10), price(1))
+        .expect("Notional value reached the threshold");
+
+    assert_eq!(bar.high(), price(20));
+    assert_eq!(bar.volume(), price(8));
+
+    // 100 / 8 = 12.5
+    assert_eq!(bar.vwap(), Some(Decimal::new(125, 1)));
+    assert_eq!(bar.trade_count(), Some(3));
+    assert_eq!(bar.buy_volume(), None);
+    assert_eq!(bar.sell_volume(), None);
+}
+
+#[test]
+fn test_zero_volume_vwap() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut builder = InformationBarBuilder::new(1, BarThreshold::Ticks(2));
+
+    assert!(builder.update(start, price(10), Decimal::ZERO).is_none());
+    let bar = builder
+        .update(start, price(12), Decimal::ZERO)
+        .expect("Second trade closes the bar");
+
+    assert_eq!(bar.vwap(), Some(price(12)));
+    assert_eq!(bar.trade_count(), Some(2));
+}
+
+#[test]
+fn test_flush_empty() {
+    let mut builder = InformationBarBuilder::new(1, BarThreshold::Ticks(1));
+    assert!(builder.flush().is_none());
+
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    assert!(builder.update(start, price(10), price(1)).is_some());
+    assert!(builder.flush().is_none());
+}
+
+#[test]
+fn test_getters() {
+    let builder = InformationBarBuilder::new(3, BarThreshold::Ticks(10));
+    assert_eq!(builder.symbol_id(), 3);
+    assert_eq!(builder.threshold(), BarThreshold::Ticks(10));
+}
@@ -1,3 +1,9 @@
+#[cfg(test)]
+mod bar_threshold_tests;
+
+#[cfg(test)]
+mod information_bar_builder_tests;
+
 #[cfg(test)]
 mod ohlcv_bar_tests;
 
@@ -21,6 +21,10 @@ fn test_new() {
     assert_eq!(data_bar.low(), low);
     assert_eq!(data_bar.close(), close);
     assert_eq!(data_bar.volume(), volume);
+    assert_eq!(data_bar.vwap(), None);
+    assert_eq!(data_bar.trade_count(), None);
+    assert_eq!(data_bar.buy_volume(), None);
+    assert_eq!(data_bar.sell_volume(), None);
 }
 
 #[test]
@@ -41,3 +45,30 @@ fn test_default() {
     assert_eq!(default_bar.close(), close);
     assert_eq!(default_bar.volume(), volume);
 }
+
+#[test]
+fn test_sequence_number() {
+    let bar = OHLCVBar::default();
+    assert_eq!(bar.sequence_number(), 0);
+
+    let bar = bar.with_sequence_number(42);
+    assert_eq!(bar.sequence_number(), 42);
+}
+
+#[test]
+fn test_vwap_and_trade_count() {
+    let bar = OHLCVBar::default()
+        .with_vwap(Decimal::new(1005, 1))
+        .with_trade_count(12);
+
+    assert_eq!(bar.vwap(), Some(Decimal::new(1005, 1)));
+    assert_eq!(bar.trade_count(), Some(12));
+}
+
+#[test]
+fn test_buy_sell_volume() {
+    let bar = OHLCVBar::default().with_buy_sell_volume(Decimal::from(3), Decimal::from(2));
+
+    assert_eq!(bar.buy_volume(), Some(Decimal::from(3)));
+    assert_eq!(bar.sell_volume(), Some(Decimal::from(2)));
+}
@@ -1,4 +1,4 @@
-use common::prelude::TimeResolution;
+use common::prelude::{TimeResolution, TimeScale};
 
 #[test]
 fn test_from_u8() {
@@ -26,3 +26,91 @@ fn test_display() {
     assert_eq!(TimeResolution::OneMonth.to_string(), "1 month");
     assert_eq!(TimeResolution::OneYear.to_string(), "1 year");
 }
+
+#[test]
+fn test_new() {
+    let three_min = TimeResolution::new(3, TimeScale::Minute).unwrap();
+    assert_eq!(
+        three_min,
+        TimeResolution::Custom {
+            count: 3,
+            scale: TimeScale::Minute
+        }
+    );
+
+    let hundred_ms = TimeResolution::new(100, TimeScale::Millisecond).unwrap();
+    assert_eq!(hundred_ms.count(), 100);
+    assert_eq!(hundred_ms.scale(), TimeScale::Millisecond);
+}
+
+#[test]
+fn test_new_named() {
+    assert_eq!(
+        TimeResolution::new(1, TimeScale::Minute).unwrap(),
+        TimeResolution::OneMin
+    );
+    assert_eq!(
+        TimeResolution::new(15, TimeScale::Minute).unwrap(),
+        TimeResolution::FifteenMin
+    );
+    assert_eq!(
+        TimeResolution::new(1, TimeScale::Year).unwrap(),
+        TimeResolution::OneYear
+    );
+}
+
+#[test]
+fn test_new_invalid() {
+    assert!(TimeResolution::new(0, TimeScale::Minute).is_err());
+    assert!(TimeResolution::new(0, TimeScale::NoScale).is_err());
+    assert!(TimeResolution::new(5, TimeScale::NoScale).is_err());
+}
+
+#[test]
+fn test_count_and_scale() {
+    let resolutions = [
+        TimeResolution::NoValue,
+        TimeResolution::OneMin,
+        TimeResolution::FiveMin,
+        TimeResolution::FifteenMin,
+        TimeResolution::ThirtyMin,
+        TimeResolution::OneHour,
+        TimeResolution::OneDay,
+        TimeResolution::OneWeek,
+        TimeResolution::OneMonth,
+        TimeResolution::OneYear,
+        TimeResolution::Custom {
+            count: 10,
+            scale: TimeScale::Second,
+        },
+    ];
+
+    for resolution in resolutions {
+        let parts = TimeResolution::from_parts(resolution.count(), resolution.scale());
+        assert_eq!(parts, resolution);
+    }
+}
+
+#[test]
+fn test_validate() {
+    assert!(TimeResolution::NoValue.validate().is_err());
+    assert!(TimeResolution::OneMin.validate().is_ok());
+    assert!(TimeResolution::from_parts(0, TimeScale::Hour)
+        .validate()
+        .is_err());
+    assert!(TimeResolution::from_parts(2, TimeScale::Hour)
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn test_display_custom() {
+    let two_hours = TimeResolution::new(2, TimeScale::Hour).unwrap();
+    assert_eq!(two_hours.to_string(), "2 hour");
+
+    let ten_seconds = TimeResolution::new(10, TimeScale::Second).unwrap();
+    assert_eq!(ten_seconds.to_string(), "10 second");
+
+    let hundred_ms = TimeResolution::new(100, TimeScale::Millisecond).unwrap();
+    assert_eq!(hundred_ms.to_string(), "100 millisecond");
+}
@@ -55,3 +55,12 @@ fn test_display() {
 
     assert_eq!(expected, format!("{}", bar));
 }
+
+#[test]
+fn test_sequence_number() {
+    let bar = TradeBar::new(1, Utc::now(), Decimal::from(100), Decimal::from(50));
+    assert_eq!(bar.sequence_number(), 0);
+
+    let bar = bar.with_sequence_number(42);
+    assert_eq!(bar.sequence_number(), 42);
+}
@@ -11,7 +11,8 @@ fn test_from_u8() {
     assert_eq!(TimeScale::from(6), TimeScale::Month);
     assert_eq!(TimeScale::from(7), TimeScale::Quarter);
     assert_eq!(TimeScale::from(8), TimeScale::Year);
-    assert_eq!(TimeScale::from(9), TimeScale::NoScale);
+    assert_eq!(TimeScale::from(9), TimeScale::Millisecond);
+    assert_eq!(TimeScale::from(10), TimeScale::NoScale);
 }
 
 #[test]
@@ -0,0 +1,133 @@
+use serde::Deserialize;
+
+use sbe_messages::prelude::DataType;
+
+/// The data a client is entitled to stream from the gateway.
+///
+/// An empty list of exchanges, allowed symbols, or data types does not restrict the client,
+/// and neither does a missing maximum number of streams. A denied symbol is never entitled,
+/// even if it matches an allowed pattern.
+///
+/// Symbol patterns match symbol names case-insensitive, where `*` matches any sequence
+/// of characters and `?` matches a single character, i.e. `eth*` matches `ethusd` and `ethaed`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Entitlements {
+    exchanges: Vec<String>,
+    allow_symbols: Vec<String>,
+    deny_symbols: Vec<String>,
+    data_types: Vec<DataType>,
+    max_streams: Option<u16>,
+}
+
+impl Entitlements {
+    /// Creates new Entitlements that do not restrict the client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the client to the exchanges with the given names.
+    pub fn with_exchanges(mut self, exchanges: Vec<String>) -> Self {
+        self.exchanges = exchanges;
+        self
+    }
+
+    /// Restricts the client to the symbols that match any of the given patterns.
+    pub fn with_allow_symbols(mut self, allow_symbols: Vec<String>) -> Self {
+        self.allow_symbols = allow_symbols;
+        self
+    }
+
+    /// Denies the client all symbols that match any of the given patterns.
+    pub fn with_deny_symbols(mut self, deny_symbols: Vec<String>) -> Self {
+        self.deny_symbols = deny_symbols;
+        self
+    }
+
+    /// Restricts the client to the given data types.
+    pub fn with_data_types(mut self, data_types: Vec<DataType>) -> Self {
+        self.data_types = data_types;
+        self
+    }
+
+    /// Limits the number of data streams the client can run at the same time.
+    pub fn with_max_streams(mut self, max_streams: u16) -> Self {
+        self.max_streams = Some(max_streams);
+        self
+    }
+}
+
+impl Entitlements {
+    /// Returns true if the client is entitled to the exchange with the given name.
+    pub fn allows_exchange(&self, exchange: &str) -> bool {
+        self.exchanges.is_empty()
+            || self
+                .exchanges
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(exchange))
+    }
+
+    /// Returns true if the client is entitled to the symbol with the given name.
+    pub fn allows_symbol(&self, symbol: &str) -> bool {
+        let allowed = self.allow_symbols.is_empty()
+            || self
+                .allow_symbols
+                .iter()
+                .any(|pattern| matches_pattern(pattern, symbol));
+
+        let denied = self
+            .deny_symbols
+            .iter()
+            .any(|pattern| matches_pattern(pattern, symbol));
+
+        allowed && !denied
+    }
+
+    /// Returns true if the client is entitled to the data type.
+    pub fn allows_data_type(&self, data_type: DataType) -> bool {
+        self.data_types.is_empty() || self.data_types.contains(&data_type)
+    }
+
+    /// Returns true if the client can run the given number of data streams at the same time.
+    pub fn allows_stream_count(&self, stream_count: usize) -> bool {
+        match self.max_streams {
+            Some(max_streams) => stream_count <= max_streams as usize,
+            None => true,
+        }
+    }
+
+    /// Returns the maximum number of data streams the client can run at the same time, if any.
+    pub fn max_streams(&self) -> Option<u16> {
+        self.max_streams
+    }
+}
+
+/// Matches a name against a pattern, ignoring ASCII case.
+/// `*` matches any sequence of characters, including none, and `?` matches a single character.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_ascii_lowercase().chars().collect();
+    let name: Vec<char> = name.to_ascii_lowercase().chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` in the pattern, and of the name where it started matching
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, start)) = backtrack {
+            // Let the last `*` match one more character and retry
+            p = star + 1;
+            n = start + 1;
+            backtrack = Some((star, start + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
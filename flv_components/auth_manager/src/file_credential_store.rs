@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use config_file::FromConfigFile;
+use serde::Deserialize;
+
+use common::prelude::InitError;
+
+use crate::CredentialStore;
+
+/// A credential store that loads the auth tokens of all clients from a TOML file.
+///
+/// The file lists one entry per client:
+///
+/// ```toml
+/// [[clients]]
+/// client_id = 77
+/// auth_token = "a-long-random-token"
+/// ```
+///
+/// The file holds secrets, so it should only be readable by the gateway.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileCredentialStore {
+    auth_tokens: HashMap<u16, String>,
+}
+
+#[derive(Deserialize)]
+struct CredentialFile {
+    clients: Vec<ClientCredential>,
+}
+
+#[derive(Deserialize)]
+struct ClientCredential {
+    client_id: u16,
+    auth_token: String,
+}
+
+impl FileCredentialStore {
+    /// Creates a new FileCredentialStore from a map of client IDs to auth tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_tokens` - Maps each client ID to its auth token
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the new FileCredentialStore or an InitError
+    /// if the auth token of a client is empty.
+    ///
+    pub fn new(auth_tokens: HashMap<u16, String>) -> Result<Self, InitError> {
+        for (client_id, auth_token) in auth_tokens.iter() {
+            if auth_token.is_empty() {
+                return Err(InitError(format!(
+                    "[FileCredentialStore]: Empty auth token for client {}",
+                    client_id
+                )));
+            }
+        }
+
+        Ok(Self { auth_tokens })
+    }
+
+    /// Loads a FileCredentialStore from the TOML file at the given path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the credential file
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the new FileCredentialStore or an InitError if:
+    ///
+    /// - The file cannot be read or parsed
+    /// - The file lists a client more than once
+    /// - The auth token of a client is empty
+    ///
+    pub fn from_file(path: &str) -> Result<Self, InitError> {
+        let credential_file = match CredentialFile::from_config_file(path) {
+            Ok(credential_file) => credential_file,
+            Err(e) => {
+                return Err(InitError(format!(
+                    "[FileCredentialStore]: Failed to load credential file {}: {}",
+                    path, e
+                )))
+            }
+        };
+
+        let mut auth_tokens = HashMap::with_capacity(credential_file.clients.len());
+
+        for client in credential_file.clients {
+            if auth_tokens.contains_key(&client.client_id) {
+                return Err(InitError(format!(
+                    "[FileCredentialStore]: Duplicate credentials for client {}",
+                    client.client_id
+                )));
+            }
+
+            auth_tokens.insert(client.client_id, client.auth_token);
+        }
+
+        Self::new(auth_tokens)
+    }
+
+    /// Returns the number of clients with credentials.
+    pub fn number_of_clients(&self) -> usize {
+        self.auth_tokens.len()
+    }
+}
+
+impl CredentialStore for FileCredentialStore {
+    fn authenticate(&self, client_id: u16, auth_token: &str) -> bool {
+        match self.auth_tokens.get(&client_id) {
+            Some(expected) => constant_time_eq(expected.as_bytes(), auth_token.as_bytes()),
+            None => false,
+        }
+    }
+}
+
+/// Compares two byte slices in time that only depends on their length,
+/// so the response time does not reveal how many leading bytes of a guessed token are right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use config_file::FromConfigFile;
+use serde::Deserialize;
+
+use common::prelude::InitError;
+
+use crate::{EntitlementStore, Entitlements};
+
+/// An entitlement store that loads the entitlements of all clients from a TOML file.
+///
+/// The file lists one entry per client. Omitted fields do not restrict the client:
+///
+/// ```toml
+/// [[clients]]
+/// client_id = 77
+/// exchanges = ["kraken"]
+/// allow_symbols = ["eth*", "xbt*"]
+/// deny_symbols = ["ethaed"]
+/// data_types = ["TradeData", "OHLCVData"]
+/// max_streams = 10
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileEntitlementStore {
+    entitlements: HashMap<u16, Entitlements>,
+}
+
+#[derive(Deserialize)]
+struct EntitlementFile {
+    clients: Vec<ClientEntitlements>,
+}
+
+#[derive(Deserialize)]
+struct ClientEntitlements {
+    client_id: u16,
+    #[serde(flatten)]
+    entitlements: Entitlements,
+}
+
+impl FileEntitlementStore {
+    /// Creates a new FileEntitlementStore from a map of client IDs to entitlements.
+    ///
+    /// # Arguments
+    ///
+    /// * `entitlements` - Maps each client ID to its entitlements
+    ///
+    pub fn new(entitlements: HashMap<u16, Entitlements>) -> Self {
+        Self { entitlements }
+    }
+
+    /// Loads a FileEntitlementStore from the TOML file at the given path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the entitlement file
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the new FileEntitlementStore or an InitError if:
+    ///
+    /// - The file cannot be read or parsed
+    /// - The file lists a client more than once
+    ///
+    pub fn from_file(path: &str) -> Result<Self, InitError> {
+        let entitlement_file = match EntitlementFile::from_config_file(path) {
+            Ok(entitlement_file) => entitlement_file,
+            Err(e) => {
+                return Err(InitError(format!(
+                    "[FileEntitlementStore]: Failed to load entitlement file {}: {}",
+                    path, e
+                )))
+            }
+        };
+
+        let mut entitlements = HashMap::with_capacity(entitlement_file.clients.len());
+
+        for client in entitlement_file.clients {
+            if entitlements.contains_key(&client.client_id) {
+                return Err(InitError(format!(
+                    "[FileEntitlementStore]: Duplicate entitlements for client {}",
+                    client.client_id
+                )));
+            }
+
+            entitlements.insert(client.client_id, client.entitlements);
+        }
+
+        Ok(Self::new(entitlements))
+    }
+
+    /// Returns the number of clients with entitlements.
+    pub fn number_of_clients(&self) -> usize {
+        self.entitlements.len()
+    }
+}
+
+impl EntitlementStore for FileEntitlementStore {
+    fn entitlements(&self, client_id: u16) -> Option<Entitlements> {
+        self.entitlements.get(&client_id).cloned()
+    }
+}
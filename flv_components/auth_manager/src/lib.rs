@@ -0,0 +1,40 @@
+mod entitlements;
+mod file_credential_store;
+mod file_entitlement_store;
+
+pub use crate::entitlements::Entitlements;
+pub use crate::file_credential_store::FileCredentialStore;
+pub use crate::file_entitlement_store::FileEntitlementStore;
+
+/// A store of client credentials that authenticates clients when they log in.
+///
+/// The gateway holds the store as trait object, so that deployments can replace
+/// the default file backed store with a store backed by a secrets manager or a database.
+///
+/// Implementations must reject an empty auth token, which is what clients
+/// of protocol versions before 5 send.
+pub trait CredentialStore: Send + Sync {
+    /// Returns true if the auth token is the valid token of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The ID of the client that logs in
+    /// * `auth_token` - The auth token the client sent with its login message
+    ///
+    fn authenticate(&self, client_id: u16, auth_token: &str) -> bool;
+}
+
+/// A store of the entitlements of each client, which the gateway
+/// checks before it starts a data stream.
+///
+/// Like the credential store, the gateway holds the store as trait object.
+pub trait EntitlementStore: Send + Sync {
+    /// Returns the entitlements of the client, or None if the client
+    /// is not entitled to any data.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The ID of the client that requests data
+    ///
+    fn entitlements(&self, client_id: u16) -> Option<Entitlements>;
+}
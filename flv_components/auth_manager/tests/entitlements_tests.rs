@@ -0,0 +1,82 @@
+use auth_manager::Entitlements;
+use sbe_messages::prelude::DataType;
+
+#[test]
+fn test_new() {
+    let entitlements = Entitlements::new();
+
+    assert!(entitlements.allows_exchange("kraken"));
+    assert!(entitlements.allows_symbol("ethaed"));
+    assert!(entitlements.allows_data_type(DataType::TradeData));
+    assert!(entitlements.allows_stream_count(1000));
+    assert_eq!(entitlements.max_streams(), None);
+}
+
+#[test]
+fn test_allows_exchange() {
+    let entitlements = Entitlements::new().with_exchanges(vec!["kraken".to_string()]);
+
+    assert!(entitlements.allows_exchange("kraken"));
+    assert!(entitlements.allows_exchange("Kraken"));
+    assert!(!entitlements.allows_exchange("bittrex"));
+}
+
+#[test]
+fn test_allows_symbol() {
+    let entitlements =
+        Entitlements::new().with_allow_symbols(vec!["eth*".to_string(), "xbt???".to_string()]);
+
+    assert!(entitlements.allows_symbol("ethaed"));
+    assert!(entitlements.allows_symbol("ETHUSD"));
+    assert!(entitlements.allows_symbol("eth"));
+    assert!(entitlements.allows_symbol("xbtusd"));
+    assert!(!entitlements.allows_symbol("xbtusdt"));
+    assert!(!entitlements.allows_symbol("apeusdt"));
+}
+
+#[test]
+fn test_allows_symbol_pattern_in_the_middle() {
+    let entitlements = Entitlements::new().with_allow_symbols(vec!["*usd*t".to_string()]);
+
+    assert!(entitlements.allows_symbol("apeusdt"));
+    assert!(entitlements.allows_symbol("usdt"));
+    assert!(entitlements.allows_symbol("ethusdcoint"));
+    assert!(!entitlements.allows_symbol("ethusd"));
+}
+
+#[test]
+fn test_deny_symbol() {
+    let entitlements = Entitlements::new()
+        .with_allow_symbols(vec!["eth*".to_string()])
+        .with_deny_symbols(vec!["ethaed".to_string()]);
+
+    assert!(entitlements.allows_symbol("ethusd"));
+    // A denied symbol is never entitled, even if it matches an allowed pattern
+    assert!(!entitlements.allows_symbol("ethaed"));
+
+    // Without allowed patterns, all symbols but the denied ones are entitled
+    let entitlements = Entitlements::new().with_deny_symbols(vec!["*aed".to_string()]);
+    assert!(entitlements.allows_symbol("apeusdt"));
+    assert!(!entitlements.allows_symbol("ethaed"));
+}
+
+#[test]
+fn test_allows_data_type() {
+    let entitlements = Entitlements::new().with_data_types(vec![DataType::TradeData]);
+
+    assert!(entitlements.allows_data_type(DataType::TradeData));
+    assert!(!entitlements.allows_data_type(DataType::OHLCVData));
+}
+
+#[test]
+fn test_allows_stream_count() {
+    let entitlements = Entitlements::new().with_max_streams(2);
+
+    assert_eq!(entitlements.max_streams(), Some(2));
+    assert!(entitlements.allows_stream_count(1));
+    assert!(entitlements.allows_stream_count(2));
+    assert!(!entitlements.allows_stream_count(3));
+
+    let entitlements = Entitlements::new().with_max_streams(0);
+    assert!(!entitlements.allows_stream_count(1));
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use auth_manager::{CredentialStore, FileCredentialStore};
+
+fn write_credential_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}_{}.toml", name, std::process::id()));
+    fs::write(&path, content).expect("Failed to write credential file");
+    path
+}
+
+fn get_test_store() -> FileCredentialStore {
+    let auth_tokens = HashMap::from([(1, "token-one".to_string()), (2, "token-two".to_string())]);
+    FileCredentialStore::new(auth_tokens).expect("Failed to create credential store")
+}
+
+#[test]
+fn test_new() {
+    let store = get_test_store();
+    assert_eq!(store.number_of_clients(), 2);
+}
+
+#[test]
+fn test_new_empty_token() {
+    let auth_tokens = HashMap::from([(1, "".to_string())]);
+    let result = FileCredentialStore::new(auth_tokens);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_authenticate() {
+    let store = get_test_store();
+
+    assert!(store.authenticate(1, "token-one"));
+    assert!(store.authenticate(2, "token-two"));
+}
+
+#[test]
+fn test_authenticate_invalid_token() {
+    let store = get_test_store();
+
+    // Token of another client
+    assert!(!store.authenticate(1, "token-two"));
+    // Prefix of the token
+    assert!(!store.authenticate(1, "token"));
+    // No token, as sent by clients before protocol version 5
+    assert!(!store.authenticate(1, ""));
+}
+
+#[test]
+fn test_authenticate_unknown_client() {
+    let store = get_test_store();
+    assert!(!store.authenticate(3, "token-one"));
+}
+
+#[test]
+fn test_from_file() {
+    let path = write_credential_file(
+        "credentials",
+        r#"
+[[clients]]
+client_id = 77
+auth_token = "token-77"
+
+[[clients]]
+client_id = 78
+auth_token = "token-78"
+"#,
+    );
+
+    let store = FileCredentialStore::from_file(path.to_str().unwrap())
+        .expect("Failed to load credential file");
+    fs::remove_file(&path).expect("Failed to remove credential file");
+
+    assert_eq!(store.number_of_clients(), 2);
+    assert!(store.authenticate(77, "token-77"));
+    assert!(store.authenticate(78, "token-78"));
+    assert!(!store.authenticate(77, "token-78"));
+}
+
+#[test]
+fn test_from_file_duplicate_client() {
+    let path = write_credential_file(
+        "credentials_duplicate",
+        r#"
+[[clients]]
+client_id = 77
+auth_token = "token-77"
+
+[[clients]]
+client_id = 77
+auth_token = "other-token"
+"#,
+    );
+
+    let result = FileCredentialStore::from_file(path.to_str().unwrap());
+    fs::remove_file(&path).expect("Failed to remove credential file");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_file_missing_file() {
+    let result = FileCredentialStore::from_file("does_not_exist.toml");
+    assert!(result.is_err());
+}
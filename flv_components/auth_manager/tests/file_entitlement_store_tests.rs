@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use auth_manager::{EntitlementStore, Entitlements, FileEntitlementStore};
+use sbe_messages::prelude::DataType;
+
+fn write_entitlement_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}_{}.toml", name, std::process::id()));
+    fs::write(&path, content).expect("Failed to write entitlement file");
+    path
+}
+
+#[test]
+fn test_new() {
+    let entitlements = HashMap::from([(1, Entitlements::new().with_max_streams(5))]);
+    let store = FileEntitlementStore::new(entitlements);
+
+    assert_eq!(store.number_of_clients(), 1);
+    assert_eq!(
+        store.entitlements(1),
+        Some(Entitlements::new().with_max_streams(5))
+    );
+    // A client without entry is not entitled to any data
+    assert_eq!(store.entitlements(2), None);
+}
+
+#[test]
+fn test_from_file() {
+    let path = write_entitlement_file(
+        "entitlements",
+        r#"
+[[clients]]
+client_id = 77
+exchanges = ["kraken"]
+allow_symbols = ["eth*"]
+deny_symbols = ["ethaed"]
+data_types = ["TradeData", "OHLCVData"]
+max_streams = 10
+
+[[clients]]
+client_id = 78
+"#,
+    );
+
+    let store = FileEntitlementStore::from_file(path.to_str().unwrap())
+        .expect("Failed to load entitlement file");
+    fs::remove_file(&path).expect("Failed to remove entitlement file");
+
+    assert_eq!(store.number_of_clients(), 2);
+
+    let expected = Entitlements::new()
+        .with_exchanges(vec!["kraken".to_string()])
+        .with_allow_symbols(vec!["eth*".to_string()])
+        .with_deny_symbols(vec!["ethaed".to_string()])
+        .with_data_types(vec![DataType::TradeData, DataType::OHLCVData])
+        .with_max_streams(10);
+    assert_eq!(store.entitlements(77), Some(expected));
+
+    // Omitted fields do not restrict the client
+    assert_eq!(store.entitlements(78), Some(Entitlements::new()));
+}
+
+#[test]
+fn test_from_file_duplicate_client() {
+    let path = write_entitlement_file(
+        "entitlements_duplicate",
+        r#"
+[[clients]]
+client_id = 77
+
+[[clients]]
+client_id = 77
+max_streams = 1
+"#,
+    );
+
+    let result = FileEntitlementStore::from_file(path.to_str().unwrap());
+    fs::remove_file(&path).expect("Failed to remove entitlement file");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_file_unknown_data_type() {
+    let path = write_entitlement_file(
+        "entitlements_unknown_data_type",
+        r#"
+[[clients]]
+client_id = 77
+data_types = ["QuoteData"]
+"#,
+    );
+
+    let result = FileEntitlementStore::from_file(path.to_str().unwrap());
+    fs::remove_file(&path).expect("Failed to remove entitlement file");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_file_missing_file() {
+    let result = FileEntitlementStore::from_file("does_not_exist.toml");
+    assert!(result.is_err());
+}
@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod entitlements_tests;
+#[cfg(test)]
+mod file_credential_store_tests;
+#[cfg(test)]
+mod file_entitlement_store_tests;
@@ -1,7 +1,7 @@
 use crate::ConfigManager;
 use common::prelude::{
-    ClickHouseConfig, EnvironmentType, ExchangeID, IggyConfig, MessageClientConfig, MetricConfig,
-    ServiceConfig, ServiceID,
+    ClickHouseConfig, DBBackend, DBConfig, EnvironmentType, ExchangeID, IggyConfig,
+    MessageClientConfig, MetricConfig, ServiceConfig, ServiceID,
 };
 
 const DEFAULT_HOST: &str = "0.0.0.0";
@@ -56,6 +56,24 @@ impl ConfigManager {
         self.db_config.clone()
     }
 
+    /// Get the database backend that serves the trade and OHLCV queries.
+    ///
+    /// # Returns
+    ///
+    /// The DBBackend enum variant.
+    pub fn db_backend(&self) -> DBBackend {
+        self.db_backend
+    }
+
+    /// Get a copy of the QuestDB config associated with this service.
+    ///
+    /// # Returns
+    ///
+    /// A cloned DBConfig value with the PostgreSQL wire connection parameters.
+    pub fn pg_db_config(&self) -> DBConfig {
+        self.pg_db_config.clone()
+    }
+
     /// Get a copy of the ServiceConfig associated with this service.
     ///
     /// # Returns
@@ -1,5 +1,6 @@
 use common::prelude::{
-    ClickHouseConfig, EnvironmentType, ExchangeID, IggyConfig, ServiceConfig, ServiceID,
+    ClickHouseConfig, DBBackend, DBConfig, EnvironmentType, ExchangeID, IggyConfig, ServiceConfig,
+    ServiceID,
 };
 use std::collections::HashMap;
 
@@ -12,8 +13,12 @@ pub struct ConfigManager {
     svc_id: ServiceID,
     /// Detected environment of this service.
     env_type: EnvironmentType,
+    /// Database backend that serves the trade and OHLCV queries.
+    db_backend: DBBackend,
     /// DB configuration relative to the detected environment.
     db_config: ClickHouseConfig,
+    /// QuestDB configuration relative to the detected environment.
+    pg_db_config: DBConfig,
     /// Configuration of this service.
     svc_config: ServiceConfig,
     /// Default exchange
@@ -46,7 +51,8 @@ impl ConfigManager {
     /// A ConfigManager instance configured with:
     ///
     /// - The detected environment
-    /// - DB config for the environment
+    /// - DB backend selected by the DB_BACKEND variable, ClickHouse by default
+    /// - DB configs of ClickHouse and QuestDB for the environment
     /// - Service config for the ID
     /// - Default exchange
     /// - Supported exchanges
@@ -71,8 +77,10 @@ impl ConfigManager {
         // Detect environment type
         let env_type = utils::get_env_type();
 
-        // Get DB config for environment
+        // Get DB backend and the DB configs for environment
+        let db_backend = utils::get_db_backend();
         let db_config = utils::get_db_config(&env_type);
+        let pg_db_config = utils::get_pg_db_config(&env_type);
 
         // Get service config for ID
         let svc_config = utils::get_service_config(&svc_id);
@@ -94,7 +102,9 @@ impl ConfigManager {
         Self {
             svc_id,
             env_type,
+            db_backend,
             db_config,
+            pg_db_config,
             svc_config,
             default_exchange,
             exchanges,
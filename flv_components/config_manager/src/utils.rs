@@ -1,7 +1,10 @@
 use common::prelude::{
-    ClickHouseConfig, EnvironmentType, ExchangeID, IggyConfig, ServiceConfig, ServiceID,
+    ClickHouseConfig, DBBackend, DBConfig, EnvironmentType, ExchangeID, IggyConfig, ServiceConfig,
+    ServiceID,
+};
+use db_specs::prelude::{
+    get_cluster_db_config, get_cluster_pg_db_config, get_local_db_config, get_local_pg_db_config,
 };
-use db_specs::prelude::{get_cluster_db_config, get_local_db_config};
 use message_specs::prelude::{get_cluster_iggy_config, get_local_iggy_config};
 use service_specs::prelude::{get_qdgw_service_config, get_symdb_service_config};
 use std::collections::HashMap;
@@ -83,6 +86,51 @@ pub(crate) fn get_db_config(env_type: &EnvironmentType) -> ClickHouseConfig {
     }
 }
 
+/// Gets the database backend from the DB_BACKEND variable.
+///
+/// # Panics
+///
+/// Panics if DB_BACKEND is set to neither ClickHouse nor QuestDB.
+///
+/// # Returns
+///
+/// DBBackend::ClickHouse if DB_BACKEND is missing or set to "ClickHouse".
+/// DBBackend::QuestDB if DB_BACKEND is set to "QuestDB".
+///
+pub(crate) fn get_db_backend() -> DBBackend {
+    let env_var = match env::var("DB_BACKEND") {
+        Ok(val) => val,
+        Err(_) => return DBBackend::default(),
+    };
+
+    match DBBackend::from_string(&env_var) {
+        Ok(db_backend) => db_backend,
+        Err(_) => {
+            panic!(
+                "[ConfigManager]: Unknown DB backend: {}. DB backend can only be ClickHouse or QuestDB",
+                env_var
+            );
+        }
+    }
+}
+
+/// Gets the QuestDB configuration for the given environment type.
+///
+/// # Arguments
+///
+/// * `env_type` - The EnvironmentType enum
+///
+/// # Returns
+///
+/// The DBConfig with the PostgreSQL wire connection parameters for the environment.
+///
+pub(crate) fn get_pg_db_config(env_type: &EnvironmentType) -> DBConfig {
+    match env_type {
+        EnvironmentType::Local => get_local_pg_db_config(),
+        EnvironmentType::Cluster => get_cluster_pg_db_config(),
+    }
+}
+
 /// Gets the Iggy configuration for the given environment type and service ID.
 ///
 pub(crate) fn get_iggy_config(env_type: &EnvironmentType, svc_id: ServiceID) -> IggyConfig {
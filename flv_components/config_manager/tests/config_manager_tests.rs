@@ -1,6 +1,6 @@
-use common::prelude::{EnvironmentType, MessageClientConfig, ServiceConfig, ServiceID};
+use common::prelude::{DBBackend, EnvironmentType, MessageClientConfig, ServiceConfig, ServiceID};
 use config_manager::ConfigManager;
-use db_specs::prelude::get_local_db_config;
+use db_specs::prelude::{get_local_db_config, get_local_pg_db_config};
 use std::env;
 
 #[test]
@@ -32,6 +32,31 @@ fn test_get_db_config() {
     assert_eq!(db_config, config.db_config());
 }
 
+#[test]
+fn test_get_db_backend() {
+    env::set_var("ENV", "Local");
+
+    let svc_id = ServiceID::QDGW;
+    let config = ConfigManager::new(svc_id);
+
+    // ClickHouse stays the default unless DB_BACKEND selects QuestDB.
+    let expected = match env::var("DB_BACKEND") {
+        Ok(val) => DBBackend::from_string(&val).unwrap(),
+        Err(_) => DBBackend::ClickHouse,
+    };
+    assert_eq!(expected, config.db_backend());
+}
+
+#[test]
+fn test_get_pg_db_config() {
+    env::set_var("ENV", "Local");
+
+    let svc_id = ServiceID::QDGW;
+    let config = ConfigManager::new(svc_id);
+
+    assert_eq!(get_local_pg_db_config(), config.pg_db_config());
+}
+
 #[test]
 fn test_get_svc_config() {
     env::set_var("ENV", "Local");
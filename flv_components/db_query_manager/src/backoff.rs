@@ -0,0 +1,43 @@
+use crate::error::QueryError;
+use std::future::Future;
+use std::time::Duration;
+
+// Number of attempts of an operation before its last transient error is returned.
+const MAX_ATTEMPTS: u32 = 5;
+
+// Delay before the first retry, which doubles with each retry up to MAX_DELAY.
+const INITIAL_DELAY: Duration = Duration::from_millis(100);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// Runs the operation and retries it with exponential backoff while it fails with a transient error.
+///
+/// Permanent errors are returned right away, since retrying cannot fix them.
+///
+/// # Parameters
+///
+/// * `operation` - Creates the future of the next attempt
+///
+/// # Errors
+///
+/// - The permanent error of an attempt, or the transient error of the last attempt
+///
+pub(crate) async fn retry_with_backoff<T, F, Fut>(mut operation: F) -> Result<T, QueryError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, QueryError>>,
+{
+    let mut delay = INITIAL_DELAY;
+    let mut attempt = 1;
+
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(e) if e.is_transient() && attempt < MAX_ATTEMPTS => {
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(MAX_DELAY);
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
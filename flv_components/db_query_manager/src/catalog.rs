@@ -0,0 +1,63 @@
+use crate::types::TableColumn;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// A cached lookup of a table; None if the table does not exist.
+struct CatalogEntry {
+    fetched_at: Instant,
+    columns: Option<Vec<TableColumn>>,
+}
+
+/// Caches the columns of the tables looked up in the catalog of the database.
+///
+/// Entries expire after the time to live, so that a dropped or recreated table
+/// is noticed without looking up the catalog before every stream.
+pub(crate) struct TableCatalog {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, CatalogEntry>>,
+}
+
+impl TableCatalog {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached lookup of the table, unless it is missing or expired.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Some(columns))` for a table that exists, `Some(None)` for a table that does not exist,
+    /// and `None` if the table has to be looked up.
+    ///
+    pub(crate) fn get(&self, table: &str) -> Option<Option<Vec<TableColumn>>> {
+        let entries = self.entries.lock().expect("Table catalog lock poisoned");
+
+        match entries.get(table) {
+            Some(entry) if entry.fetched_at.elapsed() < self.ttl => Some(entry.columns.clone()),
+            _ => None,
+        }
+    }
+
+    /// Caches the lookup of the table; `None` if the table does not exist.
+    pub(crate) fn insert(&self, table: &str, columns: Option<Vec<TableColumn>>) {
+        let mut entries = self.entries.lock().expect("Table catalog lock poisoned");
+
+        entries.insert(
+            table.to_string(),
+            CatalogEntry {
+                fetched_at: Instant::now(),
+                columns,
+            },
+        );
+    }
+
+    /// Removes all cached lookups.
+    pub(crate) fn clear(&self) {
+        let mut entries = self.entries.lock().expect("Table catalog lock poisoned");
+        entries.clear();
+    }
+}
@@ -0,0 +1,205 @@
+use crate::backoff::retry_with_backoff;
+use crate::clickhouse_pool::{classify_error, ClickHousePool};
+use crate::error::QueryError;
+use crate::storage_backend::StorageBackend;
+use crate::types::{OHLCVRow, SymbolCoverage, TableColumn, TradeRow};
+use crate::FN_NAME;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use common::prelude::{ClickHouseConfig, OHLCVBar, TimeResolution, TradeBar};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use klickhouse::Row;
+use std::time::Duration;
+
+// Number of connections of the pool unless configured otherwise.
+const DEFAULT_POOL_SIZE: usize = 4;
+
+/// The ClickHouse implementation of the StorageBackend, the default backend of the QueryDBManager.
+///
+/// Queries run on a pool of connections. Queries that fail because the connection got lost
+/// are retried with backoff on a new connection; streams only retry until their first row.
+pub struct ClickHouseBackend {
+    pool: ClickHousePool,
+}
+
+impl ClickHouseBackend {
+    /// Creates a new ClickHouseBackend connected to the configured database.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_config: ClickHouseConfig` - The database configuration containing connection parameters.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ConnectionFailed` if the connection to the database still fails after retrying with backoff.
+    ///
+    pub async fn new(db_config: ClickHouseConfig) -> Result<Self, QueryError> {
+        Self::with_pool_size(db_config, DEFAULT_POOL_SIZE).await
+    }
+
+    /// Creates a new ClickHouseBackend with a pool of `pool_size` connections to the configured database.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_config: ClickHouseConfig` - The database configuration containing connection parameters.
+    /// * `pool_size` - The number of connections, at least one
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ConnectionFailed` if a connection to the database still fails after retrying with backoff.
+    ///
+    pub async fn with_pool_size(
+        db_config: ClickHouseConfig,
+        pool_size: usize,
+    ) -> Result<Self, QueryError> {
+        let destination = db_config.connection_string();
+
+        match ClickHousePool::connect(destination, pool_size).await {
+            Ok(pool) => Ok(Self { pool }),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl ClickHouseBackend {
+    /// Runs the query on the next connection of the pool and collects all rows.
+    ///
+    /// Retries with backoff if the connection fails.
+    pub(crate) async fn query_collect<T: Row + Send + 'static>(
+        &self,
+        query: &str,
+    ) -> Result<Vec<T>, QueryError> {
+        retry_with_backoff(|| async {
+            let client = self.pool.client().await?;
+
+            match client.query_collect::<T>(query).await {
+                Ok(rows) => Ok(rows),
+                Err(e) => Err(classify_error(
+                    e,
+                    client.is_closed(),
+                    &format!("{} Failed to execute query: {}", FN_NAME, query),
+                )),
+            }
+        })
+        .await
+    }
+
+    /// Runs the query on the next connection of the pool and returns its first row.
+    ///
+    /// Retries with backoff if the connection fails.
+    pub(crate) async fn query_one<T: Row + Send + 'static>(
+        &self,
+        query: &str,
+    ) -> Result<T, QueryError> {
+        retry_with_backoff(|| async {
+            let client = self.pool.client().await?;
+
+            match client.query_one::<T>(query).await {
+                Ok(row) => Ok(row),
+                Err(e) => Err(classify_error(
+                    e,
+                    client.is_closed(),
+                    &format!("{} Failed to execute query: {}", FN_NAME, query),
+                )),
+            }
+        })
+        .await
+    }
+
+    /// Starts the query on the next connection of the pool and streams its rows.
+    ///
+    /// Retries with backoff if the connection fails before the query starts.
+    /// Once the query runs, a lost connection ends the stream with a transient error.
+    pub(crate) async fn query_stream<T: Row + Send + 'static>(
+        &self,
+        query: &str,
+        query_name: &str,
+    ) -> Result<BoxStream<'static, Result<T, QueryError>>, QueryError> {
+        let context = format!("{} Failed to execute {} query", FN_NAME, query_name);
+
+        retry_with_backoff(|| async {
+            let client = self.pool.client().await?;
+
+            // Pass the query owned, so that the stream of rows does not borrow it.
+            match client.query::<T>(query.to_string()).await {
+                Ok(rows) => {
+                    let context = context.clone();
+                    Ok(rows
+                        .map(move |row| {
+                            row.map_err(|e| classify_error(e, client.is_closed(), &context))
+                        })
+                        .boxed())
+                }
+                Err(e) => Err(classify_error(e, client.is_closed(), &context)),
+            }
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl StorageBackend for ClickHouseBackend {
+    async fn is_open(&self) -> bool {
+        self.pool.is_open().await
+    }
+
+    async fn get_all_symbols_with_ids(
+        &self,
+        symbol_table: &str,
+    ) -> Result<Vec<(u16, String)>, QueryError> {
+        ClickHouseBackend::get_all_symbols_with_ids(self, symbol_table).await
+    }
+
+    async fn get_table_columns(&self, table: &str) -> Result<Vec<TableColumn>, QueryError> {
+        ClickHouseBackend::get_table_columns(self, table).await
+    }
+
+    async fn get_all_trades(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+    ) -> Result<Vec<TradeBar>, QueryError> {
+        ClickHouseBackend::get_all_trades(self, symbol_id, trade_table).await
+    }
+
+    async fn get_all_ohlcv_bars(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+    ) -> Result<Vec<OHLCVBar>, QueryError> {
+        ClickHouseBackend::get_all_ohlcv_bars(self, symbol_id, trade_table, time_resolution).await
+    }
+
+    async fn get_symbol_coverage(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+        min_gap: Duration,
+    ) -> Result<SymbolCoverage, QueryError> {
+        ClickHouseBackend::get_symbol_coverage(self, symbol_id, trade_table, start_time, min_gap)
+            .await
+    }
+
+    async fn stream_trades<'a>(
+        &'a self,
+        trade_table: &'a str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<TradeRow, QueryError>> {
+        ClickHouseBackend::stream_trades(self, trade_table, start_time, end_time).await
+    }
+
+    async fn stream_ohlcv<'a>(
+        &'a self,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<OHLCVRow, QueryError>> {
+        ClickHouseBackend::stream_ohlcv(self, trade_table, time_resolution, start_time, end_time)
+            .await
+    }
+}
@@ -0,0 +1,115 @@
+use crate::backoff::retry_with_backoff;
+use crate::error::QueryError;
+use crate::FN_NAME;
+use klickhouse::{Client, ClientOptions, KlickhouseError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::Mutex;
+
+/// A fixed-size pool of ClickHouse connections.
+///
+/// Each ClickHouse client runs its queries one after another over a single socket,
+/// so the pool hands out its clients round-robin to let parallel streams run side by side.
+/// A client whose connection got closed is replaced by a new connection the next time it is handed out.
+pub(crate) struct ClickHousePool {
+    destination: String,
+    clients: Vec<Mutex<Client>>,
+    next: AtomicUsize,
+}
+
+impl ClickHousePool {
+    /// Connects all clients of the pool, retrying with backoff while the database is unreachable.
+    ///
+    /// # Parameters
+    ///
+    /// * `destination` - The address of the database
+    /// * `pool_size` - The number of connections, at least one
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ConnectionFailed` if a connection still fails after the last retry
+    ///
+    pub(crate) async fn connect(destination: String, pool_size: usize) -> Result<Self, QueryError> {
+        let pool_size = pool_size.max(1);
+        let mut clients = Vec::with_capacity(pool_size);
+
+        for _ in 0..pool_size {
+            let client = match retry_with_backoff(|| connect_client(&destination)).await {
+                Ok(client) => client,
+                Err(e) => return Err(e),
+            };
+            clients.push(Mutex::new(client));
+        }
+
+        Ok(Self {
+            destination,
+            clients,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Returns true if at least one client of the pool is connected.
+    pub(crate) async fn is_open(&self) -> bool {
+        for client in &self.clients {
+            if !client.lock().await.is_closed() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Hands out the next client of the pool, and reconnects it first if its connection got closed.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ConnectionFailed` if the client has to reconnect and the connection fails
+    ///
+    pub(crate) async fn client(&self) -> Result<Client, QueryError> {
+        let idx = self.next.fetch_add(1, Ordering::Relaxed) % self.clients.len();
+        let mut client = self.clients[idx].lock().await;
+
+        if client.is_closed() {
+            *client = match connect_client(&self.destination).await {
+                Ok(client) => client,
+                Err(e) => return Err(e),
+            };
+        }
+
+        Ok(client.clone())
+    }
+}
+
+async fn connect_client(destination: &str) -> Result<Client, QueryError> {
+    match Client::connect(destination, ClientOptions::default()).await {
+        Ok(client) => Ok(client),
+        Err(e) => Err(QueryError::ConnectionFailed(format!(
+            "{} Failed to connect to {}: {}",
+            FN_NAME, destination, e
+        ))),
+    }
+}
+
+/// Classifies an error of a ClickHouse query as transient or permanent.
+///
+/// # Parameters
+///
+/// * `e` - The error of the query
+/// * `connection_closed` - Whether the connection of the client got closed
+/// * `context` - Describes the failed query
+///
+/// # Returns
+///
+/// `QueryError::ConnectionFailed` if the query failed because of the connection, so that it can be retried,
+/// otherwise `QueryError::QueryFailed`.
+///
+pub(crate) fn classify_error(
+    e: KlickhouseError,
+    connection_closed: bool,
+    context: &str,
+) -> QueryError {
+    match e {
+        KlickhouseError::Io(_) => QueryError::ConnectionFailed(format!("{}: {}", context, e)),
+        _ if connection_closed => QueryError::ConnectionFailed(format!("{}: {}", context, e)),
+        _ => QueryError::QueryFailed(format!("{}: {}", context, e)),
+    }
+}
@@ -6,10 +6,28 @@ use std::fmt;
 #[derive(Debug)]
 pub enum QueryError {
     QueryFailed(String),
+    ConnectionFailed(String),
     InvalidTableName(ValidationError),
     EmptyTableName(ValidationError),
     TableNameTooLong(ValidationError),
     TableDoesNotExist(String, String),
+    InvalidTableSchema(String, String),
+    InvalidTimeResolution(ValidationError),
+    InvalidTradeData(String),
+}
+
+impl QueryError {
+    /// Returns true if the error is transient, so that the same query may succeed when retried,
+    /// i.e. after the connection to the database failed or got lost.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, QueryError::ConnectionFailed(_))
+    }
+
+    /// Returns true if the error is permanent, so that the same query fails again when retried,
+    /// i.e. after an invalid table name or a query rejected by the database.
+    pub fn is_permanent(&self) -> bool {
+        !self.is_transient()
+    }
 }
 
 impl Error for QueryError {}
@@ -20,6 +38,9 @@ impl fmt::Display for QueryError {
             QueryError::QueryFailed(e) =>
                 write!(f, "Query to DB failed: {e}"),
 
+            QueryError::ConnectionFailed(e) =>
+                write!(f, "Connection to DB failed: {e}"),
+
             QueryError::InvalidTableName(e) =>
                 write!(f, "Invalid table name provided: Only use alphanumeric characters and underscores as table name. Error: {e}"),
 
@@ -31,6 +52,15 @@ impl fmt::Display for QueryError {
 
             QueryError::TableDoesNotExist(table_name, e) =>
                 write!(f, "Table does not exist: Table {table_name} does not exist. Error: {e}"),
+
+            QueryError::InvalidTableSchema(table_name, e) =>
+                write!(f, "Invalid table schema: Table {table_name} must have a timestamp column and price and volume columns of floats. Error: {e}"),
+
+            QueryError::InvalidTimeResolution(e) =>
+                write!(f, "Invalid time resolution provided: Time resolution must be at least one unit of a time scale. Error: {e}"),
+
+            QueryError::InvalidTradeData(e) =>
+                write!(f, "Invalid trade data: Trades must have a timestamp, price, volume, and optional side. Error: {e}"),
         }
     }
 }
@@ -0,0 +1,506 @@
+use crate::error::QueryError;
+use crate::in_memory_resample::resample_ohlcv;
+use crate::query_utils::{sanitize_table_name, validate_time_resolution};
+use crate::storage_backend::StorageBackend;
+use crate::types::{ColumnType, OHLCVRow, TableColumn, TradeRow};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use common::prelude::{OHLCVBar, TimeResolution, TradeBar};
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// A trade of an in-memory trade table.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MemoryTrade {
+    row: TradeRow,
+    price: f64,
+    volume: f64,
+    // Some(true) for a buy, Some(false) for a sell, None if the side is unknown.
+    is_buy: Option<bool>,
+}
+
+impl MemoryTrade {
+    fn new(date_time: DateTime<Utc>, price: f64, volume: f64, is_buy: Option<bool>) -> Self {
+        Self {
+            row: TradeRow::new(date_time, price, volume),
+            price,
+            volume,
+            is_buy,
+        }
+    }
+}
+
+impl MemoryTrade {
+    pub(crate) fn millis(&self) -> i64 {
+        self.row.date_time().timestamp_millis()
+    }
+    pub(crate) fn price(&self) -> f64 {
+        self.price
+    }
+    pub(crate) fn volume(&self) -> f64 {
+        self.volume
+    }
+    pub(crate) fn is_buy(&self) -> Option<bool> {
+        self.is_buy
+    }
+}
+
+// The trades of one symbol, ordered by timestamp.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TradeTable {
+    trades: Vec<MemoryTrade>,
+    with_side: bool,
+}
+
+/// A StorageBackend that serves symbols and trades from memory.
+///
+/// The backend is seeded with trade bars or with trades from CSV files in the format of
+/// the Kraken trade files, and resamples the trades to OHLCV bars the same way ClickHouse does.
+/// That way, code using the QueryDBManager can be tested without a database.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use common::prelude::TradeBar;
+/// use db_query_manager::{InMemoryBackend, QueryDBManager};
+/// use rust_decimal::Decimal;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let trade = TradeBar::new(1, Utc.timestamp_opt(1_700_000_000, 0).unwrap(), Decimal::ONE, Decimal::TEN);
+///
+///     let backend = InMemoryBackend::new()
+///         .with_symbols("kraken_symbols", vec![(1, "ethaed".to_string())])
+///         .with_trades("kraken_ethaed", vec![trade])
+///         .expect("Failed to add trades");
+///
+///     let query_manager = QueryDBManager::from_backend(backend);
+///     let trades = query_manager.get_all_trades(1, "kraken_ethaed").await.unwrap();
+///     assert_eq!(trades.len(), 1);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InMemoryBackend {
+    symbol_tables: HashMap<String, Vec<(u16, String)>>,
+    trade_tables: HashMap<String, TradeTable>,
+}
+
+impl InMemoryBackend {
+    /// Creates a new InMemoryBackend without any tables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a symbol table with the given (symbol_id, symbol) pairs, replacing any table of the same name.
+    pub fn with_symbols(mut self, symbol_table: &str, symbols: Vec<(u16, String)>) -> Self {
+        self.symbol_tables.insert(symbol_table.to_string(), symbols);
+        self
+    }
+
+    /// Adds a trade table with the given trades, replacing any table of the same name.
+    ///
+    /// The symbol ID and sequence number of the trade bars are not stored; queries return
+    /// the trades with the symbol ID of the query, as ClickHouse does. The trades are ordered by
+    /// timestamp, and trades with the same timestamp keep their order.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::InvalidTradeData` if a trade is before the unix epoch,
+    ///   or if its price or volume does not convert to a finite float
+    ///
+    pub fn with_trades(
+        mut self,
+        trade_table: &str,
+        trades: Vec<TradeBar>,
+    ) -> Result<Self, QueryError> {
+        let mut memory_trades = Vec::with_capacity(trades.len());
+
+        for (idx, trade) in trades.iter().enumerate() {
+            let memory_trade = match memory_trade(trade) {
+                Ok(memory_trade) => memory_trade,
+                Err(e) => {
+                    return Err(QueryError::InvalidTradeData(format!(
+                        "Trade {}: {}",
+                        idx + 1,
+                        e
+                    )))
+                }
+            };
+
+            memory_trades.push(memory_trade);
+        }
+
+        memory_trades.sort_by_key(|trade| trade.millis());
+
+        let table = TradeTable {
+            trades: memory_trades,
+            with_side: false,
+        };
+
+        self.trade_tables.insert(trade_table.to_string(), table);
+        Ok(self)
+    }
+
+    /// Adds a trade table with the trades of a CSV file, replacing any table of the same name.
+    ///
+    /// See `with_trades_from_csv_reader` for the format of the file.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::InvalidTradeData` if the file cannot be read or holds an invalid trade
+    ///
+    pub fn with_trades_from_csv(
+        self,
+        trade_table: &str,
+        csv_path: impl AsRef<Path>,
+    ) -> Result<Self, QueryError> {
+        let csv_path = csv_path.as_ref();
+
+        let file = match File::open(csv_path) {
+            Ok(file) => file,
+            Err(e) => {
+                return Err(QueryError::InvalidTradeData(format!(
+                    "Failed to open {}: {}",
+                    csv_path.display(),
+                    e
+                )))
+            }
+        };
+
+        self.with_trades_from_csv_reader(trade_table, file)
+    }
+
+    /// Adds a trade table with the trades read from CSV data, replacing any table of the same name.
+    ///
+    /// The data has no header and one trade per line, in the format of the Kraken trade files:
+    /// the timestamp in seconds since the unix epoch,
+    /// the price, the volume, and optionally the side of the trade, 'b' for buy and 's' for sell.
+    /// The table has a side column if the first trade has a side.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::InvalidTradeData` if the data cannot be read or holds an invalid trade
+    ///
+    pub fn with_trades_from_csv_reader(
+        mut self,
+        trade_table: &str,
+        reader: impl Read,
+    ) -> Result<Self, QueryError> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let mut trades = Vec::new();
+        let mut with_side = None;
+
+        for (idx, record) in csv_reader.records().enumerate() {
+            let record = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    return Err(QueryError::InvalidTradeData(format!(
+                        "Failed to read line {}: {}",
+                        idx + 1,
+                        e
+                    )))
+                }
+            };
+
+            let has_side = *with_side.get_or_insert(record.len() == 4);
+
+            let trade = match parse_trade(&record, has_side) {
+                Ok(trade) => trade,
+                Err(e) => {
+                    return Err(QueryError::InvalidTradeData(format!(
+                        "Line {}: {}",
+                        idx + 1,
+                        e
+                    )))
+                }
+            };
+
+            trades.push(trade);
+        }
+
+        trades.sort_by_key(|trade| trade.millis());
+
+        let table = TradeTable {
+            trades,
+            with_side: with_side.unwrap_or(false),
+        };
+
+        self.trade_tables.insert(trade_table.to_string(), table);
+        Ok(self)
+    }
+}
+
+fn memory_trade(trade: &TradeBar) -> Result<MemoryTrade, String> {
+    let to_f64 = |value: Decimal, name: &str| match value.to_f64() {
+        Some(float) if float.is_finite() => Ok(float),
+        _ => Err(format!("Invalid {}: {}", name, value)),
+    };
+
+    let price = to_f64(trade.price(), "price")?;
+    let volume = to_f64(trade.volume(), "volume")?;
+
+    let date_time = trade.date_time();
+    if date_time.timestamp_millis() < 0 {
+        return Err(format!("Timestamp before the unix epoch: {}", date_time));
+    }
+
+    Ok(MemoryTrade::new(date_time, price, volume, None))
+}
+
+fn parse_trade(record: &csv::StringRecord, with_side: bool) -> Result<MemoryTrade, String> {
+    let expected_len = if with_side { 4 } else { 3 };
+    if record.len() != expected_len {
+        return Err(format!(
+            "Expected {} columns, found {}",
+            expected_len,
+            record.len()
+        ));
+    }
+
+    let parse_f64 = |idx: usize, name: &str| match record[idx].parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(format!("Invalid {}: {}", name, &record[idx])),
+    };
+
+    let seconds = parse_f64(0, "timestamp")?;
+    let price = parse_f64(1, "price")?;
+    let volume = parse_f64(2, "volume")?;
+
+    if seconds < 0.0 {
+        return Err(format!("Timestamp before the unix epoch: {}", &record[0]));
+    }
+
+    let date_time = match Utc.timestamp_millis_opt((seconds * 1000.0).round() as i64) {
+        chrono::LocalResult::Single(date_time) => date_time,
+        _ => return Err(format!("Invalid timestamp: {}", &record[0])),
+    };
+
+    let is_buy = if with_side {
+        match &record[3] {
+            "b" => Some(true),
+            "s" => Some(false),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    Ok(MemoryTrade::new(date_time, price, volume, is_buy))
+}
+
+impl InMemoryBackend {
+    fn trade_table(&self, trade_table: &str) -> Result<&TradeTable, QueryError> {
+        let sanitized_name = sanitize_table_name(trade_table)?;
+
+        match self.trade_tables.get(sanitized_name) {
+            Some(table) => Ok(table),
+            None => Err(QueryError::TableDoesNotExist(
+                sanitized_name.to_string(),
+                "No trade table of that name in memory".to_string(),
+            )),
+        }
+    }
+
+    // Returns the trades of the table in the time range [start_time, end_time).
+    fn trades_in_range(
+        &self,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> Result<(&[MemoryTrade], bool), QueryError> {
+        let table = self.trade_table(trade_table)?;
+
+        let trades = &table.trades;
+        let start = match start_time {
+            Some(start_time) => {
+                let start_millis = start_time.timestamp_millis();
+                trades.partition_point(|trade| trade.millis() < start_millis)
+            }
+            None => 0,
+        };
+        let end = match end_time {
+            Some(end_time) => {
+                let end_millis = end_time.timestamp_millis();
+                trades.partition_point(|trade| trade.millis() < end_millis)
+            }
+            None => trades.len(),
+        };
+
+        Ok((&trades[start..end.max(start)], table.with_side))
+    }
+
+    fn ohlcv_rows(
+        &self,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> Result<Vec<OHLCVRow>, QueryError> {
+        let time_resolution = validate_time_resolution(time_resolution)?;
+        let (trades, with_side) = self.trades_in_range(trade_table, start_time, end_time)?;
+
+        resample_ohlcv(trades, time_resolution, with_side)
+    }
+}
+
+#[async_trait]
+impl StorageBackend for InMemoryBackend {
+    async fn is_open(&self) -> bool {
+        true
+    }
+
+    async fn get_all_symbols_with_ids(
+        &self,
+        symbol_table: &str,
+    ) -> Result<Vec<(u16, String)>, QueryError> {
+        let sanitized_name = match sanitize_table_name(symbol_table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        match self.symbol_tables.get(sanitized_name) {
+            Some(symbols) => Ok(symbols.clone()),
+            None => Err(QueryError::TableDoesNotExist(
+                sanitized_name.to_string(),
+                "No symbol table of that name in memory".to_string(),
+            )),
+        }
+    }
+
+    async fn get_table_columns(&self, table: &str) -> Result<Vec<TableColumn>, QueryError> {
+        let sanitized_name = match sanitize_table_name(table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        if self.symbol_tables.contains_key(sanitized_name) {
+            return Ok(vec![
+                TableColumn::new("symbol_id", ColumnType::Other("UInt16".to_string())),
+                TableColumn::new("symbol", ColumnType::Text),
+            ]);
+        }
+
+        let table = match self.trade_tables.get(sanitized_name) {
+            Some(table) => table,
+            None => {
+                return Err(QueryError::TableDoesNotExist(
+                    sanitized_name.to_string(),
+                    "No table of that name in memory".to_string(),
+                ))
+            }
+        };
+
+        let mut columns = vec![
+            TableColumn::new("timestamp", ColumnType::Timestamp),
+            TableColumn::new("price", ColumnType::Float),
+            TableColumn::new("volume", ColumnType::Float),
+        ];
+        if table.with_side {
+            columns.push(TableColumn::new("side", ColumnType::Text));
+        }
+
+        Ok(columns)
+    }
+
+    async fn get_all_trades(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+    ) -> Result<Vec<TradeBar>, QueryError> {
+        let (trades, _) = match self.trades_in_range(trade_table, None, None) {
+            Ok(trades) => trades,
+            Err(e) => return Err(e),
+        };
+
+        let trades = trades
+            .iter()
+            .map(|trade| {
+                TradeBar::new(
+                    symbol_id,
+                    trade.row.date_time(),
+                    trade.row.price(),
+                    trade.row.volume(),
+                )
+            })
+            .collect();
+
+        Ok(trades)
+    }
+
+    async fn get_all_ohlcv_bars(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+    ) -> Result<Vec<OHLCVBar>, QueryError> {
+        let rows = match self.ohlcv_rows(trade_table, time_resolution, None, None) {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        let bars = rows
+            .iter()
+            .map(|row| {
+                let bar = OHLCVBar::new(
+                    symbol_id,
+                    row.date_time(),
+                    row.open(),
+                    row.high(),
+                    row.low(),
+                    row.close(),
+                    row.volume(),
+                )
+                .with_vwap(row.vwap())
+                .with_trade_count(row.trade_count());
+
+                match (row.buy_volume(), row.sell_volume()) {
+                    (Some(buy_volume), Some(sell_volume)) => {
+                        bar.with_buy_sell_volume(buy_volume, sell_volume)
+                    }
+                    _ => bar,
+                }
+            })
+            .collect();
+
+        Ok(bars)
+    }
+
+    async fn stream_trades<'a>(
+        &'a self,
+        trade_table: &'a str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<TradeRow, QueryError>> {
+        match self.trades_in_range(trade_table, start_time, end_time) {
+            Ok((trades, _)) => {
+                stream::iter(trades.iter().map(|trade| Ok(trade.row.clone()))).boxed()
+            }
+            Err(e) => stream::once(async { Err(e) }).boxed(),
+        }
+    }
+
+    async fn stream_ohlcv<'a>(
+        &'a self,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<OHLCVRow, QueryError>> {
+        match self.ohlcv_rows(trade_table, time_resolution, start_time, end_time) {
+            Ok(rows) => stream::iter(rows.into_iter().map(Ok)).boxed(),
+            Err(e) => stream::once(async { Err(e) }).boxed(),
+        }
+    }
+}
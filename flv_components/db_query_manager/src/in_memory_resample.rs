@@ -0,0 +1,163 @@
+use crate::error::QueryError;
+use crate::in_memory_backend::MemoryTrade;
+use crate::types::OHLCVRow;
+use chrono::{Datelike, TimeZone, Utc};
+use common::prelude::{TimeResolution, TimeScale};
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+// The unix epoch was a Thursday, so the first Monday is day 4.
+const FIRST_MONDAY: i64 = 4;
+
+/// Resamples trades ordered by timestamp to OHLCV bars, the same way the ClickHouse query does.
+///
+/// # Arguments
+///
+/// * `trades` - The trades to resample, ordered by timestamp
+/// * `time_resolution` - The validated time resolution of the bars
+/// * `with_side` - Whether the trades carry their side to split the volume into buy and sell volume
+///
+/// # Returns
+///
+/// The OHLCV bars ordered by their start time. A bar holds the trades of one interval;
+/// intervals without trades have no bar.
+///
+/// # Errors
+///
+/// - `QueryError::QueryFailed` if the calendar interval of a trade is out of the date range
+///
+pub(crate) fn resample_ohlcv(
+    trades: &[MemoryTrade],
+    time_resolution: &TimeResolution,
+    with_side: bool,
+) -> Result<Vec<OHLCVRow>, QueryError> {
+    let mut bars = Vec::new();
+
+    let mut start = 0;
+    while start < trades.len() {
+        let interval_start = interval_start_millis(trades[start].millis(), time_resolution)?;
+
+        // The trades of one interval are adjacent because the trades are ordered by timestamp.
+        let mut end = start + 1;
+        while end < trades.len()
+            && interval_start_millis(trades[end].millis(), time_resolution)? == interval_start
+        {
+            end += 1;
+        }
+
+        bars.push(build_bar(interval_start, &trades[start..end], with_side));
+        start = end;
+    }
+
+    Ok(bars)
+}
+
+fn build_bar(interval_start: i64, trades: &[MemoryTrade], with_side: bool) -> OHLCVRow {
+    let open = trades[0].price();
+    let close = trades[trades.len() - 1].price();
+
+    let mut high = f64::MIN;
+    let mut low = f64::MAX;
+    let mut volume = 0.0;
+    let mut notional = 0.0;
+    let mut buy_volume = 0.0;
+    let mut sell_volume = 0.0;
+
+    for trade in trades {
+        high = high.max(trade.price());
+        low = low.min(trade.price());
+        volume += trade.volume();
+        notional += trade.price() * trade.volume();
+
+        match trade.is_buy() {
+            Some(true) => buy_volume += trade.volume(),
+            Some(false) => sell_volume += trade.volume(),
+            None => {}
+        }
+    }
+
+    let vwap = if volume > 0.0 {
+        notional / volume
+    } else {
+        close
+    };
+
+    let bar = OHLCVRow::new(interval_start, open, high, low, close, volume)
+        .with_vwap(vwap)
+        .with_trade_count(trades.len() as u64);
+
+    if with_side {
+        bar.with_buy_sell_volume(buy_volume, sell_volume)
+    } else {
+        bar
+    }
+}
+
+/// Returns the start of the interval of the time resolution that holds the given time.
+///
+/// Intervals of a fixed length are aligned to the unix epoch, weeks start on Monday,
+/// and months, quarters, and years are counted in calendar months since January 1970.
+///
+/// # Arguments
+///
+/// * `millis` - The time in milliseconds since the unix epoch
+/// * `time_resolution` - The validated time resolution
+///
+/// # Errors
+///
+/// - `QueryError::QueryFailed` if a calendar interval starts out of the date range
+///
+pub(crate) fn interval_start_millis(
+    millis: i64,
+    time_resolution: &TimeResolution,
+) -> Result<i64, QueryError> {
+    let count = time_resolution.count().max(1) as i64;
+
+    let fixed_millis = |unit_millis: i64| {
+        let interval = count * unit_millis;
+        millis.div_euclid(interval) * interval
+    };
+
+    match time_resolution.scale() {
+        TimeScale::NoScale | TimeScale::Millisecond => Ok(fixed_millis(1)),
+        TimeScale::Second => Ok(fixed_millis(1_000)),
+        TimeScale::Minute => Ok(fixed_millis(60_000)),
+        TimeScale::Hour => Ok(fixed_millis(3_600_000)),
+        TimeScale::Day => Ok(fixed_millis(MILLIS_PER_DAY)),
+        TimeScale::Week => {
+            let interval = count * 7;
+            let day = millis.div_euclid(MILLIS_PER_DAY);
+            let week_start = FIRST_MONDAY + (day - FIRST_MONDAY).div_euclid(interval) * interval;
+            Ok(week_start * MILLIS_PER_DAY)
+        }
+        TimeScale::Month => calendar_months_start(millis, count),
+        TimeScale::Quarter => calendar_months_start(millis, count * 3),
+        TimeScale::Year => calendar_months_start(millis, count * 12),
+    }
+}
+
+fn calendar_months_start(millis: i64, months: i64) -> Result<i64, QueryError> {
+    let date_time = match Utc.timestamp_millis_opt(millis).single() {
+        Some(date_time) => date_time,
+        None => {
+            return Err(QueryError::QueryFailed(format!(
+                "Invalid trade timestamp: {} milliseconds",
+                millis
+            )))
+        }
+    };
+
+    let month_index = (date_time.year() as i64 - 1970) * 12 + date_time.month0() as i64;
+    let start_index = month_index.div_euclid(months) * months;
+
+    let year = 1970 + start_index.div_euclid(12) as i32;
+    let month = start_index.rem_euclid(12) as u32 + 1;
+
+    match Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single() {
+        Some(start) => Ok(start.timestamp_millis()),
+        None => Err(QueryError::QueryFailed(format!(
+            "Interval of {} months starts out of range: {}-{:02}",
+            months, year, month
+        ))),
+    }
+}
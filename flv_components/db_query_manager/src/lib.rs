@@ -1,25 +1,65 @@
+mod backoff;
+mod catalog;
+mod clickhouse_backend;
+mod clickhouse_pool;
 pub mod error;
+mod in_memory_backend;
+mod in_memory_resample;
+mod pg_wire;
+mod query_catalog;
+mod query_coverage;
 mod query_gen;
 mod query_ohlcv;
 mod query_symbols;
 mod query_trades;
 mod query_utils;
+mod questdb_backend;
+mod questdb_query_gen;
+mod storage_backend;
+mod stream_merge;
+mod stream_merged;
 mod stream_ohlcv;
 mod stream_trades;
 pub mod types;
 
-use common::prelude::ClickHouseConfig;
-use klickhouse::{Client, ClientOptions};
-use std::fmt::Error;
+pub use clickhouse_backend::ClickHouseBackend;
+pub use in_memory_backend::InMemoryBackend;
+pub use questdb_backend::QuestDBBackend;
+pub use storage_backend::StorageBackend;
+pub use stream_merge::merge_streams_by_time;
+
+use crate::catalog::TableCatalog;
+use crate::error::QueryError;
+use crate::types::{ColumnType, OHLCVRow, SymbolCoverage, TableColumn, TradeRow};
+use chrono::{DateTime, Utc};
+use common::prelude::{ClickHouseConfig, DBBackend, DBConfig, OHLCVBar, TimeResolution, TradeBar};
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use std::time::Duration;
 
 const FN_NAME: &str = "[QueryDBManager]:";
 
+// Time a table lookup stays in the catalog unless configured otherwise.
+const DEFAULT_CATALOG_TTL: Duration = Duration::from_secs(60);
+
+// The columns every trade table must have, with their types.
+const TRADE_TABLE_COLUMNS: [(&str, ColumnType); 3] = [
+    ("timestamp", ColumnType::Timestamp),
+    ("price", ColumnType::Float),
+    ("volume", ColumnType::Float),
+];
+
+/// Queries symbols, trades, and OHLCV bars from the trade database of a storage backend.
+///
+/// Before a stream starts, its trade table gets validated against the catalog of the database,
+/// which the QueryDBManager caches for a time to live.
 pub struct QueryDBManager {
-    client: Client,
+    backend: Box<dyn StorageBackend>,
+    catalog: TableCatalog,
 }
 
 impl QueryDBManager {
-    /// Creates a new QueryDBManager instance.
+    /// Creates a new QueryDBManager instance with the default ClickHouse backend.
     ///
     /// # Arguments
     ///
@@ -31,22 +71,322 @@ impl QueryDBManager {
     ///
     /// # Errors
     ///
-    /// Will return an error if the connection to the database fails.
+    /// - `QueryError::ConnectionFailed` if the connection to the database still fails after retrying with backoff.
+    ///
+    pub async fn new(db_config: ClickHouseConfig) -> Result<Self, QueryError> {
+        let backend = match ClickHouseBackend::new(db_config).await {
+            Ok(backend) => backend,
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self::from_backend(backend))
+    }
+
+    /// Creates a new QueryDBManager instance with the selected database backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_backend` - The database backend to query, usually configured by the ConfigManager
+    /// * `db_config` - The connection parameters of ClickHouse
+    /// * `pg_db_config` - The PostgreSQL wire connection parameters of QuestDB
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ConnectionFailed` if the connection to the database fails.
+    ///
+    pub async fn with_db_backend(
+        db_backend: DBBackend,
+        db_config: ClickHouseConfig,
+        pg_db_config: DBConfig,
+    ) -> Result<Self, QueryError> {
+        match db_backend {
+            DBBackend::ClickHouse => Self::new(db_config).await,
+            DBBackend::QuestDB => match QuestDBBackend::new(pg_db_config).await {
+                Ok(backend) => Ok(Self::from_backend(backend)),
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    /// Creates a new QueryDBManager instance that queries the given storage backend.
     ///
     /// # Example
     ///
-    pub async fn new(db_config: ClickHouseConfig) -> Result<Self, Error> {
-        let destination = db_config.connection_string();
-        let client = Client::connect(destination.clone(), ClientOptions::default())
-            .await
-            .expect(format!("{} Failed to connect to {}", FN_NAME, &destination).as_str());
+    /// ```
+    /// use db_query_manager::{InMemoryBackend, QueryDBManager};
+    ///
+    /// let backend = InMemoryBackend::new().with_symbols("kraken_symbols", vec![(1, "ethaed".to_string())]);
+    /// let query_manager = QueryDBManager::from_backend(backend);
+    /// ```
+    pub fn from_backend(backend: impl StorageBackend + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+            catalog: TableCatalog::new(DEFAULT_CATALOG_TTL),
+        }
+    }
 
-        Ok(Self { client })
+    /// Sets the time a table lookup stays in the catalog cache. The default is 60 seconds.
+    pub fn with_catalog_ttl(mut self, catalog_ttl: Duration) -> Self {
+        self.catalog = TableCatalog::new(catalog_ttl);
+        self
+    }
+}
+
+impl QueryDBManager {
+    /// Retrieves the columns of the given table from the catalog of the database.
+    ///
+    /// Lookups are cached for the time to live of the catalog, including the lookups of tables
+    /// that do not exist. Failed lookups are not cached.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::TableDoesNotExist` if the database has no table of that name
+    /// - Any error of the lookup in the database
+    ///
+    pub async fn get_table_columns(&self, table: &str) -> Result<Vec<TableColumn>, QueryError> {
+        match self.catalog.get(table) {
+            Some(Some(columns)) => return Ok(columns),
+            Some(None) => {
+                return Err(QueryError::TableDoesNotExist(
+                    table.to_string(),
+                    "No table of that name in the catalog".to_string(),
+                ))
+            }
+            None => {}
+        }
+
+        match self.backend.get_table_columns(table).await {
+            Ok(columns) => {
+                self.catalog.insert(table, Some(columns.clone()));
+                Ok(columns)
+            }
+            Err(QueryError::TableDoesNotExist(table_name, e)) => {
+                self.catalog.insert(table, None);
+                Err(QueryError::TableDoesNotExist(table_name, e))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Validates that the given trade table exists and has timestamp, price, and volume columns
+    /// of the expected types.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::TableDoesNotExist` if the database has no table of that name
+    /// - `QueryError::InvalidTableSchema` if a column is missing or has another type
+    /// - Any error of the lookup in the database
+    ///
+    pub async fn validate_trade_table(&self, trade_table: &str) -> Result<(), QueryError> {
+        let columns = match self.get_table_columns(trade_table).await {
+            Ok(columns) => columns,
+            Err(e) => return Err(e),
+        };
+
+        for (name, expected_type) in TRADE_TABLE_COLUMNS.iter() {
+            match columns.iter().find(|column| column.name() == *name) {
+                Some(column) if column.column_type() == expected_type => {}
+                Some(column) => {
+                    return Err(QueryError::InvalidTableSchema(
+                        trade_table.to_string(),
+                        format!(
+                            "Column {} has type {:?}, expected {:?}",
+                            name,
+                            column.column_type(),
+                            expected_type
+                        ),
+                    ))
+                }
+                None => {
+                    return Err(QueryError::InvalidTableSchema(
+                        trade_table.to_string(),
+                        format!("Column {} is missing", name),
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes all table lookups from the catalog cache, so that the next stream of each table
+    /// looks up the catalog of the database again.
+    pub fn clear_catalog(&self) {
+        self.catalog.clear();
     }
 }
 
 impl QueryDBManager {
     pub async fn is_open(&self) -> bool {
-        !self.client.is_closed()
+        self.backend.is_open().await
+    }
+
+    /// Retrieves all symbols and their IDs from the given symbol table.
+    ///
+    /// See `ClickHouseBackend` for the errors of the default backend.
+    pub async fn get_all_symbols_with_ids(
+        &self,
+        symbol_table: &str,
+    ) -> Result<Vec<(u16, String)>, QueryError> {
+        self.backend.get_all_symbols_with_ids(symbol_table).await
+    }
+
+    /// Retrieves all trade bars for the given symbol table.
+    pub async fn get_all_trades(
+        &self,
+        symbol_id: u16,
+        symbol_table: &str,
+    ) -> Result<Vec<TradeBar>, QueryError> {
+        self.backend.get_all_trades(symbol_id, symbol_table).await
+    }
+
+    /// Retrieves all OHLCV data bars for the given symbol table and time resolution.
+    pub async fn get_all_ohlcv_bars(
+        &self,
+        symbol_id: u16,
+        symbol_table: &str,
+        time_resolution: &TimeResolution,
+    ) -> Result<Vec<OHLCVBar>, QueryError> {
+        self.backend
+            .get_all_ohlcv_bars(symbol_id, symbol_table, time_resolution)
+            .await
+    }
+
+    /// Computes the time coverage of the trades of a symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - The ID of the symbol
+    /// * `trade_table` - The trade table of the symbol
+    /// * `min_gap` - Only gaps between consecutive trades longer than this threshold are recorded
+    ///
+    /// # Returns
+    ///
+    /// The first and last trade, the number of trades, and the gaps of the whole trade table.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::TableDoesNotExist` if the trade table does not exist
+    /// - `QueryError::InvalidTableSchema` if the trade table has an unexpected schema
+    /// - Any error of the query in the database
+    ///
+    pub async fn get_symbol_coverage(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        min_gap: Duration,
+    ) -> Result<SymbolCoverage, QueryError> {
+        match self.validate_trade_table(trade_table).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        self.backend
+            .get_symbol_coverage(symbol_id, trade_table, None, min_gap)
+            .await
+    }
+
+    /// Refreshes the time coverage of a symbol with the trades imported after its last trade.
+    ///
+    /// Only the trades after the last trade of the coverage are queried, so a refresh after
+    /// an import costs as much as the import, not as much as the whole trade table.
+    /// Trades imported with timestamps at or before the last trade are not picked up;
+    /// compute the coverage with `get_symbol_coverage` again after such an import.
+    ///
+    /// # Arguments
+    ///
+    /// * `coverage` - The coverage to refresh
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::TableDoesNotExist` if the trade table does not exist anymore
+    /// - `QueryError::InvalidTableSchema` if the trade table has an unexpected schema
+    /// - Any error of the query in the database
+    ///
+    pub async fn refresh_symbol_coverage(
+        &self,
+        coverage: SymbolCoverage,
+    ) -> Result<SymbolCoverage, QueryError> {
+        match self.validate_trade_table(coverage.trade_table()).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Trades are stored in milliseconds, so the next possible trade is one millisecond later
+        let start_time = coverage
+            .last_trade()
+            .map(|last_trade| last_trade + chrono::Duration::milliseconds(1));
+
+        let later = match self
+            .backend
+            .get_symbol_coverage(
+                coverage.symbol_id(),
+                coverage.trade_table(),
+                start_time,
+                coverage.min_gap(),
+            )
+            .await
+        {
+            Ok(later) => later,
+            Err(e) => return Err(e),
+        };
+
+        Ok(coverage.append(later))
+    }
+
+    /// Streams the trades of the given trade table.
+    ///
+    /// The stream yields a `QueryError` as its only item if the trade table fails validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_table` - The name of the trade table to stream
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first trade.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last trade.
+    ///
+    pub async fn stream_trades<'a>(
+        &'a self,
+        trade_table: &'a str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<TradeRow, QueryError>> {
+        // Fail before the first trade if the trade table is missing or has an unexpected schema.
+        match self.validate_trade_table(trade_table).await {
+            Ok(_) => {}
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        }
+
+        self.backend
+            .stream_trades(trade_table, start_time, end_time)
+            .await
+    }
+
+    /// Streams the OHLCV bars of the given trade table.
+    ///
+    /// The stream yields a `QueryError` as its only item if the trade table fails validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_table` - The name of the trade table to resample and stream
+    /// * `time_resolution` - The time resolution of the OHLCV bars
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first bar.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last bar.
+    ///
+    pub async fn stream_ohlcv<'a>(
+        &'a self,
+        symbol_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<OHLCVRow, QueryError>> {
+        // Fail before the first bar if the trade table is missing or has an unexpected schema.
+        match self.validate_trade_table(symbol_table).await {
+            Ok(_) => {}
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        }
+
+        self.backend
+            .stream_ohlcv(symbol_table, time_resolution, start_time, end_time)
+            .await
     }
 }
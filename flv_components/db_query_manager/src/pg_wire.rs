@@ -0,0 +1,430 @@
+use crate::backoff::retry_with_backoff;
+use crate::error::QueryError;
+use common::prelude::DBConfig;
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use std::collections::VecDeque;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+// Version 3.0 of the PostgreSQL wire protocol.
+const PROTOCOL_VERSION: i32 = 196_608;
+
+// Authentication request codes of the server.
+const AUTH_OK: i32 = 0;
+const AUTH_CLEARTEXT_PASSWORD: i32 = 3;
+
+/// A row of a query result, with each value in text format; `None` for SQL NULL.
+pub(crate) type PgRow = Vec<Option<String>>;
+
+/// A minimal client of the PostgreSQL wire protocol, as spoken by QuestDB.
+///
+/// Supports cleartext password authentication, the simple query protocol, and
+/// cursors over the unnamed portal of the extended query protocol.
+/// All values are transferred in text format.
+pub(crate) struct PgConnection {
+    stream: BufReader<TcpStream>,
+    closed: bool,
+}
+
+impl PgConnection {
+    /// Connects and authenticates to the PostgreSQL wire endpoint of the configured database.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ConnectionFailed` if the server cannot be reached, rejects the credentials,
+    ///   or asks for another authentication method than a cleartext password
+    ///
+    pub(crate) async fn connect(db_config: &DBConfig) -> Result<Self, QueryError> {
+        let address = format!("{}:{}", db_config.host(), db_config.pg_port());
+
+        let stream = match TcpStream::connect(&address).await {
+            Ok(stream) => stream,
+            Err(e) => {
+                return Err(QueryError::ConnectionFailed(format!(
+                    "Failed to connect to {}: {}",
+                    address, e
+                )))
+            }
+        };
+
+        let mut connection = Self {
+            stream: BufReader::new(stream),
+            closed: false,
+        };
+
+        match connection.startup(db_config).await {
+            Ok(_) => Ok(connection),
+            Err(QueryError::QueryFailed(e)) => Err(QueryError::ConnectionFailed(e)),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    async fn startup(&mut self, db_config: &DBConfig) -> Result<(), QueryError> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&PROTOCOL_VERSION.to_be_bytes());
+        for (key, value) in [
+            ("user", db_config.pg_user()),
+            ("database", db_config.pg_database()),
+        ] {
+            put_cstr(&mut body, key);
+            put_cstr(&mut body, value);
+        }
+        body.push(0);
+
+        // The startup message is the only message without a type byte.
+        let mut message = Vec::with_capacity(body.len() + 4);
+        message.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+        message.extend_from_slice(&body);
+        self.write(&message).await?;
+
+        loop {
+            let (tag, body) = self.read_message().await?;
+            match tag {
+                b'R' => match read_i32(&body, 0) {
+                    Some(AUTH_OK) => {}
+                    Some(AUTH_CLEARTEXT_PASSWORD) => {
+                        let mut password = Vec::new();
+                        put_cstr(&mut password, db_config.pg_password());
+                        self.write(&frame(b'p', &password)).await?;
+                    }
+                    code => {
+                        self.closed = true;
+                        return Err(QueryError::ConnectionFailed(format!(
+                            "Unsupported authentication method {:?}, only cleartext passwords are supported",
+                            code
+                        )));
+                    }
+                },
+                b'E' => {
+                    self.closed = true;
+                    return Err(QueryError::ConnectionFailed(error_message(&body)));
+                }
+                b'Z' => return Ok(()),
+                // Parameter status, backend key data, and notices
+                _ => {}
+            }
+        }
+    }
+
+    /// Runs a query with the simple query protocol and collects all rows of its result.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::QueryFailed` if the server rejects the query
+    /// - `QueryError::ConnectionFailed` if the connection fails
+    ///
+    pub(crate) async fn simple_query(&mut self, query: &str) -> Result<Vec<PgRow>, QueryError> {
+        let mut body = Vec::new();
+        put_cstr(&mut body, query);
+        self.write(&frame(b'Q', &body)).await?;
+
+        let mut rows = Vec::new();
+        let mut error = None;
+
+        loop {
+            let (tag, body) = self.read_message().await?;
+            match tag {
+                b'D' => rows.push(parse_data_row(&body)?),
+                b'E' => error = Some(error_message(&body)),
+                b'Z' => break,
+                // Row description, command complete, empty query, and notices
+                _ => {}
+            }
+        }
+
+        match error {
+            Some(e) => Err(QueryError::QueryFailed(e)),
+            None => Ok(rows),
+        }
+    }
+
+    /// Opens a cursor over the result of the query on the unnamed portal.
+    ///
+    /// The rows of the cursor are fetched with `fetch`; the server reports any error
+    /// of the query on the first fetch.
+    pub(crate) async fn open_cursor(&mut self, query: &str) -> Result<(), QueryError> {
+        // Parse the query into the unnamed statement, without parameter types.
+        let mut parse = Vec::new();
+        put_cstr(&mut parse, "");
+        put_cstr(&mut parse, query);
+        parse.extend_from_slice(&0_i16.to_be_bytes());
+
+        // Bind the unnamed statement to the unnamed portal, without parameters, all results as text.
+        let mut bind = Vec::new();
+        put_cstr(&mut bind, "");
+        put_cstr(&mut bind, "");
+        bind.extend_from_slice(&0_i16.to_be_bytes());
+        bind.extend_from_slice(&0_i16.to_be_bytes());
+        bind.extend_from_slice(&0_i16.to_be_bytes());
+
+        let mut message = frame(b'P', &parse);
+        message.extend_from_slice(&frame(b'B', &bind));
+        self.write(&message).await
+    }
+
+    /// Fetches the next rows of the cursor opened with `open_cursor`.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_rows` - The maximum number of rows to fetch
+    ///
+    /// # Returns
+    ///
+    /// The fetched rows, and whether the cursor has more rows. Once the cursor has no more rows,
+    /// the connection is ready for the next query.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::QueryFailed` if the server rejects the query
+    /// - `QueryError::ConnectionFailed` if the connection fails
+    ///
+    pub(crate) async fn fetch(
+        &mut self,
+        max_rows: i32,
+    ) -> Result<(VecDeque<PgRow>, bool), QueryError> {
+        let mut execute = Vec::new();
+        put_cstr(&mut execute, "");
+        execute.extend_from_slice(&max_rows.to_be_bytes());
+
+        let mut message = frame(b'E', &execute);
+        message.extend_from_slice(&frame(b'H', &[]));
+        self.write(&message).await?;
+
+        let mut rows = VecDeque::new();
+
+        loop {
+            let (tag, body) = self.read_message().await?;
+            match tag {
+                b'D' => rows.push_back(parse_data_row(&body)?),
+                // Portal suspended, the cursor has more rows.
+                b's' => return Ok((rows, true)),
+                // Command complete or empty query, the cursor has no more rows.
+                b'C' | b'I' => {
+                    self.sync().await?;
+                    return Ok((rows, false));
+                }
+                b'E' => {
+                    let e = error_message(&body);
+                    self.sync().await?;
+                    return Err(QueryError::QueryFailed(e));
+                }
+                // Parse complete, bind complete, row description, and notices
+                _ => {}
+            }
+        }
+    }
+
+    // Ends the extended query and waits until the server is ready for the next query.
+    async fn sync(&mut self) -> Result<(), QueryError> {
+        self.write(&frame(b'S', &[])).await?;
+
+        loop {
+            let (tag, _) = self.read_message().await?;
+            if tag == b'Z' {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn write(&mut self, message: &[u8]) -> Result<(), QueryError> {
+        let res = match self.stream.write_all(message).await {
+            Ok(_) => self.stream.flush().await,
+            Err(e) => Err(e),
+        };
+
+        match res {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.closed = true;
+                Err(QueryError::ConnectionFailed(format!(
+                    "Failed to write to the database: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    async fn read_message(&mut self) -> Result<(u8, Vec<u8>), QueryError> {
+        let res = async {
+            let tag = self.stream.read_u8().await?;
+            let len = self.stream.read_i32().await?;
+            let mut body = vec![0; (len.max(4) - 4) as usize];
+            self.stream.read_exact(&mut body).await?;
+            Ok::<_, std::io::Error>((tag, body))
+        }
+        .await;
+
+        match res {
+            Ok(message) => Ok(message),
+            Err(e) => {
+                self.closed = true;
+                Err(QueryError::ConnectionFailed(format!(
+                    "Failed to read from the database: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+// Frames a message body with its type byte and length.
+fn frame(tag: u8, body: &[u8]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(body.len() + 5);
+    message.push(tag);
+    message.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+    message.extend_from_slice(body);
+    message
+}
+
+fn put_cstr(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(0);
+}
+
+fn read_i32(buf: &[u8], offset: usize) -> Option<i32> {
+    buf.get(offset..offset + 4)
+        .map(|bytes| i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn parse_data_row(body: &[u8]) -> Result<PgRow, QueryError> {
+    let invalid = || QueryError::QueryFailed("Invalid data row from the database".to_string());
+
+    let columns = match body.get(0..2) {
+        Some(bytes) => i16::from_be_bytes([bytes[0], bytes[1]]),
+        None => return Err(invalid()),
+    };
+
+    let mut row = Vec::with_capacity(columns.max(0) as usize);
+    let mut offset = 2;
+
+    for _ in 0..columns {
+        let len = match read_i32(body, offset) {
+            Some(len) => len,
+            None => return Err(invalid()),
+        };
+        offset += 4;
+
+        // A length of -1 marks a NULL value.
+        if len < 0 {
+            row.push(None);
+            continue;
+        }
+
+        let value = match body.get(offset..offset + len as usize) {
+            Some(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+            None => return Err(invalid()),
+        };
+        offset += len as usize;
+
+        row.push(Some(value));
+    }
+
+    Ok(row)
+}
+
+// Returns the message field of an error response, or the whole response if it has none.
+fn error_message(body: &[u8]) -> String {
+    for field in body.split(|b| *b == 0) {
+        if let Some((b'M', message)) = field.split_first() {
+            return String::from_utf8_lossy(message).into_owned();
+        }
+    }
+
+    String::from_utf8_lossy(body).into_owned()
+}
+
+// The state of a cursor stream between two polls.
+struct CursorState<F> {
+    db_config: DBConfig,
+    query: String,
+    fetch_size: i32,
+    decode: F,
+    connection: Option<PgConnection>,
+    rows: VecDeque<PgRow>,
+    done: bool,
+}
+
+/// Streams the rows of a query through a cursor on its own connection.
+///
+/// The stream connects on its first poll, retrying with backoff while the connection fails,
+/// and fetches `fetch_size` rows at a time, so that a large result never has to fit into memory.
+/// The connection is closed once the stream is dropped. A failure ends the stream after yielding the error.
+///
+/// # Parameters
+///
+/// * `db_config` - The configuration of the database to connect to
+/// * `query` - The query to stream
+/// * `fetch_size` - The number of rows to fetch at a time
+/// * `decode` - Decodes a row of the result
+///
+pub(crate) fn cursor_stream<T, F>(
+    db_config: DBConfig,
+    query: String,
+    fetch_size: i32,
+    decode: F,
+) -> BoxStream<'static, Result<T, QueryError>>
+where
+    T: Send + 'static,
+    F: Fn(&PgRow) -> Result<T, QueryError> + Send + 'static,
+{
+    let state = CursorState {
+        db_config,
+        query,
+        fetch_size,
+        decode,
+        connection: None,
+        rows: VecDeque::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(row) = state.rows.pop_front() {
+                let item = (state.decode)(&row);
+                return Some((item, state));
+            }
+
+            if state.done {
+                return None;
+            }
+
+            if state.connection.is_none() {
+                let res = retry_with_backoff(|| async {
+                    let mut connection = PgConnection::connect(&state.db_config).await?;
+                    connection.open_cursor(&state.query).await?;
+                    Ok(connection)
+                })
+                .await;
+
+                match res {
+                    Ok(connection) => state.connection = Some(connection),
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+
+            let connection = state
+                .connection
+                .as_mut()
+                .expect("Cursor without connection");
+            match connection.fetch(state.fetch_size).await {
+                Ok((rows, more)) => {
+                    state.rows = rows;
+                    state.done = !more;
+                }
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+    .boxed()
+}
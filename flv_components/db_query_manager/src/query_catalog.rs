@@ -0,0 +1,80 @@
+use crate::error::QueryError;
+use crate::query_utils::sanitize_table_name;
+use crate::types::{ColumnRow, ColumnType, CountRow, TableColumn};
+use crate::ClickHouseBackend;
+
+impl ClickHouseBackend {
+    /// Retrieves the columns of the given table from system.tables and system.columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `table` - The name of the table to look up
+    ///
+    /// # Returns
+    ///
+    /// The columns of the table in the order of the table, with their types normalized to `ColumnType`.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::TableDoesNotExist` if the current database has no table of that name
+    /// - `QueryError::QueryFailed` if the query to the DB failed.
+    /// - `QueryError::ConnectionFailed` if the connection to the DB still failed after retrying.
+    /// - `QueryError::EmptyTableName` if `table` is empty
+    /// - `QueryError::InvalidTableName` if `table` contains invalid characters
+    /// - `QueryError::TableNameTooLong` if `table` is longer than 64 characters
+    ///
+    pub(crate) async fn get_table_columns(
+        &self,
+        table: &str,
+    ) -> Result<Vec<TableColumn>, QueryError> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = match sanitize_table_name(table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        // Check that the table exists
+        let query = self.build_count_table_query(sanitized_name);
+        let count = match self.query_one::<CountRow>(&query).await {
+            Ok(row) => row.count(),
+            Err(e) => return Err(e),
+        };
+
+        if count == 0 {
+            return Err(QueryError::TableDoesNotExist(
+                sanitized_name.to_string(),
+                "No table of that name in system.tables".to_string(),
+            ));
+        }
+
+        // Look up the columns of the table
+        let query = self.build_get_table_columns_query(sanitized_name);
+        let column_rows = match self.query_collect::<ColumnRow>(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        Ok(column_rows
+            .iter()
+            .map(|row| TableColumn::new(&row.name(), column_type(&row.column_type())))
+            .collect())
+    }
+}
+
+// Normalizes a ClickHouse type, i.e. Nullable(DateTime64(3, 'UTC')) or LowCardinality(String).
+fn column_type(type_name: &str) -> ColumnType {
+    let inner = type_name
+        .trim_start_matches("Nullable(")
+        .trim_start_matches("LowCardinality(")
+        .trim_end_matches(')');
+
+    if inner.starts_with("DateTime") {
+        ColumnType::Timestamp
+    } else if inner.starts_with("Float") {
+        ColumnType::Float
+    } else if inner == "String" || inner.starts_with("FixedString") || inner.starts_with("Enum") {
+        ColumnType::Text
+    } else {
+        ColumnType::Other(type_name.to_string())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::error::QueryError;
+use crate::query_utils::sanitize_table_name;
+use crate::types::{CoverageRow, GapRow, SymbolCoverage, TradeGap};
+use crate::ClickHouseBackend;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+impl ClickHouseBackend {
+    /// Computes the time coverage of the trades of the given trade table in the database.
+    ///
+    /// # Parameters
+    ///
+    /// - `symbol_id` - The ID of the symbol of the trade table
+    /// - `trade_table` - The name of the trade table to query
+    /// - `start_time` - Optional inclusive start of the time range. `None` covers all trades.
+    /// - `min_gap` - Only gaps between consecutive trades longer than this threshold are returned
+    ///
+    /// # Returns
+    ///
+    /// The first and last trade, the number of trades, and the gaps in the time range.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::QueryFailed` if the query to the DB failed or returned an invalid timestamp.
+    /// - `QueryError::ConnectionFailed` if the connection to the DB still failed after retrying.
+    /// - `QueryError::EmptyTableName` if `table_name` is empty
+    /// - `QueryError::InvalidTableName` if `table_name` contains invalid characters
+    /// - `QueryError::TableNameTooLong` if `table_name` is longer than 64 characters
+    ///
+    pub(crate) async fn get_symbol_coverage(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+        min_gap: Duration,
+    ) -> Result<SymbolCoverage, QueryError> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = match sanitize_table_name(trade_table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        let coverage = SymbolCoverage::new(symbol_id, sanitized_name, min_gap);
+
+        // Aggregate the first and last trade and the number of trades
+        let query = self.build_get_coverage_query(sanitized_name, start_time);
+        let coverage_row = match self.query_one::<CoverageRow>(&query).await {
+            Ok(row) => row,
+            Err(e) => return Err(e),
+        };
+
+        // Without trades, min and max are undefined
+        if coverage_row.row_count() == 0 {
+            return Ok(coverage);
+        }
+
+        // Select the gaps between consecutive trades
+        let query =
+            self.build_get_gaps_query(sanitized_name, start_time, min_gap.as_millis() as u64);
+        let gap_rows = match self.query_collect::<GapRow>(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        let mut gaps = Vec::with_capacity(gap_rows.len());
+        for row in &gap_rows {
+            gaps.push(TradeGap::new(row.gap_start()?, row.gap_end()?));
+        }
+
+        Ok(coverage
+            .with_trades(
+                coverage_row.first_trade()?,
+                coverage_row.last_trade()?,
+                coverage_row.row_count(),
+            )
+            .with_gaps(gaps))
+    }
+}
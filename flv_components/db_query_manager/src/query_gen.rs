@@ -1,6 +1,7 @@
+use chrono::{DateTime, Utc};
 use common::prelude::TimeResolution;
 
-impl crate::QueryDBManager {
+impl crate::ClickHouseBackend {
     /// Builds a SQL query to get all symbol IDs and symbols from a symbol table.
     ///
     /// # Arguments
@@ -20,42 +21,228 @@ impl crate::QueryDBManager {
     /// # Arguments
     ///
     /// * `trade_table` - The name of the trade table to query
-    /// * `time_resolution` - The time resolution to resample the trades to
+    /// * `time_resolution` - The validated time resolution to resample the trades to
+    /// * `start_time` - Optional inclusive start of the time range
+    /// * `end_time` - Optional exclusive end of the time range
+    /// * `with_side` - Whether the trade table has a side column to split the volume into buy and sell volume
     ///
     /// # Returns
     ///
     /// Returns a SQL query string to retrieve OHLCV bars from the trade table resampled to the time resolution.
+    /// The start of each bar is returned in milliseconds so that sub-second time resolutions keep their precision.
+    /// Each bar includes the volume weighted average price and the number of trades. The buy and sell volume
+    /// are the volume of the trades with side 'b' and 's', or NULL if the trade table has no side column.
     ///
     pub fn build_get_ohlcv_bars_query(
         &self,
         trade_table: &str,
         time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        with_side: bool,
     ) -> String {
+        let time_filter = self.build_time_range_filter(start_time, end_time);
+
+        let side_volume = if with_side {
+            r"toNullable(sumIf(volume, side = 'b')) AS buy_volume,
+              toNullable(sumIf(volume, side = 's')) AS sell_volume"
+        } else {
+            r"CAST(NULL, 'Nullable(Float64)') AS buy_volume,
+              CAST(NULL, 'Nullable(Float64)') AS sell_volume"
+        };
+
         format!(
-            r"SELECT toUnixTimestamp(toStartOfInterval(timestamp, INTERVAL {time_resolution})) AS datetime,
+            r"SELECT toUnixTimestamp64Milli(toDateTime64(toStartOfInterval(timestamp, INTERVAL {time_resolution}), 3)) AS datetime,
               argMin(price, timestamp) AS open,
               max(price) AS high,
               min(price) AS low,
               argMax(price, timestamp) AS close,
-              sum(volume) AS volume
+              sum(volume) AS volume,
+              if(sum(volume) > 0, sum(price * volume) / sum(volume), argMax(price, timestamp)) AS vwap,
+              count() AS trade_count,
+              {side_volume}
 
-            FROM {trade_table}
+            FROM {trade_table}{time_filter}
             GROUP BY datetime
             ORDER BY datetime"
         ).to_string()
     }
 
+    /// Builds a SQL query to count the side columns of a trade table.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_table` - The name of the trade table to query
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that counts the columns named side of the trade table, either 0 or 1.
+    ///
+    pub fn build_count_side_column_query(&self, trade_table: &str) -> String {
+        format!(
+            "SELECT count() AS count FROM system.columns WHERE database = currentDatabase() AND table = '{}' AND name = 'side'",
+            trade_table
+        )
+    }
+
+    /// Builds a SQL query to count the tables of the given name in the current database.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The name of the table to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that counts the tables of that name in system.tables, either 0 or 1.
+    ///
+    pub fn build_count_table_query(&self, table: &str) -> String {
+        format!(
+            "SELECT count() AS count FROM system.tables WHERE database = currentDatabase() AND name = '{}'",
+            table
+        )
+    }
+
+    /// Builds a SQL query to get the names and types of all columns of a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The name of the table to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that lists the columns of the table in system.columns, in the order of the table.
+    ///
+    pub fn build_get_table_columns_query(&self, table: &str) -> String {
+        format!(
+            "SELECT name, type AS column_type FROM system.columns WHERE database = currentDatabase() AND table = '{}' ORDER BY position",
+            table
+        )
+    }
+
+    /// Builds a SQL query to get the first and last trade and the number of trades of a trade table.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_table` - The name of the trade table to query
+    /// * `start_time` - Optional inclusive start of the time range
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that aggregates the timestamps of the trades in the time range
+    /// to the first and last trade in milliseconds and the number of trades.
+    ///
+    pub fn build_get_coverage_query(
+        &self,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+    ) -> String {
+        let time_filter = self.build_time_range_filter(start_time, None);
+
+        format!(
+            r"SELECT toUnixTimestamp64Milli(min(timestamp)) AS first_trade,
+              toUnixTimestamp64Milli(max(timestamp)) AS last_trade,
+              count() AS row_count
+            FROM {trade_table}{time_filter}"
+        )
+    }
+
+    /// Builds a SQL query to get the gaps between consecutive trades of a trade table.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_table` - The name of the trade table to query
+    /// * `start_time` - Optional inclusive start of the time range
+    /// * `min_gap_millis` - Only gaps longer than this number of milliseconds are selected
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that pairs each trade with the trade before it
+    /// and selects the start and end in milliseconds of the gaps, ordered by time.
+    ///
+    pub fn build_get_gaps_query(
+        &self,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+        min_gap_millis: u64,
+    ) -> String {
+        let time_filter = self.build_time_range_filter(start_time, None);
+
+        format!(
+            r"SELECT toUnixTimestamp64Milli(previous) AS gap_start,
+              toUnixTimestamp64Milli(timestamp) AS gap_end
+            FROM (
+              SELECT timestamp,
+                lagInFrame(timestamp) OVER (ORDER BY timestamp ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS previous,
+                row_number() OVER (ORDER BY timestamp) AS row_number
+              FROM {trade_table}{time_filter}
+            )
+            WHERE row_number > 1 AND dateDiff('millisecond', previous, timestamp) > {min_gap_millis}
+            ORDER BY timestamp"
+        )
+    }
+
     /// Builds a SQL query to get all trades from a trade table.
     ///
     /// # Arguments
     ///
     /// * `trade_table` - The name of the trade table to query
+    /// * `start_time` - Optional inclusive start of the time range
+    /// * `end_time` - Optional exclusive end of the time range
     ///
     /// # Returns
     ///
     /// Returns a SQL query string to retrieve all timestamps, prices, and volumes from the given trade table.
     ///
-    pub fn build_get_trades_query(&self, trade_table: &str) -> String {
-        format!("SELECT timestamp, price, volume FROM {}", trade_table)
+    pub fn build_get_trades_query(
+        &self,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> String {
+        let time_filter = self.build_time_range_filter(start_time, end_time);
+
+        format!(
+            "SELECT timestamp, price, volume FROM {}{}",
+            trade_table, time_filter
+        )
+    }
+
+    /// Builds a SQL WHERE clause to restrict a query to a time range on the timestamp column.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - Optional inclusive start of the time range
+    /// * `end_time` - Optional exclusive end of the time range
+    ///
+    /// # Returns
+    ///
+    /// Returns a WHERE clause with a leading space, or an empty string if neither bound is set.
+    ///
+    pub fn build_time_range_filter(
+        &self,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> String {
+        let mut conditions = Vec::with_capacity(2);
+
+        if let Some(start_time) = start_time {
+            conditions.push(format!(
+                "timestamp >= fromUnixTimestamp64Micro({})",
+                start_time.timestamp_micros()
+            ));
+        }
+
+        if let Some(end_time) = end_time {
+            conditions.push(format!(
+                "timestamp < fromUnixTimestamp64Micro({})",
+                end_time.timestamp_micros()
+            ));
+        }
+
+        if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        }
     }
 }
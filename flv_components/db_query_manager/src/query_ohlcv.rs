@@ -1,10 +1,10 @@
 use crate::error::QueryError;
+use crate::query_utils::{sanitize_table_name, validate_time_resolution};
 use crate::types::OHLCVRow;
-use crate::QueryDBManager;
-use crate::FN_NAME;
+use crate::ClickHouseBackend;
 use common::prelude::{OHLCVBar, TimeResolution};
 
-impl QueryDBManager {
+impl ClickHouseBackend {
     /// Retrieves all OHLCV data bars for the given symbol table and time resolution.
     ///
     /// # Parameters
@@ -21,11 +21,15 @@ impl QueryDBManager {
     ///
     /// - Returns a `QueryError` if:
     ///   - The table name could not be sanitized
+    ///   - The time resolution is invalid
+    ///   - The columns of the table could not be queried
     ///   - The query failed to execute
+    ///   - The connection to the DB still failed after retrying
     ///
     /// # Remarks
     ///
     /// - Sanitizes the table name to prevent SQL injection.
+    /// - Checks whether the table has a side column to compute the buy and sell volume.
     /// - Builds a SQL query based on the parameters.
     /// - Executes the query and converts the rows to `DataBar` objects.
     /// - Returns an empty vector if there are no results.
@@ -40,7 +44,7 @@ impl QueryDBManager {
     /// async fn main() {
     /// use common::prelude::ClickHouseConfig;
     /// let db_config =  ClickHouseConfig::default();
-    ///  let mut query_manager = QueryDBManager::new(db_config).await.expect("Failed to create db connection");
+    ///  let query_manager = QueryDBManager::new(db_config).await.expect("Failed to create db connection");
     ///
     ///  let time_resolution = TimeResolution::FiveMin;
     ///  let trades = query_manager.get_all_ohlcv_bars(278, "kraken_ethaed", &time_resolution)
@@ -48,26 +52,39 @@ impl QueryDBManager {
     ///
     /// }
     /// ```
-    pub async fn get_all_ohlcv_bars(
-        &mut self,
+    pub(crate) async fn get_all_ohlcv_bars(
+        &self,
         symbol_id: u16,
         symbol_table: &str,
         time_resolution: &TimeResolution,
     ) -> Result<Vec<OHLCVBar>, QueryError> {
         // Sanitize table name input to prevent SQL injection.
-        let sanitized_name = self
-            .sanitize_table_name(symbol_table)
-            .expect("Failed to sanitize table name");
+        let sanitized_name = match sanitize_table_name(symbol_table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        // Validate the time resolution before it gets into the query.
+        let time_resolution = match validate_time_resolution(time_resolution) {
+            Ok(time_resolution) => time_resolution,
+            Err(e) => return Err(e),
+        };
+
+        // Split the volume into buy and sell volume if the trades carry their side.
+        let with_side = match self.has_side_column(sanitized_name).await {
+            Ok(with_side) => with_side,
+            Err(e) => return Err(e),
+        };
 
         // Build the query
-        let query = self.build_get_ohlcv_bars_query(sanitized_name, time_resolution);
+        let query =
+            self.build_get_ohlcv_bars_query(sanitized_name, time_resolution, None, None, with_side);
 
         // Execute query
-        let ohlcv_rows = self
-            .client
-            .query_collect::<OHLCVRow>(&query)
-            .await
-            .expect(format!("{} Failed to execute query: {}", FN_NAME, query).as_str());
+        let ohlcv_rows = match self.query_collect::<OHLCVRow>(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
 
         // Check for empty result
         if ohlcv_rows.is_empty() {
@@ -85,7 +102,17 @@ impl QueryDBManager {
                 row.low(),
                 row.close(),
                 row.volume(),
-            );
+            )
+            .with_vwap(row.vwap())
+            .with_trade_count(row.trade_count());
+
+            let bar = match (row.buy_volume(), row.sell_volume()) {
+                (Some(buy_volume), Some(sell_volume)) => {
+                    bar.with_buy_sell_volume(buy_volume, sell_volume)
+                }
+                _ => bar,
+            };
+
             bars.push(bar);
         }
 
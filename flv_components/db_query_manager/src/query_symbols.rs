@@ -1,8 +1,9 @@
 use crate::error::QueryError;
+use crate::query_utils::sanitize_table_name;
 use crate::types::SymbolRow;
-use crate::{QueryDBManager, FN_NAME};
+use crate::ClickHouseBackend;
 
-impl QueryDBManager {
+impl ClickHouseBackend {
     /// Retrieves all symbols and their IDs from the given symbol table.
     ///
     /// # Arguments
@@ -16,6 +17,7 @@ impl QueryDBManager {
     ///
     /// `QueryError`
     /// - `QueryError::QueryFailed` if the query to the DB failed.
+    /// - `QueryError::ConnectionFailed` if the connection to the DB still failed after retrying.
     /// - `QueryError::EmptyTableName` if `table_name` is empty
     /// - `QueryError::InvalidTableName` if `table_name` contains invalid characters
     /// - `QueryError::TableNameTooLong` if `table_name` is longer than 64 characters
@@ -30,7 +32,7 @@ impl QueryDBManager {
     /// #[tokio::main]
     /// async fn main() {
     /// let db_config =  ClickHouseConfig::default();
-    ///  let mut query_manager = QueryDBManager::new(db_config).await.expect("Failed to create db connection");
+    ///  let query_manager = QueryDBManager::new(db_config).await.expect("Failed to create db connection");
     ///
     ///  let symbols = query_manager.get_all_symbols_with_ids("kraken_symbols")
     ///             .await
@@ -46,12 +48,12 @@ impl QueryDBManager {
     /// The result rows are parsed into a vector of `(u16, String)` tuples containing
     /// the symbol ID and name respectively. Any errors are handled and returned in
     /// the `QueryError` enum.
-    pub async fn get_all_symbols_with_ids(
-        &mut self,
+    pub(crate) async fn get_all_symbols_with_ids(
+        &self,
         symbol_table: &str,
     ) -> Result<Vec<(u16, String)>, QueryError> {
         // Sanitize table name input to prevent SQL injection.
-        let sanitized_name = match self.sanitize_table_name(symbol_table) {
+        let sanitized_name = match sanitize_table_name(symbol_table) {
             Ok(name) => name,
             Err(e) => return Err(e),
         };
@@ -60,11 +62,10 @@ impl QueryDBManager {
         let query = self.build_get_symbol_id_query(sanitized_name);
 
         // Execute query
-        let result_rows = self
-            .client
-            .query_collect::<SymbolRow>(&query)
-            .await
-            .expect(format!("{} Failed to execute query: {}", FN_NAME, query).as_str());
+        let result_rows = match self.query_collect::<SymbolRow>(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
 
         // Check for empty result
         if result_rows.is_empty() {
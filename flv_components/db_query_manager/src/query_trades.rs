@@ -1,9 +1,10 @@
 use crate::error::QueryError;
+use crate::query_utils::sanitize_table_name;
 use crate::types::TradeRow;
-use crate::{QueryDBManager, FN_NAME};
+use crate::ClickHouseBackend;
 use common::prelude::TradeBar;
 
-impl QueryDBManager {
+impl ClickHouseBackend {
     /// Retrieves all trade bars for the given symbol table from the database.
     ///
     /// # Arguments
@@ -19,6 +20,7 @@ impl QueryDBManager {
     /// This function may return the following errors:
     ///
     /// - `QueryError::QueryFailed` if the query to the DB failed.
+    /// - `QueryError::ConnectionFailed` if the connection to the DB still failed after retrying.
     /// - `QueryError::EmptyTableName` if `table_name` is empty
     /// - `QueryError::InvalidTableName` if `table_name` contains invalid characters
     /// - `QueryError::TableNameTooLong` if `table_name` is longer than 64 characters
@@ -35,33 +37,32 @@ impl QueryDBManager {
     /// #[tokio::main]
     /// async fn main() {
     /// let db_config =  ClickHouseConfig::default();
-    ///  let mut query_manager = QueryDBManager::new(db_config).await.expect("Failed to create db connection");
+    ///  let query_manager = QueryDBManager::new(db_config).await.expect("Failed to create db connection");
     ///
     ///  let trades = query_manager.get_all_trades(278, "kraken_ethaed")
     ///               .await.expect("Failed to get all trades");
     ///
     /// }
     /// ```
-    pub async fn get_all_trades(
-        &mut self,
+    pub(crate) async fn get_all_trades(
+        &self,
         symbol_id: u16,
         symbol_table: &str,
     ) -> Result<Vec<TradeBar>, QueryError> {
         // Sanitize table name input to prevent SQL injection.
-        let sanitized_name = match self.sanitize_table_name(symbol_table) {
+        let sanitized_name = match sanitize_table_name(symbol_table) {
             Ok(name) => name,
             Err(e) => return Err(e),
         };
 
         // Build the query
-        let query = self.build_get_trades_query(sanitized_name);
+        let query = self.build_get_trades_query(sanitized_name, None, None);
 
         // Execute query
-        let trade_rows = self
-            .client
-            .query_collect::<TradeRow>(&query)
-            .await
-            .expect(format!("{} Failed to execute query: {}", FN_NAME, query).as_str());
+        let trade_rows = match self.query_collect::<TradeRow>(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
 
         // Check for empty result
         if trade_rows.is_empty() {
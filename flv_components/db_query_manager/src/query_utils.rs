@@ -1,61 +1,107 @@
 use crate::error::QueryError;
-use crate::QueryDBManager;
-use common::prelude::ValidationError;
+use crate::types::CountRow;
+use crate::ClickHouseBackend;
+use common::prelude::{TimeResolution, ValidationError};
 
-impl QueryDBManager {
-    /// Sanitizes the provided table name to prevent SQL injection attacks.
+/// Sanitizes the provided table name to prevent SQL injection attacks.
+///
+/// # Arguments
+///
+/// * `table_name` - The table name to sanitize
+///
+/// # Returns
+///
+/// A `Result` containing the original table name if valid, or a `QueryError`
+/// if the name is invalid.
+///
+/// # Errors
+///
+/// - `QueryError::EmptyTableName` if `table_name` is empty
+/// - `QueryError::InvalidTableName` if `table_name` contains invalid characters
+/// - `QueryError::TableNameTooLong` if `table_name` is longer than 64 characters
+///
+///
+/// This checks `table_name` for:
+///
+/// - Emptiness
+/// - Invalid characters
+/// - Length less than 64 characters
+///
+/// If valid, it returns the original `table_name`.
+pub(crate) fn sanitize_table_name(table_name: &str) -> Result<&str, QueryError> {
+    // check for empty name
+    if table_name.is_empty() {
+        return Err(QueryError::EmptyTableName(ValidationError::new(format!(
+            "Table: {}",
+            table_name
+        ))));
+    }
+
+    // check for invalid characters
+    if table_name.chars().any(|c| !c.is_alphanumeric() && c != '_') {
+        return Err(QueryError::InvalidTableName(ValidationError::new(format!(
+            "Table: {}",
+            table_name
+        ))));
+    }
+
+    // check for length
+    if table_name.len() > 64 {
+        return Err(QueryError::TableNameTooLong(ValidationError::new(format!(
+            "Table: {}",
+            table_name
+        ))));
+    }
+
+    Ok(table_name)
+}
+
+/// Validates the provided time resolution before it gets used in a query.
+///
+/// # Arguments
+///
+/// * `time_resolution` - The time resolution to validate
+///
+/// # Returns
+///
+/// A `Result` containing the original time resolution if valid, or a `QueryError`
+/// if the time resolution is invalid.
+///
+/// # Errors
+///
+/// - `QueryError::InvalidTimeResolution` if `time_resolution` has no value,
+///   a count of zero, or no time scale
+///
+pub(crate) fn validate_time_resolution(
+    time_resolution: &TimeResolution,
+) -> Result<&TimeResolution, QueryError> {
+    match time_resolution.validate() {
+        Ok(_) => Ok(time_resolution),
+        Err(e) => Err(QueryError::InvalidTimeResolution(e)),
+    }
+}
+
+impl ClickHouseBackend {
+    /// Checks whether the trade table has a side column with the side of the aggressor of each trade.
     ///
     /// # Arguments
     ///
-    /// * `table_name` - The table name to sanitize
+    /// * `trade_table` - The sanitized name of the trade table
     ///
     /// # Returns
     ///
-    /// A `Result` containing the original table name if valid, or a `QueryError`
-    /// if the name is invalid.
+    /// A `Result` containing `true` if the trade table has a side column, `false` otherwise.
     ///
     /// # Errors
     ///
-    /// - `QueryError::EmptyTableName` if `table_name` is empty
-    /// - `QueryError::InvalidTableName` if `table_name` contains invalid characters
-    /// - `QueryError::TableNameTooLong` if `table_name` is longer than 64 characters
-    ///
-    ///
-    /// This checks `table_name` for:
-    ///
-    /// - Emptiness
-    /// - Invalid characters
-    /// - Length less than 64 characters
+    /// - `QueryError::QueryFailed` if the query of the table columns failed
     ///
-    /// If valid, it returns the original `table_name`.
-    pub(crate) fn sanitize_table_name<'l>(
-        &self,
-        table_name: &'l str,
-    ) -> Result<&'l str, QueryError> {
-        // check for empty name
-        if table_name.is_empty() {
-            return Err(QueryError::EmptyTableName(ValidationError::new(format!(
-                "Table: {}",
-                table_name
-            ))));
-        }
-
-        // check for invalid characters
-        if table_name.chars().any(|c| !c.is_alphanumeric() && c != '_') {
-            return Err(QueryError::InvalidTableName(ValidationError::new(format!(
-                "Table: {}",
-                table_name
-            ))));
-        }
+    pub(crate) async fn has_side_column(&self, trade_table: &str) -> Result<bool, QueryError> {
+        let query = self.build_count_side_column_query(trade_table);
 
-        // check for length
-        if table_name.len() > 64 {
-            return Err(QueryError::TableNameTooLong(ValidationError::new(format!(
-                "Table: {}",
-                table_name
-            ))));
+        match self.query_one::<CountRow>(&query).await {
+            Ok(row) => Ok(row.count() > 0),
+            Err(e) => Err(e),
         }
-
-        Ok(table_name)
     }
 }
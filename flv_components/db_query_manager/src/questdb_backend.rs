@@ -0,0 +1,413 @@
+use crate::backoff::retry_with_backoff;
+use crate::error::QueryError;
+use crate::pg_wire::{cursor_stream, PgConnection, PgRow};
+use crate::query_utils::{sanitize_table_name, validate_time_resolution};
+use crate::storage_backend::StorageBackend;
+use crate::types::{ColumnType, OHLCVRow, TableColumn, TradeRow};
+use crate::FN_NAME;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use common::prelude::{DBConfig, OHLCVBar, TimeResolution, TradeBar};
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use tokio::sync::Mutex;
+
+// Number of rows a stream fetches from its cursor at a time.
+const FETCH_SIZE: i32 = 10_000;
+
+/// The QuestDB implementation of the StorageBackend, over the PostgreSQL wire protocol.
+///
+/// Queries that return all rows at once share one connection, which reconnects with backoff once it got closed.
+/// Each stream fetches its rows through a cursor on a connection of its own,
+/// so that a long-running stream never blocks other queries.
+pub struct QuestDBBackend {
+    db_config: DBConfig,
+    connection: Mutex<PgConnection>,
+}
+
+impl QuestDBBackend {
+    /// Creates a new QuestDBBackend connected to the PostgreSQL wire endpoint of the configured database.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_config` - The database configuration with the PostgreSQL wire connection parameters.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ConnectionFailed` if the connection to the database still fails after retrying with backoff.
+    ///
+    pub async fn new(db_config: DBConfig) -> Result<Self, QueryError> {
+        let connection = match retry_with_backoff(|| PgConnection::connect(&db_config)).await {
+            Ok(connection) => connection,
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            db_config,
+            connection: Mutex::new(connection),
+        })
+    }
+}
+
+impl QuestDBBackend {
+    // Runs the query on the shared connection, and reconnects with backoff if the connection got closed.
+    async fn query(&self, query: &str) -> Result<Vec<PgRow>, QueryError> {
+        retry_with_backoff(|| async {
+            let mut connection = self.connection.lock().await;
+
+            if connection.is_closed() {
+                *connection = PgConnection::connect(&self.db_config).await?;
+            }
+
+            match connection.simple_query(query).await {
+                Ok(rows) => Ok(rows),
+                Err(QueryError::ConnectionFailed(e)) => Err(QueryError::ConnectionFailed(format!(
+                    "{} Failed to execute query: {}: {}",
+                    FN_NAME, query, e
+                ))),
+                Err(e) => Err(QueryError::QueryFailed(format!(
+                    "{} Failed to execute query: {}: {}",
+                    FN_NAME, query, e
+                ))),
+            }
+        })
+        .await
+    }
+
+    async fn has_side_column(&self, trade_table: &str) -> Result<bool, QueryError> {
+        let query = self.build_count_side_column_query(trade_table);
+
+        let rows = match self.query(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        match rows.first() {
+            Some(row) => Ok(parse_u64(row, 0)? > 0),
+            None => Ok(false),
+        }
+    }
+
+    async fn build_ohlcv_query(
+        &self,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> Result<String, QueryError> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = sanitize_table_name(trade_table)?;
+
+        // Validate the time resolution before it gets into the query.
+        let time_resolution = validate_time_resolution(time_resolution)?;
+
+        // Split the volume into buy and sell volume if the trades carry their side.
+        let with_side = self.has_side_column(sanitized_name).await?;
+
+        Ok(self.build_get_ohlcv_bars_query(
+            sanitized_name,
+            time_resolution,
+            start_time,
+            end_time,
+            with_side,
+        ))
+    }
+}
+
+#[async_trait]
+impl StorageBackend for QuestDBBackend {
+    async fn is_open(&self) -> bool {
+        !self.connection.lock().await.is_closed()
+    }
+
+    async fn get_all_symbols_with_ids(
+        &self,
+        symbol_table: &str,
+    ) -> Result<Vec<(u16, String)>, QueryError> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = match sanitize_table_name(symbol_table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        let query = self.build_get_symbol_id_query(sanitized_name);
+
+        let rows = match self.query(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        let mut symbol_id_name_pairs = Vec::with_capacity(rows.len());
+        for row in rows {
+            let symbol_id = parse_u64(&row, 0)? as u16;
+            let symbol = parse_string(&row, 1)?;
+            symbol_id_name_pairs.push((symbol_id, symbol));
+        }
+
+        Ok(symbol_id_name_pairs)
+    }
+
+    async fn get_table_columns(&self, table: &str) -> Result<Vec<TableColumn>, QueryError> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = match sanitize_table_name(table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        // Check that the table exists
+        let query = self.build_count_table_query(sanitized_name);
+        let rows = match self.query(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        let count = match rows.first() {
+            Some(row) => parse_u64(row, 0)?,
+            None => 0,
+        };
+
+        if count == 0 {
+            return Err(QueryError::TableDoesNotExist(
+                sanitized_name.to_string(),
+                "No table of that name in tables()".to_string(),
+            ));
+        }
+
+        // Look up the columns of the table
+        let query = self.build_get_table_columns_query(sanitized_name);
+        let rows = match self.query(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        let mut columns = Vec::with_capacity(rows.len());
+        for row in rows {
+            let name = parse_string(&row, 0)?;
+            let type_name = parse_string(&row, 1)?;
+            columns.push(TableColumn::new(&name, column_type(&type_name)));
+        }
+
+        Ok(columns)
+    }
+
+    async fn get_all_trades(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+    ) -> Result<Vec<TradeBar>, QueryError> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = match sanitize_table_name(trade_table) {
+            Ok(name) => name,
+            Err(e) => return Err(e),
+        };
+
+        let query = self.build_get_trades_query(sanitized_name, None, None);
+
+        let rows = match self.query(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        let mut trades = Vec::with_capacity(rows.len());
+        for row in rows {
+            let trade = decode_trade_row(&row)?;
+            trades.push(TradeBar::new(
+                symbol_id,
+                trade.date_time(),
+                trade.price(),
+                trade.volume(),
+            ));
+        }
+
+        Ok(trades)
+    }
+
+    async fn get_all_ohlcv_bars(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+    ) -> Result<Vec<OHLCVBar>, QueryError> {
+        let query = match self
+            .build_ohlcv_query(trade_table, time_resolution, None, None)
+            .await
+        {
+            Ok(query) => query,
+            Err(e) => return Err(e),
+        };
+
+        let rows = match self.query(&query).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(e),
+        };
+
+        let mut bars = Vec::with_capacity(rows.len());
+        for row in rows {
+            let row = decode_ohlcv_row(&row)?;
+
+            let bar = OHLCVBar::new(
+                symbol_id,
+                row.date_time(),
+                row.open(),
+                row.high(),
+                row.low(),
+                row.close(),
+                row.volume(),
+            )
+            .with_vwap(row.vwap())
+            .with_trade_count(row.trade_count());
+
+            let bar = match (row.buy_volume(), row.sell_volume()) {
+                (Some(buy_volume), Some(sell_volume)) => {
+                    bar.with_buy_sell_volume(buy_volume, sell_volume)
+                }
+                _ => bar,
+            };
+
+            bars.push(bar);
+        }
+
+        Ok(bars)
+    }
+
+    async fn stream_trades<'a>(
+        &'a self,
+        trade_table: &'a str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<TradeRow, QueryError>> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = match sanitize_table_name(trade_table) {
+            Ok(name) => name,
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        };
+
+        let query = self.build_get_trades_query(sanitized_name, start_time, end_time);
+
+        cursor_stream(self.db_config.clone(), query, FETCH_SIZE, decode_trade_row)
+    }
+
+    async fn stream_ohlcv<'a>(
+        &'a self,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<OHLCVRow, QueryError>> {
+        let query = match self
+            .build_ohlcv_query(trade_table, time_resolution, start_time, end_time)
+            .await
+        {
+            Ok(query) => query,
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        };
+
+        cursor_stream(self.db_config.clone(), query, FETCH_SIZE, decode_ohlcv_row)
+    }
+}
+
+// Decodes a row of timestamp, price, and volume.
+fn decode_trade_row(row: &PgRow) -> Result<TradeRow, QueryError> {
+    let date_time = parse_timestamp(row, 0)?;
+    if date_time.timestamp_millis() < 0 {
+        return Err(QueryError::InvalidTradeData(format!(
+            "{} Trade time {} before the unix epoch",
+            FN_NAME, date_time
+        )));
+    }
+    let price = parse_f64(row, 1)?;
+    let volume = parse_f64(row, 2)?;
+
+    Ok(TradeRow::new(date_time, price, volume))
+}
+
+// Decodes a row of the OHLCV query, with or without the buy and sell volume.
+fn decode_ohlcv_row(row: &PgRow) -> Result<OHLCVRow, QueryError> {
+    let date_time = parse_timestamp(row, 0)?;
+    let close = parse_f64(row, 4)?;
+
+    let bar = OHLCVRow::new(
+        date_time.timestamp_millis(),
+        parse_f64(row, 1)?,
+        parse_f64(row, 2)?,
+        parse_f64(row, 3)?,
+        close,
+        parse_f64(row, 5)?,
+    );
+
+    // Without volume, QuestDB divides by zero to NULL or NaN; ClickHouse takes the close price.
+    let vwap = match parse_f64(row, 6) {
+        Ok(vwap) if vwap.is_finite() => vwap,
+        _ => close,
+    };
+
+    let bar = bar.with_vwap(vwap).with_trade_count(parse_u64(row, 7)?);
+
+    if row.len() < 10 {
+        return Ok(bar);
+    }
+
+    Ok(bar.with_buy_sell_volume(parse_f64(row, 8)?, parse_f64(row, 9)?))
+}
+
+// Normalizes a QuestDB type, i.e. TIMESTAMP, DOUBLE, or SYMBOL.
+fn column_type(type_name: &str) -> ColumnType {
+    match type_name.to_uppercase().as_str() {
+        "TIMESTAMP" | "DATE" => ColumnType::Timestamp,
+        "DOUBLE" | "FLOAT" => ColumnType::Float,
+        "SYMBOL" | "STRING" | "VARCHAR" | "CHAR" => ColumnType::Text,
+        _ => ColumnType::Other(type_name.to_string()),
+    }
+}
+
+fn parse_string(row: &PgRow, idx: usize) -> Result<String, QueryError> {
+    match row.get(idx) {
+        Some(Some(value)) => Ok(value.to_string()),
+        _ => Err(QueryError::QueryFailed(format!(
+            "{} Missing value in column {}",
+            FN_NAME, idx
+        ))),
+    }
+}
+
+fn parse_f64(row: &PgRow, idx: usize) -> Result<f64, QueryError> {
+    let value = parse_string(row, idx)?;
+
+    match value.parse::<f64>() {
+        Ok(value) => Ok(value),
+        Err(e) => Err(QueryError::QueryFailed(format!(
+            "{} Invalid number {} in column {}: {}",
+            FN_NAME, value, idx, e
+        ))),
+    }
+}
+
+fn parse_u64(row: &PgRow, idx: usize) -> Result<u64, QueryError> {
+    let value = parse_string(row, idx)?;
+
+    match value.parse::<u64>() {
+        Ok(value) => Ok(value),
+        Err(e) => Err(QueryError::QueryFailed(format!(
+            "{} Invalid count {} in column {}: {}",
+            FN_NAME, value, idx, e
+        ))),
+    }
+}
+
+// Parses a UTC timestamp in the text format of the PostgreSQL wire protocol,
+// i.e. 2022-01-01 00:00:23.123456, or in ISO 8601 format.
+fn parse_timestamp(row: &PgRow, idx: usize) -> Result<DateTime<Utc>, QueryError> {
+    let value = parse_string(row, idx)?;
+    let trimmed = value.trim_end_matches('Z').trim_end_matches("+00");
+
+    for format in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"] {
+        if let Ok(date_time) = NaiveDateTime::parse_from_str(trimmed, format) {
+            return Ok(date_time.and_utc());
+        }
+    }
+
+    Err(QueryError::QueryFailed(format!(
+        "{} Invalid timestamp {} in column {}",
+        FN_NAME, value, idx
+    )))
+}
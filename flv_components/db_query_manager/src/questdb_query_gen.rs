@@ -0,0 +1,212 @@
+use crate::QuestDBBackend;
+use chrono::{DateTime, Utc};
+use common::prelude::{TimeResolution, TimeScale};
+
+impl QuestDBBackend {
+    /// Builds a SQL query to get all symbol IDs and symbols from a symbol table.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_table` - The name of the symbol table to query
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string to retrieve all symbol IDs and symbols from the given symbol table.
+    ///
+    pub fn build_get_symbol_id_query(&self, symbol_table: &str) -> String {
+        format!("SELECT symbol_id, symbol FROM {}", symbol_table)
+    }
+
+    /// Builds a SQL query to get OHLCV bars from a trade table at a given time resolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_table` - The name of the trade table to query
+    /// * `time_resolution` - The validated time resolution to resample the trades to
+    /// * `start_time` - Optional inclusive start of the time range
+    /// * `end_time` - Optional exclusive end of the time range
+    /// * `with_side` - Whether the trade table has a side column to split the volume into buy and sell volume
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that resamples the trades with `SAMPLE BY ... ALIGN TO CALENDAR`,
+    /// so that the bars start at the same calendar boundaries as the bars of ClickHouse.
+    /// Each bar includes the volume weighted average price and the number of trades.
+    /// The buy and sell volume are only selected if the trade table has a side column.
+    ///
+    pub fn build_get_ohlcv_bars_query(
+        &self,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        with_side: bool,
+    ) -> String {
+        let time_filter = self.build_time_range_filter(start_time, end_time);
+        let sample_by = self.build_sample_by_interval(time_resolution);
+
+        let side_volume = if with_side {
+            r",
+              sum(CASE WHEN side = 'b' THEN volume ELSE 0.0 END) AS buy_volume,
+              sum(CASE WHEN side = 's' THEN volume ELSE 0.0 END) AS sell_volume"
+        } else {
+            ""
+        };
+
+        format!(
+            r"SELECT timestamp,
+              first(price) AS open,
+              max(price) AS high,
+              min(price) AS low,
+              last(price) AS close,
+              sum(volume) AS volume,
+              sum(price * volume) / sum(volume) AS vwap,
+              count() AS trade_count{side_volume}
+
+            FROM {trade_table}{time_filter}
+            SAMPLE BY {sample_by}
+            ALIGN TO CALENDAR WITH OFFSET '00:00'"
+        )
+    }
+
+    /// Builds the interval of a SAMPLE BY clause for a time resolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_resolution` - The validated time resolution
+    ///
+    /// # Returns
+    ///
+    /// Returns the count and unit of the interval, i.e. `5m` for 5 minutes.
+    /// QuestDB has no quarter unit, so a quarter is sampled as three months.
+    ///
+    pub fn build_sample_by_interval(&self, time_resolution: &TimeResolution) -> String {
+        let count = time_resolution.count();
+
+        match time_resolution.scale() {
+            TimeScale::NoScale | TimeScale::Millisecond => format!("{}T", count),
+            TimeScale::Second => format!("{}s", count),
+            TimeScale::Minute => format!("{}m", count),
+            TimeScale::Hour => format!("{}h", count),
+            TimeScale::Day => format!("{}d", count),
+            TimeScale::Week => format!("{}w", count),
+            TimeScale::Month => format!("{}M", count),
+            TimeScale::Quarter => format!("{}M", count * 3),
+            TimeScale::Year => format!("{}y", count),
+        }
+    }
+
+    /// Builds a SQL query to count the side columns of a trade table.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_table` - The name of the trade table to query
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that counts the columns named side of the trade table, either 0 or 1.
+    ///
+    pub fn build_count_side_column_query(&self, trade_table: &str) -> String {
+        format!(
+            "SELECT count() FROM table_columns('{}') WHERE \"column\" = 'side'",
+            trade_table
+        )
+    }
+
+    /// Builds a SQL query to count the tables of the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The name of the table to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that counts the tables of that name in tables(), either 0 or 1.
+    ///
+    pub fn build_count_table_query(&self, table: &str) -> String {
+        format!(
+            "SELECT count() FROM tables() WHERE table_name = '{}'",
+            table
+        )
+    }
+
+    /// Builds a SQL query to get the names and types of all columns of a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The name of the table to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string that lists the columns of the table in table_columns(), in the order of the table.
+    ///
+    pub fn build_get_table_columns_query(&self, table: &str) -> String {
+        format!("SELECT \"column\", type FROM table_columns('{}')", table)
+    }
+
+    /// Builds a SQL query to get all trades from a trade table.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_table` - The name of the trade table to query
+    /// * `start_time` - Optional inclusive start of the time range
+    /// * `end_time` - Optional exclusive end of the time range
+    ///
+    /// # Returns
+    ///
+    /// Returns a SQL query string to retrieve all timestamps, prices, and volumes from the given trade table.
+    ///
+    pub fn build_get_trades_query(
+        &self,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> String {
+        let time_filter = self.build_time_range_filter(start_time, end_time);
+
+        format!(
+            "SELECT timestamp, price, volume FROM {}{}",
+            trade_table, time_filter
+        )
+    }
+
+    /// Builds a SQL WHERE clause to restrict a query to a time range on the timestamp column.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - Optional inclusive start of the time range
+    /// * `end_time` - Optional exclusive end of the time range
+    ///
+    /// # Returns
+    ///
+    /// Returns a WHERE clause with a leading space, or an empty string if neither bound is set.
+    /// The bounds are ISO 8601 timestamps in microseconds, which QuestDB compares to the timestamp column.
+    ///
+    pub fn build_time_range_filter(
+        &self,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> String {
+        let mut conditions = Vec::with_capacity(2);
+
+        if let Some(start_time) = start_time {
+            conditions.push(format!(
+                "timestamp >= '{}'",
+                start_time.format("%Y-%m-%dT%H:%M:%S%.6fZ")
+            ));
+        }
+
+        if let Some(end_time) = end_time {
+            conditions.push(format!(
+                "timestamp < '{}'",
+                end_time.format("%Y-%m-%dT%H:%M:%S%.6fZ")
+            ));
+        }
+
+        if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        }
+    }
+}
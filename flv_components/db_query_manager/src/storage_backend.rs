@@ -0,0 +1,92 @@
+use crate::error::QueryError;
+use crate::types::{OHLCVRow, SymbolCoverage, TableColumn, TradeRow};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use common::prelude::{OHLCVBar, TimeResolution, TradeBar};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use std::time::Duration;
+
+/// The storage operations the QueryDBManager runs against a trade database.
+///
+/// ClickHouse is the default implementation, and QuestDB is queried over the PostgreSQL wire protocol.
+/// The in-memory implementation serves trades from memory, so that code using the QueryDBManager
+/// can be tested without a database.
+///
+/// Each trade table holds the trades of one symbol, ordered by timestamp.
+#[async_trait]
+pub trait StorageBackend: Send + Sync {
+    /// Returns true if the backend can serve queries.
+    async fn is_open(&self) -> bool;
+
+    /// Retrieves all (symbol_id, symbol) pairs of the given symbol table.
+    async fn get_all_symbols_with_ids(
+        &self,
+        symbol_table: &str,
+    ) -> Result<Vec<(u16, String)>, QueryError>;
+
+    /// Retrieves the columns of the given table from the catalog of the database.
+    ///
+    /// Returns `QueryError::TableDoesNotExist` if the database has no table of that name.
+    async fn get_table_columns(&self, table: &str) -> Result<Vec<TableColumn>, QueryError>;
+
+    /// Retrieves all trades of the given trade table as trade bars of the given symbol.
+    async fn get_all_trades(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+    ) -> Result<Vec<TradeBar>, QueryError>;
+
+    /// Retrieves all trades of the given trade table resampled to OHLCV bars of the given symbol.
+    async fn get_all_ohlcv_bars(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+    ) -> Result<Vec<OHLCVBar>, QueryError>;
+
+    /// Computes the time coverage of the trades of the given trade table from `start_time` on.
+    ///
+    /// The default implementation streams the timestamps of all trades in the time range;
+    /// backends that can aggregate in the database override it.
+    async fn get_symbol_coverage(
+        &self,
+        symbol_id: u16,
+        trade_table: &str,
+        start_time: Option<DateTime<Utc>>,
+        min_gap: Duration,
+    ) -> Result<SymbolCoverage, QueryError> {
+        let mut coverage = SymbolCoverage::new(symbol_id, trade_table, min_gap);
+        let mut trades = self.stream_trades(trade_table, start_time, None).await;
+
+        while let Some(trade) = trades.next().await {
+            match trade {
+                Ok(trade) => coverage.add_trade(trade.date_time()),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(coverage)
+    }
+
+    /// Streams the trades of the given trade table in the time range `[start_time, end_time)`.
+    ///
+    /// An error that occurs before the first trade is yielded as the only item of the stream.
+    async fn stream_trades<'a>(
+        &'a self,
+        trade_table: &'a str,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<TradeRow, QueryError>>;
+
+    /// Streams the OHLCV bars of the given trade table in the time range `[start_time, end_time)`.
+    ///
+    /// An error that occurs before the first bar is yielded as the only item of the stream.
+    async fn stream_ohlcv<'a>(
+        &'a self,
+        trade_table: &str,
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<OHLCVRow, QueryError>>;
+}
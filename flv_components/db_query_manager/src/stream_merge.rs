@@ -0,0 +1,113 @@
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+
+// A per-symbol stream together with its next, not yet merged, item.
+struct MergeSource<'a, T, E> {
+    symbol_id: u16,
+    stream: BoxStream<'a, Result<T, E>>,
+    head: Option<Result<T, E>>,
+    done: bool,
+}
+
+/// Merges per-symbol streams into a single stream in timestamp order (k-way merge).
+///
+/// Each input stream must be ordered by timestamp. The merged stream yields each row
+/// tagged with the symbol id of its input stream. Rows with the same timestamp are
+/// yielded in the order of the input streams. An error of any input stream is yielded
+/// as soon as it is read.
+///
+/// # Arguments
+///
+/// * `streams` - The (symbol_id, stream) pairs to merge
+/// * `date_time` - Returns the timestamp of a row
+///
+/// # Returns
+///
+/// A stream of (symbol_id, row) pairs ordered by timestamp.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use db_query_manager::merge_streams_by_time;
+/// use futures::stream::{self, StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let time = |secs: i64| Utc.timestamp_opt(secs, 0).unwrap();
+///     let a = stream::iter(vec![Ok::<_, ()>(time(1)), Ok(time(3))]).boxed();
+///     let b = stream::iter(vec![Ok::<_, ()>(time(2))]).boxed();
+///
+///     let merged: Vec<_> = merge_streams_by_time(vec![(1, a), (2, b)], |t: &DateTime<Utc>| *t)
+///         .collect()
+///         .await;
+///
+///     assert_eq!(merged, vec![Ok((1, time(1))), Ok((2, time(2))), Ok((1, time(3)))]);
+/// }
+/// ```
+pub fn merge_streams_by_time<'a, T, E, F>(
+    streams: Vec<(u16, BoxStream<'a, Result<T, E>>)>,
+    date_time: F,
+) -> BoxStream<'a, Result<(u16, T), E>>
+where
+    T: Send + 'a,
+    E: Send + 'a,
+    F: Fn(&T) -> DateTime<Utc> + Send + Sync + 'a,
+{
+    let sources: Vec<MergeSource<'a, T, E>> = streams
+        .into_iter()
+        .map(|(symbol_id, stream)| MergeSource {
+            symbol_id,
+            stream,
+            head: None,
+            done: false,
+        })
+        .collect();
+
+    futures::stream::unfold(
+        (sources, date_time),
+        |(mut sources, date_time)| async move {
+            // Read the next item of each stream whose head has been merged.
+            for source in sources.iter_mut() {
+                if source.head.is_none() && !source.done {
+                    match source.stream.next().await {
+                        Some(item) => source.head = Some(item),
+                        None => source.done = true,
+                    }
+                }
+            }
+
+            // Pass errors on right away.
+            if let Some(source) = sources
+                .iter_mut()
+                .find(|source| matches!(source.head, Some(Err(_))))
+            {
+                if let Some(Err(e)) = source.head.take() {
+                    return Some((Err(e), (sources, date_time)));
+                }
+            }
+
+            // Take the row with the earliest timestamp; the first stream wins ties.
+            let next = sources
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, source)| match &source.head {
+                    Some(Ok(row)) => Some((idx, date_time(row))),
+                    _ => None,
+                })
+                .min_by_key(|(idx, time)| (*time, *idx))
+                .map(|(idx, _)| idx);
+
+            // All streams have ended.
+            let idx = next?;
+
+            let symbol_id = sources[idx].symbol_id;
+            match sources[idx].head.take() {
+                Some(Ok(row)) => Some((Ok((symbol_id, row)), (sources, date_time))),
+                _ => None,
+            }
+        },
+    )
+    .boxed()
+}
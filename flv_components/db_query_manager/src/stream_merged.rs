@@ -0,0 +1,77 @@
+use crate::error::QueryError;
+use crate::stream_merge::merge_streams_by_time;
+use crate::types::{OHLCVRow, TradeRow};
+use crate::QueryDBManager;
+use chrono::{DateTime, Utc};
+use common::prelude::TimeResolution;
+use futures::stream::BoxStream;
+
+impl QueryDBManager {
+    /// Stream the trades of several symbols from the database, merged in timestamp order.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_tables` - The (symbol_id, trade_table) pairs to stream
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first trade.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last trade.
+    ///
+    /// # Returns
+    ///
+    /// A stream of (symbol_id, trade) pairs ordered by timestamp.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields a `QueryError` if any of the per-symbol streams fails.
+    ///
+    pub async fn stream_trades_merged<'a>(
+        &'a self,
+        trade_tables: &'a [(u16, String)],
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<(u16, TradeRow), QueryError>> {
+        let mut streams = Vec::with_capacity(trade_tables.len());
+
+        for (symbol_id, trade_table) in trade_tables {
+            let stream = self.stream_trades(trade_table, start_time, end_time).await;
+            streams.push((*symbol_id, stream));
+        }
+
+        merge_streams_by_time(streams, TradeRow::date_time)
+    }
+
+    /// Stream the OHLCV bars of several symbols from the database, merged in timestamp order.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_tables` - The (symbol_id, symbol_table) pairs to stream
+    /// * `time_resolution` - The time resolution of the OHLCV bars
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first bar.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last bar.
+    ///
+    /// # Returns
+    ///
+    /// A stream of (symbol_id, OHLCV bar) pairs ordered by timestamp.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields a `QueryError` if any of the per-symbol streams fails.
+    ///
+    pub async fn stream_ohlcv_merged<'a>(
+        &'a self,
+        symbol_tables: &'a [(u16, String)],
+        time_resolution: &TimeResolution,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<(u16, OHLCVRow), QueryError>> {
+        let mut streams = Vec::with_capacity(symbol_tables.len());
+
+        for (symbol_id, symbol_table) in symbol_tables {
+            let stream = self
+                .stream_ohlcv(symbol_table, time_resolution, start_time, end_time)
+                .await;
+            streams.push((*symbol_id, stream));
+        }
+
+        merge_streams_by_time(streams, OHLCVRow::date_time)
+    }
+}
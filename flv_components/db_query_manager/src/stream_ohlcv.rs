@@ -1,29 +1,51 @@
+use crate::error::QueryError;
+use crate::query_utils::{sanitize_table_name, validate_time_resolution};
 use crate::types::OHLCVRow;
-use crate::{QueryDBManager, FN_NAME};
+use crate::ClickHouseBackend;
+use chrono::{DateTime, Utc};
 use common::prelude::TimeResolution;
-use futures::stream::BoxStream;
+use futures::stream::{self, BoxStream};
 use futures::StreamExt;
-use klickhouse::KlickhouseError;
 
-impl QueryDBManager {
-    pub async fn stream_ohlcv<'a>(
+impl ClickHouseBackend {
+    pub(crate) async fn stream_ohlcv<'a>(
         &'a self,
         symbol_table: &str,
         time_resolution: &TimeResolution,
-    ) -> BoxStream<Result<OHLCVRow, KlickhouseError>> {
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<OHLCVRow, QueryError>> {
         // Sanitize table name input to prevent SQL injection.
-        let sanitized_name = self
-            .sanitize_table_name(symbol_table)
-            .expect("Failed to sanitize table name");
+        let sanitized_name = match sanitize_table_name(symbol_table) {
+            Ok(name) => name,
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        };
+
+        // Validate the time resolution before it gets into the query.
+        let time_resolution = match validate_time_resolution(time_resolution) {
+            Ok(time_resolution) => time_resolution,
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        };
+
+        // Split the volume into buy and sell volume if the trades carry their side.
+        let with_side = match self.has_side_column(sanitized_name).await {
+            Ok(with_side) => with_side,
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        };
 
         // Build the query
-        let query = self.build_get_ohlcv_bars_query(sanitized_name, time_resolution);
+        let query = self.build_get_ohlcv_bars_query(
+            sanitized_name,
+            time_resolution,
+            start_time,
+            end_time,
+            with_side,
+        );
 
         // Return the stream of rows
-        self.client
-            .query::<OHLCVRow>(query)
-            .await
-            .expect(format!("{} Failed to execute stream_ohlcv query ", FN_NAME).as_str())
-            .boxed()
+        match self.query_stream::<OHLCVRow>(&query, "stream_ohlcv").await {
+            Ok(rows) => rows,
+            Err(e) => stream::once(async { Err(e) }).boxed(),
+        }
     }
 }
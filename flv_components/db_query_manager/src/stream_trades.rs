@@ -1,10 +1,12 @@
+use crate::error::QueryError;
+use crate::query_utils::sanitize_table_name;
 use crate::types::TradeRow;
-use crate::{QueryDBManager, FN_NAME};
-use futures::stream::BoxStream;
+use crate::ClickHouseBackend;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream};
 use futures::StreamExt;
-use klickhouse::KlickhouseError;
 
-impl QueryDBManager {
+impl ClickHouseBackend {
     /// Stream trade bars for the given symbol from the database.
     ///
     /// This returns a stream of `TradeBar` structs for the specified `symbol_id`.
@@ -14,10 +16,13 @@ impl QueryDBManager {
     ///
     /// * `symbol_id` - The symbol ID to fetch trade bars for
     /// * `trade_table` - The name of the DB table to query and stream
+    /// * `start_time` - Optional inclusive start of the time range. `None` streams from the first trade.
+    /// * `end_time` - Optional exclusive end of the time range. `None` streams until the last trade.
     ///
     /// # Errors
     ///
-    /// This function may return connection errors or other database errors.
+    /// The stream yields a `QueryError` if the table name is invalid, the query fails,
+    /// or the connection fails while streaming.
     ///
     /// # Example
     ///
@@ -35,7 +40,7 @@ impl QueryDBManager {
     /// let trade_table = "kraken_ethaed";
     /// let symbol_id = 284; // 284 = ethaed on Kraken
     ///
-    ///     let mut stream = query_manager.stream_trades( trade_table).await;
+    ///     let mut stream = query_manager.stream_trades(trade_table, None, None).await;
     ///
     ///     while let Some(record) = stream.next().await {
     ///         assert!(record.is_ok());
@@ -44,21 +49,24 @@ impl QueryDBManager {
     ///     }
     /// }
     /// ```
-    pub async fn stream_trades<'a>(
+    pub(crate) async fn stream_trades<'a>(
         &'a self,
         trade_table: &'a str,
-    ) -> BoxStream<Result<TradeRow, KlickhouseError>> {
-        let sanitized_name = self
-            .sanitize_table_name(trade_table)
-            .expect("Invalid table name");
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, Result<TradeRow, QueryError>> {
+        // Sanitize table name input to prevent SQL injection.
+        let sanitized_name = match sanitize_table_name(trade_table) {
+            Ok(name) => name,
+            Err(e) => return stream::once(async { Err(e) }).boxed(),
+        };
 
         // Build the query
-        let query = self.build_get_trades_query(sanitized_name);
+        let query = self.build_get_trades_query(sanitized_name, start_time, end_time);
 
-        self.client
-            .query::<TradeRow>(query)
-            .await
-            .expect(format!("{} Failed to execute stream_trades query ", FN_NAME).as_str())
-            .boxed()
+        match self.query_stream::<TradeRow>(&query, "stream_trades").await {
+            Ok(rows) => rows,
+            Err(e) => stream::once(async { Err(e) }).boxed(),
+        }
     }
 }
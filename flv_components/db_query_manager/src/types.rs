@@ -1,8 +1,10 @@
+use crate::error::QueryError;
 use chrono::{DateTime, TimeZone, Utc};
 use klickhouse::{DateTime64, Row};
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Row, Serialize, Deserialize)]
 pub struct TradeRow {
@@ -11,6 +13,23 @@ pub struct TradeRow {
     volume: f64,
 }
 
+impl TradeRow {
+    /// Creates a new TradeRow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the trade time is before the unix epoch, which a ClickHouse DateTime64 cannot hold either.
+    pub(crate) fn new(date_time: DateTime<Utc>, price: f64, volume: f64) -> Self {
+        let date_time = DateTime64::try_from(date_time).expect("Trade time before the unix epoch");
+
+        Self {
+            date_time,
+            price,
+            volume,
+        }
+    }
+}
+
 impl TradeRow {
     pub fn date_time(&self) -> DateTime<Utc> {
         Utc.timestamp_millis_opt(self.date_time.1 as i64).unwrap()
@@ -25,17 +44,64 @@ impl TradeRow {
 
 #[derive(Debug, Clone, PartialEq, Row, Serialize, Deserialize)]
 pub struct OHLCVRow {
-    datetime: u32,
+    datetime: i64,
     open: f64,
     high: f64,
     low: f64,
     close: f64,
     volume: f64,
+    vwap: f64,
+    trade_count: u64,
+    // Only set if the trade table has a side column.
+    buy_volume: Option<f64>,
+    sell_volume: Option<f64>,
+}
+
+impl OHLCVRow {
+    /// Creates a new OHLCVRow of one trade, with the close price as volume weighted average price.
+    pub(crate) fn new(
+        datetime: i64,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> Self {
+        Self {
+            datetime,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            vwap: close,
+            trade_count: 1,
+            buy_volume: None,
+            sell_volume: None,
+        }
+    }
+
+    pub(crate) fn with_vwap(mut self, vwap: f64) -> Self {
+        self.vwap = vwap;
+        self
+    }
+
+    pub(crate) fn with_trade_count(mut self, trade_count: u64) -> Self {
+        self.trade_count = trade_count;
+        self
+    }
+
+    pub(crate) fn with_buy_sell_volume(mut self, buy_volume: f64, sell_volume: f64) -> Self {
+        self.buy_volume = Some(buy_volume);
+        self.sell_volume = Some(sell_volume);
+        self
+    }
 }
 
 impl OHLCVRow {
     pub fn date_time(&self) -> DateTime<Utc> {
-        Utc.timestamp_millis_opt(self.datetime as i64).unwrap()
+        // toUnixTimestamp64Milli returns milliseconds since epoch
+        Utc.timestamp_millis_opt(self.datetime).unwrap()
     }
     pub fn open(&self) -> Decimal {
         Decimal::from_f64(self.open).unwrap()
@@ -52,6 +118,18 @@ impl OHLCVRow {
     pub fn volume(&self) -> Decimal {
         Decimal::from_f64(self.volume).unwrap()
     }
+    pub fn vwap(&self) -> Decimal {
+        Decimal::from_f64(self.vwap).unwrap()
+    }
+    pub fn trade_count(&self) -> u64 {
+        self.trade_count
+    }
+    pub fn buy_volume(&self) -> Option<Decimal> {
+        self.buy_volume.and_then(Decimal::from_f64)
+    }
+    pub fn sell_volume(&self) -> Option<Decimal> {
+        self.sell_volume.and_then(Decimal::from_f64)
+    }
 }
 
 #[derive(Debug, Eq, Clone, PartialEq, Row, Serialize, Deserialize)]
@@ -68,3 +146,267 @@ impl SymbolRow {
         self.symbol.to_string()
     }
 }
+
+#[derive(Debug, Eq, Clone, PartialEq, Row, Serialize, Deserialize)]
+pub struct CountRow {
+    count: u64,
+}
+
+impl CountRow {
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+#[derive(Debug, Eq, Clone, PartialEq, Row, Serialize, Deserialize)]
+pub struct ColumnRow {
+    name: String,
+    column_type: String,
+}
+
+impl ColumnRow {
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+    pub fn column_type(&self) -> String {
+        self.column_type.to_string()
+    }
+}
+
+/// The type of a table column, normalized across the storage backends.
+#[derive(Debug, Eq, Clone, PartialEq)]
+pub enum ColumnType {
+    /// A point in time, i.e. DateTime64 in ClickHouse or TIMESTAMP in QuestDB.
+    Timestamp,
+    /// A floating point number, i.e. Float64 in ClickHouse or DOUBLE in QuestDB.
+    Float,
+    /// Text, i.e. String in ClickHouse or SYMBOL in QuestDB.
+    Text,
+    /// Any other type, with its name in the database.
+    Other(String),
+}
+
+/// A column of a table, as listed in the catalog of the database.
+#[derive(Debug, Eq, Clone, PartialEq)]
+pub struct TableColumn {
+    name: String,
+    column_type: ColumnType,
+}
+
+impl TableColumn {
+    pub fn new(name: &str, column_type: ColumnType) -> Self {
+        Self {
+            name: name.to_string(),
+            column_type,
+        }
+    }
+}
+
+impl TableColumn {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn column_type(&self) -> &ColumnType {
+        &self.column_type
+    }
+}
+
+#[derive(Debug, Eq, Clone, PartialEq, Row, Serialize, Deserialize)]
+pub struct CoverageRow {
+    first_trade: i64,
+    last_trade: i64,
+    row_count: u64,
+}
+
+impl CoverageRow {
+    pub fn first_trade(&self) -> Result<DateTime<Utc>, QueryError> {
+        row_timestamp("first_trade", self.first_trade)
+    }
+    pub fn last_trade(&self) -> Result<DateTime<Utc>, QueryError> {
+        row_timestamp("last_trade", self.last_trade)
+    }
+    pub fn row_count(&self) -> u64 {
+        self.row_count
+    }
+}
+
+#[derive(Debug, Eq, Clone, PartialEq, Row, Serialize, Deserialize)]
+pub struct GapRow {
+    gap_start: i64,
+    gap_end: i64,
+}
+
+impl GapRow {
+    pub fn gap_start(&self) -> Result<DateTime<Utc>, QueryError> {
+        row_timestamp("gap_start", self.gap_start)
+    }
+    pub fn gap_end(&self) -> Result<DateTime<Utc>, QueryError> {
+        row_timestamp("gap_end", self.gap_end)
+    }
+}
+
+/// Converts a timestamp in milliseconds of a result row into a UTC date time.
+///
+/// # Errors
+///
+/// - `QueryError::QueryFailed` if the timestamp is out of range.
+///
+fn row_timestamp(column: &str, millis: i64) -> Result<DateTime<Utc>, QueryError> {
+    match Utc.timestamp_millis_opt(millis).single() {
+        Some(date_time) => Ok(date_time),
+        None => Err(QueryError::QueryFailed(format!(
+            "Invalid {} timestamp in result row: {} milliseconds",
+            column, millis
+        ))),
+    }
+}
+
+/// A gap between two consecutive trades of a trade table.
+#[derive(Debug, Eq, Clone, Copy, PartialEq)]
+pub struct TradeGap {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+impl TradeGap {
+    /// Creates a new TradeGap from the trade before the gap to the trade after the gap.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl TradeGap {
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+    pub fn end(&self) -> DateTime<Utc> {
+        self.end
+    }
+    pub fn duration(&self) -> chrono::Duration {
+        self.end - self.start
+    }
+}
+
+/// The time coverage of the trades of a symbol: the first and last trade, the number of trades,
+/// and the gaps between consecutive trades longer than the gap threshold.
+///
+/// A coverage can be refreshed with the trades after its last trade, so that
+/// an import of new trades does not require a scan of the whole trade table.
+#[derive(Debug, Eq, Clone, PartialEq)]
+pub struct SymbolCoverage {
+    symbol_id: u16,
+    trade_table: String,
+    min_gap: Duration,
+    first_trade: Option<DateTime<Utc>>,
+    last_trade: Option<DateTime<Utc>>,
+    row_count: u64,
+    gaps: Vec<TradeGap>,
+}
+
+impl SymbolCoverage {
+    /// Creates a new SymbolCoverage of a trade table without trades.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - The ID of the symbol
+    /// * `trade_table` - The trade table of the symbol
+    /// * `min_gap` - Only gaps longer than this threshold are recorded
+    ///
+    pub fn new(symbol_id: u16, trade_table: &str, min_gap: Duration) -> Self {
+        Self {
+            symbol_id,
+            trade_table: trade_table.to_string(),
+            min_gap,
+            first_trade: None,
+            last_trade: None,
+            row_count: 0,
+            gaps: Vec::new(),
+        }
+    }
+
+    /// Sets the first and last trade and the number of trades.
+    pub fn with_trades(
+        mut self,
+        first_trade: DateTime<Utc>,
+        last_trade: DateTime<Utc>,
+        row_count: u64,
+    ) -> Self {
+        self.first_trade = Some(first_trade);
+        self.last_trade = Some(last_trade);
+        self.row_count = row_count;
+        self
+    }
+
+    /// Sets the gaps between consecutive trades, ordered by time.
+    pub fn with_gaps(mut self, gaps: Vec<TradeGap>) -> Self {
+        self.gaps = gaps;
+        self
+    }
+}
+
+impl SymbolCoverage {
+    /// Adds a trade at the given time, which must not be before the last trade.
+    pub(crate) fn add_trade(&mut self, date_time: DateTime<Utc>) {
+        if let Some(last_trade) = self.last_trade {
+            if self.is_gap(last_trade, date_time) {
+                self.gaps.push(TradeGap::new(last_trade, date_time));
+            }
+        }
+
+        self.first_trade.get_or_insert(date_time);
+        self.last_trade = Some(date_time);
+        self.row_count += 1;
+    }
+
+    /// Appends the coverage of the trades after the last trade of this coverage,
+    /// including the gap between the two coverages.
+    pub(crate) fn append(mut self, later: SymbolCoverage) -> Self {
+        let (first_trade, last_trade) = match (later.first_trade, later.last_trade) {
+            (Some(first_trade), Some(last_trade)) => (first_trade, last_trade),
+            _ => return self,
+        };
+
+        if let Some(previous_trade) = self.last_trade {
+            if self.is_gap(previous_trade, first_trade) {
+                self.gaps.push(TradeGap::new(previous_trade, first_trade));
+            }
+        }
+
+        self.first_trade.get_or_insert(first_trade);
+        self.last_trade = Some(last_trade);
+        self.row_count += later.row_count;
+        self.gaps.extend(later.gaps);
+        self
+    }
+
+    fn is_gap(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        match (end - start).to_std() {
+            Ok(duration) => duration > self.min_gap,
+            Err(_) => false,
+        }
+    }
+}
+
+impl SymbolCoverage {
+    pub fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+    pub fn trade_table(&self) -> &str {
+        &self.trade_table
+    }
+    pub fn min_gap(&self) -> Duration {
+        self.min_gap
+    }
+    pub fn first_trade(&self) -> Option<DateTime<Utc>> {
+        self.first_trade
+    }
+    pub fn last_trade(&self) -> Option<DateTime<Utc>> {
+        self.last_trade
+    }
+    pub fn row_count(&self) -> u64 {
+        self.row_count
+    }
+    pub fn gaps(&self) -> &[TradeGap] {
+        &self.gaps
+    }
+}
@@ -1,4 +1,6 @@
+use chrono::{TimeZone, Utc};
 use common::prelude::{ClickHouseConfig, TimeResolution};
+use db_query_manager::error::QueryError;
 use db_query_manager::QueryDBManager;
 use futures::StreamExt;
 use std::str::FromStr;
@@ -7,10 +9,25 @@ fn get_local_db_config() -> ClickHouseConfig {
     ClickHouseConfig::default()
 }
 
+#[tokio::test]
+async fn test_new_connection_failed() {
+    // Nothing listens on port 1
+    let db_config = ClickHouseConfig::new(
+        "127.0.0.1".to_string(),
+        1,
+        "".to_string(),
+        "".to_string(),
+        "default".to_string(),
+    );
+
+    let result = QueryDBManager::new(db_config).await;
+    assert!(matches!(result, Err(QueryError::ConnectionFailed(_))));
+}
+
 #[tokio::test]
 async fn test_get_all_symbol_ids() {
     let db_config = get_local_db_config();
-    let mut manager = QueryDBManager::new(db_config)
+    let manager = QueryDBManager::new(db_config)
         .await
         .expect("Failed to create db connection");
 
@@ -40,7 +57,7 @@ async fn test_get_all_symbol_ids() {
 #[tokio::test]
 async fn test_get_all_trades() {
     let db_config = get_local_db_config();
-    let mut manager = QueryDBManager::new(db_config)
+    let manager = QueryDBManager::new(db_config)
         .await
         .expect("Failed to create db connection");
 
@@ -68,17 +85,39 @@ async fn test_stream_trades() {
     let trade_table = "kraken_ethaed";
 
     // Call method under tes
-    let mut stream = manager.stream_trades(trade_table).await;
+    let mut stream = manager.stream_trades(trade_table, None, None).await;
 
     while let Some(Ok(record)) = stream.next().await {
         println!("Got {:?}", record);
     }
 }
 
+#[tokio::test]
+async fn test_stream_trades_time_range() {
+    let db_config = get_local_db_config();
+    let manager = QueryDBManager::new(db_config)
+        .await
+        .expect("Failed to create db connection");
+
+    let trade_table = "kraken_ethaed";
+    let start_time = Utc.with_ymd_and_hms(2018, 1, 1, 0, 0, 0).unwrap();
+    let end_time = Utc.with_ymd_and_hms(2019, 1, 1, 0, 0, 0).unwrap();
+
+    // Call method under test
+    let mut stream = manager
+        .stream_trades(trade_table, Some(start_time), Some(end_time))
+        .await;
+
+    while let Some(Ok(record)) = stream.next().await {
+        assert!(record.date_time() >= start_time);
+        assert!(record.date_time() < end_time);
+    }
+}
+
 #[tokio::test]
 async fn test_get_all_ohlcv_bars() {
     let db_config = get_local_db_config();
-    let mut manager = QueryDBManager::new(db_config)
+    let manager = QueryDBManager::new(db_config)
         .await
         .expect("Failed to create db connection");
 
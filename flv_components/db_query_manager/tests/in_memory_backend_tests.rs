@@ -0,0 +1,427 @@
+use chrono::{DateTime, TimeZone, Utc};
+use common::prelude::{OHLCVBar, TimeResolution, TimeScale, TradeBar};
+use db_query_manager::error::QueryError;
+use db_query_manager::types::{ColumnType, SymbolCoverage, TableColumn, TradeGap};
+use db_query_manager::{InMemoryBackend, QueryDBManager};
+use futures::StreamExt;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+const TRADES_CSV: &str = "\
+1699999980,10.0,1.0,b
+1700000010,12.0,2.0,s
+1700000039,11.0,1.0,b
+1700000040,9.0,4.0,s
+1700000165.5,8.0,0.0,s
+";
+
+fn time(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(secs, 0).unwrap()
+}
+
+fn get_trade(secs: i64, price: i64, volume: i64) -> TradeBar {
+    TradeBar::new(99, time(secs), Decimal::from(price), Decimal::from(volume))
+}
+
+fn get_query_manager() -> QueryDBManager {
+    let backend = InMemoryBackend::new()
+        .with_symbols(
+            "kraken_symbols",
+            vec![(1, "ethaed".to_string()), (2, "xbtusd".to_string())],
+        )
+        .with_trades(
+            "kraken_xbtusd",
+            vec![
+                get_trade(20, 3, 1),
+                get_trade(10, 1, 1),
+                get_trade(30, 5, 2),
+            ],
+        )
+        .expect("Failed to add trades")
+        .with_trades_from_csv_reader("kraken_ethaed", TRADES_CSV.as_bytes())
+        .expect("Failed to load trades from CSV");
+
+    QueryDBManager::from_backend(backend)
+}
+
+#[tokio::test]
+async fn test_is_open() {
+    let manager = get_query_manager();
+    assert!(manager.is_open().await);
+}
+
+#[tokio::test]
+async fn test_get_all_symbols_with_ids() {
+    let manager = get_query_manager();
+
+    let symbols = manager
+        .get_all_symbols_with_ids("kraken_symbols")
+        .await
+        .expect("Failed to get all symbols");
+
+    assert_eq!(
+        symbols,
+        vec![(1, "ethaed".to_string()), (2, "xbtusd".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_get_all_symbols_with_ids_unknown_table() {
+    let manager = get_query_manager();
+
+    let result = manager.get_all_symbols_with_ids("binance_symbols").await;
+    assert!(matches!(result, Err(QueryError::TableDoesNotExist(_, _))));
+
+    let result = manager.get_all_symbols_with_ids("kraken; DROP TABLE").await;
+    assert!(matches!(result, Err(QueryError::InvalidTableName(_))));
+}
+
+#[tokio::test]
+async fn test_get_all_trades() {
+    let manager = get_query_manager();
+
+    let trades = manager
+        .get_all_trades(2, "kraken_xbtusd")
+        .await
+        .expect("Failed to get all trades");
+
+    // The trades are ordered by timestamp and carry the symbol ID of the query.
+    let expected = vec![
+        TradeBar::new(2, time(10), Decimal::from(1), Decimal::from(1)),
+        TradeBar::new(2, time(20), Decimal::from(3), Decimal::from(1)),
+        TradeBar::new(2, time(30), Decimal::from(5), Decimal::from(2)),
+    ];
+    assert_eq!(trades, expected);
+}
+
+#[tokio::test]
+async fn test_stream_trades_time_range() {
+    let manager = get_query_manager();
+
+    let trades: Vec<DateTime<Utc>> = manager
+        .stream_trades("kraken_xbtusd", Some(time(10)), Some(time(30)))
+        .await
+        .map(|record| record.expect("Failed to stream trade").date_time())
+        .collect()
+        .await;
+
+    // The start of the time range is inclusive, the end exclusive.
+    assert_eq!(trades, vec![time(10), time(20)]);
+}
+
+#[tokio::test]
+async fn test_stream_trades_unknown_table() {
+    let manager = get_query_manager();
+
+    let records: Vec<_> = manager
+        .stream_trades("kraken_unknown", None, None)
+        .await
+        .collect()
+        .await;
+
+    assert_eq!(records.len(), 1);
+    assert!(matches!(
+        records[0],
+        Err(QueryError::TableDoesNotExist(_, _))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_table_columns() {
+    let manager = get_query_manager();
+
+    let columns = manager
+        .get_table_columns("kraken_ethaed")
+        .await
+        .expect("Failed to get table columns");
+
+    assert_eq!(
+        columns,
+        vec![
+            TableColumn::new("timestamp", ColumnType::Timestamp),
+            TableColumn::new("price", ColumnType::Float),
+            TableColumn::new("volume", ColumnType::Float),
+            TableColumn::new("side", ColumnType::Text),
+        ]
+    );
+
+    // Lookups of missing tables are cached, too
+    for _ in 0..2 {
+        let result = manager.get_table_columns("kraken_unknown").await;
+        assert!(matches!(result, Err(QueryError::TableDoesNotExist(_, _))));
+    }
+}
+
+#[tokio::test]
+async fn test_validate_trade_table() {
+    let manager = get_query_manager().with_catalog_ttl(Duration::ZERO);
+
+    assert!(manager.validate_trade_table("kraken_xbtusd").await.is_ok());
+
+    let result = manager.validate_trade_table("kraken_unknown").await;
+    assert!(matches!(result, Err(QueryError::TableDoesNotExist(_, _))));
+
+    // A symbol table has no trade columns
+    let result = manager.validate_trade_table("kraken_symbols").await;
+    assert!(matches!(result, Err(QueryError::InvalidTableSchema(_, _))));
+
+    manager.clear_catalog();
+    let result = manager.validate_trade_table("kraken_symbols").await;
+    assert!(result.err().unwrap().is_permanent());
+}
+
+#[tokio::test]
+async fn test_get_symbol_coverage() {
+    let manager = get_query_manager();
+    let min_gap = Duration::from_secs(29);
+
+    let coverage = manager
+        .get_symbol_coverage(1, "kraken_ethaed", min_gap)
+        .await
+        .expect("Failed to get symbol coverage");
+
+    assert_eq!(coverage.symbol_id(), 1);
+    assert_eq!(coverage.trade_table(), "kraken_ethaed");
+    assert_eq!(coverage.row_count(), 5);
+    assert_eq!(coverage.first_trade(), Some(time(1699999980)));
+    let last_trade = Utc.timestamp_millis_opt(1700000165500).unwrap();
+    assert_eq!(coverage.last_trade(), Some(last_trade));
+
+    // Only the gaps longer than 29 seconds
+    let gaps = coverage.gaps();
+    assert_eq!(gaps.len(), 2);
+    assert_eq!(gaps[0], TradeGap::new(time(1699999980), time(1700000010)));
+    assert_eq!(gaps[1], TradeGap::new(time(1700000040), last_trade));
+    assert_eq!(gaps[1].duration().num_milliseconds(), 125500);
+}
+
+#[tokio::test]
+async fn test_get_symbol_coverage_unknown_table() {
+    let manager = get_query_manager();
+
+    let result = manager
+        .get_symbol_coverage(3, "kraken_unknown", Duration::from_secs(60))
+        .await;
+    assert!(matches!(result, Err(QueryError::TableDoesNotExist(_, _))));
+}
+
+#[tokio::test]
+async fn test_refresh_symbol_coverage() {
+    let manager = get_query_manager();
+    let min_gap = Duration::from_secs(29);
+
+    // The coverage before the last three trades got imported
+    let coverage = SymbolCoverage::new(1, "kraken_ethaed", min_gap)
+        .with_trades(time(1699999980), time(1700000010), 2)
+        .with_gaps(vec![TradeGap::new(time(1699999980), time(1700000010))]);
+
+    let refreshed = manager
+        .refresh_symbol_coverage(coverage)
+        .await
+        .expect("Failed to refresh symbol coverage");
+
+    let expected = manager
+        .get_symbol_coverage(1, "kraken_ethaed", min_gap)
+        .await
+        .expect("Failed to get symbol coverage");
+    assert_eq!(refreshed, expected);
+
+    // Nothing was imported since
+    let refreshed_again = manager
+        .refresh_symbol_coverage(refreshed.clone())
+        .await
+        .expect("Failed to refresh symbol coverage");
+    assert_eq!(refreshed_again, refreshed);
+}
+
+#[tokio::test]
+async fn test_get_all_ohlcv_bars() {
+    let manager = get_query_manager();
+
+    let bars = manager
+        .get_all_ohlcv_bars(1, "kraken_ethaed", &TimeResolution::OneMin)
+        .await
+        .expect("Failed to get all OHLCV bars");
+
+    assert_eq!(bars.len(), 3);
+
+    let first = &bars[0];
+    assert_eq!(first.symbol_id(), 1);
+    assert_eq!(first.date_time(), time(1_699_999_980));
+    assert_eq!(first.open(), Decimal::from(10));
+    assert_eq!(first.high(), Decimal::from(12));
+    assert_eq!(first.low(), Decimal::from(10));
+    assert_eq!(first.close(), Decimal::from(11));
+    assert_eq!(first.volume(), Decimal::from(4));
+    // (10 * 1 + 12 * 2 + 11 * 1) / 4
+    assert_eq!(
+        first.vwap(),
+        Some(Decimal::from_str_exact("11.25").unwrap())
+    );
+    assert_eq!(first.trade_count(), Some(3));
+    assert_eq!(first.buy_volume(), Some(Decimal::from(2)));
+    assert_eq!(first.sell_volume(), Some(Decimal::from(2)));
+
+    // Intervals without trades have no bar.
+    assert_eq!(bars[1].date_time(), time(1_700_000_040));
+    assert_eq!(bars[2].date_time(), time(1_700_000_160));
+
+    // Without volume, the vwap is the close price.
+    assert_eq!(bars[2].vwap(), Some(Decimal::from(8)));
+}
+
+#[tokio::test]
+async fn test_get_all_ohlcv_bars_without_side() {
+    let manager = get_query_manager();
+
+    let bars = manager
+        .get_all_ohlcv_bars(2, "kraken_xbtusd", &TimeResolution::OneMin)
+        .await
+        .expect("Failed to get all OHLCV bars");
+
+    assert_eq!(bars.len(), 1);
+    assert_eq!(bars[0].volume(), Decimal::from(4));
+    assert_eq!(bars[0].trade_count(), Some(3));
+    assert_eq!(bars[0].buy_volume(), None);
+    assert_eq!(bars[0].sell_volume(), None);
+}
+
+#[tokio::test]
+async fn test_get_all_ohlcv_bars_calendar_intervals() {
+    let date = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 12, 0, 0).unwrap();
+    let start_of_day = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+
+    let trades = vec![
+        TradeBar::new(1, date(2024, 1, 3), Decimal::ONE, Decimal::ONE),
+        TradeBar::new(1, date(2024, 1, 8), Decimal::ONE, Decimal::ONE),
+        TradeBar::new(1, date(2024, 5, 10), Decimal::ONE, Decimal::ONE),
+    ];
+    let backend = InMemoryBackend::new()
+        .with_trades("kraken_ethaed", trades)
+        .expect("Failed to add trades");
+    let manager = QueryDBManager::from_backend(backend);
+
+    let bar_times = |bars: Vec<OHLCVBar>| -> Vec<DateTime<Utc>> {
+        bars.iter().map(|bar| bar.date_time()).collect()
+    };
+
+    // Weeks start on Monday.
+    let bars = manager
+        .get_all_ohlcv_bars(1, "kraken_ethaed", &TimeResolution::OneWeek)
+        .await
+        .expect("Failed to get weekly bars");
+    assert_eq!(
+        bar_times(bars),
+        vec![
+            start_of_day(2024, 1, 1),
+            start_of_day(2024, 1, 8),
+            start_of_day(2024, 5, 6)
+        ]
+    );
+
+    let bars = manager
+        .get_all_ohlcv_bars(1, "kraken_ethaed", &TimeResolution::OneMonth)
+        .await
+        .expect("Failed to get monthly bars");
+    assert_eq!(
+        bar_times(bars),
+        vec![start_of_day(2024, 1, 1), start_of_day(2024, 5, 1)]
+    );
+
+    let quarter = TimeResolution::new(1, TimeScale::Quarter).unwrap();
+    let bars = manager
+        .get_all_ohlcv_bars(1, "kraken_ethaed", &quarter)
+        .await
+        .expect("Failed to get quarterly bars");
+    assert_eq!(
+        bar_times(bars),
+        vec![start_of_day(2024, 1, 1), start_of_day(2024, 4, 1)]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_ohlcv() {
+    let manager = get_query_manager();
+    let two_min = TimeResolution::new(2, TimeScale::Minute).unwrap();
+
+    let bars: Vec<(DateTime<Utc>, Decimal)> = manager
+        .stream_ohlcv("kraken_ethaed", &two_min, Some(time(1_700_000_010)), None)
+        .await
+        .map(|record| {
+            let bar = record.expect("Failed to stream OHLCV bar");
+            (bar.date_time(), bar.open())
+        })
+        .collect()
+        .await;
+
+    assert_eq!(
+        bars,
+        vec![
+            (time(1_699_999_920), Decimal::from(12)),
+            (time(1_700_000_040), Decimal::from(9)),
+            (time(1_700_000_160), Decimal::from(8)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_ohlcv_invalid_time_resolution() {
+    let manager = get_query_manager();
+
+    let records: Vec<_> = manager
+        .stream_ohlcv("kraken_ethaed", &TimeResolution::NoValue, None, None)
+        .await
+        .collect()
+        .await;
+
+    assert_eq!(records.len(), 1);
+    assert!(matches!(
+        records[0],
+        Err(QueryError::InvalidTimeResolution(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_stream_trades_merged() {
+    let manager = get_query_manager();
+    let trade_tables = vec![
+        (1, "kraken_ethaed".to_string()),
+        (2, "kraken_xbtusd".to_string()),
+    ];
+
+    let symbol_ids: Vec<u16> = manager
+        .stream_trades_merged(&trade_tables, None, None)
+        .await
+        .map(|record| record.expect("Failed to stream trade").0)
+        .collect()
+        .await;
+
+    assert_eq!(symbol_ids, vec![2, 2, 2, 1, 1, 1, 1, 1]);
+}
+
+#[test]
+fn test_with_trades_from_csv_reader_invalid_data() {
+    let result = InMemoryBackend::new()
+        .with_trades_from_csv_reader("kraken_ethaed", "1700000000,abc,1.0\n".as_bytes());
+    assert!(matches!(result, Err(QueryError::InvalidTradeData(_))));
+
+    let result = InMemoryBackend::new().with_trades_from_csv_reader(
+        "kraken_ethaed",
+        "1700000000,1.0,1.0,b\n1700000001,1.0,1.0\n".as_bytes(),
+    );
+    assert!(matches!(result, Err(QueryError::InvalidTradeData(_))));
+}
+
+#[test]
+fn test_with_trades_before_epoch() {
+    let trades = vec![get_trade(10, 1, 1), get_trade(-10, 1, 1)];
+
+    let result = InMemoryBackend::new().with_trades("kraken_ethaed", trades);
+    assert!(matches!(result, Err(QueryError::InvalidTradeData(_))));
+}
+
+#[test]
+fn test_with_trades_from_csv_missing_file() {
+    let result = InMemoryBackend::new().with_trades_from_csv("kraken_ethaed", "/no/such/file.csv");
+    assert!(matches!(result, Err(QueryError::InvalidTradeData(_))));
+}
@@ -1 +1,4 @@
 mod db_query_manager_tests;
+mod in_memory_backend_tests;
+mod questdb_backend_tests;
+mod stream_merge_tests;
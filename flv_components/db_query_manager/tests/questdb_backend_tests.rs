@@ -0,0 +1,498 @@
+use chrono::{TimeZone, Utc};
+use common::prelude::{DBConfig, TimeResolution, TimeScale};
+use db_query_manager::error::QueryError;
+use db_query_manager::types::{ColumnType, TableColumn};
+use db_query_manager::{QueryDBManager, QuestDBBackend, StorageBackend};
+use futures::StreamExt;
+use rust_decimal::Decimal;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+// A mock QuestDB that answers the queries of the QuestDBBackend over the PostgreSQL wire protocol.
+async fn start_mock_questdb() -> DBConfig {
+    start_mock_questdb_dropping(0).await
+}
+
+// A mock QuestDB that drops the connection instead of answering the first `drops` simple queries.
+async fn start_mock_questdb_dropping(drops: usize) -> DBConfig {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind mock QuestDB");
+    let port = listener.local_addr().unwrap().port();
+    let drops = Arc::new(AtomicUsize::new(drops));
+
+    tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            tokio::spawn(serve_connection(socket, drops.clone()));
+        }
+    });
+
+    DBConfig::new_with_pg_config(
+        9009,
+        "127.0.0.1".to_string(),
+        "admin".to_string(),
+        "quest".to_string(),
+        "qdb".to_string(),
+        port,
+        10,
+    )
+}
+
+async fn serve_connection(mut socket: TcpStream, drops: Arc<AtomicUsize>) {
+    // Startup message without type byte
+    let len = socket.read_i32().await.unwrap();
+    let mut startup = vec![0; len as usize - 4];
+    socket.read_exact(&mut startup).await.unwrap();
+
+    let mut reply = frame(b'R', &0_i32.to_be_bytes());
+    reply.extend_from_slice(&frame(b'Z', b"I"));
+    socket.write_all(&reply).await.unwrap();
+
+    let mut portal_query = String::new();
+
+    loop {
+        let tag = match socket.read_u8().await {
+            Ok(tag) => tag,
+            Err(_) => return,
+        };
+        let len = socket.read_i32().await.unwrap();
+        let mut body = vec![0; len as usize - 4];
+        socket.read_exact(&mut body).await.unwrap();
+
+        let reply = match tag {
+            b'Q' => {
+                let drop = drops.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |drops| {
+                    drops.checked_sub(1)
+                });
+                if drop.is_ok() {
+                    return;
+                }
+
+                let query = cstr(&body);
+                let mut reply = query_reply(&query);
+                reply.extend_from_slice(&frame(b'Z', b"I"));
+                reply
+            }
+            b'P' => {
+                // Skip the statement name
+                let start = body.iter().position(|b| *b == 0).unwrap() + 1;
+                portal_query = cstr(&body[start..]);
+                Vec::new()
+            }
+            b'E' => query_reply(&portal_query),
+            b'S' => frame(b'Z', b"I"),
+            b'X' => return,
+            // Bind and flush
+            _ => Vec::new(),
+        };
+
+        socket.write_all(&reply).await.unwrap();
+    }
+}
+
+fn query_reply(query: &str) -> Vec<u8> {
+    if query.contains("missing") {
+        let mut body = b"SERROR\0Mtable does not exist [table=missing]\0".to_vec();
+        body.push(0);
+        return frame(b'E', &body);
+    }
+
+    let rows: Vec<Vec<Option<&str>>> = if query.starts_with("SELECT symbol_id") {
+        vec![
+            vec![Some("1"), Some("ethaed")],
+            vec![Some("2"), Some("xbtusd")],
+        ]
+    } else if query.contains("tables()") {
+        // Dropped tables do not exist
+        if query.contains("kraken_dropped") {
+            vec![vec![Some("0")]]
+        } else {
+            vec![vec![Some("1")]]
+        }
+    } else if query.starts_with("SELECT \"column\", type") {
+        // Tables with a bad schema store prices as strings
+        let price_type = if query.contains("kraken_badschema") {
+            "STRING"
+        } else {
+            "DOUBLE"
+        };
+        vec![
+            vec![Some("timestamp"), Some("TIMESTAMP")],
+            vec![Some("price"), Some(price_type)],
+            vec![Some("volume"), Some("DOUBLE")],
+            vec![Some("side"), Some("SYMBOL")],
+        ]
+    } else if query.contains("table_columns") {
+        vec![vec![Some("1")]]
+    } else if query.contains("SAMPLE BY") {
+        vec![
+            vec![
+                Some("2023-11-14 22:13:00.000000"),
+                Some("100.0"),
+                Some("102.0"),
+                Some("99.0"),
+                Some("101.0"),
+                Some("4.0"),
+                Some("100.5"),
+                Some("3"),
+                Some("3.0"),
+                Some("1.0"),
+            ],
+            vec![
+                Some("2023-11-14 22:14:00.000000"),
+                Some("101.0"),
+                Some("101.0"),
+                Some("101.0"),
+                Some("101.0"),
+                Some("0.0"),
+                None,
+                Some("1"),
+                Some("0.0"),
+                Some("0.0"),
+            ],
+        ]
+    } else if query.starts_with("SELECT timestamp, price, volume") {
+        vec![
+            vec![
+                Some("2023-11-14 22:13:20.000000"),
+                Some("100.0"),
+                Some("1.5"),
+            ],
+            vec![
+                Some("2023-11-14T22:13:21.500000Z"),
+                Some("101.0"),
+                Some("2.5"),
+            ],
+        ]
+    } else {
+        Vec::new()
+    };
+
+    let mut reply = Vec::new();
+    for row in rows {
+        let mut body = (row.len() as i16).to_be_bytes().to_vec();
+        for value in row {
+            match value {
+                Some(value) => {
+                    body.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                    body.extend_from_slice(value.as_bytes());
+                }
+                None => body.extend_from_slice(&(-1_i32).to_be_bytes()),
+            }
+        }
+        reply.extend_from_slice(&frame(b'D', &body));
+    }
+    reply.extend_from_slice(&frame(b'C', b"SELECT\0"));
+    reply
+}
+
+fn frame(tag: u8, body: &[u8]) -> Vec<u8> {
+    let mut message = vec![tag];
+    message.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+    message.extend_from_slice(body);
+    message
+}
+
+fn cstr(buf: &[u8]) -> String {
+    let end = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+    String::from_utf8_lossy(&buf[..end]).into_owned()
+}
+
+#[tokio::test]
+async fn test_questdb_connection_failed() {
+    // Nothing listens on port 1
+    let db_config = DBConfig::new_with_pg_config(
+        9009,
+        "127.0.0.1".to_string(),
+        "admin".to_string(),
+        "quest".to_string(),
+        "qdb".to_string(),
+        1,
+        10,
+    );
+
+    let result = QuestDBBackend::new(db_config).await;
+    assert!(matches!(result, Err(QueryError::ConnectionFailed(_))));
+    assert!(result.err().unwrap().is_transient());
+}
+
+#[tokio::test]
+async fn test_questdb_reconnect() {
+    let backend = QuestDBBackend::new(start_mock_questdb_dropping(2).await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    // The backend reconnects after each dropped connection and retries the query
+    let trades = backend
+        .get_all_trades(1, "kraken_ethaed")
+        .await
+        .expect("Failed to get trades after reconnecting");
+
+    assert_eq!(trades.len(), 2);
+    assert!(backend.is_open().await);
+}
+
+#[tokio::test]
+async fn test_questdb_reconnect_gives_up() {
+    let backend = QuestDBBackend::new(start_mock_questdb_dropping(usize::MAX).await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let result = backend.get_all_trades(1, "kraken_ethaed").await;
+    assert!(matches!(result, Err(QueryError::ConnectionFailed(_))));
+}
+
+#[tokio::test]
+async fn test_questdb_get_all_symbols_with_ids() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+    assert!(backend.is_open().await);
+
+    let symbols = backend
+        .get_all_symbols_with_ids("kraken_symbols")
+        .await
+        .expect("Failed to get symbols");
+
+    assert_eq!(
+        symbols,
+        vec![(1, "ethaed".to_string()), (2, "xbtusd".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_questdb_get_all_trades() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let trades = backend
+        .get_all_trades(1, "kraken_ethaed")
+        .await
+        .expect("Failed to get trades");
+
+    assert_eq!(trades.len(), 2);
+    assert_eq!(trades[0].symbol_id(), 1);
+    assert_eq!(
+        trades[0].date_time(),
+        Utc.with_ymd_and_hms(2023, 11, 14, 22, 13, 20).unwrap()
+    );
+    assert_eq!(trades[1].price(), Decimal::from(101));
+    assert_eq!(trades[1].volume(), Decimal::new(25, 1));
+}
+
+#[tokio::test]
+async fn test_questdb_query_failed() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let result = backend.get_all_trades(1, "missing").await;
+    assert!(matches!(result, Err(QueryError::QueryFailed(_))));
+    assert!(result.err().unwrap().is_permanent());
+
+    // The connection stays usable after a failed query
+    let result = backend.get_all_trades(1, "kraken_ethaed").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_questdb_invalid_table_name() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let result = backend
+        .get_all_trades(1, "kraken_ethaed; DROP TABLE x")
+        .await;
+    assert!(matches!(result, Err(QueryError::InvalidTableName(_))));
+}
+
+#[tokio::test]
+async fn test_questdb_get_all_ohlcv_bars() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let bars = backend
+        .get_all_ohlcv_bars(1, "kraken_ethaed", &TimeResolution::OneMin)
+        .await
+        .expect("Failed to get OHLCV bars");
+
+    assert_eq!(bars.len(), 2);
+    assert_eq!(bars[0].open(), Decimal::from(100));
+    assert_eq!(bars[0].high(), Decimal::from(102));
+    assert_eq!(bars[0].low(), Decimal::from(99));
+    assert_eq!(bars[0].close(), Decimal::from(101));
+    assert_eq!(bars[0].volume(), Decimal::from(4));
+    assert_eq!(bars[0].vwap(), Some(Decimal::new(1005, 1)));
+    assert_eq!(bars[0].trade_count(), Some(3));
+    assert_eq!(bars[0].buy_volume(), Some(Decimal::from(3)));
+    assert_eq!(bars[0].sell_volume(), Some(Decimal::from(1)));
+
+    // Without volume, the VWAP falls back to the close price
+    assert_eq!(bars[1].vwap(), Some(Decimal::from(101)));
+}
+
+#[tokio::test]
+async fn test_questdb_stream_trades() {
+    let db_config = start_mock_questdb().await;
+    let manager = QueryDBManager::from_backend(
+        QuestDBBackend::new(db_config)
+            .await
+            .expect("Failed to connect to mock QuestDB"),
+    );
+
+    let start = Utc.with_ymd_and_hms(2023, 11, 14, 0, 0, 0).unwrap();
+    let trades: Vec<_> = manager
+        .stream_trades("kraken_ethaed", Some(start), None)
+        .await
+        .collect()
+        .await;
+
+    assert_eq!(trades.len(), 2);
+    let trade = trades[1].as_ref().expect("Failed to stream trade");
+    assert_eq!(trade.date_time().timestamp_millis(), 1_700_000_001_500);
+    assert_eq!(trade.price(), Decimal::from(101));
+}
+
+#[tokio::test]
+async fn test_questdb_stream_query_failed() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let items: Vec<_> = backend
+        .stream_trades("missing", None, None)
+        .await
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0], Err(QueryError::QueryFailed(_))));
+}
+
+#[tokio::test]
+async fn test_questdb_stream_ohlcv() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let bars: Vec<_> = backend
+        .stream_ohlcv("kraken_ethaed", &TimeResolution::OneMin, None, None)
+        .await
+        .collect()
+        .await;
+
+    assert_eq!(bars.len(), 2);
+    let bar = bars[0].as_ref().expect("Failed to stream bar");
+    assert_eq!(bar.trade_count(), 3);
+    assert_eq!(bar.buy_volume(), Some(Decimal::from(3)));
+}
+
+#[tokio::test]
+async fn test_questdb_build_sample_by_interval() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    assert_eq!(
+        backend.build_sample_by_interval(&TimeResolution::OneMin),
+        "1m"
+    );
+    assert_eq!(
+        backend.build_sample_by_interval(&TimeResolution::from_parts(4, TimeScale::Hour)),
+        "4h"
+    );
+    assert_eq!(
+        backend.build_sample_by_interval(&TimeResolution::OneWeek),
+        "1w"
+    );
+    assert_eq!(
+        backend.build_sample_by_interval(&TimeResolution::OneMonth),
+        "1M"
+    );
+    assert_eq!(
+        backend.build_sample_by_interval(&TimeResolution::from_parts(1, TimeScale::Quarter)),
+        "3M"
+    );
+    assert_eq!(
+        backend.build_sample_by_interval(&TimeResolution::OneYear),
+        "1y"
+    );
+}
+
+#[tokio::test]
+async fn test_questdb_build_get_trades_query() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let start = Utc.with_ymd_and_hms(2023, 11, 14, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 11, 15, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        backend.build_get_trades_query("kraken_ethaed", None, None),
+        "SELECT timestamp, price, volume FROM kraken_ethaed"
+    );
+    assert_eq!(
+        backend.build_get_trades_query("kraken_ethaed", Some(start), Some(end)),
+        "SELECT timestamp, price, volume FROM kraken_ethaed \
+        WHERE timestamp >= '2023-11-14T00:00:00.000000Z' AND timestamp < '2023-11-15T00:00:00.000000Z'"
+    );
+}
+
+#[tokio::test]
+async fn test_questdb_get_table_columns() {
+    let backend = QuestDBBackend::new(start_mock_questdb().await)
+        .await
+        .expect("Failed to connect to mock QuestDB");
+
+    let columns = backend
+        .get_table_columns("kraken_ethaed")
+        .await
+        .expect("Failed to get table columns");
+
+    assert_eq!(
+        columns,
+        vec![
+            TableColumn::new("timestamp", ColumnType::Timestamp),
+            TableColumn::new("price", ColumnType::Float),
+            TableColumn::new("volume", ColumnType::Float),
+            TableColumn::new("side", ColumnType::Text),
+        ]
+    );
+
+    let result = backend.get_table_columns("kraken_dropped").await;
+    assert!(matches!(result, Err(QueryError::TableDoesNotExist(_, _))));
+}
+
+#[tokio::test]
+async fn test_questdb_stream_validates_trade_table() {
+    let manager = QueryDBManager::from_backend(
+        QuestDBBackend::new(start_mock_questdb().await)
+            .await
+            .expect("Failed to connect to mock QuestDB"),
+    );
+
+    let items: Vec<_> = manager
+        .stream_trades("kraken_dropped", None, None)
+        .await
+        .collect()
+        .await;
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0], Err(QueryError::TableDoesNotExist(_, _))));
+
+    let items: Vec<_> = manager
+        .stream_ohlcv("kraken_badschema", &TimeResolution::OneMin, None, None)
+        .await
+        .collect()
+        .await;
+    assert_eq!(items.len(), 1);
+    assert!(matches!(
+        items[0],
+        Err(QueryError::InvalidTableSchema(_, _))
+    ));
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, TimeZone, Utc};
+use db_query_manager::merge_streams_by_time;
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+
+type TimeStream = BoxStream<'static, Result<DateTime<Utc>, String>>;
+
+fn time(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(secs, 0).unwrap()
+}
+
+fn get_stream(secs: Vec<i64>) -> TimeStream {
+    stream::iter(secs.into_iter().map(|s| Ok(time(s)))).boxed()
+}
+
+#[tokio::test]
+async fn test_merge_streams_by_time() {
+    let streams = vec![
+        (1, get_stream(vec![1, 4, 7])),
+        (2, get_stream(vec![2, 5, 8])),
+        (3, get_stream(vec![3, 6, 9])),
+    ];
+
+    let merged: Vec<_> = merge_streams_by_time(streams, |t: &DateTime<Utc>| *t)
+        .collect()
+        .await;
+
+    let expected: Vec<Result<(u16, DateTime<Utc>), String>> = vec![
+        Ok((1, time(1))),
+        Ok((2, time(2))),
+        Ok((3, time(3))),
+        Ok((1, time(4))),
+        Ok((2, time(5))),
+        Ok((3, time(6))),
+        Ok((1, time(7))),
+        Ok((2, time(8))),
+        Ok((3, time(9))),
+    ];
+    assert_eq!(expected, merged);
+}
+
+#[tokio::test]
+async fn test_merge_streams_by_time_uneven() {
+    let streams = vec![
+        (1, get_stream(vec![])),
+        (2, get_stream(vec![1, 2, 3, 10])),
+        (3, get_stream(vec![5])),
+    ];
+
+    let merged: Vec<_> = merge_streams_by_time(streams, |t: &DateTime<Utc>| *t)
+        .collect()
+        .await;
+
+    let expected: Vec<Result<(u16, DateTime<Utc>), String>> = vec![
+        Ok((2, time(1))),
+        Ok((2, time(2))),
+        Ok((2, time(3))),
+        Ok((3, time(5))),
+        Ok((2, time(10))),
+    ];
+    assert_eq!(expected, merged);
+}
+
+#[tokio::test]
+async fn test_merge_streams_by_time_ties() {
+    let streams = vec![(7, get_stream(vec![1, 2])), (3, get_stream(vec![1, 2]))];
+
+    let merged: Vec<_> = merge_streams_by_time(streams, |t: &DateTime<Utc>| *t)
+        .collect()
+        .await;
+
+    // Equal timestamps keep the order of the input streams
+    let expected: Vec<Result<(u16, DateTime<Utc>), String>> = vec![
+        Ok((7, time(1))),
+        Ok((3, time(1))),
+        Ok((7, time(2))),
+        Ok((3, time(2))),
+    ];
+    assert_eq!(expected, merged);
+}
+
+#[tokio::test]
+async fn test_merge_streams_by_time_error() {
+    let failing = stream::iter(vec![Ok(time(2)), Err("connection lost".to_string())]).boxed();
+    let streams = vec![(1, get_stream(vec![1, 3])), (2, failing)];
+
+    let mut merged = merge_streams_by_time(streams, |t: &DateTime<Utc>| *t);
+
+    assert_eq!(merged.next().await, Some(Ok((1, time(1)))));
+    assert_eq!(merged.next().await, Some(Ok((2, time(2)))));
+    assert_eq!(
+        merged.next().await,
+        Some(Err("connection lost".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_merge_streams_by_time_empty() {
+    let streams: Vec<(u16, TimeStream)> = vec![];
+
+    let merged: Vec<_> = merge_streams_by_time(streams, |t: &DateTime<Utc>| *t)
+        .collect()
+        .await;
+
+    assert!(merged.is_empty());
+}
@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// A snapshot of the current usage of a client.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClientUsage {
+    control_tokens: u32,
+    rows_in_window: u64,
+    bytes_in_window: u64,
+}
+
+impl ClientUsage {
+    pub fn new(control_tokens: u32, rows_in_window: u64, bytes_in_window: u64) -> Self {
+        Self {
+            control_tokens,
+            rows_in_window,
+            bytes_in_window,
+        }
+    }
+}
+
+impl ClientUsage {
+    /// Returns the number of control messages the client can send without waiting.
+    pub fn control_tokens(&self) -> u32 {
+        self.control_tokens
+    }
+    /// Returns the number of rows streamed to the client in the current window.
+    pub fn rows_in_window(&self) -> u64 {
+        self.rows_in_window
+    }
+    /// Returns the number of bytes streamed to the client in the current window.
+    pub fn bytes_in_window(&self) -> u64 {
+        self.bytes_in_window
+    }
+}
+
+impl fmt::Display for ClientUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ClientUsage {{ control_tokens: {}, rows_in_window: {}, bytes_in_window: {} }}",
+            self.control_tokens, self.rows_in_window, self.bytes_in_window
+        )
+    }
+}
@@ -0,0 +1,13 @@
+mod client_usage;
+mod rate_limit_exceeded;
+mod rate_limiter;
+mod rate_limits;
+mod token_bucket;
+mod usage_window;
+
+pub use crate::client_usage::ClientUsage;
+pub use crate::rate_limit_exceeded::RateLimitExceeded;
+pub use crate::rate_limiter::RateLimiter;
+pub use crate::rate_limits::RateLimits;
+pub use crate::token_bucket::TokenBucket;
+pub use crate::usage_window::UsageWindow;
@@ -0,0 +1,37 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+/// The error returned when a client exceeds one of its rate limits.
+///
+/// Carries the time after which the client can retry, if it is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitExceeded {
+    retry_after: Option<Duration>,
+}
+
+impl RateLimitExceeded {
+    pub fn new(retry_after: Option<Duration>) -> Self {
+        Self { retry_after }
+    }
+
+    /// Returns the time after which the client can retry, if it is known.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+}
+
+impl Error for RateLimitExceeded {}
+
+impl fmt::Display for RateLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.retry_after {
+            Some(retry_after) => write!(
+                f,
+                "RateLimitExceeded: retry after {} ms",
+                retry_after.as_millis()
+            ),
+            None => write!(f, "RateLimitExceeded"),
+        }
+    }
+}
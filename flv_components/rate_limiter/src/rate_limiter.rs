@@ -0,0 +1,231 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Instant;
+
+use crate::{ClientUsage, RateLimitExceeded, RateLimits, TokenBucket, UsageWindow};
+
+/// Applies the same RateLimits to each client.
+///
+/// The state of a client is created with its first request and kept until
+/// it gets removed, usually when the client logs out. Login attempts are limited
+/// per credential instead, so that attempts with wrong credentials never use up
+/// the attempts of the client that holds the right ones. All methods take the
+/// current time, so the caller decides on the clock.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limits: RateLimits,
+    clients: Mutex<HashMap<u16, ClientState>>,
+    // Keyed by a hash of the credential, so that no auth token is kept in memory.
+    logins: Mutex<HashMap<u64, TokenBucket>>,
+}
+
+#[derive(Debug)]
+struct ClientState {
+    control: TokenBucket,
+    usage: UsageWindow,
+}
+
+impl ClientState {
+    fn new(limits: &RateLimits, now: Instant) -> Self {
+        Self {
+            control: TokenBucket::new(limits.control_burst(), limits.control_per_second(), now),
+            usage: UsageWindow::new(limits.window(), now),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Creates a new RateLimiter without any client state.
+    pub fn new(limits: RateLimits) -> Self {
+        Self {
+            limits,
+            clients: Mutex::new(HashMap::new()),
+            logins: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the limits applied to each client.
+    pub fn limits(&self) -> &RateLimits {
+        &self.limits
+    }
+}
+
+impl RateLimiter {
+    /// Takes one token from the control message bucket of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The ID of the client that sent the control message
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// Ok if the client is within its rate, otherwise a RateLimitExceeded
+    /// with the time until the client can send the next control message.
+    ///
+    pub fn check_control_message(
+        &self,
+        client_id: u16,
+        now: Instant,
+    ) -> Result<(), RateLimitExceeded> {
+        let mut clients = self.lock_clients();
+        let client = clients
+            .entry(client_id)
+            .or_insert_with(|| ClientState::new(&self.limits, now));
+
+        match client.control.try_acquire(now) {
+            Ok(_) => Ok(()),
+            Err(retry_after) => Err(RateLimitExceeded::new(Some(retry_after))),
+        }
+    }
+
+    /// Takes one token from the login bucket of the given credential.
+    ///
+    /// Buckets that refilled completely are dropped when a new credential shows up,
+    /// as they do not differ from a new bucket, so that the number of buckets stays
+    /// bounded by the rate of login attempts.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The ID of the client that attempts to log in
+    /// * `auth_token` - The auth token of the login attempt
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// Ok if the credential is within its rate, otherwise a RateLimitExceeded
+    /// with the time until the next login attempt with the credential.
+    ///
+    pub fn check_login_attempt(
+        &self,
+        client_id: u16,
+        auth_token: &str,
+        now: Instant,
+    ) -> Result<(), RateLimitExceeded> {
+        let mut hasher = DefaultHasher::new();
+        (client_id, auth_token).hash(&mut hasher);
+        let credential = hasher.finish();
+
+        let mut logins = self.lock_logins();
+        if !logins.contains_key(&credential) {
+            logins.retain(|_, bucket| bucket.available(now) < bucket.capacity());
+        }
+
+        let bucket = logins.entry(credential).or_insert_with(|| {
+            TokenBucket::new(
+                self.limits.control_burst(),
+                self.limits.control_per_second(),
+                now,
+            )
+        });
+
+        match bucket.try_acquire(now) {
+            Ok(_) => Ok(()),
+            Err(retry_after) => Err(RateLimitExceeded::new(Some(retry_after))),
+        }
+    }
+
+    /// Checks if a client can start new data streams.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The ID of the client that starts the streams
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// Ok if the client can start the streams. Otherwise, a RateLimitExceeded with
+    /// the time until the current window ends if the client used up its rows or bytes.
+    ///
+    pub fn check_stream_start(
+        &self,
+        client_id: u16,
+        now: Instant,
+    ) -> Result<(), RateLimitExceeded> {
+        let mut clients = self.lock_clients();
+        let client = clients
+            .entry(client_id)
+            .or_insert_with(|| ClientState::new(&self.limits, now));
+
+        let rows_exceeded = self
+            .limits
+            .max_rows_per_window()
+            .is_some_and(|max_rows| client.usage.rows(now) >= max_rows);
+
+        let bytes_exceeded = self
+            .limits
+            .max_bytes_per_window()
+            .is_some_and(|max_bytes| client.usage.bytes(now) >= max_bytes);
+
+        if rows_exceeded || bytes_exceeded {
+            return Err(RateLimitExceeded::new(Some(client.usage.remaining(now))));
+        }
+
+        Ok(())
+    }
+
+    /// Adds the rows and bytes streamed to a client to its current window.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The ID of the client the data was streamed to
+    /// * `rows` - The number of streamed rows
+    /// * `bytes` - The number of streamed bytes
+    /// * `now` - The current time
+    ///
+    pub fn record_data(&self, client_id: u16, rows: u64, bytes: u64, now: Instant) {
+        let mut clients = self.lock_clients();
+        clients
+            .entry(client_id)
+            .or_insert_with(|| ClientState::new(&self.limits, now))
+            .usage
+            .record(rows, bytes, now);
+    }
+
+    /// Returns the current usage of a client.
+    /// A client without state has a full control bucket and no usage.
+    pub fn usage(&self, client_id: u16, now: Instant) -> ClientUsage {
+        let mut clients = self.lock_clients();
+
+        match clients.get_mut(&client_id) {
+            Some(client) => ClientUsage::new(
+                client.control.available(now),
+                client.usage.rows(now),
+                client.usage.bytes(now),
+            ),
+            None => ClientUsage::new(self.limits.control_burst().max(1), 0, 0),
+        }
+    }
+
+    /// Removes the state of a client, which resets all of its limits.
+    pub fn remove_client(&self, client_id: u16) {
+        self.lock_clients().remove(&client_id);
+    }
+
+    /// Returns the number of clients with state.
+    pub fn number_of_clients(&self) -> usize {
+        self.lock_clients().len()
+    }
+
+    /// Returns the number of credentials with a login bucket.
+    pub fn number_of_logins(&self) -> usize {
+        self.lock_logins().len()
+    }
+
+    fn lock_clients(&self) -> MutexGuard<'_, HashMap<u16, ClientState>> {
+        // The state stays consistent even if a thread panicked while holding the lock
+        match self.clients.lock() {
+            Ok(clients) => clients,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn lock_logins(&self) -> MutexGuard<'_, HashMap<u64, TokenBucket>> {
+        match self.logins.lock() {
+            Ok(logins) => logins,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+/// The limits the gateway applies to each client.
+///
+/// Control messages and login attempts pass through token buckets that allow bursts of
+/// `control_burst` messages and refill at `control_per_second` messages per second.
+/// The rows and bytes streamed per window are not limited unless configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimits {
+    control_burst: u32,
+    control_per_second: u32,
+    window: Duration,
+    max_rows_per_window: Option<u64>,
+    max_bytes_per_window: Option<u64>,
+}
+
+impl RateLimits {
+    /// Creates new RateLimits that only limit the rate of control messages and login attempts.
+    ///
+    /// # Arguments
+    ///
+    /// * `control_burst` - The largest burst of control messages
+    /// * `control_per_second` - The sustained number of control messages per second
+    ///
+    pub fn new(control_burst: u32, control_per_second: u32) -> Self {
+        Self {
+            control_burst,
+            control_per_second,
+            window: Duration::from_secs(60),
+            max_rows_per_window: None,
+            max_bytes_per_window: None,
+        }
+    }
+
+    /// Sets the length of the window in which rows and bytes are counted.
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Limits the number of rows streamed to a client per window.
+    pub fn with_max_rows_per_window(mut self, max_rows: u64) -> Self {
+        self.max_rows_per_window = Some(max_rows);
+        self
+    }
+
+    /// Limits the number of bytes streamed to a client per window.
+    pub fn with_max_bytes_per_window(mut self, max_bytes: u64) -> Self {
+        self.max_bytes_per_window = Some(max_bytes);
+        self
+    }
+}
+
+impl RateLimits {
+    pub fn control_burst(&self) -> u32 {
+        self.control_burst
+    }
+    pub fn control_per_second(&self) -> u32 {
+        self.control_per_second
+    }
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+    pub fn max_rows_per_window(&self) -> Option<u64> {
+        self.max_rows_per_window
+    }
+    pub fn max_bytes_per_window(&self) -> Option<u64> {
+        self.max_bytes_per_window
+    }
+}
@@ -0,0 +1,79 @@
+use std::time::{Duration, Instant};
+
+/// A token bucket that allows bursts of up to `capacity` requests
+/// and refills at a steady rate of `refill_per_second` tokens.
+///
+/// Each request takes one token. The bucket starts full.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_second: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a new, full TokenBucket.
+    ///
+    /// A capacity or refill rate of zero is raised to one,
+    /// so that a client can always send requests eventually.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of tokens, i.e. the largest burst of requests
+    /// * `refill_per_second` - The number of tokens added per second
+    /// * `now` - The current time
+    ///
+    pub fn new(capacity: u32, refill_per_second: u32, now: Instant) -> Self {
+        let capacity = capacity.max(1) as f64;
+
+        Self {
+            capacity,
+            refill_per_second: refill_per_second.max(1) as f64,
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    /// Takes one token from the bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// Ok if a token was available, otherwise an Err with the time
+    /// until the next token becomes available.
+    ///
+    pub fn try_acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        self.refill(now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            let missing = 1.0 - self.tokens;
+            Err(Duration::from_secs_f64(missing / self.refill_per_second))
+        }
+    }
+
+    /// Returns the number of whole tokens available at the given time.
+    pub fn available(&mut self, now: Instant) -> u32 {
+        self.refill(now);
+        self.tokens.floor() as u32
+    }
+
+    /// Returns the maximum number of tokens in the bucket.
+    pub fn capacity(&self) -> u32 {
+        self.capacity as u32
+    }
+
+    fn refill(&mut self, now: Instant) {
+        // A time before the last refill adds no tokens
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.refill_per_second).min(self.capacity);
+        self.last_refill = self.last_refill.max(now);
+    }
+}
@@ -0,0 +1,65 @@
+use std::time::{Duration, Instant};
+
+/// Counts the rows and bytes streamed to a client in a fixed time window.
+///
+/// The counters reset when the window ends, and the next window
+/// starts with the first use after that.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageWindow {
+    length: Duration,
+    started: Instant,
+    rows: u64,
+    bytes: u64,
+}
+
+impl UsageWindow {
+    /// Creates a new, empty UsageWindow that starts at the given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The length of the window
+    /// * `now` - The current time
+    ///
+    pub fn new(length: Duration, now: Instant) -> Self {
+        Self {
+            length,
+            started: now,
+            rows: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Adds the given number of rows and bytes to the current window.
+    pub fn record(&mut self, rows: u64, bytes: u64, now: Instant) {
+        self.roll(now);
+        self.rows = self.rows.saturating_add(rows);
+        self.bytes = self.bytes.saturating_add(bytes);
+    }
+
+    /// Returns the number of rows streamed in the current window.
+    pub fn rows(&mut self, now: Instant) -> u64 {
+        self.roll(now);
+        self.rows
+    }
+
+    /// Returns the number of bytes streamed in the current window.
+    pub fn bytes(&mut self, now: Instant) -> u64 {
+        self.roll(now);
+        self.bytes
+    }
+
+    /// Returns the time until the current window ends.
+    pub fn remaining(&mut self, now: Instant) -> Duration {
+        self.roll(now);
+        self.length
+            .saturating_sub(now.saturating_duration_since(self.started))
+    }
+
+    fn roll(&mut self, now: Instant) {
+        if now.saturating_duration_since(self.started) >= self.length {
+            self.started = now;
+            self.rows = 0;
+            self.bytes = 0;
+        }
+    }
+}
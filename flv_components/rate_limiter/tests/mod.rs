@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod rate_limiter_tests;
+#[cfg(test)]
+mod token_bucket_tests;
+#[cfg(test)]
+mod usage_window_tests;
@@ -0,0 +1,163 @@
+use std::time::{Duration, Instant};
+
+use rate_limiter::{ClientUsage, RateLimitExceeded, RateLimiter, RateLimits};
+
+#[test]
+fn test_rate_limits() {
+    let limits = RateLimits::new(10, 5);
+
+    assert_eq!(limits.control_burst(), 10);
+    assert_eq!(limits.control_per_second(), 5);
+    assert_eq!(limits.window(), Duration::from_secs(60));
+    assert_eq!(limits.max_rows_per_window(), None);
+    assert_eq!(limits.max_bytes_per_window(), None);
+
+    let limits = limits
+        .with_window(Duration::from_secs(10))
+        .with_max_rows_per_window(1000)
+        .with_max_bytes_per_window(4000);
+
+    assert_eq!(limits.window(), Duration::from_secs(10));
+    assert_eq!(limits.max_rows_per_window(), Some(1000));
+    assert_eq!(limits.max_bytes_per_window(), Some(4000));
+}
+
+#[test]
+fn test_check_control_message() {
+    let limiter = RateLimiter::new(RateLimits::new(2, 1));
+    let now = Instant::now();
+
+    assert!(limiter.check_control_message(1, now).is_ok());
+    assert!(limiter.check_control_message(1, now).is_ok());
+    assert_eq!(
+        limiter.check_control_message(1, now),
+        Err(RateLimitExceeded::new(Some(Duration::from_secs(1))))
+    );
+
+    // Each client has its own bucket
+    assert!(limiter.check_control_message(2, now).is_ok());
+
+    let later = now + Duration::from_secs(1);
+    assert!(limiter.check_control_message(1, later).is_ok());
+}
+
+#[test]
+fn test_check_login_attempt() {
+    let limiter = RateLimiter::new(RateLimits::new(2, 1));
+    let now = Instant::now();
+
+    assert!(limiter.check_login_attempt(1, "wrong", now).is_ok());
+    assert!(limiter.check_login_attempt(1, "wrong", now).is_ok());
+    assert_eq!(
+        limiter.check_login_attempt(1, "wrong", now),
+        Err(RateLimitExceeded::new(Some(Duration::from_secs(1))))
+    );
+
+    // Attempts with other credentials have their own bucket
+    assert!(limiter.check_login_attempt(1, "secret", now).is_ok());
+    assert!(limiter.check_login_attempt(2, "wrong", now).is_ok());
+
+    // Login attempts do not count against the control messages of the client
+    assert_eq!(limiter.number_of_clients(), 0);
+    assert!(limiter.check_control_message(1, now).is_ok());
+
+    let later = now + Duration::from_secs(1);
+    assert!(limiter.check_login_attempt(1, "wrong", later).is_ok());
+}
+
+#[test]
+fn test_check_login_attempt_drops_refilled_buckets() {
+    let limiter = RateLimiter::new(RateLimits::new(2, 1));
+    let now = Instant::now();
+
+    limiter.check_login_attempt(1, "first", now).unwrap();
+    limiter.check_login_attempt(2, "second", now).unwrap();
+    assert_eq!(limiter.number_of_logins(), 2);
+
+    // Both buckets refilled, so only the bucket of the new credential remains
+    let later = now + Duration::from_secs(1);
+    limiter.check_login_attempt(3, "third", later).unwrap();
+    assert_eq!(limiter.number_of_logins(), 1);
+}
+
+#[test]
+fn test_check_stream_start_window_quota() {
+    let limits = RateLimits::new(2, 1)
+        .with_window(Duration::from_secs(60))
+        .with_max_rows_per_window(100)
+        .with_max_bytes_per_window(1000);
+    let limiter = RateLimiter::new(limits);
+    let now = Instant::now();
+
+    limiter.record_data(1, 99, 500, now);
+    assert!(limiter.check_stream_start(1, now).is_ok());
+
+    limiter.record_data(1, 1, 40, now);
+    let later = now + Duration::from_secs(45);
+    assert_eq!(
+        limiter.check_stream_start(1, later),
+        Err(RateLimitExceeded::new(Some(Duration::from_secs(15))))
+    );
+
+    // Other clients have their own quota
+    assert!(limiter.check_stream_start(2, later).is_ok());
+
+    // The quota resets when the window ends
+    let next_window = now + Duration::from_secs(60);
+    assert!(limiter.check_stream_start(1, next_window).is_ok());
+}
+
+#[test]
+fn test_check_stream_start_byte_quota() {
+    let limiter = RateLimiter::new(RateLimits::new(2, 1).with_max_bytes_per_window(1000));
+    let now = Instant::now();
+
+    limiter.record_data(1, 1, 1000, now);
+    assert!(limiter.check_stream_start(1, now).is_err());
+}
+
+#[test]
+fn test_usage() {
+    let limiter = RateLimiter::new(RateLimits::new(5, 1));
+    let now = Instant::now();
+
+    assert_eq!(limiter.usage(1, now), ClientUsage::new(5, 0, 0));
+    assert_eq!(limiter.number_of_clients(), 0);
+
+    limiter.check_control_message(1, now).unwrap();
+    limiter.record_data(1, 10, 400, now);
+
+    let usage = limiter.usage(1, now);
+    assert_eq!(usage.control_tokens(), 4);
+    assert_eq!(usage.rows_in_window(), 10);
+    assert_eq!(usage.bytes_in_window(), 400);
+    assert_eq!(limiter.number_of_clients(), 1);
+}
+
+#[test]
+fn test_remove_client() {
+    let limiter = RateLimiter::new(RateLimits::new(1, 1));
+    let now = Instant::now();
+
+    limiter.check_control_message(1, now).unwrap();
+    assert!(limiter.check_control_message(1, now).is_err());
+
+    limiter.remove_client(1);
+    assert_eq!(limiter.number_of_clients(), 0);
+    assert!(limiter.check_control_message(1, now).is_ok());
+}
+
+#[test]
+fn test_display() {
+    let err = RateLimitExceeded::new(Some(Duration::from_millis(1500)));
+    assert_eq!(format!("{}", err), "RateLimitExceeded: retry after 1500 ms");
+
+    let err = RateLimitExceeded::new(None);
+    assert_eq!(format!("{}", err), "RateLimitExceeded");
+
+    let usage = ClientUsage::new(4, 10, 400);
+    assert_eq!(
+        format!("{}", usage),
+        "ClientUsage { control_tokens: 4, rows_in_window: 10, bytes_in_window: 400 }"
+    );
+}
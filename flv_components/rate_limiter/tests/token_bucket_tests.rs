@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use rate_limiter::TokenBucket;
+
+#[test]
+fn test_new() {
+    let now = Instant::now();
+    let mut bucket = TokenBucket::new(5, 1, now);
+
+    assert_eq!(bucket.capacity(), 5);
+    assert_eq!(bucket.available(now), 5);
+}
+
+#[test]
+fn test_new_zero() {
+    let now = Instant::now();
+    let mut bucket = TokenBucket::new(0, 0, now);
+
+    assert_eq!(bucket.capacity(), 1);
+    assert!(bucket.try_acquire(now).is_ok());
+    assert_eq!(bucket.try_acquire(now), Err(Duration::from_secs(1)));
+}
+
+#[test]
+fn test_try_acquire() {
+    let now = Instant::now();
+    let mut bucket = TokenBucket::new(2, 4, now);
+
+    assert!(bucket.try_acquire(now).is_ok());
+    assert!(bucket.try_acquire(now).is_ok());
+    assert_eq!(bucket.available(now), 0);
+
+    // One token refills every 250 ms
+    assert_eq!(bucket.try_acquire(now), Err(Duration::from_millis(250)));
+}
+
+#[test]
+fn test_refill() {
+    let now = Instant::now();
+    let mut bucket = TokenBucket::new(2, 4, now);
+
+    assert!(bucket.try_acquire(now).is_ok());
+    assert!(bucket.try_acquire(now).is_ok());
+
+    let later = now + Duration::from_millis(250);
+    assert!(bucket.try_acquire(later).is_ok());
+    assert!(bucket.try_acquire(later).is_err());
+
+    // The bucket never holds more than its capacity
+    let much_later = later + Duration::from_secs(60);
+    assert_eq!(bucket.available(much_later), 2);
+}
+
+#[test]
+fn test_time_before_last_refill() {
+    let now = Instant::now();
+    let later = now + Duration::from_secs(1);
+    let mut bucket = TokenBucket::new(1, 1, later);
+
+    assert!(bucket.try_acquire(later).is_ok());
+    assert!(bucket.try_acquire(now).is_err());
+    assert_eq!(bucket.available(later), 0);
+}
@@ -0,0 +1,42 @@
+use std::time::{Duration, Instant};
+
+use rate_limiter::UsageWindow;
+
+#[test]
+fn test_new() {
+    let now = Instant::now();
+    let mut window = UsageWindow::new(Duration::from_secs(60), now);
+
+    assert_eq!(window.rows(now), 0);
+    assert_eq!(window.bytes(now), 0);
+    assert_eq!(window.remaining(now), Duration::from_secs(60));
+}
+
+#[test]
+fn test_record() {
+    let now = Instant::now();
+    let mut window = UsageWindow::new(Duration::from_secs(60), now);
+
+    window.record(10, 400, now);
+    window.record(5, 200, now + Duration::from_secs(20));
+
+    let later = now + Duration::from_secs(30);
+    assert_eq!(window.rows(later), 15);
+    assert_eq!(window.bytes(later), 600);
+    assert_eq!(window.remaining(later), Duration::from_secs(30));
+}
+
+#[test]
+fn test_window_ends() {
+    let now = Instant::now();
+    let mut window = UsageWindow::new(Duration::from_secs(60), now);
+
+    window.record(10, 400, now);
+
+    // The next window starts with the first use after the current window ends
+    let next = now + Duration::from_secs(90);
+    window.record(1, 40, next);
+    assert_eq!(window.rows(next), 1);
+    assert_eq!(window.bytes(next), 40);
+    assert_eq!(window.remaining(next), Duration::from_secs(60));
+}
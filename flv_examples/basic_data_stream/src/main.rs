@@ -2,6 +2,7 @@ use crate::handle_data::handle_data_message;
 use client_utils::prelude::{handle_error_utils, handle_utils, print_utils};
 use common::prelude::{ExchangeID, MessageClientConfig, TimeResolution};
 use qd_client::QDClient;
+use sbe_messages::prelude::ReplayMode;
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -78,7 +79,14 @@ async fn main() {
     let exchange_id = ExchangeID::Kraken;
     let symbol_id = ETH_AED;
     client
-        .start_trade_data(exchange_id, symbol_id)
+        .start_trade_data(
+            exchange_id,
+            symbol_id,
+            None,
+            None,
+            ReplayMode::Unthrottled,
+            0,
+        )
         .await
         .expect("Failed to send start trade data message");
 
@@ -92,7 +100,15 @@ async fn main() {
 
     println!("{FN_NAME}: Start streaming 5 MIN OHLCV data for OP/USD with symbol id: {OP_USD}");
     client
-        .start_ohlcv_data(exchange_id, symbol_id, time_resolution)
+        .start_ohlcv_data(
+            exchange_id,
+            symbol_id,
+            time_resolution,
+            None,
+            None,
+            ReplayMode::Unthrottled,
+            0,
+        )
         .await
         .expect("Failed to start OHLCV data");
 
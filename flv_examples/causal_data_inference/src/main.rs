@@ -7,6 +7,7 @@ use common::prelude::{ExchangeID, MessageClientConfig, SampledDataBars, ServiceI
 use config_manager::ConfigManager;
 use deep_causality::prelude::TimeScale;
 use qd_client::QDClient;
+use sbe_messages::prelude::ReplayMode;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -80,7 +81,14 @@ async fn main() {
 
     println!("{FN_NAME}: Send start streaming message for symbol id: {symbol_id}",);
     client
-        .start_trade_data(exchange_id, symbol_id)
+        .start_trade_data(
+            exchange_id,
+            symbol_id,
+            None,
+            None,
+            ReplayMode::Unthrottled,
+            0,
+        )
         .await
         .expect("Failed to send start trade data message");
 
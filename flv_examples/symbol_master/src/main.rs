@@ -5,6 +5,7 @@ use tokio::time::sleep;
 use client_utils::prelude::print_utils;
 use common::prelude::{ExchangeID, IggyConfig, IggyUser};
 use qd_client::QDClient;
+use sbe_messages::prelude::ReplayMode;
 use symdb_client::SymdbClient;
 
 mod handle_data;
@@ -16,6 +17,9 @@ const FN_NAME: &str = "symbol_master/main";
 
 const CLIENT_ID: u16 = 23;
 
+// Must match the auth token of the client in qdgw_credentials.toml
+const AUTH_TOKEN: &str = "symbol-master-example-token";
+
 /// The main function demonstrates streaming trade data for a symbol from the QDGW.
 /// It first creates a MessageClientConfig to hold the client configuration.
 /// It gets the SymdbClient configuration and creates a SymdbClient to lookup symbol IDs.
@@ -52,7 +56,7 @@ async fn main() {
     let mut symdb_client = SymdbClient::new(symdb_client_config).await.unwrap();
 
     println!("{FN_NAME}: Build QD Client",);
-    let qd_client = QDClient::new(CLIENT_ID, client_config.clone())
+    let qd_client = QDClient::new(CLIENT_ID, AUTH_TOKEN, client_config.clone())
         .await
         .expect("basic_data_stream/main: Failed to create QD Gateway client");
 
@@ -90,7 +94,14 @@ async fn main() {
     );
     let exchange_id = ExchangeID::Kraken;
     qd_client
-        .start_trade_data(exchange_id, symbol_id)
+        .start_trade_data(
+            exchange_id,
+            symbol_id,
+            None,
+            None,
+            ReplayMode::Unthrottled,
+            0,
+        )
         .await
         .expect("Failed to send start trade data message");
 
@@ -3,10 +3,10 @@ use crate::*;
 pub use decoder::ClientLoginDecoder;
 pub use encoder::ClientLoginEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 4;
+pub const SBE_BLOCK_LENGTH: u16 = 10;
 pub const SBE_TEMPLATE_ID: u16 = 101;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 6;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -83,6 +83,44 @@ pub mod encoder {
             let offset = self.offset + 2;
             self.get_buf_mut().put_u16_at(offset, value);
         }
+
+        /// primitive field 'protocolVersion'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 4
+        /// - encodedLength: 2
+        #[inline]
+        pub fn protocol_version(&mut self, value: u16) {
+            let offset = self.offset + 4;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// primitive field 'capabilities'
+        /// - min value: 0
+        /// - max value: 4294967294
+        /// - null value: 4294967295
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 6
+        /// - encodedLength: 4
+        #[inline]
+        pub fn capabilities(&mut self, value: u32) {
+            let offset = self.offset + 6;
+            self.get_buf_mut().put_u32_at(offset, value);
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn auth_token(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 2 + data_length);
+            self.get_buf_mut().put_u16_at(limit, data_length as u16);
+            self.get_buf_mut().put_slice_at(limit + 2, value.as_bytes());
+        }
     }
 } // end encoder
 
@@ -165,5 +203,58 @@ pub mod decoder {
         pub fn client_id(&self) -> u16 {
             self.get_buf().get_u16_at(self.offset + 2)
         }
+
+        /// primitive field - 'OPTIONAL' { null_value: '65535' }
+        #[inline]
+        pub fn protocol_version(&self) -> Option<u16> {
+            if self.acting_version > 0 && self.acting_version < 4 {
+                return None;
+            }
+
+            let value = self.get_buf().get_u16_at(self.offset + 4);
+            if value == 0xffff_u16 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '4294967295' }
+        #[inline]
+        pub fn capabilities(&self) -> Option<u32> {
+            if self.acting_version > 0 && self.acting_version < 4 {
+                return None;
+            }
+
+            let value = self.get_buf().get_u32_at(self.offset + 6);
+            if value == 0xffffffff_u32 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn auth_token_decoder(&mut self) -> (usize, usize) {
+            if self.acting_version > 0 && self.acting_version < 5 {
+                return (self.get_limit(), 0);
+            }
+
+            let offset = self.get_limit();
+            let data_length = self.get_buf().get_u16_at(offset) as usize;
+            self.set_limit(offset + 2 + data_length);
+            (offset + 2, data_length)
+        }
+
+        #[inline]
+        pub fn auth_token_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
+            if self.acting_version > 0 && self.acting_version < 5 {
+                return &[] as &[u8];
+            }
+
+            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
+            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        }
     }
 } // end decoder
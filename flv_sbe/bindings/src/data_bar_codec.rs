@@ -3,10 +3,10 @@ use crate::*;
 pub use decoder::DataBarDecoder;
 pub use encoder::DataBarEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 32;
+pub const SBE_BLOCK_LENGTH: u16 = 128;
 pub const SBE_TEMPLATE_ID: u16 = 204;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 6;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -167,6 +167,132 @@ pub mod encoder {
             let offset = self.offset + 28;
             self.get_buf_mut().put_f32_at(offset, value);
         }
+
+        /// primitive field 'sequenceNumber'
+        /// - min value: 0
+        /// - max value: -2
+        /// - null value: -1
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 32
+        /// - encodedLength: 8
+        #[inline]
+        pub fn sequence_number(&mut self, value: u64) {
+            let offset = self.offset + 32;
+            self.get_buf_mut().put_u64_at(offset, value);
+        }
+
+        /// primitive field 'vwap'
+        /// - min value: 1.401298464324817E-45
+        /// - max value: 3.4028234663852886E38
+        /// - null value: NaN
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 40
+        /// - encodedLength: 4
+        #[inline]
+        pub fn vwap(&mut self, value: f32) {
+            let offset = self.offset + 40;
+            self.get_buf_mut().put_f32_at(offset, value);
+        }
+
+        /// primitive field 'tradeCount'
+        /// - min value: 0
+        /// - max value: 4294967294
+        /// - null value: 4294967295
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 44
+        /// - encodedLength: 4
+        #[inline]
+        pub fn trade_count(&mut self, value: u32) {
+            let offset = self.offset + 44;
+            self.get_buf_mut().put_u32_at(offset, value);
+        }
+
+        /// primitive field 'buyVolume'
+        /// - min value: 1.401298464324817E-45
+        /// - max value: 3.4028234663852886E38
+        /// - null value: NaN
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 48
+        /// - encodedLength: 4
+        #[inline]
+        pub fn buy_volume(&mut self, value: f32) {
+            let offset = self.offset + 48;
+            self.get_buf_mut().put_f32_at(offset, value);
+        }
+
+        /// primitive field 'sellVolume'
+        /// - min value: 1.401298464324817E-45
+        /// - max value: 3.4028234663852886E38
+        /// - null value: NaN
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 52
+        /// - encodedLength: 4
+        #[inline]
+        pub fn sell_volume(&mut self, value: f32) {
+            let offset = self.offset + 52;
+            self.get_buf_mut().put_f32_at(offset, value);
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn open_price_decimal_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 56;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn high_price_decimal_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 65;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn low_price_decimal_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 74;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn close_price_decimal_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 83;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn volume_decimal_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 92;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn vwap_decimal_encoder(self) -> DecimalNULLEncoder<Self> {
+            let offset = self.offset + 101;
+            DecimalNULLEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn buy_volume_decimal_encoder(self) -> DecimalNULLEncoder<Self> {
+            let offset = self.offset + 110;
+            DecimalNULLEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn sell_volume_decimal_encoder(self) -> DecimalNULLEncoder<Self> {
+            let offset = self.offset + 119;
+            DecimalNULLEncoder::default().wrap(self, offset)
+        }
     }
 } // end encoder
 
@@ -285,5 +411,159 @@ pub mod decoder {
         pub fn volume(&self) -> f32 {
             self.get_buf().get_f32_at(self.offset + 28)
         }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn sequence_number(&self) -> u64 {
+            self.get_buf().get_u64_at(self.offset + 32)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: 'NaN' }
+        #[inline]
+        pub fn vwap(&self) -> Option<f32> {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return None;
+            }
+
+            let value = self.get_buf().get_f32_at(self.offset + 40);
+            if value.is_nan() {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '4294967295' }
+        #[inline]
+        pub fn trade_count(&self) -> Option<u32> {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return None;
+            }
+
+            let value = self.get_buf().get_u32_at(self.offset + 44);
+            if value == 0xffffffff_u32 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: 'NaN' }
+        #[inline]
+        pub fn buy_volume(&self) -> Option<f32> {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return None;
+            }
+
+            let value = self.get_buf().get_f32_at(self.offset + 48);
+            if value.is_nan() {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: 'NaN' }
+        #[inline]
+        pub fn sell_volume(&self) -> Option<f32> {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return None;
+            }
+
+            let value = self.get_buf().get_f32_at(self.offset + 52);
+            if value.is_nan() {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn open_price_decimal_decoder(self) -> Either<Self, DecimalDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 56;
+            Either::Right(DecimalDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn high_price_decimal_decoder(self) -> Either<Self, DecimalDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 65;
+            Either::Right(DecimalDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn low_price_decimal_decoder(self) -> Either<Self, DecimalDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 74;
+            Either::Right(DecimalDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn close_price_decimal_decoder(self) -> Either<Self, DecimalDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 83;
+            Either::Right(DecimalDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn volume_decimal_decoder(self) -> Either<Self, DecimalDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 92;
+            Either::Right(DecimalDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn vwap_decimal_decoder(self) -> Either<Self, DecimalNULLDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 101;
+            Either::Right(DecimalNULLDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn buy_volume_decimal_decoder(self) -> Either<Self, DecimalNULLDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 110;
+            Either::Right(DecimalNULLDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn sell_volume_decimal_decoder(self) -> Either<Self, DecimalNULLDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 119;
+            Either::Right(DecimalNULLDecoder::default().wrap(self, offset))
+        }
     }
 } // end decoder
@@ -3,10 +3,10 @@ use crate::*;
 pub use decoder::DataErrorDecoder;
 pub use encoder::DataErrorEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 5;
+pub const SBE_BLOCK_LENGTH: u16 = 9;
 pub const SBE_TEMPLATE_ID: u16 = 802;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 6;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -97,6 +97,20 @@ pub mod encoder {
             let offset = self.offset + 4;
             self.get_buf_mut().put_u8_at(offset, value);
         }
+
+        /// primitive field 'retryAfterMillis'
+        /// - min value: 0
+        /// - max value: 4294967294
+        /// - null value: 4294967295
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 5
+        /// - encodedLength: 4
+        #[inline]
+        pub fn retry_after_millis(&mut self, value: u32) {
+            let offset = self.offset + 5;
+            self.get_buf_mut().put_u32_at(offset, value);
+        }
     }
 } // end encoder
 
@@ -190,5 +204,20 @@ pub mod decoder {
                 Some(value)
             }
         }
+
+        /// primitive field - 'OPTIONAL' { null_value: '4294967295' }
+        #[inline]
+        pub fn retry_after_millis(&self) -> Option<u32> {
+            if self.acting_version > 0 && self.acting_version < 6 {
+                return None;
+            }
+
+            let value = self.get_buf().get_u32_at(self.offset + 5);
+            if value == 0xffffffff_u32 {
+                None
+            } else {
+                Some(value)
+            }
+        }
     }
 } // end decoder
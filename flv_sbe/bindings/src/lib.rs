@@ -7,39 +7,65 @@ use ::core::convert::TryInto;
 
 pub mod client_error_codec;
 pub mod client_error_type;
+pub mod client_heartbeat_codec;
+pub mod client_login_ack_codec;
 pub mod client_login_codec;
+pub mod client_logout_ack_codec;
 pub mod client_logout_codec;
+pub mod continue_data_msg_codec;
 pub mod data_bar_codec;
 pub mod data_error_codec;
+pub mod decimal_codec;
+pub mod decimal_null_codec;
 pub mod exchange_id;
 pub mod first_data_bar_codec;
 pub mod first_trade_bar_codec;
+pub mod group_size_encoding_codec;
 pub mod last_data_bar_codec;
 pub mod last_trade_bar_codec;
 pub mod message_header_codec;
 pub mod message_type;
+pub mod pause_data_msg_codec;
+pub mod resume_data_msg_codec;
 pub mod start_data_msg_codec;
+pub mod start_multi_data_msg_codec;
 pub mod stop_all_data_msg_codec;
 pub mod stop_data_msg_codec;
+pub mod time_resolution_codec;
+pub mod time_scale;
 pub mod trade_bar_codec;
+pub mod var_string_encoding_codec;
 
 pub use crate::client_error_codec::*;
 pub use crate::client_error_type::*;
+pub use crate::client_heartbeat_codec::*;
+pub use crate::client_login_ack_codec::*;
 pub use crate::client_login_codec::*;
+pub use crate::client_logout_ack_codec::*;
 pub use crate::client_logout_codec::*;
+pub use crate::continue_data_msg_codec::*;
 pub use crate::data_bar_codec::*;
 pub use crate::data_error_codec::*;
+pub use crate::decimal_codec::*;
+pub use crate::decimal_null_codec::*;
 pub use crate::exchange_id::*;
 pub use crate::first_data_bar_codec::*;
 pub use crate::first_trade_bar_codec::*;
+pub use crate::group_size_encoding_codec::*;
 pub use crate::last_data_bar_codec::*;
 pub use crate::last_trade_bar_codec::*;
 pub use crate::message_header_codec::*;
 pub use crate::message_type::*;
+pub use crate::pause_data_msg_codec::*;
+pub use crate::resume_data_msg_codec::*;
 pub use crate::start_data_msg_codec::*;
+pub use crate::start_multi_data_msg_codec::*;
 pub use crate::stop_all_data_msg_codec::*;
 pub use crate::stop_data_msg_codec::*;
+pub use crate::time_resolution_codec::*;
+pub use crate::time_scale::*;
 pub use crate::trade_bar_codec::*;
+pub use crate::var_string_encoding_codec::*;
 
 pub type SbeResult<T> = core::result::Result<T, SbeErr>;
 
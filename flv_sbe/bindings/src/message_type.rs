@@ -4,6 +4,9 @@ pub enum MessageType {
     UnknownMessageType = 0x0_u16,
     ClientLogin = 0x65_u16,
     ClientLogout = 0x66_u16,
+    ClientLoginAck = 0x67_u16,
+    ClientLogoutAck = 0x68_u16,
+    ClientHeartbeat = 0x69_u16,
     StartData = 0xc9_u16,
     StopData = 0xca_u16,
     StopAllData = 0xcb_u16,
@@ -13,6 +16,10 @@ pub enum MessageType {
     TradeBar = 0xcf_u16,
     FirstTradeBar = 0xd0_u16,
     LastTradeBar = 0xd1_u16,
+    PauseData = 0xd2_u16,
+    ContinueData = 0xd3_u16,
+    StartMultiData = 0xd4_u16,
+    ResumeData = 0xd5_u16,
     ClientError = 0x321_u16,
     DataError = 0x322_u16,
     #[default]
@@ -25,6 +32,9 @@ impl From<u16> for MessageType {
             0x0_u16 => Self::UnknownMessageType,
             0x65_u16 => Self::ClientLogin,
             0x66_u16 => Self::ClientLogout,
+            0x67_u16 => Self::ClientLoginAck,
+            0x68_u16 => Self::ClientLogoutAck,
+            0x69_u16 => Self::ClientHeartbeat,
             0xc9_u16 => Self::StartData,
             0xca_u16 => Self::StopData,
             0xcb_u16 => Self::StopAllData,
@@ -34,6 +44,10 @@ impl From<u16> for MessageType {
             0xcf_u16 => Self::TradeBar,
             0xd0_u16 => Self::FirstTradeBar,
             0xd1_u16 => Self::LastTradeBar,
+            0xd2_u16 => Self::PauseData,
+            0xd3_u16 => Self::ContinueData,
+            0xd4_u16 => Self::StartMultiData,
+            0xd5_u16 => Self::ResumeData,
             0x321_u16 => Self::ClientError,
             0x322_u16 => Self::DataError,
             _ => Self::NullVal,
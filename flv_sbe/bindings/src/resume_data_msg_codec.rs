@@ -0,0 +1,403 @@
+use crate::*;
+
+pub use decoder::ResumeDataMsgDecoder;
+pub use encoder::ResumeDataMsgEncoder;
+
+pub const SBE_BLOCK_LENGTH: u16 = 56;
+pub const SBE_TEMPLATE_ID: u16 = 213;
+pub const SBE_SCHEMA_ID: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 6;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+
+pub mod encoder {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct ResumeDataMsgEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for ResumeDataMsgEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for ResumeDataMsgEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> ResumeDataMsgEncoder<'a> {
+        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn message_type(&mut self, value: MessageType) {
+            let offset = self.offset;
+            self.get_buf_mut().put_u16_at(offset, value as u16)
+        }
+
+        /// primitive field 'clientID'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 2
+        /// - encodedLength: 2
+        #[inline]
+        pub fn client_id(&mut self, value: u16) {
+            let offset = self.offset + 2;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// primitive field 'exchangeID'
+        /// - min value: 0
+        /// - max value: 254
+        /// - null value: 255
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 4
+        /// - encodedLength: 1
+        #[inline]
+        pub fn exchange_id(&mut self, value: u8) {
+            let offset = self.offset + 4;
+            self.get_buf_mut().put_u8_at(offset, value);
+        }
+
+        /// primitive field 'symbolID'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 5
+        /// - encodedLength: 2
+        #[inline]
+        pub fn symbol_id(&mut self, value: u16) {
+            let offset = self.offset + 5;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn time_resolution_encoder(self) -> TimeResolutionEncoder<Self> {
+            let offset = self.offset + 7;
+            TimeResolutionEncoder::default().wrap(self, offset)
+        }
+
+        /// primitive field 'dataTypeID'
+        /// - min value: 0
+        /// - max value: 254
+        /// - null value: 255
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 12
+        /// - encodedLength: 1
+        #[inline]
+        pub fn data_type_id(&mut self, value: u8) {
+            let offset = self.offset + 12;
+            self.get_buf_mut().put_u8_at(offset, value);
+        }
+
+        /// primitive field 'startTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: 9223372036854775807
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 13
+        /// - encodedLength: 8
+        #[inline]
+        pub fn start_time(&mut self, value: i64) {
+            let offset = self.offset + 13;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'endTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: 9223372036854775807
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 21
+        /// - encodedLength: 8
+        #[inline]
+        pub fn end_time(&mut self, value: i64) {
+            let offset = self.offset + 21;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'replayMode'
+        /// - min value: 0
+        /// - max value: 254
+        /// - null value: 255
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 29
+        /// - encodedLength: 1
+        #[inline]
+        pub fn replay_mode(&mut self, value: u8) {
+            let offset = self.offset + 29;
+            self.get_buf_mut().put_u8_at(offset, value);
+        }
+
+        /// primitive field 'replaySpeed'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 30
+        /// - encodedLength: 2
+        #[inline]
+        pub fn replay_speed(&mut self, value: u16) {
+            let offset = self.offset + 30;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// primitive field 'resumeSequenceNumber'
+        /// - min value: 0
+        /// - max value: -2
+        /// - null value: -1
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 32
+        /// - encodedLength: 8
+        #[inline]
+        pub fn resume_sequence_number(&mut self, value: u64) {
+            let offset = self.offset + 32;
+            self.get_buf_mut().put_u64_at(offset, value);
+        }
+
+        /// primitive field 'resumeTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: 9223372036854775807
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 40
+        /// - encodedLength: 8
+        #[inline]
+        pub fn resume_time(&mut self, value: i64) {
+            let offset = self.offset + 40;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'threshold'
+        /// - min value: 4.9E-324
+        /// - max value: 1.7976931348623157E308
+        /// - null value: NaN
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 48
+        /// - encodedLength: 8
+        #[inline]
+        pub fn threshold(&mut self, value: f64) {
+            let offset = self.offset + 48;
+            self.get_buf_mut().put_f64_at(offset, value);
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct ResumeDataMsgDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for ResumeDataMsgDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for ResumeDataMsgDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> ResumeDataMsgDecoder<'a> {
+        pub fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> Self {
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
+            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent().unwrap(),
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn message_type(&self) -> MessageType {
+            self.get_buf().get_u16_at(self.offset).into()
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn client_id(&self) -> u16 {
+            self.get_buf().get_u16_at(self.offset + 2)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn exchange_id(&self) -> u8 {
+            self.get_buf().get_u8_at(self.offset + 4)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn symbol_id(&self) -> u16 {
+            self.get_buf().get_u16_at(self.offset + 5)
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn time_resolution_decoder(self) -> TimeResolutionDecoder<Self> {
+            let offset = self.offset + 7;
+            TimeResolutionDecoder::default().wrap(self, offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn data_type_id(&self) -> u8 {
+            self.get_buf().get_u8_at(self.offset + 12)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '9223372036854775807' }
+        #[inline]
+        pub fn start_time(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 13);
+            if value == 9223372036854775807_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '9223372036854775807' }
+        #[inline]
+        pub fn end_time(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 21);
+            if value == 9223372036854775807_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn replay_mode(&self) -> u8 {
+            self.get_buf().get_u8_at(self.offset + 29)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn replay_speed(&self) -> u16 {
+            self.get_buf().get_u16_at(self.offset + 30)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-1' }
+        #[inline]
+        pub fn resume_sequence_number(&self) -> Option<u64> {
+            let value = self.get_buf().get_u64_at(self.offset + 32);
+            if value == 0xffffffffffffffff_u64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '9223372036854775807' }
+        #[inline]
+        pub fn resume_time(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 40);
+            if value == 9223372036854775807_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn threshold(&self) -> f64 {
+            self.get_buf().get_f64_at(self.offset + 48)
+        }
+    }
+} // end decoder
@@ -3,10 +3,10 @@ use crate::*;
 pub use decoder::StartDataMsgDecoder;
 pub use encoder::StartDataMsgEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 9;
+pub const SBE_BLOCK_LENGTH: u16 = 40;
 pub const SBE_TEMPLATE_ID: u16 = 201;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 6;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -112,33 +112,96 @@ pub mod encoder {
             self.get_buf_mut().put_u16_at(offset, value);
         }
 
-        /// primitive field 'timeResolution'
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn time_resolution_encoder(self) -> TimeResolutionEncoder<Self> {
+            let offset = self.offset + 7;
+            TimeResolutionEncoder::default().wrap(self, offset)
+        }
+
+        /// primitive field 'dataTypeID'
         /// - min value: 0
         /// - max value: 254
         /// - null value: 255
         /// - characterEncoding: null
         /// - semanticType: null
-        /// - encodedOffset: 7
+        /// - encodedOffset: 12
         /// - encodedLength: 1
         #[inline]
-        pub fn time_resolution(&mut self, value: u8) {
-            let offset = self.offset + 7;
+        pub fn data_type_id(&mut self, value: u8) {
+            let offset = self.offset + 12;
             self.get_buf_mut().put_u8_at(offset, value);
         }
 
-        /// primitive field 'dataTypeID'
+        /// primitive field 'startTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: 9223372036854775807
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 13
+        /// - encodedLength: 8
+        #[inline]
+        pub fn start_time(&mut self, value: i64) {
+            let offset = self.offset + 13;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'endTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: 9223372036854775807
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 21
+        /// - encodedLength: 8
+        #[inline]
+        pub fn end_time(&mut self, value: i64) {
+            let offset = self.offset + 21;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'replayMode'
         /// - min value: 0
         /// - max value: 254
         /// - null value: 255
         /// - characterEncoding: null
         /// - semanticType: null
-        /// - encodedOffset: 8
+        /// - encodedOffset: 29
         /// - encodedLength: 1
         #[inline]
-        pub fn data_type_id(&mut self, value: u8) {
-            let offset = self.offset + 8;
+        pub fn replay_mode(&mut self, value: u8) {
+            let offset = self.offset + 29;
             self.get_buf_mut().put_u8_at(offset, value);
         }
+
+        /// primitive field 'replaySpeed'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 30
+        /// - encodedLength: 2
+        #[inline]
+        pub fn replay_speed(&mut self, value: u16) {
+            let offset = self.offset + 30;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// primitive field 'threshold'
+        /// - min value: 4.9E-324
+        /// - max value: 1.7976931348623157E308
+        /// - null value: NaN
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 32
+        /// - encodedLength: 8
+        #[inline]
+        pub fn threshold(&mut self, value: f64) {
+            let offset = self.offset + 32;
+            self.get_buf_mut().put_f64_at(offset, value);
+        }
     }
 } // end encoder
 
@@ -234,16 +297,77 @@ pub mod decoder {
             self.get_buf().get_u16_at(self.offset + 5)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
-        pub fn time_resolution(&self) -> u8 {
-            self.get_buf().get_u8_at(self.offset + 7)
+        pub fn time_resolution_decoder(self) -> TimeResolutionDecoder<Self> {
+            let offset = self.offset + 7;
+            TimeResolutionDecoder::default().wrap(self, offset)
         }
 
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn data_type_id(&self) -> u8 {
-            self.get_buf().get_u8_at(self.offset + 8)
+            self.get_buf().get_u8_at(self.offset + 12)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '9223372036854775807' }
+        #[inline]
+        pub fn start_time(&self) -> Option<i64> {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return None;
+            }
+
+            let value = self.get_buf().get_i64_at(self.offset + 13);
+            if value == 9223372036854775807_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '9223372036854775807' }
+        #[inline]
+        pub fn end_time(&self) -> Option<i64> {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return None;
+            }
+
+            let value = self.get_buf().get_i64_at(self.offset + 21);
+            if value == 9223372036854775807_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn replay_mode(&self) -> u8 {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return 0xff_u8;
+            }
+
+            self.get_buf().get_u8_at(self.offset + 29)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn replay_speed(&self) -> u16 {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return 0xffff_u16;
+            }
+
+            self.get_buf().get_u16_at(self.offset + 30)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn threshold(&self) -> f64 {
+            if self.acting_version > 0 && self.acting_version < 2 {
+                return f64::NAN;
+            }
+
+            self.get_buf().get_f64_at(self.offset + 32)
         }
     }
 } // end decoder
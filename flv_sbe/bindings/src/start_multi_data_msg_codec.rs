@@ -0,0 +1,520 @@
+use crate::*;
+
+pub use decoder::StartMultiDataMsgDecoder;
+pub use encoder::StartMultiDataMsgEncoder;
+
+pub const SBE_BLOCK_LENGTH: u16 = 30;
+pub const SBE_TEMPLATE_ID: u16 = 212;
+pub const SBE_SCHEMA_ID: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 6;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+
+pub mod encoder {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct StartMultiDataMsgEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for StartMultiDataMsgEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for StartMultiDataMsgEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> StartMultiDataMsgEncoder<'a> {
+        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn message_type(&mut self, value: MessageType) {
+            let offset = self.offset;
+            self.get_buf_mut().put_u16_at(offset, value as u16)
+        }
+
+        /// primitive field 'clientID'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 2
+        /// - encodedLength: 2
+        #[inline]
+        pub fn client_id(&mut self, value: u16) {
+            let offset = self.offset + 2;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// primitive field 'exchangeID'
+        /// - min value: 0
+        /// - max value: 254
+        /// - null value: 255
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 4
+        /// - encodedLength: 1
+        #[inline]
+        pub fn exchange_id(&mut self, value: u8) {
+            let offset = self.offset + 4;
+            self.get_buf_mut().put_u8_at(offset, value);
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn time_resolution_encoder(self) -> TimeResolutionEncoder<Self> {
+            let offset = self.offset + 5;
+            TimeResolutionEncoder::default().wrap(self, offset)
+        }
+
+        /// primitive field 'dataTypeID'
+        /// - min value: 0
+        /// - max value: 254
+        /// - null value: 255
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 10
+        /// - encodedLength: 1
+        #[inline]
+        pub fn data_type_id(&mut self, value: u8) {
+            let offset = self.offset + 10;
+            self.get_buf_mut().put_u8_at(offset, value);
+        }
+
+        /// primitive field 'startTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: 9223372036854775807
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 11
+        /// - encodedLength: 8
+        #[inline]
+        pub fn start_time(&mut self, value: i64) {
+            let offset = self.offset + 11;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'endTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: 9223372036854775807
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 19
+        /// - encodedLength: 8
+        #[inline]
+        pub fn end_time(&mut self, value: i64) {
+            let offset = self.offset + 19;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'replayMode'
+        /// - min value: 0
+        /// - max value: 254
+        /// - null value: 255
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 27
+        /// - encodedLength: 1
+        #[inline]
+        pub fn replay_mode(&mut self, value: u8) {
+            let offset = self.offset + 27;
+            self.get_buf_mut().put_u8_at(offset, value);
+        }
+
+        /// primitive field 'replaySpeed'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 28
+        /// - encodedLength: 2
+        #[inline]
+        pub fn replay_speed(&mut self, value: u16) {
+            let offset = self.offset + 28;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// GROUP ENCODER (id=10)
+        #[inline]
+        pub fn symbols_encoder(
+            self,
+            count: u16,
+            symbols_encoder: SymbolsEncoder<Self>,
+        ) -> SymbolsEncoder<Self> {
+            symbols_encoder.wrap(self, count)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct SymbolsEncoder<P> {
+        parent: Option<P>,
+        count: u16,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for SymbolsEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for SymbolsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> SymbolsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 4);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        pub fn block_length() -> u16 {
+            2
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field 'symbolID'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 2
+        #[inline]
+        pub fn symbol_id(&mut self, value: u16) {
+            let offset = self.offset;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct StartMultiDataMsgDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for StartMultiDataMsgDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for StartMultiDataMsgDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> StartMultiDataMsgDecoder<'a> {
+        pub fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> Self {
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
+            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent().unwrap(),
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn message_type(&self) -> MessageType {
+            self.get_buf().get_u16_at(self.offset).into()
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn client_id(&self) -> u16 {
+            self.get_buf().get_u16_at(self.offset + 2)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn exchange_id(&self) -> u8 {
+            self.get_buf().get_u8_at(self.offset + 4)
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn time_resolution_decoder(self) -> TimeResolutionDecoder<Self> {
+            let offset = self.offset + 5;
+            TimeResolutionDecoder::default().wrap(self, offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn data_type_id(&self) -> u8 {
+            self.get_buf().get_u8_at(self.offset + 10)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '9223372036854775807' }
+        #[inline]
+        pub fn start_time(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 11);
+            if value == 9223372036854775807_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '9223372036854775807' }
+        #[inline]
+        pub fn end_time(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 19);
+            if value == 9223372036854775807_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn replay_mode(&self) -> u8 {
+            self.get_buf().get_u8_at(self.offset + 27)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn replay_speed(&self) -> u16 {
+            self.get_buf().get_u16_at(self.offset + 28)
+        }
+
+        /// GROUP DECODER (id=10)
+        #[inline]
+        pub fn symbols_decoder(self) -> SymbolsDecoder<Self> {
+            SymbolsDecoder::default().wrap(self)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct SymbolsDecoder<P> {
+        parent: Option<P>,
+        block_length: usize,
+        count: u16,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for SymbolsDecoder<P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            self.parent.as_ref().expect("parent missing").get_buf()
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for SymbolsDecoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> SymbolsDecoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        pub fn wrap(mut self, mut parent: P) -> Self {
+            let initial_offset = parent.get_limit();
+            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
+            let count = parent.get_buf().get_u16_at(initial_offset + 2);
+            parent.set_limit(initial_offset + 4);
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            self
+        }
+
+        /// group token - Token{signal=BEGIN_GROUP, name='symbols', referencedName='null', description='null', packageName='null', id=10, version=0, deprecated=0, encodedLength=2, offset=30, componentTokenCount=9, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        #[inline]
+        pub fn count(&self) -> u16 {
+            self.count
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + self.block_length as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn symbol_id(&self) -> u16 {
+            self.get_buf().get_u16_at(self.offset)
+        }
+    }
+} // end decoder
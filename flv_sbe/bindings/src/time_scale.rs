@@ -0,0 +1,34 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum TimeScale {
+    NoScale = 0x0_u8,
+    Second = 0x1_u8,
+    Minute = 0x2_u8,
+    Hour = 0x3_u8,
+    Day = 0x4_u8,
+    Week = 0x5_u8,
+    Month = 0x6_u8,
+    Quarter = 0x7_u8,
+    Year = 0x8_u8,
+    Millisecond = 0x9_u8,
+    #[default]
+    NullVal = 0xff_u8,
+}
+impl From<u8> for TimeScale {
+    #[inline]
+    fn from(v: u8) -> Self {
+        match v {
+            0x0_u8 => Self::NoScale,
+            0x1_u8 => Self::Second,
+            0x2_u8 => Self::Minute,
+            0x3_u8 => Self::Hour,
+            0x4_u8 => Self::Day,
+            0x5_u8 => Self::Week,
+            0x6_u8 => Self::Month,
+            0x7_u8 => Self::Quarter,
+            0x8_u8 => Self::Year,
+            0x9_u8 => Self::Millisecond,
+            _ => Self::NullVal,
+        }
+    }
+}
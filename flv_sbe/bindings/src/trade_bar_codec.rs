@@ -3,10 +3,10 @@ use crate::*;
 pub use decoder::TradeBarDecoder;
 pub use encoder::TradeBarEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 20;
+pub const SBE_BLOCK_LENGTH: u16 = 46;
 pub const SBE_TEMPLATE_ID: u16 = 207;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 6;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -125,6 +125,34 @@ pub mod encoder {
             let offset = self.offset + 16;
             self.get_buf_mut().put_f32_at(offset, value);
         }
+
+        /// primitive field 'sequenceNumber'
+        /// - min value: 0
+        /// - max value: -2
+        /// - null value: -1
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 20
+        /// - encodedLength: 8
+        #[inline]
+        pub fn sequence_number(&mut self, value: u64) {
+            let offset = self.offset + 20;
+            self.get_buf_mut().put_u64_at(offset, value);
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn price_decimal_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 28;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn volume_decimal_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 37;
+            DecimalEncoder::default().wrap(self, offset)
+        }
     }
 } // end encoder
 
@@ -225,5 +253,33 @@ pub mod decoder {
         pub fn volume(&self) -> f32 {
             self.get_buf().get_f32_at(self.offset + 16)
         }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn sequence_number(&self) -> u64 {
+            self.get_buf().get_u64_at(self.offset + 20)
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn price_decimal_decoder(self) -> Either<Self, DecimalDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 28;
+            Either::Right(DecimalDecoder::default().wrap(self, offset))
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        pub fn volume_decimal_decoder(self) -> Either<Self, DecimalDecoder<Self>> {
+            if self.acting_version > 0 && self.acting_version < 3 {
+                return Either::Left(self);
+            }
+
+            let offset = self.offset + 37;
+            Either::Right(DecimalDecoder::default().wrap(self, offset))
+        }
     }
 } // end decoder
@@ -0,0 +1,180 @@
+use crate::errors::SbeDecodeError;
+use rust_decimal::Decimal;
+use sbe_bindings::{DecimalDecoder, DecimalNULLDecoder, Either, Reader};
+
+/// Largest scale of a Decimal.
+const MAX_SCALE: i32 = 28;
+
+/// Converts a Decimal into the mantissa and exponent of an SBE decimal composite.
+///
+/// The value equals `mantissa * 10^exponent`. Trailing zeros after the decimal point
+/// are removed, so equal values always encode into the same mantissa and exponent.
+///
+/// # Arguments
+///
+/// * `value` - Decimal to convert
+///
+/// # Returns
+///
+/// (i64, i8) - Tuple containing mantissa and exponent
+///
+/// # Remarks
+///
+/// Every Decimal with a mantissa that fits into an i64, which holds any 18 significant digits,
+/// converts exactly. i64::MIN is never used as mantissa, since it is the null value of
+/// an optional decimal. A Decimal with a larger mantissa, e.g. the result of a division,
+/// gets rounded to the nearest value, ties to even, with the most digits that fit into an i64.
+///
+pub fn encode_decimal(value: Decimal) -> (i64, i8) {
+    let value = value.normalize();
+    let mantissa = value.mantissa();
+    let exponent = -(value.scale() as i32);
+
+    // i64::MIN is left out, as it is the null value of an optional decimal
+    let max = i64::MAX as i128;
+
+    if mantissa.abs() <= max {
+        return (mantissa as i64, exponent as i8);
+    }
+
+    // Drop as few digits as needed to fit the mantissa into an i64
+    let mut digits: u32 = 1;
+    loop {
+        let divisor = 10i128.pow(digits);
+        let quotient = mantissa / divisor;
+        let remainder = (mantissa % divisor).abs();
+        let half = divisor / 2;
+
+        let round_up = remainder > half || (remainder == half && quotient % 2 != 0);
+        let rounded = if round_up {
+            quotient + mantissa.signum()
+        } else {
+            quotient
+        };
+
+        if rounded.abs() <= max {
+            // Rounding up may add trailing decimal places, e.g. 9.99 to 10.0
+            let (mut rounded, mut exponent) = (rounded as i64, exponent + digits as i32);
+            while exponent < 0 && rounded % 10 == 0 {
+                rounded /= 10;
+                exponent += 1;
+            }
+
+            return (rounded, exponent as i8);
+        }
+
+        digits += 1;
+    }
+}
+
+/// Converts the mantissa and exponent of an SBE decimal composite into a Decimal.
+///
+/// # Arguments
+///
+/// * `mantissa` - Mantissa of the value
+/// * `exponent` - Exponent of the value
+///
+/// # Returns
+///
+/// The Decimal equal to `mantissa * 10^exponent`.
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError::DecodeError` if the value cannot be represented as Decimal,
+/// i.e. the exponent is below -28 or the value exceeds the range of a Decimal.
+///
+pub fn decode_decimal(mantissa: i64, exponent: i8) -> Result<Decimal, SbeDecodeError> {
+    let exponent = exponent as i32;
+
+    let value = if exponent <= 0 {
+        if -exponent > MAX_SCALE {
+            None
+        } else {
+            Decimal::try_from_i128_with_scale(mantissa as i128, -exponent as u32).ok()
+        }
+    } else {
+        10i128
+            .checked_pow(exponent as u32)
+            .and_then(|factor| (mantissa as i128).checked_mul(factor))
+            .and_then(|value| Decimal::try_from_i128_with_scale(value, 0).ok())
+    };
+
+    match value {
+        Some(value) => Ok(value),
+        None => Err(SbeDecodeError::DecodeError(format!(
+            "Invalid decimal: mantissa {} and exponent {} exceed the range of a Decimal",
+            mantissa, exponent
+        ))),
+    }
+}
+
+/// Reads a decimal field from the decoder of a message.
+///
+/// # Arguments
+///
+/// * `decoder` - Either the message decoder, if the field is absent in the schema version
+///   of the message, or the decoder of the decimal field
+///
+/// # Returns
+///
+/// Tuple containing the message decoder and the decimal, or None if the field is absent.
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError::DecodeError` if the decimal cannot be represented as Decimal.
+///
+pub(crate) fn read_decimal<'a, P>(
+    decoder: Either<P, DecimalDecoder<P>>,
+) -> Result<(P, Option<Decimal>), SbeDecodeError>
+where
+    P: Reader<'a> + Default,
+{
+    match decoder {
+        Either::Left(parent) => Ok((parent, None)),
+        Either::Right(mut decoder) => {
+            let value = decode_decimal(decoder.mantissa(), decoder.exponent())?;
+
+            match decoder.parent() {
+                Ok(parent) => Ok((parent, Some(value))),
+                Err(e) => Err(SbeDecodeError::DecodeError(format!("{:?}", e))),
+            }
+        }
+    }
+}
+
+/// Reads an optional decimal field from the decoder of a message.
+///
+/// # Arguments
+///
+/// * `decoder` - Either the message decoder, if the field is absent in the schema version
+///   of the message, or the decoder of the optional decimal field
+///
+/// # Returns
+///
+/// Tuple containing the message decoder and the decimal, or None if the field is absent or null.
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError::DecodeError` if the decimal cannot be represented as Decimal.
+///
+pub(crate) fn read_decimal_null<'a, P>(
+    decoder: Either<P, DecimalNULLDecoder<P>>,
+) -> Result<(P, Option<Decimal>), SbeDecodeError>
+where
+    P: Reader<'a> + Default,
+{
+    match decoder {
+        Either::Left(parent) => Ok((parent, None)),
+        Either::Right(mut decoder) => {
+            let value = match decoder.mantissa() {
+                Some(mantissa) => Some(decode_decimal(mantissa, decoder.exponent())?),
+                None => None,
+            };
+
+            match decoder.parent() {
+                Ok(parent) => Ok((parent, value)),
+                Err(e) => Err(SbeDecodeError::DecodeError(format!("{:?}", e))),
+            }
+        }
+    }
+}
@@ -26,13 +26,19 @@ impl fmt::Display for SbeEncodeError {
 
 impl std::error::Error for SbeEncodeError {}
 
-/// SbeDecodeError struct definition.
+/// SbeDecodeError enum definition.
 ///
 /// Used to represent SBE decoding errors.
 ///
-/// # Fields
+/// # Variants
 ///
-/// `0` - Error message string
+/// `DecodeError` - Error message string
+/// `BufferTooShort` - The buffer is shorter than the message header or message block
+/// `UnknownTemplateId` - The template id does not identify a message of the schema
+/// `InvalidBlockLength` - The block length does not match the block length of the message
+/// `InvalidSchemaId` - The schema id differs from the schema id of the bindings
+/// `InvalidVersion` - The schema version is not a valid schema version
+/// `InvalidMessageType` - The message type field differs from the template id
 ///
 /// # Implements
 ///
@@ -41,13 +47,51 @@ impl std::error::Error for SbeEncodeError {}
 /// `std::error::Error` - Implements std::error::Error trait
 ///
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct SbeDecodeError(pub String);
+pub enum SbeDecodeError {
+    DecodeError(String),
+    BufferTooShort { expected: usize, actual: usize },
+    UnknownTemplateId(u16),
+    InvalidBlockLength { expected: u16, actual: u16 },
+    InvalidSchemaId { expected: u16, actual: u16 },
+    InvalidVersion { expected: u16, actual: u16 },
+    InvalidMessageType { expected: u16, actual: u16 },
+}
 
 impl std::error::Error for SbeDecodeError {}
 
 impl fmt::Display for SbeDecodeError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SbeDecodeError: {}", self.0)
+        match self {
+            SbeDecodeError::DecodeError(e) => write!(f, "SbeDecodeError: {}", e),
+            SbeDecodeError::BufferTooShort { expected, actual } => write!(
+                f,
+                "SbeDecodeError: Buffer too short: expected at least {} bytes, got {}",
+                expected, actual
+            ),
+            SbeDecodeError::UnknownTemplateId(template_id) => {
+                write!(f, "SbeDecodeError: Unknown template id: {}", template_id)
+            }
+            SbeDecodeError::InvalidBlockLength { expected, actual } => write!(
+                f,
+                "SbeDecodeError: Invalid block length: expected {}, got {}",
+                expected, actual
+            ),
+            SbeDecodeError::InvalidSchemaId { expected, actual } => write!(
+                f,
+                "SbeDecodeError: Invalid schema id: expected {}, got {}",
+                expected, actual
+            ),
+            SbeDecodeError::InvalidVersion { expected, actual } => write!(
+                f,
+                "SbeDecodeError: Invalid schema version {}: versions start at 1, the current version is {}",
+                actual, expected
+            ),
+            SbeDecodeError::InvalidMessageType { expected, actual } => write!(
+                f,
+                "SbeDecodeError: Invalid message type: expected {}, got {}",
+                expected, actual
+            ),
+        }
     }
 }
@@ -0,0 +1,206 @@
+use crate::errors::SbeDecodeError;
+use crate::prelude::MessageType;
+use sbe_bindings::{
+    client_error_codec, client_heartbeat_codec, client_login_ack_codec, client_login_codec,
+    client_logout_ack_codec, client_logout_codec, continue_data_msg_codec, data_bar_codec,
+    data_error_codec, first_data_bar_codec, first_trade_bar_codec, last_data_bar_codec,
+    last_trade_bar_codec, message_header_codec, pause_data_msg_codec, resume_data_msg_codec,
+    start_data_msg_codec, start_multi_data_msg_codec, stop_all_data_msg_codec, stop_data_msg_codec,
+    trade_bar_codec, MessageHeaderDecoder, ReadBuf,
+};
+
+use std::cmp::Ordering;
+
+// Schema id and version are the same in all generated codecs.
+use sbe_bindings::client_login_codec::{SBE_SCHEMA_ID, SBE_SCHEMA_VERSION};
+
+/// Validates the frame of a raw SBE message before it gets decoded.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer of the raw message
+///
+/// # Returns
+///
+/// The MessageType of the message if the frame is valid.
+///
+/// # Errors
+///
+/// Returns an `SbeDecodeError` if:
+///
+/// - The buffer is shorter than the message header (BufferTooShort)
+/// - The template id does not identify a message of the schema (UnknownTemplateId)
+/// - The block length does not match the block length of the message in the schema version
+///   of the message, or is shorter than the fields the message has in its schema version
+///   (InvalidBlockLength)
+/// - The schema id differs from the schema id of the bindings (InvalidSchemaId)
+/// - The schema version is zero (InvalidVersion)
+/// - The buffer is shorter than the message header plus the block length (BufferTooShort)
+/// - The message type field differs from the template id (InvalidMessageType)
+///
+/// # Process
+///
+/// - Check that the buffer holds the message header
+/// - Decode the header with the MessageHeaderDecoder
+/// - Validate schema id, version, template id, and block length
+/// - Accept a shorter block of an older schema version and a longer block of a newer schema version,
+///   but never a block shorter than the fields of the message in its schema version
+/// - Check that the buffer holds the message block
+/// - Validate the message type field that starts every message block
+///
+pub fn validate_frame(buffer: &[u8]) -> Result<MessageType, SbeDecodeError> {
+    let header_length = message_header_codec::ENCODED_LENGTH;
+
+    if buffer.len() < header_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: header_length,
+            actual: buffer.len(),
+        });
+    }
+
+    let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(buffer), 0);
+
+    let schema_id = header.schema_id();
+    if schema_id != SBE_SCHEMA_ID {
+        return Err(SbeDecodeError::InvalidSchemaId {
+            expected: SBE_SCHEMA_ID,
+            actual: schema_id,
+        });
+    }
+
+    // Schema versions start at 1.
+    let version = header.version();
+    if version == 0 {
+        return Err(SbeDecodeError::InvalidVersion {
+            expected: SBE_SCHEMA_VERSION,
+            actual: version,
+        });
+    }
+
+    let template_id = header.template_id();
+    let message_type = MessageType::from(template_id);
+    let expected_block_length = match block_length(&message_type) {
+        Some(block_length) => block_length,
+        None => return Err(SbeDecodeError::UnknownTemplateId(template_id)),
+    };
+
+    // Each schema version only appends fields to a message block, so a message of an older
+    // version may have a shorter block and a message of a newer version a longer block.
+    // The decoders read the fields of the block the message has and ignore the fields they don't know.
+    // Fields without sinceVersion are read in every version, so no block may be shorter than those.
+    let block_length = header.block_length();
+    let min_block_length = min_block_length(&message_type, version);
+    if block_length < min_block_length {
+        return Err(SbeDecodeError::InvalidBlockLength {
+            expected: min_block_length,
+            actual: block_length,
+        });
+    }
+
+    let valid_block_length = match version.cmp(&SBE_SCHEMA_VERSION) {
+        Ordering::Less => block_length <= expected_block_length,
+        Ordering::Equal => block_length == expected_block_length,
+        Ordering::Greater => block_length >= expected_block_length,
+    };
+
+    if !valid_block_length {
+        return Err(SbeDecodeError::InvalidBlockLength {
+            expected: expected_block_length,
+            actual: block_length,
+        });
+    }
+
+    let frame_length = header_length + block_length as usize;
+    if buffer.len() < frame_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: frame_length,
+            actual: buffer.len(),
+        });
+    }
+
+    // Every message block starts with the message type field.
+    let message_type_field = u16::from_le_bytes([buffer[header_length], buffer[header_length + 1]]);
+    if message_type_field != template_id {
+        return Err(SbeDecodeError::InvalidMessageType {
+            expected: template_id,
+            actual: message_type_field,
+        });
+    }
+
+    Ok(message_type)
+}
+
+/// Returns the block length of the given message type, or `None` for an unknown message type.
+fn block_length(message_type: &MessageType) -> Option<u16> {
+    let block_length = match message_type {
+        MessageType::UnknownMessageType => return None,
+        // Client Message Types
+        MessageType::ClientLogin => client_login_codec::SBE_BLOCK_LENGTH,
+        MessageType::ClientLogout => client_logout_codec::SBE_BLOCK_LENGTH,
+        MessageType::ClientLoginAck => client_login_ack_codec::SBE_BLOCK_LENGTH,
+        MessageType::ClientLogoutAck => client_logout_ack_codec::SBE_BLOCK_LENGTH,
+        MessageType::ClientHeartbeat => client_heartbeat_codec::SBE_BLOCK_LENGTH,
+        // Data Message Types
+        MessageType::StartData => start_data_msg_codec::SBE_BLOCK_LENGTH,
+        MessageType::StopData => stop_data_msg_codec::SBE_BLOCK_LENGTH,
+        MessageType::StopAllData => stop_all_data_msg_codec::SBE_BLOCK_LENGTH,
+        MessageType::OHLCVBar => data_bar_codec::SBE_BLOCK_LENGTH,
+        MessageType::FirstOHLCVBar => first_data_bar_codec::SBE_BLOCK_LENGTH,
+        MessageType::LastOHLCVBar => last_data_bar_codec::SBE_BLOCK_LENGTH,
+        MessageType::TradeBar => trade_bar_codec::SBE_BLOCK_LENGTH,
+        MessageType::FirstTradeBar => first_trade_bar_codec::SBE_BLOCK_LENGTH,
+        MessageType::LastTradeBar => last_trade_bar_codec::SBE_BLOCK_LENGTH,
+        MessageType::PauseData => pause_data_msg_codec::SBE_BLOCK_LENGTH,
+        MessageType::ContinueData => continue_data_msg_codec::SBE_BLOCK_LENGTH,
+        MessageType::StartMultiData => start_multi_data_msg_codec::SBE_BLOCK_LENGTH,
+        MessageType::ResumeData => resume_data_msg_codec::SBE_BLOCK_LENGTH,
+        // Error Message Types
+        MessageType::ClientError => client_error_codec::SBE_BLOCK_LENGTH,
+        MessageType::DataError => data_error_codec::SBE_BLOCK_LENGTH,
+    };
+
+    Some(block_length)
+}
+
+/// Returns the minimum block length of the given message type in the given schema version.
+///
+/// The minimum block length is the length of the fields the message has in the schema version:
+/// the fields without sinceVersion plus the fields with a sinceVersion up to the schema version.
+/// A newer schema version than the bindings has at least the fields of the bindings.
+/// Returns zero for an unknown message type.
+pub fn min_block_length(message_type: &MessageType, version: u16) -> u16 {
+    // The block length of each schema version that added fields to the message,
+    // ordered by schema version. The last entry is the block length of the bindings.
+    let versions: &[(u16, u16)] = match message_type {
+        MessageType::UnknownMessageType => return 0,
+        // Client Message Types
+        MessageType::ClientLogin => &[(1, 4), (4, client_login_codec::SBE_BLOCK_LENGTH)],
+        MessageType::ClientLogout => &[(1, client_logout_codec::SBE_BLOCK_LENGTH)],
+        MessageType::ClientLoginAck => &[(1, 16), (4, client_login_ack_codec::SBE_BLOCK_LENGTH)],
+        MessageType::ClientLogoutAck => &[(1, client_logout_ack_codec::SBE_BLOCK_LENGTH)],
+        MessageType::ClientHeartbeat => &[(1, client_heartbeat_codec::SBE_BLOCK_LENGTH)],
+        // Data Message Types
+        MessageType::StartData => &[(1, 13), (2, start_data_msg_codec::SBE_BLOCK_LENGTH)],
+        MessageType::StopData => &[(1, stop_data_msg_codec::SBE_BLOCK_LENGTH)],
+        MessageType::StopAllData => &[(1, stop_all_data_msg_codec::SBE_BLOCK_LENGTH)],
+        MessageType::OHLCVBar => &[(1, 40), (2, 56), (3, data_bar_codec::SBE_BLOCK_LENGTH)],
+        MessageType::FirstOHLCVBar => &[(1, first_data_bar_codec::SBE_BLOCK_LENGTH)],
+        MessageType::LastOHLCVBar => &[(1, last_data_bar_codec::SBE_BLOCK_LENGTH)],
+        MessageType::TradeBar => &[(1, 28), (3, trade_bar_codec::SBE_BLOCK_LENGTH)],
+        MessageType::FirstTradeBar => &[(1, first_trade_bar_codec::SBE_BLOCK_LENGTH)],
+        MessageType::LastTradeBar => &[(1, last_trade_bar_codec::SBE_BLOCK_LENGTH)],
+        MessageType::PauseData => &[(1, pause_data_msg_codec::SBE_BLOCK_LENGTH)],
+        MessageType::ContinueData => &[(1, continue_data_msg_codec::SBE_BLOCK_LENGTH)],
+        MessageType::StartMultiData => &[(1, start_multi_data_msg_codec::SBE_BLOCK_LENGTH)],
+        MessageType::ResumeData => &[(1, resume_data_msg_codec::SBE_BLOCK_LENGTH)],
+        // Error Message Types
+        MessageType::ClientError => &[(1, client_error_codec::SBE_BLOCK_LENGTH)],
+        MessageType::DataError => &[(1, 5), (6, data_error_codec::SBE_BLOCK_LENGTH)],
+    };
+
+    versions
+        .iter()
+        .take_while(|(since_version, _)| *since_version <= version)
+        .last()
+        .map_or(0, |(_, block_length)| *block_length)
+}
@@ -1,4 +1,6 @@
+pub mod decimal;
 pub mod errors;
+pub mod frame;
 pub mod messages;
 pub mod prelude;
 pub mod types;
@@ -0,0 +1,12 @@
+use crate::prelude::ClientHeartbeatMessage;
+use std::fmt;
+
+impl fmt::Display for ClientHeartbeatMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ClientHeartbeatMessage {{ client_id: {} }}",
+            self.client_id()
+        )
+    }
+}
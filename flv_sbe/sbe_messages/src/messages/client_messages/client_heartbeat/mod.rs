@@ -0,0 +1,58 @@
+use crate::prelude::MessageType;
+use serde::{Deserialize, Serialize};
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ClientHeartbeatMessage {
+    message_type: MessageType,
+    client_id: u16,
+}
+
+impl ClientHeartbeatMessage {
+    /// Creates a new ClientHeartbeatMessage instance.
+    ///
+    /// Sets the message_type to ClientHeartbeat.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    ///
+    /// # Returns
+    ///
+    /// ClientHeartbeatMessage instance
+    ///
+    pub fn new(client_id: u16) -> Self {
+        let message_type = MessageType::ClientHeartbeat;
+        Self {
+            message_type,
+            client_id,
+        }
+    }
+}
+
+impl From<&[u8]> for ClientHeartbeatMessage {
+    /// Implements the From trait to decode a ClientHeartbeatMessage from a byte slice.
+    ///
+    /// Calls the sbe_decode::decode_client_heartbeat_message function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded ClientHeartbeatMessage
+    ///
+    /// # Errors
+    ///
+    /// Panics if decode fails
+    #[inline]
+    fn from(value: &[u8]) -> Self {
+        sbe_decode::decode_client_heartbeat_message(value)
+            .expect("Failed to decode ClientHeartbeatMessage")
+    }
+}
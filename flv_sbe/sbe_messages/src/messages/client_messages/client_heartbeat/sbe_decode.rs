@@ -0,0 +1,44 @@
+use crate::prelude::{ClientHeartbeatMessage, MessageType};
+use sbe_bindings::client_heartbeat_codec::SBE_TEMPLATE_ID;
+use sbe_bindings::{ClientHeartbeatDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
+
+/// Decodes a ClientHeartbeatMessage from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded ClientHeartbeatMessage
+///
+/// # Errors
+///
+/// Returns Err if decode fails
+///
+/// # Process
+///
+/// - Create default ClientHeartbeatDecoder
+/// - Wrap buffer in ReadBuf
+/// - Decode header and validate template ID
+/// - Decode message_type and validate
+/// - Decode client_id
+/// - Create and return ClientHeartbeatMessage
+pub fn decode_client_heartbeat_message(buffer: &[u8]) -> SbeResult<ClientHeartbeatMessage> {
+    let mut csg = ClientHeartbeatDecoder::default();
+    let buf = ReadBuf::new(buffer);
+
+    let header = MessageHeaderDecoder::default().wrap(buf, 0);
+    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+    csg = csg.header(header);
+
+    let sbe_message_type = csg.message_type();
+    let message_type = MessageType::from(sbe_message_type as u16);
+    assert_eq!(message_type, MessageType::ClientHeartbeat);
+
+    let client_id = csg.client_id();
+
+    let message = ClientHeartbeatMessage::new(client_id);
+
+    Ok(message)
+}
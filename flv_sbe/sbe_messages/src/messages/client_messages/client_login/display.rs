@@ -5,8 +5,10 @@ impl fmt::Display for ClientLoginMessage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "ClientLoginMessage {{ client_id: {} }}",
+            "ClientLoginMessage {{ client_id: {}, protocol_version: {}, capabilities: {} }}",
             self.client_id(),
+            self.protocol_version(),
+            self.capabilities(),
         )
     }
 }
@@ -1,5 +1,5 @@
 use crate::messages::client_messages::client_login::ClientLoginMessage;
-use crate::prelude::MessageType;
+use crate::prelude::{Capabilities, MessageType};
 
 impl ClientLoginMessage {
     pub fn message_type(&self) -> &MessageType {
@@ -8,4 +8,13 @@ impl ClientLoginMessage {
     pub fn client_id(&self) -> u16 {
         self.client_id
     }
+    pub fn protocol_version(&self) -> u16 {
+        self.protocol_version
+    }
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+    pub fn auth_token(&self) -> &str {
+        &self.auth_token
+    }
 }
@@ -1,4 +1,4 @@
-use crate::prelude::MessageType;
+use crate::prelude::{Capabilities, MessageType, SbeDecodeError, PROTOCOL_VERSION};
 use serde::{Deserialize, Serialize};
 
 mod display;
@@ -10,12 +10,17 @@ mod sbe_encode;
 pub struct ClientLoginMessage {
     message_type: MessageType,
     client_id: u16,
+    protocol_version: u16,
+    capabilities: Capabilities,
+    auth_token: String,
 }
 
 impl ClientLoginMessage {
     /// Creates a new ClientLoginMessage instance.
     ///
-    /// Sets the message_type to ClientLogin.
+    /// Sets the message_type to ClientLogin, the protocol_version to the
+    /// protocol version of this build, the capabilities to the supported capabilities,
+    /// and no auth token.
     ///
     /// # Arguments
     ///
@@ -31,14 +36,66 @@ impl ClientLoginMessage {
         Self {
             message_type,
             client_id,
+            protocol_version: PROTOCOL_VERSION,
+            capabilities: Capabilities::supported(),
+            auth_token: String::new(),
         }
     }
+
+    /// Sets the protocol version of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `protocol_version` - u16 protocol version of the client
+    ///
+    /// # Returns
+    ///
+    /// ClientLoginMessage instance with the protocol version
+    ///
+    pub fn with_protocol_version(mut self, protocol_version: u16) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    /// Sets the capabilities of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `capabilities` - Capabilities the client supports
+    ///
+    /// # Returns
+    ///
+    /// ClientLoginMessage instance with the capabilities
+    ///
+    pub fn with_capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Sets the auth token the gateway uses to authenticate the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_token` - Auth token of the client
+    ///
+    /// # Returns
+    ///
+    /// ClientLoginMessage instance with the auth token
+    ///
+    pub fn with_auth_token(mut self, auth_token: &str) -> Self {
+        self.auth_token = auth_token.to_string();
+        self
+    }
 }
 
-impl From<&[u8]> for ClientLoginMessage {
-    /// Implements the From trait to decode a ClientLoginMessage from a byte slice.
+impl TryFrom<&[u8]> for ClientLoginMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the TryFrom trait to decode a ClientLoginMessage from a byte slice.
     ///
     /// Calls the sbe_decode::decode_client_login_message function to decode the message.
+    /// Since schema version 5, the auth token makes the length of the message variable,
+    /// which is why decoding fails with an error instead of a panic.
     ///
     /// # Arguments
     ///
@@ -50,9 +107,9 @@ impl From<&[u8]> for ClientLoginMessage {
     ///
     /// # Errors
     ///
-    /// Panics if decode fails
+    /// Returns an SbeDecodeError if the buffer does not hold the message with its auth token.
     #[inline]
-    fn from(value: &[u8]) -> Self {
-        sbe_decode::decode_client_login_message(value).expect("Failed to decode ClientLoginMessage")
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_client_login_message(value)
     }
 }
@@ -1,6 +1,9 @@
-use crate::prelude::{ClientLoginMessage, MessageType};
+use crate::messages::client_messages::{read_token, TOKEN_SINCE_VERSION};
+use crate::prelude::{Capabilities, ClientLoginMessage, MessageType, SbeDecodeError};
 use sbe_bindings::client_login_codec::SBE_TEMPLATE_ID;
-use sbe_bindings::{ClientLoginDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
+use sbe_bindings::{
+    message_header_codec, ClientLoginDecoder, Decoder, MessageHeaderDecoder, ReadBuf,
+};
 
 /// Decodes a ClientLoginMessage from a byte buffer.
 ///
@@ -14,32 +17,83 @@ use sbe_bindings::{ClientLoginDecoder, MessageHeaderDecoder, ReadBuf, SbeResult}
 ///
 /// # Errors
 ///
-/// Returns Err if decode fails
+/// Returns an SbeDecodeError if:
+///
+/// - The buffer is shorter than the message block or the auth token (BufferTooShort)
+/// - The template id is not the ClientLogin template id (UnknownTemplateId)
+/// - The message type is not ClientLogin (InvalidMessageType)
+/// - The auth token is not valid UTF-8 (DecodeError)
 ///
 /// # Process
 ///
+/// - Check that the buffer holds the message header
 /// - Create default ClientLoginDecoder
 /// - Wrap buffer in ReadBuf
 /// - Decode header and validate template ID
+/// - Check that the buffer holds the message block
 /// - Decode message_type and validate
 /// - Decode client_id
+/// - Decode protocol_version and capabilities; messages before schema version 4 have none,
+///   so the protocol version is the schema version of the message and the capabilities are empty
+/// - Decode the auth_token; messages before schema version 5 have none, so the auth token is empty
 /// - Create and return ClientLoginMessage
 ///
-pub fn decode_client_login_message(buffer: &[u8]) -> SbeResult<ClientLoginMessage> {
+pub fn decode_client_login_message(buffer: &[u8]) -> Result<ClientLoginMessage, SbeDecodeError> {
+    let header_length = message_header_codec::ENCODED_LENGTH;
+    if buffer.len() < header_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: header_length,
+            actual: buffer.len(),
+        });
+    }
+
     let mut csg = ClientLoginDecoder::default();
     let buf = ReadBuf::new(buffer);
 
     let header = MessageHeaderDecoder::default().wrap(buf, 0);
-    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+    let template_id = header.template_id();
+    if template_id != SBE_TEMPLATE_ID {
+        return Err(SbeDecodeError::UnknownTemplateId(template_id));
+    }
     csg = csg.header(header);
 
+    // The message block ends where the auth token starts.
+    let block_end = csg.get_limit();
+    if buffer.len() < block_end {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: block_end,
+            actual: buffer.len(),
+        });
+    }
+
     let sbe_message_type = csg.message_type();
     let message_type = MessageType::from(sbe_message_type as u16);
-    assert_eq!(message_type, MessageType::ClientLogin);
+    if message_type != MessageType::ClientLogin {
+        return Err(SbeDecodeError::InvalidMessageType {
+            expected: MessageType::ClientLogin as u16,
+            actual: message_type as u16,
+        });
+    }
 
     let client_id = csg.client_id();
 
-    let message = ClientLoginMessage::new(client_id);
+    let protocol_version = csg.protocol_version().unwrap_or(csg.acting_version);
+
+    let capabilities = match csg.capabilities() {
+        Some(capabilities) => Capabilities::from(capabilities),
+        None => Capabilities::NONE,
+    };
+
+    let auth_token = if csg.acting_version < TOKEN_SINCE_VERSION {
+        String::new()
+    } else {
+        read_token(buffer, block_end)?
+    };
+
+    let message = ClientLoginMessage::new(client_id)
+        .with_protocol_version(protocol_version)
+        .with_capabilities(capabilities)
+        .with_auth_token(&auth_token);
 
     Ok(message)
 }
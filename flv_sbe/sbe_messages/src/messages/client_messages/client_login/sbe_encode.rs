@@ -1,4 +1,5 @@
 use crate::errors::SbeEncodeError;
+use crate::messages::client_messages::check_token_length;
 use crate::prelude::ClientLoginMessage;
 use sbe_bindings::MessageType as SbeMessageType;
 use sbe_bindings::{message_header_codec, ClientLoginEncoder, Encoder, WriteBuf};
@@ -16,21 +17,26 @@ impl ClientLoginMessage {
     ///
     /// # Errors
     ///
-    /// Returns Err if encoding fails
+    /// Returns Err if encoding fails or the auth token is longer than 1024 bytes
     ///
     /// # Process
     ///
-    /// - Create a 12 byte buffer
+    /// - Check the length of the auth token
+    /// - Create a buffer of 18 bytes plus 2 bytes for the length of the auth token and the auth token
     /// - Create default ClientLoginEncoder
     /// - Wrap buffer in WriteBuf
     /// - Encode header
     /// - Encode message_type
     /// - Encode client_id
+    /// - Encode protocol_version and capabilities
+    /// - Encode auth_token
     /// - Return encoded size and buffer
     ///
     pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-        // precise buffer size is 12 bytes for the entire message.
-        let mut buffer = vec![0u8; 12];
+        check_token_length(&self.auth_token)?;
+
+        // precise buffer size is 18 bytes for header and block, plus 2 bytes for the length of the auth token and the auth token.
+        let mut buffer = vec![0u8; 20 + self.auth_token.len()];
 
         let mut csg = ClientLoginEncoder::default();
 
@@ -50,6 +56,14 @@ impl ClientLoginMessage {
         let value = self.client_id;
         csg.client_id(value);
 
+        let value = self.protocol_version;
+        csg.protocol_version(value);
+
+        let value = self.capabilities.bits();
+        csg.capabilities(value);
+
+        csg.auth_token(&self.auth_token);
+
         let limit = csg.get_limit();
         Ok((limit, buffer))
     }
@@ -0,0 +1,17 @@
+use crate::prelude::ClientLoginAckMessage;
+use std::fmt;
+
+impl fmt::Display for ClientLoginAckMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ClientLoginAckMessage {{ client_id: {}, stream_id: {}, topic_id: {}, partition_id: {}, protocol_version: {}, capabilities: {} }}",
+            self.client_id(),
+            self.stream_id(),
+            self.topic_id(),
+            self.partition_id(),
+            self.protocol_version(),
+            self.capabilities()
+        )
+    }
+}
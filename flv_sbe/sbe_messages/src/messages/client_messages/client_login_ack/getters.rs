@@ -0,0 +1,28 @@
+use crate::prelude::{Capabilities, ClientLoginAckMessage, MessageType};
+
+impl ClientLoginAckMessage {
+    pub fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    pub fn client_id(&self) -> u16 {
+        self.client_id
+    }
+    pub fn stream_id(&self) -> u32 {
+        self.stream_id
+    }
+    pub fn topic_id(&self) -> u32 {
+        self.topic_id
+    }
+    pub fn partition_id(&self) -> u32 {
+        self.partition_id
+    }
+    pub fn protocol_version(&self) -> u16 {
+        self.protocol_version
+    }
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+}
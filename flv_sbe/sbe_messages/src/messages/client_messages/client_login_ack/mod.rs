@@ -0,0 +1,124 @@
+use crate::prelude::{Capabilities, MessageType, SbeDecodeError, PROTOCOL_VERSION};
+use serde::{Deserialize, Serialize};
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ClientLoginAckMessage {
+    message_type: MessageType,
+    client_id: u16,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    protocol_version: u16,
+    capabilities: Capabilities,
+    access_token: String,
+}
+
+impl ClientLoginAckMessage {
+    /// Creates a new ClientLoginAckMessage instance.
+    ///
+    /// Sets the message_type to ClientLoginAck, the protocol_version to the
+    /// protocol version of this build, the capabilities to the supported capabilities,
+    /// and no access token.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `stream_id` - u32 stream ID of the data channel assigned to the client
+    /// * `topic_id` - u32 topic ID of the data channel assigned to the client
+    /// * `partition_id` - u32 partition ID of the data channel assigned to the client
+    ///
+    /// # Returns
+    ///
+    /// ClientLoginAckMessage instance
+    ///
+    pub fn new(client_id: u16, stream_id: u32, topic_id: u32, partition_id: u32) -> Self {
+        let message_type = MessageType::ClientLoginAck;
+
+        Self {
+            message_type,
+            client_id,
+            stream_id,
+            topic_id,
+            partition_id,
+            protocol_version: PROTOCOL_VERSION,
+            capabilities: Capabilities::supported(),
+            access_token: String::new(),
+        }
+    }
+
+    /// Sets the protocol version negotiated with the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `protocol_version` - u16 negotiated protocol version
+    ///
+    /// # Returns
+    ///
+    /// ClientLoginAckMessage instance with the protocol version
+    ///
+    pub fn with_protocol_version(mut self, protocol_version: u16) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    /// Sets the capabilities negotiated with the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `capabilities` - Capabilities both the client and the gateway support
+    ///
+    /// # Returns
+    ///
+    /// ClientLoginAckMessage instance with the capabilities
+    ///
+    pub fn with_capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Sets the personal access token of the iggy user the gateway issued to the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_token` - Personal access token of the iggy user of the client
+    ///
+    /// # Returns
+    ///
+    /// ClientLoginAckMessage instance with the access token
+    ///
+    pub fn with_access_token(mut self, access_token: &str) -> Self {
+        self.access_token = access_token.to_string();
+        self
+    }
+}
+
+impl TryFrom<&[u8]> for ClientLoginAckMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the TryFrom trait to decode a ClientLoginAckMessage from a byte slice.
+    ///
+    /// Calls the sbe_decode::decode_client_login_ack_message function to decode the message.
+    /// Since schema version 5, the access token makes the length of the message variable,
+    /// which is why decoding fails with an error instead of a panic.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded ClientLoginAckMessage
+    ///
+    /// # Errors
+    ///
+    /// Returns an SbeDecodeError if the buffer does not hold the message with its access token.
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_client_login_ack_message(value)
+    }
+}
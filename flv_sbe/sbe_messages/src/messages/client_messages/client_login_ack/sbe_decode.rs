@@ -0,0 +1,105 @@
+use crate::messages::client_messages::{read_token, TOKEN_SINCE_VERSION};
+use crate::prelude::{Capabilities, ClientLoginAckMessage, MessageType, SbeDecodeError};
+use sbe_bindings::client_login_ack_codec::SBE_TEMPLATE_ID;
+use sbe_bindings::{
+    message_header_codec, ClientLoginAckDecoder, Decoder, MessageHeaderDecoder, ReadBuf,
+};
+
+/// Decodes a ClientLoginAckMessage from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded ClientLoginAckMessage
+///
+/// # Errors
+///
+/// Returns an SbeDecodeError if:
+///
+/// - The buffer is shorter than the message block or the access token (BufferTooShort)
+/// - The template id is not the ClientLoginAck template id (UnknownTemplateId)
+/// - The message type is not ClientLoginAck (InvalidMessageType)
+/// - The access token is not valid UTF-8 (DecodeError)
+///
+/// # Process
+///
+/// - Check that the buffer holds the message header
+/// - Create default ClientLoginAckDecoder
+/// - Wrap buffer in ReadBuf
+/// - Decode header and validate template ID
+/// - Check that the buffer holds the message block
+/// - Decode message_type and validate
+/// - Decode client_id
+/// - Decode stream_id, topic_id, and partition_id
+/// - Decode protocol_version and capabilities; messages before schema version 4 have none,
+///   so the protocol version is the schema version of the message and the capabilities are empty
+/// - Decode the access_token; messages before schema version 5 have none, so the access token is empty
+/// - Create and return ClientLoginAckMessage
+///
+pub fn decode_client_login_ack_message(
+    buffer: &[u8],
+) -> Result<ClientLoginAckMessage, SbeDecodeError> {
+    let header_length = message_header_codec::ENCODED_LENGTH;
+    if buffer.len() < header_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: header_length,
+            actual: buffer.len(),
+        });
+    }
+
+    let mut csg = ClientLoginAckDecoder::default();
+    let buf = ReadBuf::new(buffer);
+
+    let header = MessageHeaderDecoder::default().wrap(buf, 0);
+    let template_id = header.template_id();
+    if template_id != SBE_TEMPLATE_ID {
+        return Err(SbeDecodeError::UnknownTemplateId(template_id));
+    }
+    csg = csg.header(header);
+
+    // The message block ends where the access token starts.
+    let block_end = csg.get_limit();
+    if buffer.len() < block_end {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: block_end,
+            actual: buffer.len(),
+        });
+    }
+
+    let sbe_message_type = csg.message_type();
+    let message_type = MessageType::from(sbe_message_type as u16);
+    if message_type != MessageType::ClientLoginAck {
+        return Err(SbeDecodeError::InvalidMessageType {
+            expected: MessageType::ClientLoginAck as u16,
+            actual: message_type as u16,
+        });
+    }
+
+    let client_id = csg.client_id();
+    let stream_id = csg.stream_id();
+    let topic_id = csg.topic_id();
+    let partition_id = csg.partition_id();
+
+    let protocol_version = csg.protocol_version().unwrap_or(csg.acting_version);
+
+    let capabilities = match csg.capabilities() {
+        Some(capabilities) => Capabilities::from(capabilities),
+        None => Capabilities::NONE,
+    };
+
+    let access_token = if csg.acting_version < TOKEN_SINCE_VERSION {
+        String::new()
+    } else {
+        read_token(buffer, block_end)?
+    };
+
+    let message = ClientLoginAckMessage::new(client_id, stream_id, topic_id, partition_id)
+        .with_protocol_version(protocol_version)
+        .with_capabilities(capabilities)
+        .with_access_token(&access_token);
+
+    Ok(message)
+}
@@ -0,0 +1,80 @@
+use crate::errors::SbeEncodeError;
+use crate::messages::client_messages::check_token_length;
+use crate::prelude::ClientLoginAckMessage;
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{message_header_codec, ClientLoginAckEncoder, Encoder, WriteBuf};
+
+impl ClientLoginAckMessage {
+    /// Encodes a ClientLoginAckMessage to a byte buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - ClientLoginAckMessage to encode
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails or the access token is longer than 1024 bytes
+    ///
+    /// # Process
+    ///
+    /// - Check the length of the access token
+    /// - Create a buffer of 30 bytes plus 2 bytes for the length of the access token and the access token
+    /// - Create default ClientLoginAckEncoder
+    /// - Wrap buffer in WriteBuf
+    /// - Encode header
+    /// - Encode message_type
+    /// - Encode client_id
+    /// - Encode stream_id, topic_id, and partition_id
+    /// - Encode protocol_version and capabilities
+    /// - Encode access_token
+    /// - Return encoded size and buffer
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        check_token_length(&self.access_token)?;
+
+        // precise buffer size is 30 bytes for header and block, plus 2 bytes for the length of the access token and the access token.
+        let mut buffer = vec![0u8; 32 + self.access_token.len()];
+
+        let mut csg = ClientLoginAckEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[ClientLoginAckMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.client_id;
+        csg.client_id(value);
+
+        let value = self.stream_id;
+        csg.stream_id(value);
+
+        let value = self.topic_id;
+        csg.topic_id(value);
+
+        let value = self.partition_id;
+        csg.partition_id(value);
+
+        let value = self.protocol_version;
+        csg.protocol_version(value);
+
+        let value = self.capabilities.bits();
+        csg.capabilities(value);
+
+        csg.access_token(&self.access_token);
+
+        let limit = csg.get_limit();
+        Ok((limit, buffer))
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::ClientLogoutAckMessage;
+use std::fmt;
+
+impl fmt::Display for ClientLogoutAckMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ClientLogoutAckMessage {{ client_id: {} }}",
+            self.client_id()
+        )
+    }
+}
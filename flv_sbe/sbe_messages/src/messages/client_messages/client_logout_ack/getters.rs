@@ -0,0 +1,10 @@
+use crate::prelude::{ClientLogoutAckMessage, MessageType};
+
+impl ClientLogoutAckMessage {
+    pub fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    pub fn client_id(&self) -> u16 {
+        self.client_id
+    }
+}
@@ -0,0 +1,58 @@
+use crate::prelude::MessageType;
+use serde::{Deserialize, Serialize};
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ClientLogoutAckMessage {
+    message_type: MessageType,
+    client_id: u16,
+}
+
+impl ClientLogoutAckMessage {
+    /// Creates a new ClientLogoutAckMessage instance.
+    ///
+    /// Sets the message_type to ClientLogoutAck.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    ///
+    /// # Returns
+    ///
+    /// ClientLogoutAckMessage instance
+    ///
+    pub fn new(client_id: u16) -> Self {
+        let message_type = MessageType::ClientLogoutAck;
+        Self {
+            message_type,
+            client_id,
+        }
+    }
+}
+
+impl From<&[u8]> for ClientLogoutAckMessage {
+    /// Implements the From trait to decode a ClientLogoutAckMessage from a byte slice.
+    ///
+    /// Calls the sbe_decode::decode_client_logout_ack_message function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded ClientLogoutAckMessage
+    ///
+    /// # Errors
+    ///
+    /// Panics if decode fails
+    #[inline]
+    fn from(value: &[u8]) -> Self {
+        sbe_decode::decode_client_logout_ack_message(value)
+            .expect("Failed to decode ClientLogoutAckMessage")
+    }
+}
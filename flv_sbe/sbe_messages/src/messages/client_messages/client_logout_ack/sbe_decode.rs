@@ -0,0 +1,44 @@
+use crate::prelude::{ClientLogoutAckMessage, MessageType};
+use sbe_bindings::client_logout_ack_codec::SBE_TEMPLATE_ID;
+use sbe_bindings::{ClientLogoutAckDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
+
+/// Decodes a ClientLogoutAckMessage from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded ClientLogoutAckMessage
+///
+/// # Errors
+///
+/// Returns Err if decode fails
+///
+/// # Process
+///
+/// - Create default ClientLogoutAckDecoder
+/// - Wrap buffer in ReadBuf
+/// - Decode header and validate template ID
+/// - Decode message_type and validate
+/// - Decode client_id
+/// - Create and return ClientLogoutAckMessage
+pub fn decode_client_logout_ack_message(buffer: &[u8]) -> SbeResult<ClientLogoutAckMessage> {
+    let mut csg = ClientLogoutAckDecoder::default();
+    let buf = ReadBuf::new(buffer);
+
+    let header = MessageHeaderDecoder::default().wrap(buf, 0);
+    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+    csg = csg.header(header);
+
+    let sbe_message_type = csg.message_type();
+    let message_type = MessageType::from(sbe_message_type as u16);
+    assert_eq!(message_type, MessageType::ClientLogoutAck);
+
+    let client_id = csg.client_id();
+
+    let message = ClientLogoutAckMessage::new(client_id);
+
+    Ok(message)
+}
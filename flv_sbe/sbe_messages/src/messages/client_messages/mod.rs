@@ -1,17 +1,98 @@
-/// Module containing client request messages.
+/// Module containing client request and acknowledgement messages.
 ///
 /// This includes messages like:
 ///
 /// - ClientLoginMessage
 /// - ClientLogoutMessage
+/// - ClientLoginAckMessage
+/// - ClientLogoutAckMessage
+/// - ClientHeartbeatMessage
 ///
 ///
 /// The client messages are exposed in the prelude for convenient importing.
 ///
 /// # Exports
 ///
+/// - `client_heartbeat` - ClientHeartbeatMessage
 /// - `client_login` - ClientLoginMessage
+/// - `client_login_ack` - ClientLoginAckMessage
 /// - `client_logout` - ClientLogoutMessage
+/// - `client_logout_ack` - ClientLogoutAckMessage
 ///
+pub mod client_heartbeat;
 pub mod client_login;
+pub mod client_login_ack;
 pub mod client_logout;
+pub mod client_logout_ack;
+
+use crate::errors::{SbeDecodeError, SbeEncodeError};
+
+/// The schema version that added the auth token to the ClientLogin message
+/// and the access token to the ClientLoginAck message.
+pub(crate) const TOKEN_SINCE_VERSION: u16 = 5;
+
+/// The maximum length of a token in bytes, as limited by the varStringEncoding of the schema.
+pub(crate) const MAX_TOKEN_LENGTH: usize = 1024;
+
+/// Checks that a token fits into the variable length string field of a message.
+///
+/// # Errors
+///
+/// Returns an SbeEncodeError if the token is longer than `MAX_TOKEN_LENGTH` bytes.
+///
+pub(crate) fn check_token_length(token: &str) -> Result<(), SbeEncodeError> {
+    if token.len() > MAX_TOKEN_LENGTH {
+        return Err(SbeEncodeError(format!(
+            "Token of {} bytes exceeds the maximum length of {} bytes",
+            token.len(),
+            MAX_TOKEN_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reads a token from the variable length string field that starts at the given offset.
+///
+/// The generated decoder reads past the end of a truncated buffer,
+/// so the length prefix and the token are checked against the buffer first.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer of the message
+/// * `offset` - Offset of the length prefix of the field, which is the end of the message block
+///
+/// # Returns
+///
+/// The token, which is empty if the sender did not set a token.
+///
+/// # Errors
+///
+/// Returns an SbeDecodeError if:
+///
+/// - The buffer does not hold the length prefix or the token (BufferTooShort)
+/// - The token is not valid UTF-8 (DecodeError)
+///
+pub(crate) fn read_token(buffer: &[u8], offset: usize) -> Result<String, SbeDecodeError> {
+    let data_offset = offset + 2;
+    if buffer.len() < data_offset {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: data_offset,
+            actual: buffer.len(),
+        });
+    }
+
+    let length = u16::from_le_bytes([buffer[offset], buffer[offset + 1]]) as usize;
+    let frame_length = data_offset + length;
+    if buffer.len() < frame_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: frame_length,
+            actual: buffer.len(),
+        });
+    }
+
+    match std::str::from_utf8(&buffer[data_offset..frame_length]) {
+        Ok(token) => Ok(token.to_string()),
+        Err(e) => Err(SbeDecodeError::DecodeError(format!("Invalid token: {}", e))),
+    }
+}
@@ -3,9 +3,13 @@
 /// This includes messages like:
 ///
 /// - StartDataMessage
+/// - StartMultiDataMessage
 /// - StopDataMessage  
 /// - StartAllDataMessage
 /// - StopAllDataMessage
+/// - PauseDataMessage
+/// - ContinueDataMessage
+/// - ResumeDataMessage
 /// - OHLCVBarMessage
 /// - TradeBarMessage
 ///
@@ -16,10 +20,14 @@
 ///
 /// # Exports
 ///
+/// - `continue_data` - ContinueDataMessage
 /// - `ohlcv_bar` - OHLCVBarMessage
 /// - `ohlcv_bar_first` - OHLCVBarFirstMessage
 /// - `ohlcv_bar_last` - OHLCVBarLastMessage
+/// - `pause_data` - PauseDataMessage
+/// - `resume_data` - ResumeDataMessage
 /// - `start_data` - StartDataMessage
+/// - `start_multi_data` - StartMultiDataMessage
 /// - `stop_data` - StopDataMessage
 /// - `start_all_data` - StartAllDataMessage
 /// - `stop_all_data` - StopAllDataMessage
@@ -27,12 +35,55 @@
 /// - `trade_bar_first` - TradeBarFirstMessage
 /// - `trade_bar_last` - TradeBarLastMessage
 ///
+pub mod continue_data;
 pub mod ohlcv_bar;
 pub mod ohlcv_bar_first;
 pub mod ohlcv_bar_last;
+pub mod pause_data;
+pub mod resume_data;
 pub mod start_data;
+pub mod start_multi_data;
 pub mod stop_all_data;
 pub mod stop_data;
 pub mod trade_bar;
 pub mod trade_bar_first;
 pub mod trade_bar_last;
+
+use crate::errors::SbeDecodeError;
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+
+/// Decodes a timestamp in microseconds since the Unix epoch.
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError::DecodeError` if the timestamp is out of the range of DateTime,
+/// which a malformed message can carry.
+pub(crate) fn decode_timestamp(micros: i64) -> Result<DateTime<Utc>, SbeDecodeError> {
+    match Utc.timestamp_micros(micros).single() {
+        Some(date_time) => Ok(date_time),
+        None => Err(SbeDecodeError::DecodeError(format!(
+            "Timestamp out of range: {} microseconds",
+            micros
+        ))),
+    }
+}
+
+/// Decodes an f32 field of a message into a Decimal.
+///
+/// Messages before schema version 3 only carry the f32 value of a price or volume.
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError::DecodeError` if the value is NaN or infinite,
+/// or exceeds the range of a Decimal.
+pub(crate) fn decode_float(value: f32, field: &str) -> Result<Decimal, SbeDecodeError> {
+    match Decimal::from_f32(value) {
+        Some(value) => Ok(value),
+        None => Err(SbeDecodeError::DecodeError(format!(
+            "Invalid {}: {} is not a valid decimal",
+            field, value
+        ))),
+    }
+}
@@ -1,6 +1,7 @@
+use crate::decimal::{read_decimal, read_decimal_null};
 use crate::errors::SbeDecodeError;
+use crate::messages::data_messages::{decode_float, decode_timestamp};
 use crate::prelude::MessageType;
-use chrono::{DateTime, TimeZone, Utc};
 use common::prelude::OHLCVBar;
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
@@ -19,7 +20,8 @@ use sbe_bindings::{DataBarDecoder, MessageHeaderDecoder, ReadBuf};
 ///
 /// # Errors
 ///
-/// Returns Err if decode fails
+/// Returns Err if decode fails, the timestamp is out of range,
+/// or a message without exact decimals carries a price or volume that is NaN or infinite
 ///
 /// # Process
 ///
@@ -29,11 +31,13 @@ use sbe_bindings::{DataBarDecoder, MessageHeaderDecoder, ReadBuf};
 /// - Decode and validate message_type
 /// - Decode symbol_id
 /// - Decode and parse date_time
-/// - Decode and parse open_price
-/// - Decode and parse high_price
-/// - Decode and parse low_price
-/// - Decode and parse close_price
-/// - Decode and parse volume
+/// - Decode open_price, high_price, low_price, close_price, and volume as f32
+/// - Decode sequence_number
+/// - Decode the optional vwap, trade_count, buy_volume, and sell_volume,
+///   which are null in messages of schema version 1
+/// - Decode the exact decimals of prices, volumes, and vwap,
+///   which are absent in messages before schema version 3
+/// - Convert the f32 values to Decimal only if their decimals are absent
 /// - Create and return OHLCVBar
 ///
 pub fn decode_data_bar_message(buffer: &[u8]) -> Result<OHLCVBar, SbeDecodeError> {
@@ -51,27 +55,84 @@ pub fn decode_data_bar_message(buffer: &[u8]) -> Result<OHLCVBar, SbeDecodeError
     let symbol_id = csg.symbol_id();
 
     let sbe_date_time = csg.date_time();
-    let date_time: DateTime<Utc> = Utc.timestamp_micros(sbe_date_time).unwrap();
+    let date_time = decode_timestamp(sbe_date_time)?;
 
     let sbe_open_price = csg.open_price();
-    let open =
-        Decimal::from_f32(sbe_open_price).expect("[FileManager]: Failed to parse open price");
-
     let sbe_high_price = csg.high_price();
-    let high =
-        Decimal::from_f32(sbe_high_price).expect("[FileManager]: Failed to parse high price");
-
     let sbe_low_price = csg.low_price();
-    let low = Decimal::from_f32(sbe_low_price).expect("[FileManager]: Failed to parse low price");
-
     let sbe_close_price = csg.close_price();
-    let close =
-        Decimal::from_f32(sbe_close_price).expect("[FileManager]: Failed to parse close price");
-
     let sbe_volume = csg.volume();
-    let volume = Decimal::from_f32(sbe_volume).expect("[FileManager]: Failed to parse volume");
 
-    let data_bar = OHLCVBar::new(symbol_id, date_time, open, high, low, close, volume);
+    let sequence_number = csg.sequence_number();
+
+    let sbe_vwap = csg.vwap();
+    let trade_count = csg.trade_count();
+    let sbe_buy_volume = csg.buy_volume();
+    let sbe_sell_volume = csg.sell_volume();
+
+    // Messages of schema version 3 and later carry exact decimals
+    let (csg, open_decimal) = read_decimal(csg.open_price_decimal_decoder())?;
+
+    let (csg, high_decimal) = read_decimal(csg.high_price_decimal_decoder())?;
+
+    let (csg, low_decimal) = read_decimal(csg.low_price_decimal_decoder())?;
+
+    let (csg, close_decimal) = read_decimal(csg.close_price_decimal_decoder())?;
+
+    let (csg, volume_decimal) = read_decimal(csg.volume_decimal_decoder())?;
+
+    let (csg, vwap_decimal) = read_decimal_null(csg.vwap_decimal_decoder())?;
+
+    let (csg, buy_volume_decimal) = read_decimal_null(csg.buy_volume_decimal_decoder())?;
+
+    let (_, sell_volume_decimal) = read_decimal_null(csg.sell_volume_decimal_decoder())?;
+
+    // Messages before schema version 3 have no decimals and keep their f32 values
+    let exact = open_decimal.is_some();
+    let open = match open_decimal {
+        Some(open) => open,
+        None => decode_float(sbe_open_price, "open price")?,
+    };
+    let high = match high_decimal {
+        Some(high) => high,
+        None => decode_float(sbe_high_price, "high price")?,
+    };
+    let low = match low_decimal {
+        Some(low) => low,
+        None => decode_float(sbe_low_price, "low price")?,
+    };
+    let close = match close_decimal {
+        Some(close) => close,
+        None => decode_float(sbe_close_price, "close price")?,
+    };
+    let volume = match volume_decimal {
+        Some(volume) => volume,
+        None => decode_float(sbe_volume, "volume")?,
+    };
+    let (vwap, buy_volume, sell_volume) = if exact {
+        (vwap_decimal, buy_volume_decimal, sell_volume_decimal)
+    } else {
+        (
+            sbe_vwap.and_then(Decimal::from_f32),
+            sbe_buy_volume.and_then(Decimal::from_f32),
+            sbe_sell_volume.and_then(Decimal::from_f32),
+        )
+    };
+
+    let mut data_bar = OHLCVBar::new(symbol_id, date_time, open, high, low, close, volume)
+        .with_sequence_number(sequence_number);
+
+    if let Some(vwap) = vwap {
+        data_bar = data_bar.with_vwap(vwap);
+    }
+
+    if let Some(trade_count) = trade_count {
+        data_bar = data_bar.with_trade_count(trade_count as u64);
+    }
+
+    if let (Some(buy_volume), Some(sell_volume)) = (buy_volume, sell_volume) {
+        data_bar = data_bar.with_buy_sell_volume(buy_volume, sell_volume);
+    }
 
     Ok(data_bar)
 }
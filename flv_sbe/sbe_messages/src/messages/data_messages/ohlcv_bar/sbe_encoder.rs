@@ -1,3 +1,4 @@
+use crate::decimal::encode_decimal;
 use crate::prelude::SbeEncodeError;
 use common::prelude::OHLCVBar;
 use rust_decimal::prelude::ToPrimitive;
@@ -22,7 +23,7 @@ use sbe_bindings::{
 ///
 /// # Process
 ///
-/// - Create 40 byte buffer
+/// - Create 136 byte buffer
 /// - Create default DataBarEncoder
 /// - Wrap buffer in WriteBuf
 /// - Encode header
@@ -34,11 +35,17 @@ use sbe_bindings::{
 /// - Encode and convert low_price to f32
 /// - Encode and convert close_price to f32
 /// - Encode and convert volume to f32
+/// - Encode sequence_number
+/// - Encode and convert vwap to f32 or null value if not set
+/// - Encode trade_count or null value if not set
+/// - Encode and convert buy_volume and sell_volume to f32 or null value if not set
+/// - Encode prices and volume as exact decimals
+/// - Encode vwap, buy_volume, and sell_volume as exact decimals or null value if not set
 /// - Return encoded size and buffer
 ///
 pub fn encode_data_bar_message(bar: OHLCVBar) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-    // precise buffer size is 40 bytes for the entire message.
-    let mut buffer = vec![0u8; 40];
+    // precise buffer size is 136 bytes for the entire message.
+    let mut buffer = vec![0u8; 136];
 
     let mut csg = DataBarEncoder::default();
 
@@ -88,6 +95,97 @@ pub fn encode_data_bar_message(bar: OHLCVBar) -> Result<(usize, Vec<u8>), SbeEnc
         .expect("Failed to convert volume to u64");
     csg.volume(volume);
 
+    let sequence_number = bar.sequence_number();
+    csg.sequence_number(sequence_number);
+
+    // NaN is the SBE null value of an optional float field.
+    let vwap = bar.vwap().map_or(f32::NAN, |v| {
+        v.to_f32().expect("Failed to convert vwap to f32")
+    });
+    csg.vwap(vwap);
+
+    // u32::MAX is the SBE null value of an optional uInt32NULL field.
+    let trade_count = bar
+        .trade_count()
+        .map_or(u32::MAX, |c| c.min(u32::MAX as u64 - 1) as u32);
+    csg.trade_count(trade_count);
+
+    let buy_volume = bar.buy_volume().map_or(f32::NAN, |v| {
+        v.to_f32().expect("Failed to convert buy volume to f32")
+    });
+    csg.buy_volume(buy_volume);
+
+    let sell_volume = bar.sell_volume().map_or(f32::NAN, |v| {
+        v.to_f32().expect("Failed to convert sell volume to f32")
+    });
+    csg.sell_volume(sell_volume);
+
+    // The exact decimals follow the f32 values, which remain for schema version 2 decoders.
+    let (mantissa, exponent) = encode_decimal(bar.open());
+    let mut open_price_decimal = csg.open_price_decimal_encoder();
+    open_price_decimal.mantissa(mantissa);
+    open_price_decimal.exponent(exponent);
+    csg = open_price_decimal
+        .parent()
+        .expect("Failed to encode open price decimal");
+
+    let (mantissa, exponent) = encode_decimal(bar.high());
+    let mut high_price_decimal = csg.high_price_decimal_encoder();
+    high_price_decimal.mantissa(mantissa);
+    high_price_decimal.exponent(exponent);
+    csg = high_price_decimal
+        .parent()
+        .expect("Failed to encode high price decimal");
+
+    let (mantissa, exponent) = encode_decimal(bar.low());
+    let mut low_price_decimal = csg.low_price_decimal_encoder();
+    low_price_decimal.mantissa(mantissa);
+    low_price_decimal.exponent(exponent);
+    csg = low_price_decimal
+        .parent()
+        .expect("Failed to encode low price decimal");
+
+    let (mantissa, exponent) = encode_decimal(bar.close());
+    let mut close_price_decimal = csg.close_price_decimal_encoder();
+    close_price_decimal.mantissa(mantissa);
+    close_price_decimal.exponent(exponent);
+    csg = close_price_decimal
+        .parent()
+        .expect("Failed to encode close price decimal");
+
+    let (mantissa, exponent) = encode_decimal(bar.volume());
+    let mut volume_decimal = csg.volume_decimal_encoder();
+    volume_decimal.mantissa(mantissa);
+    volume_decimal.exponent(exponent);
+    csg = volume_decimal
+        .parent()
+        .expect("Failed to encode volume decimal");
+
+    // i64::MIN is the null value of the mantissa of an optional decimal.
+    let (mantissa, exponent) = bar.vwap().map_or((i64::MIN, 0), encode_decimal);
+    let mut vwap_decimal = csg.vwap_decimal_encoder();
+    vwap_decimal.mantissa(mantissa);
+    vwap_decimal.exponent(exponent);
+    csg = vwap_decimal
+        .parent()
+        .expect("Failed to encode vwap decimal");
+
+    let (mantissa, exponent) = bar.buy_volume().map_or((i64::MIN, 0), encode_decimal);
+    let mut buy_volume_decimal = csg.buy_volume_decimal_encoder();
+    buy_volume_decimal.mantissa(mantissa);
+    buy_volume_decimal.exponent(exponent);
+    csg = buy_volume_decimal
+        .parent()
+        .expect("Failed to encode buy volume decimal");
+
+    let (mantissa, exponent) = bar.sell_volume().map_or((i64::MIN, 0), encode_decimal);
+    let mut sell_volume_decimal = csg.sell_volume_decimal_encoder();
+    sell_volume_decimal.mantissa(mantissa);
+    sell_volume_decimal.exponent(exponent);
+    csg = sell_volume_decimal
+        .parent()
+        .expect("Failed to encode sell volume decimal");
+
     let limit = csg.get_limit();
     Ok((limit, buffer))
 }
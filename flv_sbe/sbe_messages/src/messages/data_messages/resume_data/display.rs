@@ -0,0 +1,11 @@
+use crate::prelude::ResumeDataMessage;
+use std::fmt;
+
+impl fmt::Display for ResumeDataMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+            "ResumeDataMessage[message_type: {}, client_id: {}, exchange_id: {}, symbol_id: {} time_resolution: {} data_type: {} start_time: {:?} end_time: {:?} replay_mode: {} replay_speed: {} resume_sequence_number: {:?} resume_time: {:?} threshold: {}]",
+            self.message_type, self.client_id, self.exchange_id, self.symbol_id, self.time_resolution, self.data_type_id, self.start_time, self.end_time, self.replay_mode, self.replay_speed, self.resume_sequence_number, self.resume_time, self.threshold
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use crate::prelude::{DataType, MessageType, ReplayMode, ResumeDataMessage};
+use chrono::{DateTime, Utc};
+use common::prelude::{ExchangeID, TimeResolution};
+
+impl ResumeDataMessage {
+    pub fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    pub fn client_id(&self) -> &u16 {
+        &self.client_id
+    }
+    pub fn exchange_id(&self) -> &ExchangeID {
+        &self.exchange_id
+    }
+    pub fn symbol_id(&self) -> &u16 {
+        &self.symbol_id
+    }
+    pub fn data_type_id(&self) -> &DataType {
+        &self.data_type_id
+    }
+    pub fn time_resolution(&self) -> &TimeResolution {
+        &self.time_resolution
+    }
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        self.start_time
+    }
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        self.end_time
+    }
+    pub fn replay_mode(&self) -> &ReplayMode {
+        &self.replay_mode
+    }
+    pub fn replay_speed(&self) -> &u16 {
+        &self.replay_speed
+    }
+    pub fn resume_sequence_number(&self) -> Option<u64> {
+        self.resume_sequence_number
+    }
+    pub fn resume_time(&self) -> Option<DateTime<Utc>> {
+        self.resume_time
+    }
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
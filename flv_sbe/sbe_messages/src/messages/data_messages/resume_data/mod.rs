@@ -0,0 +1,134 @@
+use crate::prelude::{DataType, MessageType, ReplayMode, SbeDecodeError};
+use chrono::{DateTime, Utc};
+use common::prelude::{ExchangeID, TimeResolution};
+use serde::{Deserialize, Serialize};
+
+mod display;
+mod getter;
+mod sbe_decode;
+mod sbe_encode;
+
+/// Requests to restart a data stream from a sequence number or a timestamp.
+///
+/// Carries the parameters of the original StartDataMessage, so that the gateway
+/// can rebuild the stream and number its bars the same way as the original stream.
+/// If both resume fields are set, the sequence number takes precedence.
+/// If none is set, the stream restarts from the first bar.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ResumeDataMessage {
+    message_type: MessageType,
+    client_id: u16,
+    exchange_id: ExchangeID,
+    symbol_id: u16,
+    time_resolution: TimeResolution,
+    data_type_id: DataType,
+    start_time: Option<DateTime<Utc>>,
+    end_time: Option<DateTime<Utc>>,
+    replay_mode: ReplayMode,
+    replay_speed: u16,
+    resume_sequence_number: Option<u64>,
+    resume_time: Option<DateTime<Utc>>,
+    threshold: f64,
+}
+
+impl ResumeDataMessage {
+    /// Creates a new ResumeDataMessage instance.
+    ///
+    /// Sets the message_type to ResumeData.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `exchange_id` - ExchangeID exchange ID
+    /// * `symbol_id` - u16 symbol ID
+    /// * `time_resolution` - TimeResolution time resolution of the original request
+    /// * `data_type_id` - DataType data type ID of the original request
+    /// * `start_time` - Optional inclusive start of the time range of the original request
+    /// * `end_time` - Optional exclusive end of the time range of the original request
+    /// * `replay_mode` - ReplayMode pacing of the resumed data stream
+    /// * `replay_speed` - u16 speed multiplier; only applies to `ReplayMode::Scaled`
+    /// * `resume_sequence_number` - Optional sequence number of the first bar to resend
+    /// * `resume_time` - Optional timestamp of the first bar to resend
+    ///
+    /// # Returns
+    ///
+    /// ResumeDataMessage instance
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client_id: u16,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        time_resolution: TimeResolution,
+        data_type_id: DataType,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
+        resume_sequence_number: Option<u64>,
+        resume_time: Option<DateTime<Utc>>,
+    ) -> Self {
+        let message_type = MessageType::ResumeData;
+
+        Self {
+            message_type,
+            client_id,
+            exchange_id,
+            symbol_id,
+            time_resolution,
+            data_type_id,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
+            resume_sequence_number,
+            resume_time,
+            threshold: 0.0,
+        }
+    }
+
+    /// Sets the bar threshold of an information bar data type.
+    ///
+    /// The threshold is the number of ticks, the traded volume, or the traded
+    /// dollar value at which a tick, volume, or dollar bar closes.
+    /// Zero means no threshold and is the default for all other data types.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - f64 bar threshold
+    ///
+    /// # Returns
+    ///
+    /// The message with the threshold set
+    ///
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl TryFrom<&[u8]> for ResumeDataMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the TryFrom trait to decode a ResumeDataMessage from a byte slice.
+    ///
+    /// Calls the sbe_decode::decode_resume_data_message function to decode the message.
+    /// The timestamps arrive from the network and may be out of range,
+    /// which is why decoding fails with an error instead of a panic.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded ResumeDataMessage
+    ///
+    /// # Errors
+    ///
+    /// Returns an SbeDecodeError if the frame is invalid or a timestamp is out of range.
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_resume_data_message(value)
+    }
+}
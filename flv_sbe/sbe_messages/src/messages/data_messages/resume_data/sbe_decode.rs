@@ -0,0 +1,112 @@
+use crate::messages::data_messages::decode_timestamp;
+use crate::prelude::{
+    validate_frame, DataType, MessageType, ReplayMode, ResumeDataMessage, SbeDecodeError,
+};
+use common::prelude::{ExchangeID, TimeResolution, TimeScale};
+use sbe_bindings::{MessageHeaderDecoder, ReadBuf, ResumeDataMsgDecoder};
+
+/// Decodes a ResumeDataMessage from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded ResumeDataMessage
+///
+/// # Errors
+///
+/// Returns an SbeDecodeError if:
+///
+/// - The frame of the message is invalid, see `validate_frame`
+/// - The message is not a ResumeData message (InvalidMessageType)
+/// - The start time, end time, or resume time is out of range (DecodeError)
+///
+/// # Process
+///
+/// - Validate the frame of the message
+/// - Create default ResumeDataMsgDecoder
+/// - Wrap buffer in ReadBuf
+/// - Decode header
+/// - Decode client_id
+/// - Decode and create exchange_id
+/// - Decode symbol_id
+/// - Decode and create data_type_id
+/// - Decode and create time_resolution from count and time scale
+/// - Decode optional start_time and end_time as timestamps and create DateTime
+/// - Decode and create replay_mode
+/// - Decode replay_speed
+/// - Decode optional resume_sequence_number
+/// - Decode optional resume_time as timestamp and create DateTime
+/// - Decode threshold
+/// - Create and return ResumeDataMessage
+///
+pub fn decode_resume_data_message(buffer: &[u8]) -> Result<ResumeDataMessage, SbeDecodeError> {
+    // The frame validation checks the header, the block length of the schema version,
+    // and that the buffer holds the block, so the decoder only reads inside the buffer.
+    let message_type = validate_frame(buffer)?;
+
+    if message_type != MessageType::ResumeData {
+        return Err(SbeDecodeError::InvalidMessageType {
+            expected: MessageType::ResumeData as u16,
+            actual: message_type as u16,
+        });
+    }
+
+    let mut csg = ResumeDataMsgDecoder::default();
+    let buf = ReadBuf::new(buffer);
+
+    let header = MessageHeaderDecoder::default().wrap(buf, 0);
+    csg = csg.header(header);
+
+    let client_id = csg.client_id();
+
+    let exchange_id = ExchangeID::from(csg.exchange_id());
+
+    let symbol_id = csg.symbol_id();
+
+    let data_type_id = DataType::from(csg.data_type_id());
+
+    let mut time_resolution_decoder = csg.time_resolution_decoder();
+    let time_resolution = TimeResolution::from_parts(
+        time_resolution_decoder.count(),
+        TimeScale::from(time_resolution_decoder.time_scale() as u8),
+    );
+    csg = match time_resolution_decoder.parent() {
+        Ok(csg) => csg,
+        Err(e) => return Err(SbeDecodeError::DecodeError(e.to_string())),
+    };
+
+    let start_time = csg.start_time().map(decode_timestamp).transpose()?;
+
+    let end_time = csg.end_time().map(decode_timestamp).transpose()?;
+
+    let replay_mode = ReplayMode::from(csg.replay_mode());
+
+    let replay_speed = csg.replay_speed();
+
+    let resume_sequence_number = csg.resume_sequence_number();
+
+    let resume_time = csg.resume_time().map(decode_timestamp).transpose()?;
+
+    let threshold = csg.threshold();
+
+    let message = ResumeDataMessage {
+        message_type,
+        client_id,
+        exchange_id,
+        symbol_id,
+        time_resolution,
+        data_type_id,
+        start_time,
+        end_time,
+        replay_mode,
+        replay_speed,
+        resume_sequence_number,
+        resume_time,
+        threshold,
+    };
+
+    Ok(message)
+}
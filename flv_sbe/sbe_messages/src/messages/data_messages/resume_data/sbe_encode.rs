@@ -0,0 +1,103 @@
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::TimeScale as SbeTimeScale;
+use sbe_bindings::{message_header_codec, Encoder, ResumeDataMsgEncoder, WriteBuf};
+
+use crate::prelude::{ResumeDataMessage, SbeEncodeError};
+
+impl ResumeDataMessage {
+    /// Encodes a ResumeDataMessage to a byte buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - ResumeDataMessage to encode
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple containing encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    /// # Process
+    ///
+    /// - Create 64 byte buffer
+    /// - Create default ResumeDataMsgEncoder
+    /// - Wrap buffer in WriteBuf
+    /// - Encode header
+    /// - Encode message_type
+    /// - Encode client_id
+    /// - Encode exchange_id
+    /// - Encode symbol_id
+    /// - Encode time_resolution as count and time scale
+    /// - Encode data_type_id
+    /// - Encode start_time and end_time as timestamps or null value if not set
+    /// - Encode replay_mode
+    /// - Encode replay_speed
+    /// - Encode resume_sequence_number or null value if not set
+    /// - Encode resume_time as timestamp or null value if not set
+    /// - Encode threshold
+    /// - Return encoded size and buffer
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        // precise buffer size is 64 bytes for the entire message.
+        let mut buffer = vec![0u8; 64];
+
+        let mut csg = ResumeDataMsgEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg.header(0).parent().expect("Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.client_id;
+        csg.client_id(value);
+
+        let value = self.exchange_id as u8;
+        csg.exchange_id(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let mut time_resolution = csg.time_resolution_encoder();
+        time_resolution.count(self.time_resolution.count());
+        time_resolution.time_scale(SbeTimeScale::from(self.time_resolution.scale() as u8));
+        csg = time_resolution
+            .parent()
+            .expect("Failed to encode time resolution");
+
+        let value = self.data_type_id as u8;
+        csg.data_type_id(value);
+
+        // i64::MAX is the SBE null value of an optional Int64NULL field.
+        let value = self.start_time.map_or(i64::MAX, |t| t.timestamp_micros());
+        csg.start_time(value);
+
+        let value = self.end_time.map_or(i64::MAX, |t| t.timestamp_micros());
+        csg.end_time(value);
+
+        let value = self.replay_mode as u8;
+        csg.replay_mode(value);
+
+        let value = self.replay_speed;
+        csg.replay_speed(value);
+
+        // u64::MAX is the SBE null value of an optional uInt64NULL field.
+        let value = self.resume_sequence_number.unwrap_or(u64::MAX);
+        csg.resume_sequence_number(value);
+
+        let value = self.resume_time.map_or(i64::MAX, |t| t.timestamp_micros());
+        csg.resume_time(value);
+
+        let value = self.threshold;
+        csg.threshold(value);
+
+        let limit = csg.get_limit();
+        Ok((limit, buffer))
+    }
+}
@@ -4,8 +4,8 @@ use std::fmt;
 impl fmt::Display for StartDataMessage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f,
-            "StartDataMessage[message_type: {}, client_id: {}, exchange_id: {}, symbol_id: {} time_resolution: {} data_type: {}]",
-            self.message_type, self.client_id, self.exchange_id, self.symbol_id, self.time_resolution, self.data_type_id
+            "StartDataMessage[message_type: {}, client_id: {}, exchange_id: {}, symbol_id: {} time_resolution: {} data_type: {} start_time: {:?} end_time: {:?} replay_mode: {} replay_speed: {} threshold: {}]",
+            self.message_type, self.client_id, self.exchange_id, self.symbol_id, self.time_resolution, self.data_type_id, self.start_time, self.end_time, self.replay_mode, self.replay_speed, self.threshold
         )
     }
 }
@@ -1,4 +1,5 @@
-use crate::prelude::{DataType, MessageType, StartDataMessage};
+use crate::prelude::{DataType, MessageType, ReplayMode, StartDataMessage};
+use chrono::{DateTime, Utc};
 use common::prelude::{ExchangeID, TimeResolution};
 
 impl StartDataMessage {
@@ -20,4 +21,19 @@ impl StartDataMessage {
     pub fn time_resolution(&self) -> &TimeResolution {
         &self.time_resolution
     }
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        self.start_time
+    }
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        self.end_time
+    }
+    pub fn replay_mode(&self) -> &ReplayMode {
+        &self.replay_mode
+    }
+    pub fn replay_speed(&self) -> &u16 {
+        &self.replay_speed
+    }
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
 }
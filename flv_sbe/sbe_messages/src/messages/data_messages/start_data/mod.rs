@@ -1,5 +1,8 @@
-use crate::prelude::{DataType, MessageType};
-use common::prelude::{ExchangeID, TimeResolution};
+use crate::prelude::{DataType, MessageType, ReplayMode, SbeDecodeError};
+use chrono::{DateTime, Utc};
+use common::prelude::{BarThreshold, ExchangeID, TimeResolution};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 mod display;
@@ -15,6 +18,11 @@ pub struct StartDataMessage {
     symbol_id: u16,
     time_resolution: TimeResolution,
     data_type_id: DataType,
+    start_time: Option<DateTime<Utc>>,
+    end_time: Option<DateTime<Utc>>,
+    replay_mode: ReplayMode,
+    replay_speed: u16,
+    threshold: f64,
 }
 
 impl StartDataMessage {
@@ -29,17 +37,26 @@ impl StartDataMessage {
     /// * `symbol_id` - u16 symbol ID
     /// * `time_resolution` - TimeResolution time resolution
     /// * `data_type_id` - DataType data type ID
+    /// * `start_time` - Optional inclusive start of the requested time range
+    /// * `end_time` - Optional exclusive end of the requested time range
+    /// * `replay_mode` - ReplayMode pacing of the data stream
+    /// * `replay_speed` - u16 speed multiplier; only applies to `ReplayMode::Scaled`
     ///
     /// # Returns
     ///
     /// StartDataMessage instance
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         client_id: u16,
         exchange_id: ExchangeID,
         symbol_id: u16,
         time_resolution: TimeResolution,
         data_type_id: DataType,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
     ) -> Self {
         let message_type = MessageType::StartData;
 
@@ -50,14 +67,69 @@ impl StartDataMessage {
             symbol_id,
             time_resolution,
             data_type_id,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
+            threshold: 0.0,
+        }
+    }
+
+    /// Sets the bar threshold of an information bar data type.
+    ///
+    /// The threshold is the number of ticks, the traded volume, or the traded
+    /// dollar value at which a tick, volume, or dollar bar closes.
+    /// Zero means no threshold and is the default for all other data types.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - f64 bar threshold
+    ///
+    /// # Returns
+    ///
+    /// The message with the threshold set
+    ///
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the bar threshold of an information bar data type.
+    ///
+    /// # Returns
+    ///
+    /// The threshold as `BarThreshold` if the data type is a tick, volume, or dollar bar
+    /// and the threshold can be represented as such. A tick threshold must be a whole number.
+    /// Returns `None` for all other data types, or if the threshold cannot be represented.
+    /// The returned threshold is not validated.
+    ///
+    pub fn bar_threshold(&self) -> Option<BarThreshold> {
+        if !self.threshold.is_finite() {
+            return None;
+        }
+
+        match self.data_type_id {
+            DataType::TickBarData => {
+                if self.threshold < 0.0 || self.threshold.fract() != 0.0 {
+                    return None;
+                }
+                Some(BarThreshold::Ticks(self.threshold as u64))
+            }
+            DataType::VolumeBarData => Decimal::from_f64(self.threshold).map(BarThreshold::Volume),
+            DataType::DollarBarData => Decimal::from_f64(self.threshold).map(BarThreshold::Dollar),
+            _ => None,
         }
     }
 }
 
-impl From<&[u8]> for StartDataMessage {
-    /// Implements the From trait to decode a StartDataMessage from a byte slice.
+impl TryFrom<&[u8]> for StartDataMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the TryFrom trait to decode a StartDataMessage from a byte slice.
     ///
     /// Calls the sbe_decode::decode_start_data_message function to decode the message.
+    /// The start and end time arrive from the network and may be out of range,
+    /// which is why decoding fails with an error instead of a panic.
     ///
     /// # Arguments
     ///
@@ -69,9 +141,9 @@ impl From<&[u8]> for StartDataMessage {
     ///
     /// # Errors
     ///
-    /// Panics if decode fails
+    /// Returns an SbeDecodeError if the frame is invalid or a timestamp is out of range.
     #[inline]
-    fn from(value: &[u8]) -> Self {
-        sbe_decode::decode_start_data_message(value).expect("Failed to decode start data message")
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_start_data_message(value)
     }
 }
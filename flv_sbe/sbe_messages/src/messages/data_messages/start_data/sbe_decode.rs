@@ -1,8 +1,12 @@
-use crate::prelude::{DataType, MessageType, StartDataMessage};
-use common::prelude::{ExchangeID, TimeResolution};
-use sbe_bindings::{MessageHeaderDecoder, ReadBuf, SbeResult, StartDataMsgDecoder};
+use crate::messages::data_messages::decode_timestamp;
+use crate::prelude::{
+    validate_frame, DataType, MessageType, ReplayMode, SbeDecodeError, StartDataMessage,
+};
+use common::prelude::{ExchangeID, TimeResolution, TimeScale};
+use sbe_bindings::{MessageHeaderDecoder, ReadBuf, StartDataMsgDecoder};
 
-use sbe_bindings::start_data_msg_codec::SBE_TEMPLATE_ID;
+/// The schema version that added the time range, replay mode and speed, and threshold.
+const TIME_RANGE_SINCE_VERSION: u16 = 2;
 
 /// Decodes a StartDataMessage from a byte buffer.
 ///
@@ -16,33 +20,48 @@ use sbe_bindings::start_data_msg_codec::SBE_TEMPLATE_ID;
 ///
 /// # Errors
 ///
-/// Returns Err if decode fails
+/// Returns an SbeDecodeError if:
+///
+/// - The frame of the message is invalid, see `validate_frame`
+/// - The message is not a StartData message (InvalidMessageType)
+/// - The start time or end time is out of range (DecodeError)
 ///
 /// # Process
 ///
+/// - Validate the frame of the message
 /// - Create default StartDataMsgDecoder
 /// - Wrap buffer in ReadBuf
-/// - Decode header and validate template ID
-/// - Decode and validate message_type
+/// - Decode header
 /// - Decode client_id
 /// - Decode and create exchange_id
 /// - Decode symbol_id
 /// - Decode and create data_type_id
-/// - Decode and create time_resolution
+/// - Decode and create time_resolution from count and time scale
+/// - Decode optional start_time and end_time as timestamps and create DateTime
+/// - Decode and create replay_mode
+/// - Decode replay_speed
+/// - Decode threshold; messages before schema version 2 have no time range,
+///   replay mode and speed, and threshold, so these are unset
 /// - Create and return StartDataMessage
 ///
-pub fn decode_start_data_message(buffer: &[u8]) -> SbeResult<StartDataMessage> {
+pub fn decode_start_data_message(buffer: &[u8]) -> Result<StartDataMessage, SbeDecodeError> {
+    // The frame validation checks the header, the block length of the schema version,
+    // and that the buffer holds the block, so the decoder only reads inside the buffer.
+    let message_type = validate_frame(buffer)?;
+
+    if message_type != MessageType::StartData {
+        return Err(SbeDecodeError::InvalidMessageType {
+            expected: MessageType::StartData as u16,
+            actual: message_type as u16,
+        });
+    }
+
     let mut csg = StartDataMsgDecoder::default();
     let buf = ReadBuf::new(buffer);
 
     let header = MessageHeaderDecoder::default().wrap(buf, 0);
-    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
     csg = csg.header(header);
 
-    let sbe_message_type = csg.message_type();
-    let message_type = MessageType::from(sbe_message_type as u16);
-    assert_eq!(message_type, MessageType::StartData);
-
     let client_id = csg.client_id();
 
     let exchange_id = ExchangeID::from(csg.exchange_id());
@@ -51,15 +70,45 @@ pub fn decode_start_data_message(buffer: &[u8]) -> SbeResult<StartDataMessage> {
 
     let data_type_id = DataType::from(csg.data_type_id());
 
-    let time_resolution = TimeResolution::from(csg.time_resolution());
+    let mut time_resolution_decoder = csg.time_resolution_decoder();
+    let time_resolution = TimeResolution::from_parts(
+        time_resolution_decoder.count(),
+        TimeScale::from(time_resolution_decoder.time_scale() as u8),
+    );
+    csg = match time_resolution_decoder.parent() {
+        Ok(csg) => csg,
+        Err(e) => return Err(SbeDecodeError::DecodeError(e.to_string())),
+    };
+
+    let start_time = csg.start_time().map(decode_timestamp).transpose()?;
+
+    let end_time = csg.end_time().map(decode_timestamp).transpose()?;
 
-    let message = StartDataMessage {
-        message_type,
-        client_id,
-        exchange_id,
-        symbol_id,
-        time_resolution,
-        data_type_id,
+    let message = if csg.acting_version < TIME_RANGE_SINCE_VERSION {
+        StartDataMessage::new(
+            client_id,
+            exchange_id,
+            symbol_id,
+            time_resolution,
+            data_type_id,
+            None,
+            None,
+            ReplayMode::Unthrottled,
+            0,
+        )
+    } else {
+        StartDataMessage::new(
+            client_id,
+            exchange_id,
+            symbol_id,
+            time_resolution,
+            data_type_id,
+            start_time,
+            end_time,
+            ReplayMode::from(csg.replay_mode()),
+            csg.replay_speed(),
+        )
+        .with_threshold(csg.threshold())
     };
 
     Ok(message)
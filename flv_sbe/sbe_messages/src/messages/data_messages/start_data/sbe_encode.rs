@@ -1,4 +1,5 @@
 use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::TimeScale as SbeTimeScale;
 use sbe_bindings::{message_header_codec, Encoder, StartDataMsgEncoder, WriteBuf};
 
 use crate::prelude::{SbeEncodeError, StartDataMessage};
@@ -20,7 +21,7 @@ impl StartDataMessage {
     ///
     /// # Process
     ///
-    /// - Create 17 byte buffer
+    /// - Create 48 byte buffer
     /// - Create default StartDataMsgEncoder
     /// - Wrap buffer in WriteBuf
     /// - Encode header
@@ -28,13 +29,17 @@ impl StartDataMessage {
     /// - Encode client_id
     /// - Encode exchange_id
     /// - Encode symbol_id
-    /// - Encode time_resolution
+    /// - Encode time_resolution as count and time scale
     /// - Encode data_type_id
+    /// - Encode start_time and end_time as timestamps or null value if not set
+    /// - Encode replay_mode
+    /// - Encode replay_speed
+    /// - Encode threshold
     /// - Return encoded size and buffer
     ///
     pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-        // precise buffer size is 17 bytes for the entire message.
-        let mut buffer = vec![0u8; 17];
+        // precise buffer size is 48 bytes for the entire message.
+        let mut buffer = vec![0u8; 48];
 
         let mut csg = StartDataMsgEncoder::default();
 
@@ -57,12 +62,32 @@ impl StartDataMessage {
         let value = self.symbol_id;
         csg.symbol_id(value);
 
-        let value = self.time_resolution as u8;
-        csg.time_resolution(value);
+        let mut time_resolution = csg.time_resolution_encoder();
+        time_resolution.count(self.time_resolution.count());
+        time_resolution.time_scale(SbeTimeScale::from(self.time_resolution.scale() as u8));
+        csg = time_resolution
+            .parent()
+            .expect("Failed to encode time resolution");
 
         let value = self.data_type_id as u8;
         csg.data_type_id(value);
 
+        // i64::MAX is the SBE null value of an optional Int64NULL field.
+        let value = self.start_time.map_or(i64::MAX, |t| t.timestamp_micros());
+        csg.start_time(value);
+
+        let value = self.end_time.map_or(i64::MAX, |t| t.timestamp_micros());
+        csg.end_time(value);
+
+        let value = self.replay_mode as u8;
+        csg.replay_mode(value);
+
+        let value = self.replay_speed;
+        csg.replay_speed(value);
+
+        let value = self.threshold;
+        csg.threshold(value);
+
         let limit = csg.get_limit();
         Ok((limit, buffer))
     }
@@ -0,0 +1,11 @@
+use crate::prelude::StartMultiDataMessage;
+use std::fmt;
+
+impl fmt::Display for StartMultiDataMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+            "StartMultiDataMessage[message_type: {}, client_id: {}, exchange_id: {}, symbol_ids: {:?} time_resolution: {} data_type: {} start_time: {:?} end_time: {:?} replay_mode: {} replay_speed: {}]",
+            self.message_type, self.client_id, self.exchange_id, self.symbol_ids, self.time_resolution, self.data_type_id, self.start_time, self.end_time, self.replay_mode, self.replay_speed
+        )
+    }
+}
@@ -0,0 +1,99 @@
+use crate::prelude::{DataType, MessageType, ReplayMode, SbeDecodeError};
+use chrono::{DateTime, Utc};
+use common::prelude::{ExchangeID, TimeResolution};
+use serde::{Deserialize, Serialize};
+
+mod display;
+mod getter;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct StartMultiDataMessage {
+    message_type: MessageType,
+    client_id: u16,
+    exchange_id: ExchangeID,
+    time_resolution: TimeResolution,
+    data_type_id: DataType,
+    start_time: Option<DateTime<Utc>>,
+    end_time: Option<DateTime<Utc>>,
+    replay_mode: ReplayMode,
+    replay_speed: u16,
+    symbol_ids: Vec<u16>,
+}
+
+impl StartMultiDataMessage {
+    /// Creates a new StartMultiDataMessage instance.
+    ///
+    /// Sets the message_type to StartMultiData.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `exchange_id` - ExchangeID exchange ID
+    /// * `symbol_ids` - `Vec<u16>` symbol IDs to stream as one merged data stream
+    /// * `time_resolution` - TimeResolution time resolution
+    /// * `data_type_id` - DataType data type ID
+    /// * `start_time` - Optional inclusive start of the requested time range
+    /// * `end_time` - Optional exclusive end of the requested time range
+    /// * `replay_mode` - ReplayMode pacing of the data stream
+    /// * `replay_speed` - u16 speed multiplier; only applies to `ReplayMode::Scaled`
+    ///
+    /// # Returns
+    ///
+    /// StartMultiDataMessage instance
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client_id: u16,
+        exchange_id: ExchangeID,
+        symbol_ids: Vec<u16>,
+        time_resolution: TimeResolution,
+        data_type_id: DataType,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        replay_mode: ReplayMode,
+        replay_speed: u16,
+    ) -> Self {
+        let message_type = MessageType::StartMultiData;
+
+        Self {
+            message_type,
+            client_id,
+            exchange_id,
+            time_resolution,
+            data_type_id,
+            start_time,
+            end_time,
+            replay_mode,
+            replay_speed,
+            symbol_ids,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for StartMultiDataMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the TryFrom trait to decode a StartMultiDataMessage from a byte slice.
+    ///
+    /// Calls the sbe_decode::decode_start_multi_data_message function to decode the message.
+    /// Unlike fixed size messages, the length of the message depends on the number of symbols,
+    /// which is why decoding fails with an error instead of a panic.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded StartMultiDataMessage
+    ///
+    /// # Errors
+    ///
+    /// Returns an SbeDecodeError if the buffer does not hold the message with all its symbols.
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_start_multi_data_message(value)
+    }
+}
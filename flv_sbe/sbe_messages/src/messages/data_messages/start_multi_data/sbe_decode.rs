@@ -0,0 +1,148 @@
+use crate::messages::data_messages::decode_timestamp;
+use crate::prelude::{DataType, MessageType, ReplayMode, SbeDecodeError, StartMultiDataMessage};
+use common::prelude::{ExchangeID, TimeResolution, TimeScale};
+use sbe_bindings::start_multi_data_msg_codec::encoder::SymbolsEncoder;
+use sbe_bindings::start_multi_data_msg_codec::{SBE_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::{
+    message_header_codec, Decoder, MessageHeaderDecoder, ReadBuf, StartMultiDataMsgDecoder,
+    StartMultiDataMsgEncoder,
+};
+
+// Length of the group header with the block length and the number of entries.
+const GROUP_HEADER_LENGTH: usize = 4;
+
+/// Decodes a StartMultiDataMessage from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded StartMultiDataMessage
+///
+/// # Errors
+///
+/// Returns an SbeDecodeError if:
+///
+/// - The buffer is shorter than the message block, the group header, or the group entries (BufferTooShort)
+/// - The template id is not the StartMultiData template id (UnknownTemplateId)
+/// - The block length of the group entries is not the block length of a symbol entry (InvalidBlockLength)
+/// - The message type is not StartMultiData (InvalidMessageType)
+/// - The start time or end time is out of range (DecodeError)
+///
+/// # Process
+///
+/// - Check that the buffer holds the message header, message block, and group header
+/// - Decode header and validate template ID
+/// - Decode and validate message_type
+/// - Decode client_id
+/// - Decode and create exchange_id
+/// - Decode and create time_resolution from count and time scale
+/// - Decode and create data_type_id
+/// - Decode optional start_time and end_time as timestamps and create DateTime
+/// - Decode and create replay_mode
+/// - Decode replay_speed
+/// - Validate the group header and check that the buffer holds all group entries
+/// - Decode symbol_ids from the symbols group
+/// - Create and return StartMultiDataMessage
+///
+pub fn decode_start_multi_data_message(
+    buffer: &[u8],
+) -> Result<StartMultiDataMessage, SbeDecodeError> {
+    let group_offset = message_header_codec::ENCODED_LENGTH + SBE_BLOCK_LENGTH as usize;
+    let group_entries_offset = group_offset + GROUP_HEADER_LENGTH;
+    if buffer.len() < group_entries_offset {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: group_entries_offset,
+            actual: buffer.len(),
+        });
+    }
+
+    let mut csg = StartMultiDataMsgDecoder::default();
+    let buf = ReadBuf::new(buffer);
+
+    let header = MessageHeaderDecoder::default().wrap(buf, 0);
+    let template_id = header.template_id();
+    if template_id != SBE_TEMPLATE_ID {
+        return Err(SbeDecodeError::UnknownTemplateId(template_id));
+    }
+    csg = csg.header(header);
+
+    let sbe_message_type = csg.message_type();
+    let message_type = MessageType::from(sbe_message_type as u16);
+    if message_type != MessageType::StartMultiData {
+        return Err(SbeDecodeError::InvalidMessageType {
+            expected: MessageType::StartMultiData as u16,
+            actual: message_type as u16,
+        });
+    }
+
+    let client_id = csg.client_id();
+
+    let exchange_id = ExchangeID::from(csg.exchange_id());
+
+    let mut time_resolution_decoder = csg.time_resolution_decoder();
+    let time_resolution = TimeResolution::from_parts(
+        time_resolution_decoder.count(),
+        TimeScale::from(time_resolution_decoder.time_scale() as u8),
+    );
+    csg = match time_resolution_decoder.parent() {
+        Ok(csg) => csg,
+        Err(e) => return Err(SbeDecodeError::DecodeError(e.to_string())),
+    };
+
+    let data_type_id = DataType::from(csg.data_type_id());
+
+    let start_time = csg.start_time().map(decode_timestamp).transpose()?;
+
+    let end_time = csg.end_time().map(decode_timestamp).transpose()?;
+
+    let replay_mode = ReplayMode::from(csg.replay_mode());
+
+    let replay_speed = csg.replay_speed();
+
+    // The group decoder reads past the end of the buffer if the group header
+    // claims more entries than the buffer holds, so check the group first.
+    let group_block_length = u16::from_le_bytes([buffer[group_offset], buffer[group_offset + 1]]);
+    let expected_block_length = SymbolsEncoder::<StartMultiDataMsgEncoder>::block_length();
+    if group_block_length != expected_block_length {
+        return Err(SbeDecodeError::InvalidBlockLength {
+            expected: expected_block_length,
+            actual: group_block_length,
+        });
+    }
+
+    let mut symbols = csg.symbols_decoder();
+    let count = symbols.count() as usize;
+
+    let frame_length = group_entries_offset + count * group_block_length as usize;
+    if buffer.len() < frame_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: frame_length,
+            actual: buffer.len(),
+        });
+    }
+
+    let mut symbol_ids = Vec::with_capacity(count);
+    while let Ok(Some(_)) = symbols.advance() {
+        symbol_ids.push(symbols.symbol_id());
+    }
+
+    debug_assert_eq!(symbols.get_limit(), frame_length);
+
+    let message = StartMultiDataMessage {
+        message_type,
+        client_id,
+        exchange_id,
+        time_resolution,
+        data_type_id,
+        start_time,
+        end_time,
+        replay_mode,
+        replay_speed,
+        symbol_ids,
+    };
+
+    Ok(message)
+}
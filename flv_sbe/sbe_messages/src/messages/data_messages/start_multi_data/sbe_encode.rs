@@ -0,0 +1,113 @@
+use sbe_bindings::start_multi_data_msg_codec::encoder::SymbolsEncoder;
+use sbe_bindings::start_multi_data_msg_codec::SBE_BLOCK_LENGTH;
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::TimeScale as SbeTimeScale;
+use sbe_bindings::{message_header_codec, Encoder, StartMultiDataMsgEncoder, WriteBuf};
+
+use crate::prelude::{SbeEncodeError, StartMultiDataMessage};
+
+impl StartMultiDataMessage {
+    /// Encodes a StartMultiDataMessage to a byte buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - StartMultiDataMessage to encode
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple containing encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails or if the message holds more than `u16::MAX` symbols
+    ///
+    /// # Process
+    ///
+    /// - Create a buffer of 42 bytes plus 2 bytes per symbol
+    /// - Create default StartMultiDataMsgEncoder
+    /// - Wrap buffer in WriteBuf
+    /// - Encode header
+    /// - Encode message_type
+    /// - Encode client_id
+    /// - Encode exchange_id
+    /// - Encode time_resolution as count and time scale
+    /// - Encode data_type_id
+    /// - Encode start_time and end_time as timestamps or null value if not set
+    /// - Encode replay_mode
+    /// - Encode replay_speed
+    /// - Encode symbol_ids as symbols group
+    /// - Return encoded size and buffer
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let count = match u16::try_from(self.symbol_ids.len()) {
+            Ok(count) => count,
+            Err(_) => {
+                return Err(SbeEncodeError(format!(
+                    "Too many symbols: {}, max: {}",
+                    self.symbol_ids.len(),
+                    u16::MAX
+                )))
+            }
+        };
+
+        // Message header, message block, group header, and one entry per symbol.
+        let entry_length = SymbolsEncoder::<StartMultiDataMsgEncoder>::block_length() as usize;
+        let size = message_header_codec::ENCODED_LENGTH
+            + SBE_BLOCK_LENGTH as usize
+            + 4
+            + entry_length * self.symbol_ids.len();
+        let mut buffer = vec![0u8; size];
+
+        let mut csg = StartMultiDataMsgEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg.header(0).parent().expect("Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.client_id;
+        csg.client_id(value);
+
+        let value = self.exchange_id as u8;
+        csg.exchange_id(value);
+
+        let mut time_resolution = csg.time_resolution_encoder();
+        time_resolution.count(self.time_resolution.count());
+        time_resolution.time_scale(SbeTimeScale::from(self.time_resolution.scale() as u8));
+        csg = time_resolution
+            .parent()
+            .expect("Failed to encode time resolution");
+
+        let value = self.data_type_id as u8;
+        csg.data_type_id(value);
+
+        // i64::MAX is the SBE null value of an optional Int64NULL field.
+        let value = self.start_time.map_or(i64::MAX, |t| t.timestamp_micros());
+        csg.start_time(value);
+
+        let value = self.end_time.map_or(i64::MAX, |t| t.timestamp_micros());
+        csg.end_time(value);
+
+        let value = self.replay_mode as u8;
+        csg.replay_mode(value);
+
+        let value = self.replay_speed;
+        csg.replay_speed(value);
+
+        let mut symbols = csg.symbols_encoder(count, SymbolsEncoder::default());
+        for symbol_id in &self.symbol_ids {
+            match symbols.advance() {
+                Ok(Some(_)) => symbols.symbol_id(*symbol_id),
+                _ => return Err(SbeEncodeError("Failed to encode symbols".to_string())),
+            }
+        }
+
+        let limit = symbols.get_limit();
+        Ok((limit, buffer))
+    }
+}
@@ -1,9 +1,8 @@
+use crate::decimal::read_decimal;
 use crate::errors::SbeDecodeError;
+use crate::messages::data_messages::{decode_float, decode_timestamp};
 use crate::prelude::MessageType;
-use chrono::{DateTime, TimeZone, Utc};
 use common::prelude::TradeBar;
-use rust_decimal::prelude::FromPrimitive;
-use rust_decimal::Decimal;
 use sbe_bindings::trade_bar_codec::SBE_TEMPLATE_ID;
 use sbe_bindings::{MessageHeaderDecoder, ReadBuf, TradeBarDecoder};
 
@@ -19,7 +18,8 @@ use sbe_bindings::{MessageHeaderDecoder, ReadBuf, TradeBarDecoder};
 ///
 /// # Errors
 ///
-/// Returns Err if decoding fails
+/// Returns Err if decoding fails, the timestamp is out of range,
+/// or a message without exact decimals carries a price or volume that is NaN or infinite
 ///
 /// # Process
 ///
@@ -29,8 +29,11 @@ use sbe_bindings::{MessageHeaderDecoder, ReadBuf, TradeBarDecoder};
 /// - Decode and validate message_type
 /// - Decode symbol_id
 /// - Decode date_time as timestamp and create DateTime
-/// - Decode price as f32 and convert to Decimal
-/// - Decode volume as f32 and convert to Decimal
+/// - Decode price and volume as f32
+/// - Decode sequence_number
+/// - Decode the exact decimals of price and volume,
+///   which are absent in messages before schema version 3
+/// - Convert the f32 price and volume to Decimal only if their decimals are absent
 /// - Create and return TradeBar
 ///
 pub fn decode_trade_bar_message(buffer: &[u8]) -> Result<TradeBar, SbeDecodeError> {
@@ -48,15 +51,28 @@ pub fn decode_trade_bar_message(buffer: &[u8]) -> Result<TradeBar, SbeDecodeErro
     let symbol_id = csg.symbol_id();
 
     let sbe_date_time = csg.date_time();
-    let date_time: DateTime<Utc> = Utc.timestamp_micros(sbe_date_time).unwrap();
+    let date_time = decode_timestamp(sbe_date_time)?;
 
     let sbe_price = csg.price();
-    let price = Decimal::from_f32(sbe_price).expect("[FileManager]: Failed to parse open price");
 
     let sbe_volume = csg.volume();
-    let volume = Decimal::from_f32(sbe_volume).expect("[FileManager]: Failed to parse volume");
 
-    let trade_bar = TradeBar::new(symbol_id, date_time, price, volume);
+    let sequence_number = csg.sequence_number();
+
+    let (csg, price_decimal) = read_decimal(csg.price_decimal_decoder())?;
+    let price = match price_decimal {
+        Some(price) => price,
+        None => decode_float(sbe_price, "price")?,
+    };
+
+    let (_, volume_decimal) = read_decimal(csg.volume_decimal_decoder())?;
+    let volume = match volume_decimal {
+        Some(volume) => volume,
+        None => decode_float(sbe_volume, "volume")?,
+    };
+
+    let trade_bar =
+        TradeBar::new(symbol_id, date_time, price, volume).with_sequence_number(sequence_number);
 
     Ok(trade_bar)
 }
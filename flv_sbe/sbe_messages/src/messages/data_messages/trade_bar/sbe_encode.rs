@@ -1,10 +1,10 @@
+use crate::decimal::encode_decimal;
 use crate::prelude::SbeEncodeError;
 use common::prelude::TradeBar;
 use rust_decimal::prelude::ToPrimitive;
 
-use sbe_bindings::{
-    Encoder, MessageType as SbeMessageType, TradeBarEncoder, WriteBuf, ENCODED_LENGTH,
-};
+use sbe_bindings::message_header_codec::ENCODED_LENGTH;
+use sbe_bindings::{Encoder, MessageType as SbeMessageType, TradeBarEncoder, WriteBuf};
 
 /// Encodes a TradeBar message to a byte buffer.
 ///
@@ -22,7 +22,7 @@ use sbe_bindings::{
 ///
 /// # Process
 ///
-/// - Create 28 byte buffer
+/// - Create 54 byte buffer
 /// - Create default TradeBarEncoder
 /// - Wrap buffer in WriteBuf
 /// - Encode header
@@ -33,10 +33,13 @@ use sbe_bindings::{
 /// - Encode price
 /// - Convert volume to f32
 /// - Encode volume
+/// - Encode sequence_number
+/// - Encode price as exact decimal
+/// - Encode volume as exact decimal
 /// - Return encoded size and buffer
 ///
 pub fn encode_trade_bar_message(bar: TradeBar) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-    let mut buffer = vec![0u8; 28];
+    let mut buffer = vec![0u8; 54];
 
     let mut csg = TradeBarEncoder::default();
 
@@ -65,6 +68,26 @@ pub fn encode_trade_bar_message(bar: TradeBar) -> Result<(usize, Vec<u8>), SbeEn
         .expect("Failed to convert volume to u64");
     csg.volume(volume);
 
+    let sequence_number = bar.sequence_number();
+    csg.sequence_number(sequence_number);
+
+    // The exact decimals follow the f32 values, which remain for schema version 2 decoders.
+    let (mantissa, exponent) = encode_decimal(bar.price());
+    let mut price_decimal = csg.price_decimal_encoder();
+    price_decimal.mantissa(mantissa);
+    price_decimal.exponent(exponent);
+    csg = price_decimal
+        .parent()
+        .expect("Failed to encode price decimal");
+
+    let (mantissa, exponent) = encode_decimal(bar.volume());
+    let mut volume_decimal = csg.volume_decimal_encoder();
+    volume_decimal.mantissa(mantissa);
+    volume_decimal.exponent(exponent);
+    csg = volume_decimal
+        .parent()
+        .expect("Failed to encode volume decimal");
+
     let limit = csg.get_limit();
     Ok((limit, buffer))
 }
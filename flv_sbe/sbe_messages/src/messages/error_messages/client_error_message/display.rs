@@ -10,3 +10,6 @@ impl fmt::Display for ClientErrorMessage {
         )
     }
 }
+
+// The QD client returns a client error of the gateway as error of the rejected request.
+impl std::error::Error for ClientErrorMessage {}
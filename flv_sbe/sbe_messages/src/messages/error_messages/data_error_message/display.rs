@@ -5,8 +5,11 @@ impl Display for DataErrorMessage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "DataErrorMessage {{ message_type: {:?}, client_id: {}, data_error_type: {:?} }}",
-            self.message_type, self.client_id, self.data_error_type
+            "DataErrorMessage {{ message_type: {:?}, client_id: {}, data_error_type: {:?}, retry_after_millis: {:?} }}",
+            self.message_type, self.client_id, self.data_error_type, self.retry_after_millis
         )
     }
 }
+
+// The QD client returns a data error of the gateway as error of the rejected request.
+impl std::error::Error for DataErrorMessage {}
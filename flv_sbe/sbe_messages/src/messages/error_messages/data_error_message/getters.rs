@@ -11,4 +11,8 @@ impl DataErrorMessage {
     pub fn data_error_type(&self) -> DataErrorType {
         self.data_error_type
     }
+    /// Returns the time in milliseconds after which the client may retry, if the gateway sent one.
+    pub fn retry_after_millis(&self) -> Option<u32> {
+        self.retry_after_millis
+    }
 }
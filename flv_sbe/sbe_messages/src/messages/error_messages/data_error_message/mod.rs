@@ -11,6 +11,7 @@ pub struct DataErrorMessage {
     message_type: MessageType,
     client_id: u16,
     data_error_type: DataErrorType,
+    retry_after_millis: Option<u32>,
 }
 
 impl DataErrorMessage {
@@ -35,8 +36,18 @@ impl DataErrorMessage {
             message_type,
             client_id,
             data_error_type,
+            retry_after_millis: None,
         }
     }
+
+    /// Sets the time in milliseconds after which the client may retry the rejected request.
+    ///
+    /// Only a RateLimited error carries a retry-after hint.
+    ///
+    pub fn with_retry_after_millis(mut self, retry_after_millis: u32) -> Self {
+        self.retry_after_millis = Some(retry_after_millis);
+        self
+    }
 }
 
 impl From<&[u8]> for DataErrorMessage {
@@ -24,6 +24,7 @@ use sbe_bindings::{DataErrorDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
 /// - Decode and validate message_type
 /// - Decode client_id
 /// - Decode and validate data_error_type
+/// - Decode retry_after_millis; messages before schema version 6 have none
 /// - Create and return DataErrorMessage
 ///
 pub fn decode_client_error_message(buffer: &[u8]) -> SbeResult<DataErrorMessage> {
@@ -46,7 +47,11 @@ pub fn decode_client_error_message(buffer: &[u8]) -> SbeResult<DataErrorMessage>
 
     let data_error_type = DataErrorType::from(data_error_type_raw);
 
-    let message = DataErrorMessage::new(client_id, data_error_type);
+    let message = match csg.retry_after_millis() {
+        Some(retry_after_millis) => DataErrorMessage::new(client_id, data_error_type)
+            .with_retry_after_millis(retry_after_millis),
+        None => DataErrorMessage::new(client_id, data_error_type),
+    };
 
     Ok(message)
 }
@@ -20,17 +20,18 @@ impl DataErrorMessage {
     ///
     /// # Process
     ///
-    /// - Create 13 byte buffer
+    /// - Create 17 byte buffer
     /// - Create default DataErrorEncoder
     /// - Wrap buffer in WriteBuf
     /// - Encode header
     /// - Encode message_type
     /// - Encode client_id
     /// - Encode data_error_type
+    /// - Encode retry_after_millis, or the null value if there is no retry-after hint
     /// - Return encoded size and buffer
     ///
     pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-        let mut buffer = vec![0u8; 13];
+        let mut buffer = vec![0u8; 17];
 
         let mut csg = DataErrorEncoder::default();
 
@@ -50,6 +51,12 @@ impl DataErrorMessage {
         let value = self.data_error_type as u8;
         csg.data_error_type(value);
 
+        // u32::MAX is the SBE null value of an optional uInt32NULL field.
+        let value = self
+            .retry_after_millis
+            .map_or(u32::MAX, |millis| millis.min(u32::MAX - 1));
+        csg.retry_after_millis(value);
+
         let limit = csg.get_limit();
         Ok((limit, buffer))
     }
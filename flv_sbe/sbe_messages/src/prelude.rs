@@ -1,18 +1,33 @@
 //
 pub use crate::errors::*;
+// Decimal conversion
+pub use crate::decimal::{decode_decimal, encode_decimal};
+// Frame validation
+pub use crate::frame::{min_block_length, validate_frame};
 // Message types
 pub use crate::types::client_error_types::ClientErrorType;
 pub use crate::types::data_error_types::DataErrorType;
 pub use crate::types::data_type::DataType;
 pub use crate::types::message_types::MessageType;
+pub use crate::types::protocol::{
+    negotiate_protocol_version, Capabilities, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION,
+};
+pub use crate::types::replay_mode::ReplayMode;
 // Client messages
+pub use crate::messages::client_messages::client_heartbeat::ClientHeartbeatMessage;
 pub use crate::messages::client_messages::client_login::ClientLoginMessage;
+pub use crate::messages::client_messages::client_login_ack::ClientLoginAckMessage;
 pub use crate::messages::client_messages::client_logout::ClientLogoutMessage;
+pub use crate::messages::client_messages::client_logout_ack::ClientLogoutAckMessage;
 // Data messages
+pub use crate::messages::data_messages::continue_data::ContinueDataMessage;
 pub use crate::messages::data_messages::ohlcv_bar::SbeOHLCVBar;
 pub use crate::messages::data_messages::ohlcv_bar_first::FirstOHLCVBar;
 pub use crate::messages::data_messages::ohlcv_bar_last::LastOHLCVBar;
+pub use crate::messages::data_messages::pause_data::PauseDataMessage;
+pub use crate::messages::data_messages::resume_data::ResumeDataMessage;
 pub use crate::messages::data_messages::start_data::StartDataMessage;
+pub use crate::messages::data_messages::start_multi_data::StartMultiDataMessage;
 pub use crate::messages::data_messages::stop_all_data::StopAllDataMessage;
 pub use crate::messages::data_messages::stop_data::StopDataMessage;
 pub use crate::messages::data_messages::trade_bar::SbeTradeBar;
@@ -10,6 +10,8 @@ use std::fmt::{Display, Formatter};
 /// - ClientLogInError = 2_u8
 /// - ClientNotLoggedIn = 3_u8
 /// - ClientLogOutError = 4_u8
+/// - ProtocolVersionMismatch = 5_u8
+/// - ClientAuthenticationError = 6_u8
 ///
 /// # Remarks
 ///
@@ -33,6 +35,8 @@ pub enum ClientErrorType {
     ClientLogInError = 2_u8,
     ClientNotLoggedIn = 3_u8,
     ClientLogOutError = 4_u8,
+    ProtocolVersionMismatch = 5_u8,
+    ClientAuthenticationError = 6_u8,
 }
 
 impl From<u8> for ClientErrorType {
@@ -51,6 +55,8 @@ impl From<u8> for ClientErrorType {
     /// - 2 -> ClientLogInError
     /// - 3 -> ClientNotLoggedIn
     /// - 4 -> ClientLogOutError
+    /// - 5 -> ProtocolVersionMismatch
+    /// - 6 -> ClientAuthenticationError
     /// - Other -> UnknownClientError
     ///
     /// # Remarks
@@ -66,6 +72,8 @@ impl From<u8> for ClientErrorType {
             2_u8 => ClientErrorType::ClientLogInError,
             3_u8 => ClientErrorType::ClientNotLoggedIn,
             4_u8 => ClientErrorType::ClientLogOutError,
+            5_u8 => ClientErrorType::ProtocolVersionMismatch,
+            6_u8 => ClientErrorType::ClientAuthenticationError,
             _ => ClientErrorType::UnknownClientError,
         }
     }
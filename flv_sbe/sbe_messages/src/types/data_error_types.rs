@@ -12,6 +12,10 @@ use std::fmt::{Display, Formatter};
 /// * `DataTableNotFound` - The requested data table does not exist.
 /// * `DataSendError` - Error sending the requested data.
 /// * `DataChannelError` - Error getting the clients data channel.
+/// * `TimeResolutionNotValidError` - The requested time resolution is not valid.
+/// * `ThresholdNotValidError` - The requested bar threshold is not valid.
+/// * `NotEntitled` - The client is not entitled to the requested data.
+/// * `RateLimited` - The client exceeded its request rate or data quota.
 ///
 /// The enum variants are represented as `u8` values for serialization.
 #[derive(
@@ -27,6 +31,10 @@ pub enum DataErrorType {
     DataTableNotFound = 4_u8,
     DataSendError = 5_u8,
     DataChannelError = 6_u8,
+    TimeResolutionNotValidError = 7_u8,
+    ThresholdNotValidError = 8_u8,
+    NotEntitled = 9_u8,
+    RateLimited = 10_u8,
 }
 
 impl From<u8> for DataErrorType {
@@ -41,6 +49,10 @@ impl From<u8> for DataErrorType {
     /// * 4 -> `DataTableNotFound`
     /// * 5 -> `DataSendError`
     /// * 6 -> `DataChannelError`
+    /// * 7 -> `TimeResolutionNotValidError`
+    /// * 8 -> `ThresholdNotValidError`
+    /// * 9 -> `NotEntitled`
+    /// * 10 -> `RateLimited`
     ///
     /// Any other `u8` value maps to `UnknownDataError`.
     ///
@@ -55,6 +67,10 @@ impl From<u8> for DataErrorType {
             4_u8 => DataErrorType::DataTableNotFound,
             5_u8 => DataErrorType::DataSendError,
             6_u8 => DataErrorType::DataChannelError,
+            7_u8 => DataErrorType::TimeResolutionNotValidError,
+            8_u8 => DataErrorType::ThresholdNotValidError,
+            9_u8 => DataErrorType::NotEntitled,
+            10_u8 => DataErrorType::RateLimited,
             _ => DataErrorType::UnknownDataError,
         }
     }
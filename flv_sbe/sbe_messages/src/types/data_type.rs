@@ -10,6 +10,12 @@ use std::fmt::{Display, Formatter};
 /// - `OHLCVData` - Open-high-low-close-volume bar data
 /// - `OrderBookData` - Current order book data
 /// - `QuoteData` - Quote data
+/// - `TickBarData` - OHLCV bars of a fixed number of trades
+/// - `VolumeBarData` - OHLCV bars of a fixed traded volume
+/// - `DollarBarData` - OHLCV bars of a fixed traded notional value
+///
+/// Tick, volume, and dollar bars are sampled from the trade stream and
+/// sent as OHLCV bars, so they need a threshold instead of a time resolution.
 ///
 /// The enum is represented as a `u8` under the hood.
 #[derive(
@@ -23,6 +29,26 @@ pub enum DataType {
     OHLCVData = 2_u8,
     // OrderBookData = 3_u8,
     // QuoteData = 4_u8,
+    TickBarData = 5_u8,
+    VolumeBarData = 6_u8,
+    DollarBarData = 7_u8,
+}
+
+impl DataType {
+    /// Returns `true` for the information-driven bar types sampled from the trade stream,
+    /// i.e. tick, volume, and dollar bars.
+    pub fn is_information_bar(&self) -> bool {
+        matches!(
+            self,
+            DataType::TickBarData | DataType::VolumeBarData | DataType::DollarBarData
+        )
+    }
+
+    /// Returns `true` for the data types sent as OHLCV bars,
+    /// i.e. OHLCV data and the information-driven bar types.
+    pub fn is_ohlcv_bar(&self) -> bool {
+        *self == DataType::OHLCVData || self.is_information_bar()
+    }
 }
 
 impl From<u8> for DataType {
@@ -39,6 +65,9 @@ impl From<u8> for DataType {
     /// - `0_u8` maps to `DataType::UnknownDataType`
     /// - `1_u8` maps to `DataType::TradeData`
     /// - `2_u8` maps to `DataType::OHLCVData`
+    /// - `5_u8` maps to `DataType::TickBarData`
+    /// - `6_u8` maps to `DataType::VolumeBarData`
+    /// - `7_u8` maps to `DataType::DollarBarData`
     ///
     /// Any other value maps to `DataType::UnknownDataType`.
     #[inline]
@@ -47,6 +76,9 @@ impl From<u8> for DataType {
             0_u8 => DataType::UnknownDataType,
             1_u8 => DataType::TradeData,
             2_u8 => DataType::OHLCVData,
+            5_u8 => DataType::TickBarData,
+            6_u8 => DataType::VolumeBarData,
+            7_u8 => DataType::DollarBarData,
             _ => DataType::UnknownDataType,
         }
     }
@@ -10,6 +10,9 @@ use std::fmt::Display;
 /// 1xx = ClientTypes
 /// - ClientLogin = 101_u16
 /// - ClientLogout = 102_u16
+/// - ClientLoginAck = 103_u16
+/// - ClientLogoutAck = 104_u16
+/// - ClientHeartbeat = 105_u16
 /// 2xx = DataTypes
 /// - StartData = 201_u16
 /// - StopData = 202_u16
@@ -20,6 +23,10 @@ use std::fmt::Display;
 /// - TradeBar = 207_u16
 /// - FirstTradeBar = 208_u16
 /// - LastTradeBar = 209_u16
+/// - PauseData = 210_u16
+/// - ContinueData = 211_u16
+/// - StartMultiData = 212_u16
+/// - ResumeData = 213_u16
 /// 8xx = ErrorTypes
 /// - ClientError = 801_u16
 /// - DataError = 802_u16
@@ -45,6 +52,9 @@ pub enum MessageType {
     // Client Message Types
     ClientLogin = 101_u16,
     ClientLogout = 102_u16,
+    ClientLoginAck = 103_u16,
+    ClientLogoutAck = 104_u16,
+    ClientHeartbeat = 105_u16,
     // Data Message Types
     StartData = 201_u16,
     StopData = 202_u16,
@@ -55,6 +65,10 @@ pub enum MessageType {
     TradeBar = 207_u16,
     FirstTradeBar = 208_u16,
     LastTradeBar = 209_u16,
+    PauseData = 210_u16,
+    ContinueData = 211_u16,
+    StartMultiData = 212_u16,
+    ResumeData = 213_u16,
     // Error Message Types
     ClientError = 801_u16,
     DataError = 802_u16,
@@ -73,6 +87,9 @@ pub enum MessageType {
 /// - 0 -> UnknownMessageType
 /// - 101 -> ClientLogin
 /// - 102 -> ClientLogout
+/// - 103 -> ClientLoginAck
+/// - 104 -> ClientLogoutAck
+/// - 105 -> ClientHeartbeat
 /// - 201 -> StartData
 /// - 202 -> StopData
 /// - 203 -> StopAllData
@@ -82,6 +99,10 @@ pub enum MessageType {
 /// - 207 -> TradeBar
 /// - 208 -> FirstTradeBar
 /// - 209 -> LastTradeBar
+/// - 210 -> PauseData
+/// - 211 -> ContinueData
+/// - 212 -> StartMultiData
+/// - 213 -> ResumeData
 /// - 801 -> ClientError
 /// - 802 -> DataError
 /// - Other -> UnknownMessageType
@@ -99,6 +120,9 @@ impl From<u16> for MessageType {
             // Client Message Types
             101_u16 => MessageType::ClientLogin,
             102_u16 => MessageType::ClientLogout,
+            103_u16 => MessageType::ClientLoginAck,
+            104_u16 => MessageType::ClientLogoutAck,
+            105_u16 => MessageType::ClientHeartbeat,
             // Data Message Types
             201_u16 => MessageType::StartData,
             202_u16 => MessageType::StopData,
@@ -109,6 +133,10 @@ impl From<u16> for MessageType {
             207_u16 => MessageType::TradeBar,
             208_u16 => MessageType::FirstTradeBar,
             209_u16 => MessageType::LastTradeBar,
+            210_u16 => MessageType::PauseData,
+            211_u16 => MessageType::ContinueData,
+            212_u16 => MessageType::StartMultiData,
+            213_u16 => MessageType::ResumeData,
             // Error Message Types
             801_u16 => MessageType::ClientError,
             802_u16 => MessageType::DataError,
@@ -122,6 +150,9 @@ impl Display for MessageType {
         match self {
             MessageType::ClientLogin => write!(f, "ClientLogin"),
             MessageType::ClientLogout => write!(f, "ClientLogout"),
+            MessageType::ClientLoginAck => write!(f, "ClientLoginAck"),
+            MessageType::ClientLogoutAck => write!(f, "ClientLogoutAck"),
+            MessageType::ClientHeartbeat => write!(f, "ClientHeartbeat"),
             MessageType::StartData => write!(f, "StartData"),
             MessageType::StopData => write!(f, "StopData"),
             MessageType::StopAllData => write!(f, "StopAllData"),
@@ -131,6 +162,10 @@ impl Display for MessageType {
             MessageType::TradeBar => write!(f, "TradeBar"),
             MessageType::FirstTradeBar => write!(f, "FirstTradeBar"),
             MessageType::LastTradeBar => write!(f, "LastTradeBar"),
+            MessageType::PauseData => write!(f, "PauseData"),
+            MessageType::ContinueData => write!(f, "ContinueData"),
+            MessageType::StartMultiData => write!(f, "StartMultiData"),
+            MessageType::ResumeData => write!(f, "ResumeData"),
             MessageType::UnknownMessageType => write!(f, "UnknownMessageType"),
             MessageType::ClientError => write!(f, "ClientError"),
             MessageType::DataError => write!(f, "DataError"),
@@ -6,6 +6,8 @@
 /// - Data errors for requests
 /// - Exchange identifiers
 /// - Message types
+/// - Protocol version and capabilities
+/// - Replay modes
 ///
 /// Grouping these common types into a module avoids duplication
 /// and provides a single source of truth for type definitions.
@@ -19,8 +21,12 @@
 /// - `data_error_types` - Enumeration of data error types
 /// - `exchange_id` - Enumeration of exchange identifiers
 /// - `message_type` - Enumeration of message types
+/// - `protocol` - Protocol version and set of capabilities
+/// - `replay_mode` - Enumeration of replay modes
 ///
 pub mod client_error_types;
 pub mod data_error_types;
 pub mod data_type;
 pub mod message_types;
+pub mod protocol;
+pub mod replay_mode;
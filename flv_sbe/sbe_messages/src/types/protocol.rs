@@ -0,0 +1,128 @@
+use sbe_bindings::client_login_codec::SBE_SCHEMA_VERSION;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The protocol version of this build, which is the version of the SBE schema.
+pub const PROTOCOL_VERSION: u16 = SBE_SCHEMA_VERSION;
+
+/// The oldest protocol version the gateway serves.
+///
+/// Decoders of version 1 reject messages of any newer schema version,
+/// whereas decoders since version 2 accept messages of newer schema versions.
+pub const MIN_PROTOCOL_VERSION: u16 = 2;
+
+/// Negotiates the protocol version between a client and the gateway.
+///
+/// # Arguments
+///
+/// * `client_version` - The protocol version of the client
+///
+/// # Returns
+///
+/// The newest protocol version both sides understand,
+/// or None if the client version is older than `MIN_PROTOCOL_VERSION`.
+///
+pub fn negotiate_protocol_version(client_version: u16) -> Option<u16> {
+    if client_version < MIN_PROTOCOL_VERSION {
+        None
+    } else {
+        Some(client_version.min(PROTOCOL_VERSION))
+    }
+}
+
+/// The Capabilities struct represents a set of optional protocol features as bit flags.
+///
+/// The flags represent the following features:
+///
+/// - `RESUME_DATA` - Sequence numbered bars and resuming a data stream after a gap
+/// - `MULTI_DATA` - Merged data streams of several symbols
+/// - `INFORMATION_BARS` - Tick, volume, and dollar bars
+/// - `BAR_STATISTICS` - VWAP, trade count, and buy and sell volume of OHLCV bars
+/// - `EXACT_DECIMALS` - Prices and volumes as exact decimals
+///
+/// The client sends its capabilities with the login and the gateway confirms
+/// the capabilities both sides support with the login ack.
+///
+/// The set is represented as a `u32` under the hood.
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct Capabilities(u32);
+
+impl Capabilities {
+    pub const NONE: Capabilities = Capabilities(0);
+    pub const RESUME_DATA: Capabilities = Capabilities(1 << 0);
+    pub const MULTI_DATA: Capabilities = Capabilities(1 << 1);
+    pub const INFORMATION_BARS: Capabilities = Capabilities(1 << 2);
+    pub const BAR_STATISTICS: Capabilities = Capabilities(1 << 3);
+    pub const EXACT_DECIMALS: Capabilities = Capabilities(1 << 4);
+
+    const NAMED: [(Capabilities, &'static str); 5] = [
+        (Capabilities::RESUME_DATA, "ResumeData"),
+        (Capabilities::MULTI_DATA, "MultiData"),
+        (Capabilities::INFORMATION_BARS, "InformationBars"),
+        (Capabilities::BAR_STATISTICS, "BarStatistics"),
+        (Capabilities::EXACT_DECIMALS, "ExactDecimals"),
+    ];
+
+    /// Returns the capabilities this build supports.
+    pub fn supported() -> Self {
+        Capabilities::RESUME_DATA
+            .with(Capabilities::MULTI_DATA)
+            .with(Capabilities::INFORMATION_BARS)
+            .with(Capabilities::BAR_STATISTICS)
+            .with(Capabilities::EXACT_DECIMALS)
+    }
+
+    /// Returns the union of both sets of capabilities.
+    pub fn with(self, other: Capabilities) -> Self {
+        Capabilities(self.0 | other.0)
+    }
+
+    /// Returns the capabilities contained in both sets.
+    pub fn intersection(self, other: Capabilities) -> Self {
+        Capabilities(self.0 & other.0)
+    }
+
+    /// Returns true if all capabilities of `other` are contained in this set.
+    pub fn contains(self, other: Capabilities) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if the set contains no capabilities.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the bit flags of the set.
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for Capabilities {
+    /// Converts bit flags into a set of capabilities.
+    ///
+    /// Unknown flags of a newer protocol version are kept,
+    /// so that the intersection with the supported capabilities removes them.
+    #[inline]
+    fn from(value: u32) -> Self {
+        Capabilities(value)
+    }
+}
+
+impl Display for Capabilities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = Capabilities::NAMED
+            .iter()
+            .filter(|(capability, _)| self.contains(*capability))
+            .map(|(_, name)| *name)
+            .collect();
+
+        if names.is_empty() {
+            write!(f, "None")
+        } else {
+            write!(f, "{}", names.join("|"))
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The ReplayMode enum represents the pacing of a historical data stream.
+///
+/// The variants represent the following replay modes:
+///
+/// - `Unthrottled` - Default; sends data as fast as the database returns it
+/// - `RealTime` - Sends data at the wall-clock pace of the original timestamps
+/// - `Scaled` - Sends data at a multiple of the real-time pace i.e. 10x or 100x
+///
+/// The speed multiplier of the `Scaled` mode is set separately in the StartDataMessage.
+///
+/// The enum is represented as a `u8` under the hood.
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[repr(u8)]
+pub enum ReplayMode {
+    #[default]
+    Unthrottled = 0_u8,
+    RealTime = 1_u8,
+    Scaled = 2_u8,
+}
+
+impl From<u8> for ReplayMode {
+    /// Converts a `u8` value to a `ReplayMode` enum variant.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The `u8` value to convert.
+    ///
+    /// # Returns
+    ///
+    /// The corresponding `ReplayMode` variant:
+    ///
+    /// - `0_u8` maps to `ReplayMode::Unthrottled`
+    /// - `1_u8` maps to `ReplayMode::RealTime`
+    /// - `2_u8` maps to `ReplayMode::Scaled`
+    ///
+    /// Any other value maps to `ReplayMode::Unthrottled`.
+    #[inline]
+    fn from(value: u8) -> Self {
+        match value {
+            0_u8 => ReplayMode::Unthrottled,
+            1_u8 => ReplayMode::RealTime,
+            2_u8 => ReplayMode::Scaled,
+            _ => ReplayMode::Unthrottled,
+        }
+    }
+}
+
+impl Display for ReplayMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,126 @@
+use proptest::prelude::*;
+use rust_decimal::Decimal;
+use sbe_messages::prelude::{decode_decimal, encode_decimal};
+use std::str::FromStr;
+
+#[test]
+fn test_encode_decimal() {
+    let value = Decimal::from_str("65432.10").unwrap();
+
+    let (mantissa, exponent) = encode_decimal(value);
+    assert_eq!(mantissa, 654321);
+    assert_eq!(exponent, -1);
+}
+
+#[test]
+fn test_encode_decimal_zero() {
+    assert_eq!(encode_decimal(Decimal::ZERO), (0, 0));
+}
+
+#[test]
+fn test_encode_decimal_integer() {
+    assert_eq!(encode_decimal(Decimal::from(1_000_000)), (1_000_000, 0));
+}
+
+#[test]
+fn test_encode_decimal_rounds_large_mantissa() {
+    // 28 significant digits do not fit into an i64 mantissa.
+    let value = Decimal::from(1) / Decimal::from(3);
+
+    let (mantissa, exponent) = encode_decimal(value);
+    assert_eq!(mantissa, 3333333333333333333);
+    assert_eq!(exponent, -19);
+}
+
+#[test]
+fn test_encode_decimal_rounds_half_to_even() {
+    // 20 significant digits ending in 25 round to 19 digits ending in 2.
+    let value = Decimal::from_str("1.0000000000000000025").unwrap();
+
+    let (mantissa, exponent) = encode_decimal(value);
+    assert_eq!(mantissa, 1000000000000000002);
+    assert_eq!(exponent, -18);
+}
+
+#[test]
+fn test_encode_decimal_rounds_up_trailing_zeros() {
+    let value = Decimal::from_str("99999999999999999999").unwrap();
+
+    let (mantissa, exponent) = encode_decimal(value);
+    assert_eq!(mantissa, 1_000_000_000_000_000_000);
+    assert_eq!(exponent, 2);
+    assert_eq!(
+        decode_decimal(mantissa, exponent),
+        Ok(Decimal::from_str("1e20").unwrap())
+    );
+}
+
+#[test]
+fn test_encode_decimal_min_mantissa() {
+    // i64::MIN is the null value of an optional decimal.
+    let value = Decimal::from(i64::MIN);
+
+    let (mantissa, exponent) = encode_decimal(value);
+    assert_ne!(mantissa, i64::MIN);
+    assert_eq!(mantissa, -922337203685477581);
+    assert_eq!(exponent, 1);
+}
+
+#[test]
+fn test_decode_decimal() {
+    let value = decode_decimal(123, -8).unwrap();
+    assert_eq!(value, Decimal::from_str("0.00000123").unwrap());
+}
+
+#[test]
+fn test_decode_decimal_positive_exponent() {
+    let value = decode_decimal(-5, 3).unwrap();
+    assert_eq!(value, Decimal::from(-5000));
+}
+
+#[test]
+fn test_decode_decimal_exponent_too_small() {
+    assert!(decode_decimal(1, -29).is_err());
+}
+
+#[test]
+fn test_decode_decimal_value_too_large() {
+    assert!(decode_decimal(1, 29).is_err());
+    assert!(decode_decimal(i64::MAX, 127).is_err());
+}
+
+proptest! {
+    #[test]
+    fn test_decimal_round_trip(mantissa in -i64::MAX..=i64::MAX, scale in 0u32..=28) {
+        let value = Decimal::new(mantissa, scale);
+
+        let (mantissa, exponent) = encode_decimal(value);
+        let decoded = decode_decimal(mantissa, exponent).unwrap();
+
+        prop_assert_eq!(decoded, value);
+        prop_assert_eq!(decoded.normalize().scale(), value.normalize().scale());
+    }
+
+    #[test]
+    fn test_decimal_round_trip_rounded(
+        lo in any::<u32>(),
+        mid in any::<u32>(),
+        hi in any::<u32>(),
+        negative in any::<bool>(),
+        scale in 0u32..=28,
+    ) {
+        let value = Decimal::from_parts(lo, mid, hi, negative, scale);
+
+        let (mantissa, exponent) = encode_decimal(value);
+        let decoded = decode_decimal(mantissa, exponent).unwrap();
+
+        // A mantissa beyond the range of an i64 keeps at least 18 significant digits.
+        let error = (decoded - value).abs();
+        let tolerance = value.abs() / Decimal::from(100_000_000_000_000_000i64);
+        prop_assert!(error <= tolerance, "{} decoded as {}", value, decoded);
+
+        if value.normalize().mantissa().abs() <= i64::MAX as i128 {
+            prop_assert_eq!(decoded, value);
+        }
+    }
+}
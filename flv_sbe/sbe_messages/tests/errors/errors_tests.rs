@@ -8,6 +8,24 @@ fn test_sbe_encode_error_display() {
 
 #[test]
 fn test_sbe_decode_error_display() {
-    let error = SbeDecodeError("test error".to_string());
+    let error = SbeDecodeError::DecodeError("test error".to_string());
     assert_eq!(error.to_string(), "SbeDecodeError: test error");
 }
+
+#[test]
+fn test_sbe_decode_error_frame_display() {
+    let error = SbeDecodeError::BufferTooShort {
+        expected: 8,
+        actual: 2,
+    };
+    assert_eq!(
+        error.to_string(),
+        "SbeDecodeError: Buffer too short: expected at least 8 bytes, got 2"
+    );
+
+    let error = SbeDecodeError::UnknownTemplateId(999);
+    assert_eq!(
+        error.to_string(),
+        "SbeDecodeError: Unknown template id: 999"
+    );
+}
@@ -0,0 +1,282 @@
+use common::prelude::{ExchangeID, OHLCVBar, TimeResolution};
+use sbe_bindings::client_login_codec::SBE_SCHEMA_VERSION;
+use sbe_messages::prelude::{
+    min_block_length, validate_frame, ClientLoginMessage, DataType, MessageType, ReplayMode,
+    SbeDecodeError, SbeOHLCVBar, StartDataMessage, StartMultiDataMessage,
+};
+
+fn get_client_login_buffer() -> Vec<u8> {
+    let (_, buffer) = ClientLoginMessage::new(100).encode().unwrap();
+    buffer
+}
+
+#[test]
+fn test_validate_frame() {
+    let buffer = get_client_login_buffer();
+
+    let message_type = validate_frame(&buffer);
+    assert_eq!(message_type, Ok(MessageType::ClientLogin));
+}
+
+#[test]
+fn test_validate_frame_start_data() {
+    let message = StartDataMessage::new(
+        100,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::OneMin,
+        DataType::TradeData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    );
+    let (_, buffer) = message.encode().unwrap();
+
+    let message_type = validate_frame(&buffer);
+    assert_eq!(message_type, Ok(MessageType::StartData));
+}
+
+#[test]
+fn test_validate_frame_start_multi_data() {
+    let message = StartMultiDataMessage::new(
+        100,
+        ExchangeID::Kraken,
+        vec![1, 2, 3],
+        TimeResolution::OneMin,
+        DataType::TradeData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    );
+    let (_, buffer) = message.encode().unwrap();
+
+    let message_type = validate_frame(&buffer);
+    assert_eq!(message_type, Ok(MessageType::StartMultiData));
+}
+
+#[test]
+fn test_validate_frame_empty_buffer() {
+    let buffer: Vec<u8> = vec![];
+
+    let expected = SbeDecodeError::BufferTooShort {
+        expected: 8,
+        actual: 0,
+    };
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_truncated_header() {
+    let buffer = get_client_login_buffer();
+
+    let expected = SbeDecodeError::BufferTooShort {
+        expected: 8,
+        actual: 3,
+    };
+    assert_eq!(validate_frame(&buffer[..3]), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_truncated_block() {
+    let buffer = get_client_login_buffer();
+
+    let expected = SbeDecodeError::BufferTooShort {
+        expected: 18,
+        actual: 10,
+    };
+    assert_eq!(validate_frame(&buffer[..10]), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_unknown_template_id() {
+    let mut buffer = get_client_login_buffer();
+    buffer[2] = 99;
+
+    let expected = SbeDecodeError::UnknownTemplateId(99);
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_invalid_block_length() {
+    let mut buffer = get_client_login_buffer();
+    buffer[0] = 7;
+
+    let expected = SbeDecodeError::InvalidBlockLength {
+        expected: 10,
+        actual: 7,
+    };
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_invalid_schema_id() {
+    let mut buffer = get_client_login_buffer();
+    buffer[4] = 2;
+
+    let expected = SbeDecodeError::InvalidSchemaId {
+        expected: 1,
+        actual: 2,
+    };
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_invalid_version() {
+    let mut buffer = get_client_login_buffer();
+    buffer[6] = 0;
+
+    let expected = SbeDecodeError::InvalidVersion {
+        expected: 6,
+        actual: 0,
+    };
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_older_version() {
+    // A version 1 OHLCV bar has no vwap, trade count, buy volume, and sell volume.
+    let (_, mut buffer) = SbeOHLCVBar::encode(OHLCVBar::default()).unwrap();
+    buffer[0] = 40;
+    buffer[6] = 1;
+    buffer.truncate(48);
+
+    let message_type = validate_frame(&buffer);
+    assert_eq!(message_type, Ok(MessageType::OHLCVBar));
+}
+
+#[test]
+fn test_validate_frame_older_version_longer_block() {
+    let mut buffer = get_client_login_buffer();
+    buffer[0] = 12;
+    buffer[6] = 1;
+    buffer.extend_from_slice(&[0, 0]);
+
+    let expected = SbeDecodeError::InvalidBlockLength {
+        expected: 10,
+        actual: 12,
+    };
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_newer_version() {
+    // A newer version may append fields to the block.
+    let mut buffer = get_client_login_buffer();
+    buffer[0] = 12;
+    buffer[6] = 9;
+    buffer.extend_from_slice(&[0, 0]);
+
+    let message_type = validate_frame(&buffer);
+    assert_eq!(message_type, Ok(MessageType::ClientLogin));
+}
+
+#[test]
+fn test_validate_frame_newer_version_shorter_block() {
+    let mut buffer = get_client_login_buffer();
+    buffer[0] = 2;
+    buffer[6] = 9;
+
+    let expected = SbeDecodeError::InvalidBlockLength {
+        expected: 10,
+        actual: 2,
+    };
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+#[test]
+fn test_validate_frame_invalid_message_type() {
+    let mut buffer = get_client_login_buffer();
+    buffer[8] = 102;
+
+    let expected = SbeDecodeError::InvalidMessageType {
+        expected: 101,
+        actual: 102,
+    };
+    assert_eq!(validate_frame(&buffer), Err(expected));
+}
+
+const TEMPLATE_IDS: [u16; 20] = [
+    101, 102, 103, 104, 105, 201, 202, 203, 204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 801,
+    802,
+];
+
+/// Builds a frame with the given header and a block of the given length
+/// that starts with the message type field.
+fn get_frame(block_length: u16, template_id: u16, version: u16) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    buffer.extend_from_slice(&block_length.to_le_bytes());
+    buffer.extend_from_slice(&template_id.to_le_bytes());
+    buffer.extend_from_slice(&1u16.to_le_bytes());
+    buffer.extend_from_slice(&version.to_le_bytes());
+    buffer.extend_from_slice(&template_id.to_le_bytes());
+    buffer.resize(8 + block_length.max(2) as usize, 0);
+    buffer
+}
+
+#[test]
+fn test_validate_frame_short_block_every_template() {
+    // A block with only the message type field is shorter than every message in every version.
+    for template_id in TEMPLATE_IDS {
+        let message_type = MessageType::from(template_id);
+
+        for version in 1..=SBE_SCHEMA_VERSION {
+            let buffer = get_frame(2, template_id, version);
+
+            let expected = SbeDecodeError::InvalidBlockLength {
+                expected: min_block_length(&message_type, version),
+                actual: 2,
+            };
+            assert_eq!(
+                validate_frame(&buffer),
+                Err(expected),
+                "template {template_id} version {version}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_validate_frame_min_block_every_template() {
+    for template_id in TEMPLATE_IDS {
+        let message_type = MessageType::from(template_id);
+
+        for version in 1..=SBE_SCHEMA_VERSION {
+            let block_length = min_block_length(&message_type, version);
+
+            let buffer = get_frame(block_length, template_id, version);
+            assert_eq!(validate_frame(&buffer), Ok(message_type));
+
+            let buffer = get_frame(block_length - 1, template_id, version);
+            let expected = SbeDecodeError::InvalidBlockLength {
+                expected: block_length,
+                actual: block_length - 1,
+            };
+            assert_eq!(validate_frame(&buffer), Err(expected));
+        }
+    }
+}
+
+#[test]
+fn test_min_block_length() {
+    assert_eq!(min_block_length(&MessageType::ClientLogin, 1), 4);
+    assert_eq!(min_block_length(&MessageType::ClientLogin, 4), 10);
+    assert_eq!(min_block_length(&MessageType::OHLCVBar, 2), 56);
+    assert_eq!(min_block_length(&MessageType::DataError, 5), 5);
+    assert_eq!(min_block_length(&MessageType::DataError, 9), 9);
+    assert_eq!(min_block_length(&MessageType::UnknownMessageType, 1), 0);
+}
+
+#[test]
+fn test_validate_frame_short_start_data() {
+    // A version 1 StartData frame with a block of two bytes must not reach the decoder.
+    let buffer = get_frame(2, 201, 1);
+    assert_eq!(buffer.len(), 10);
+
+    let result = validate_frame(&buffer);
+    assert!(matches!(
+        result,
+        Err(SbeDecodeError::InvalidBlockLength { actual: 2, .. })
+    ));
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod frame_tests;
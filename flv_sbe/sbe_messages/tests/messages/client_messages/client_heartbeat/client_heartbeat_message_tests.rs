@@ -0,0 +1,46 @@
+use sbe_messages::prelude::{ClientHeartbeatMessage, MessageType};
+
+#[test]
+fn test_new() {
+    let client_id = 100;
+    let message = ClientHeartbeatMessage::new(client_id);
+
+    assert_eq!(message.message_type(), &MessageType::ClientHeartbeat);
+    assert_eq!(message.client_id(), client_id);
+}
+
+#[test]
+fn test_encode() {
+    let client_id = 100;
+    let message = ClientHeartbeatMessage::new(client_id);
+
+    let enc = message.encode();
+    assert!(enc.is_ok());
+
+    let (limit, buffer) = enc.unwrap();
+    assert_eq!(limit, 12);
+
+    let expected: Vec<u8> = vec![4, 0, 105, 0, 1, 0, 6, 0, 105, 0, 100, 0];
+    let actual = buffer;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_decode() {
+    let encoded: Vec<u8> = vec![4, 0, 105, 0, 1, 0, 6, 0, 105, 0, 100, 0];
+    let buffer = encoded.as_slice();
+
+    let message = ClientHeartbeatMessage::from(buffer);
+    assert_eq!(message.message_type(), &MessageType::ClientHeartbeat);
+    assert_eq!(message.client_id(), 100);
+}
+
+#[test]
+fn test_display() {
+    let client_id = 100;
+
+    let actual = ClientHeartbeatMessage::new(client_id);
+    let expected = "ClientHeartbeatMessage { client_id: 100 }";
+
+    assert_eq!(format!("{}", actual), expected);
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod client_heartbeat_message_tests;
@@ -1,4 +1,6 @@
-use sbe_messages::prelude::{ClientLoginMessage, MessageType};
+use sbe_messages::prelude::{
+    Capabilities, ClientLoginMessage, MessageType, SbeDecodeError, PROTOCOL_VERSION,
+};
 
 fn get_client_login_message(client_id: u16) -> ClientLoginMessage {
     ClientLoginMessage::new(client_id)
@@ -11,6 +13,9 @@ fn test_new() {
 
     assert_eq!(message.message_type(), &MessageType::ClientLogin);
     assert_eq!(message.client_id(), client_id);
+    assert_eq!(message.protocol_version(), PROTOCOL_VERSION);
+    assert_eq!(message.capabilities(), Capabilities::supported());
+    assert_eq!(message.auth_token(), "");
 }
 
 #[test]
@@ -25,21 +30,107 @@ fn test_encode() {
     assert!(enc.is_ok());
 
     let (limit, buffer) = enc.unwrap();
-    assert_eq!(limit, 12);
+    assert_eq!(limit, 20);
 
-    let expected: Vec<u8> = vec![4, 0, 101, 0, 1, 0, 1, 0, 101, 0, 100, 0];
+    let expected: Vec<u8> = vec![
+        10, 0, 101, 0, 1, 0, 6, 0, 101, 0, 100, 0, 6, 0, 31, 0, 0, 0, 0, 0,
+    ];
     let actual = buffer;
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn test_encode_auth_token() {
+    let message = get_client_login_message(100).with_auth_token("abc");
+
+    let (limit, buffer) = message.encode().unwrap();
+    assert_eq!(limit, 23);
+
+    let expected: Vec<u8> = vec![
+        10, 0, 101, 0, 1, 0, 6, 0, 101, 0, 100, 0, 6, 0, 31, 0, 0, 0, 3, 0, 97, 98, 99,
+    ];
+    assert_eq!(expected, buffer);
+}
+
+#[test]
+fn test_encode_auth_token_too_long() {
+    let auth_token = "a".repeat(1025);
+    let message = get_client_login_message(100).with_auth_token(&auth_token);
+
+    assert!(message.encode().is_err());
+}
+
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![4, 0, 101, 0, 1, 0, 1, 0, 101, 0, 100, 0];
+    let encoded: Vec<u8> = vec![
+        10, 0, 101, 0, 1, 0, 6, 0, 101, 0, 100, 0, 5, 0, 3, 0, 0, 0, 3, 0, 97, 98, 99,
+    ];
     let buffer = encoded.as_slice();
 
-    let message = ClientLoginMessage::from(buffer);
+    let message = ClientLoginMessage::try_from(buffer).unwrap();
     assert_eq!(message.message_type(), &MessageType::ClientLogin);
     assert_eq!(message.client_id(), 100);
+    assert_eq!(message.protocol_version(), 5);
+    assert_eq!(
+        message.capabilities(),
+        Capabilities::RESUME_DATA.with(Capabilities::MULTI_DATA)
+    );
+    assert_eq!(message.auth_token(), "abc");
+}
+
+#[test]
+fn test_decode_version_4() {
+    // A version 4 message has no auth token.
+    let encoded: Vec<u8> = vec![10, 0, 101, 0, 1, 0, 4, 0, 101, 0, 100, 0, 4, 0, 3, 0, 0, 0];
+    let buffer = encoded.as_slice();
+
+    let message = ClientLoginMessage::try_from(buffer).unwrap();
+    assert_eq!(message.client_id(), 100);
+    assert_eq!(message.protocol_version(), 4);
+    assert_eq!(message.auth_token(), "");
+}
+
+#[test]
+fn test_decode_truncated_auth_token() {
+    // The length prefix announces a longer auth token than the buffer holds.
+    let encoded: Vec<u8> = vec![
+        10, 0, 101, 0, 1, 0, 6, 0, 101, 0, 100, 0, 5, 0, 3, 0, 0, 0, 9, 0, 97, 98, 99,
+    ];
+    let buffer = encoded.as_slice();
+
+    let result = ClientLoginMessage::try_from(buffer);
+    assert_eq!(
+        result,
+        Err(SbeDecodeError::BufferTooShort {
+            expected: 29,
+            actual: 23
+        })
+    );
+}
+
+#[test]
+fn test_decode_version_3() {
+    // A version 3 message has no protocol version and capabilities.
+    let encoded: Vec<u8> = vec![4, 0, 101, 0, 1, 0, 3, 0, 101, 0, 100, 0];
+    let buffer = encoded.as_slice();
+
+    let message = ClientLoginMessage::try_from(buffer).unwrap();
+    assert_eq!(message.client_id(), 100);
+    assert_eq!(message.protocol_version(), 3);
+    assert_eq!(message.capabilities(), Capabilities::NONE);
+}
+
+#[test]
+fn test_encode_decode() {
+    let message = ClientLoginMessage::new(7)
+        .with_protocol_version(2)
+        .with_capabilities(Capabilities::EXACT_DECIMALS)
+        .with_auth_token("secret-token");
+
+    let (_, buffer) = message.encode().unwrap();
+    let decoded = ClientLoginMessage::try_from(buffer.as_slice()).unwrap();
+
+    assert_eq!(message, decoded);
 }
 
 #[test]
@@ -63,7 +154,7 @@ fn test_display() {
     let client_id = 100;
 
     let actual = ClientLoginMessage::new(client_id);
-    let expected = "ClientLoginMessage { client_id: 100 }";
+    let expected = "ClientLoginMessage { client_id: 100, protocol_version: 6, capabilities: ResumeData|MultiData|InformationBars|BarStatistics|ExactDecimals }";
 
     assert_eq!(format!("{}", actual), expected);
 }
@@ -0,0 +1,116 @@
+use sbe_messages::prelude::{Capabilities, ClientLoginAckMessage, MessageType, PROTOCOL_VERSION};
+
+fn get_client_login_ack_message(client_id: u16) -> ClientLoginAckMessage {
+    ClientLoginAckMessage::new(
+        client_id,
+        client_id as u32,
+        client_id as u32,
+        client_id as u32,
+    )
+}
+
+#[test]
+fn test_new() {
+    let client_id = 100;
+    let message = get_client_login_ack_message(client_id);
+
+    assert_eq!(message.message_type(), &MessageType::ClientLoginAck);
+    assert_eq!(message.client_id(), client_id);
+    assert_eq!(message.stream_id(), 100);
+    assert_eq!(message.topic_id(), 100);
+    assert_eq!(message.partition_id(), 100);
+    assert_eq!(message.protocol_version(), PROTOCOL_VERSION);
+    assert_eq!(message.capabilities(), Capabilities::supported());
+    assert_eq!(message.access_token(), "");
+}
+
+#[test]
+fn test_encode() {
+    let client_id = 100;
+    let message = get_client_login_ack_message(client_id);
+
+    let enc = message.encode();
+    assert!(enc.is_ok());
+
+    let (limit, buffer) = enc.unwrap();
+    assert_eq!(limit, 32);
+
+    let expected: Vec<u8> = vec![
+        22, 0, 103, 0, 1, 0, 6, 0, 103, 0, 100, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 6, 0,
+        31, 0, 0, 0, 0, 0,
+    ];
+    let actual = buffer;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_decode() {
+    let encoded: Vec<u8> = vec![
+        22, 0, 103, 0, 1, 0, 6, 0, 103, 0, 100, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 5, 0,
+        31, 0, 0, 0, 3, 0, 97, 98, 99,
+    ];
+    let buffer = encoded.as_slice();
+
+    let message = ClientLoginAckMessage::try_from(buffer).unwrap();
+    assert_eq!(message.client_id(), 100);
+    assert_eq!(message.protocol_version(), 5);
+    assert_eq!(message.capabilities(), Capabilities::supported());
+    assert_eq!(message.access_token(), "abc");
+}
+
+#[test]
+fn test_decode_version_4() {
+    // A version 4 message has no access token.
+    let encoded: Vec<u8> = vec![
+        22, 0, 103, 0, 1, 0, 4, 0, 103, 0, 100, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 4, 0,
+        31, 0, 0, 0,
+    ];
+    let buffer = encoded.as_slice();
+
+    let message = ClientLoginAckMessage::try_from(buffer).unwrap();
+    assert_eq!(message.client_id(), 100);
+    assert_eq!(message.protocol_version(), 4);
+    assert_eq!(message.access_token(), "");
+}
+
+#[test]
+fn test_decode_version_3() {
+    // A version 3 message has no protocol version and capabilities.
+    let encoded: Vec<u8> = vec![
+        16, 0, 103, 0, 1, 0, 3, 0, 103, 0, 100, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0,
+    ];
+    let buffer = encoded.as_slice();
+
+    let message = ClientLoginAckMessage::try_from(buffer).unwrap();
+    assert_eq!(message.message_type(), &MessageType::ClientLoginAck);
+    assert_eq!(message.client_id(), 100);
+    assert_eq!(message.stream_id(), 100);
+    assert_eq!(message.topic_id(), 100);
+    assert_eq!(message.partition_id(), 100);
+    assert_eq!(message.protocol_version(), 3);
+    assert_eq!(message.capabilities(), Capabilities::NONE);
+}
+
+#[test]
+fn test_encode_decode() {
+    let message = ClientLoginAckMessage::new(7, 42, 43, 44)
+        .with_protocol_version(3)
+        .with_capabilities(Capabilities::RESUME_DATA)
+        .with_access_token("access-token");
+
+    let (_, buffer) = message.encode().unwrap();
+    let decoded = ClientLoginAckMessage::try_from(buffer.as_slice()).unwrap();
+
+    assert_eq!(message, decoded);
+}
+
+#[test]
+fn test_display() {
+    let client_id = 100;
+
+    let actual = get_client_login_ack_message(client_id);
+    let expected =
+        "ClientLoginAckMessage { client_id: 100, stream_id: 100, topic_id: 100, partition_id: 100, protocol_version: 6, capabilities: ResumeData|MultiData|InformationBars|BarStatistics|ExactDecimals }";
+
+    assert_eq!(format!("{}", actual), expected);
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod client_login_ack_message_tests;
@@ -0,0 +1,46 @@
+use sbe_messages::prelude::{ClientLogoutAckMessage, MessageType};
+
+#[test]
+fn test_new() {
+    let client_id = 100;
+    let message = ClientLogoutAckMessage::new(client_id);
+
+    assert_eq!(message.message_type(), &MessageType::ClientLogoutAck);
+    assert_eq!(message.client_id(), client_id);
+}
+
+#[test]
+fn test_encode() {
+    let client_id = 100;
+    let message = ClientLogoutAckMessage::new(client_id);
+
+    let enc = message.encode();
+    assert!(enc.is_ok());
+
+    let (limit, buffer) = enc.unwrap();
+    assert_eq!(limit, 12);
+
+    let expected: Vec<u8> = vec![4, 0, 104, 0, 1, 0, 6, 0, 104, 0, 100, 0];
+    let actual = buffer;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_decode() {
+    let encoded: Vec<u8> = vec![4, 0, 104, 0, 1, 0, 6, 0, 104, 0, 100, 0];
+    let buffer = encoded.as_slice();
+
+    let message = ClientLogoutAckMessage::from(buffer);
+    assert_eq!(message.message_type(), &MessageType::ClientLogoutAck);
+    assert_eq!(message.client_id(), 100);
+}
+
+#[test]
+fn test_display() {
+    let client_id = 100;
+
+    let actual = ClientLogoutAckMessage::new(client_id);
+    let expected = "ClientLogoutAckMessage { client_id: 100 }";
+
+    assert_eq!(format!("{}", actual), expected);
+}
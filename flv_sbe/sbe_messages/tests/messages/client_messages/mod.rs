@@ -1,2 +1,5 @@
+mod client_heartbeat;
 mod client_login;
+mod client_login_ack;
 mod client_logout;
+mod client_logout_ack;
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod continue_data_message_tests;
@@ -1,7 +1,11 @@
+mod continue_data;
 mod ohlcv_bar;
 mod ohlcv_bar_first;
 mod ohlcv_bar_last;
+mod pause_data;
+mod resume_data;
 mod start_data;
+mod start_multi_data;
 mod stop_all_data;
 mod stop_data;
 mod trade_bar;
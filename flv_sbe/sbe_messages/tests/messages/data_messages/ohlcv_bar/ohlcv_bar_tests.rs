@@ -1,5 +1,9 @@
+use chrono::Utc;
 use common::prelude::OHLCVBar;
+use proptest::prelude::*;
+use rust_decimal::Decimal;
 use sbe_messages::prelude::SbeOHLCVBar;
+use std::str::FromStr;
 
 #[test]
 fn test_encode_data_bar_message() {
@@ -10,7 +14,7 @@ fn test_encode_data_bar_message() {
     assert!(result.is_ok()); // Assert encode passes
 
     let (size, encoded) = result.unwrap();
-    assert_eq!(size, 40); // Assert encoded message size matches expected
+    assert_eq!(size, 136); // Assert encoded message size matches expected
     assert!(!encoded.is_empty()); // Assert non-empty encoded message
 }
 
@@ -19,7 +23,7 @@ fn test_decode_data_bar_message() {
     // Encode a sample DataBar
     let bar = OHLCVBar::default();
     let (size, encoded) = SbeOHLCVBar::encode(bar.clone()).unwrap();
-    assert_eq!(size, 40); // Assert encoded message size matches expected
+    assert_eq!(size, 136); // Assert encoded message size matches expected
     assert!(!encoded.is_empty()); // Assert non-empty encoded message
 
     // Decode the encoded message
@@ -42,3 +46,175 @@ fn test_decode_data_bar_message() {
     assert_eq!(decoded_bar.close(), original_bar.close());
     assert_eq!(decoded_bar.volume(), original_bar.volume());
 }
+
+#[test]
+fn test_encode_decode_sequence_number() {
+    let bar = OHLCVBar::default().with_sequence_number(7);
+
+    let (size, encoded) = SbeOHLCVBar::encode(bar).unwrap();
+    assert_eq!(size, 136);
+
+    let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.sequence_number(), 7);
+}
+
+#[test]
+fn test_encode_decode_vwap_and_trade_count() {
+    let bar = OHLCVBar::default()
+        .with_vwap(Decimal::new(1005, 1))
+        .with_trade_count(42);
+
+    let (_, encoded) = SbeOHLCVBar::encode(bar).unwrap();
+
+    let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.vwap(), Some(Decimal::new(1005, 1)));
+    assert_eq!(decoded.trade_count(), Some(42));
+    assert_eq!(decoded.buy_volume(), None);
+    assert_eq!(decoded.sell_volume(), None);
+}
+
+#[test]
+fn test_encode_decode_buy_sell_volume() {
+    let bar = OHLCVBar::default().with_buy_sell_volume(Decimal::new(15, 1), Decimal::from(2));
+
+    let (_, encoded) = SbeOHLCVBar::encode(bar).unwrap();
+
+    let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.buy_volume(), Some(Decimal::new(15, 1)));
+    assert_eq!(decoded.sell_volume(), Some(Decimal::from(2)));
+}
+
+#[test]
+fn test_decode_version_1_data_bar_message() {
+    let bar = OHLCVBar::default()
+        .with_sequence_number(3)
+        .with_vwap(Decimal::from(5))
+        .with_trade_count(8);
+    let (_, mut encoded) = SbeOHLCVBar::encode(bar).unwrap();
+
+    // A version 1 message has a block length of 40 bytes without the fields of version 2.
+    encoded[0] = 40;
+    encoded[6] = 1;
+    encoded.truncate(48);
+
+    let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.sequence_number(), 3);
+    assert_eq!(decoded.vwap(), None);
+    assert_eq!(decoded.trade_count(), None);
+    assert_eq!(decoded.buy_volume(), None);
+    assert_eq!(decoded.sell_volume(), None);
+}
+
+#[test]
+fn test_decode_version_2_data_bar_message() {
+    let bar = OHLCVBar::default()
+        .with_sequence_number(3)
+        .with_vwap(Decimal::from(5))
+        .with_trade_count(8)
+        .with_buy_sell_volume(Decimal::from(1), Decimal::from(2));
+    let (_, mut encoded) = SbeOHLCVBar::encode(bar).unwrap();
+
+    // A version 2 message has a block length of 56 bytes without the decimals of version 3.
+    encoded[0] = 56;
+    encoded[6] = 2;
+    encoded.truncate(64);
+
+    let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.sequence_number(), 3);
+    assert_eq!(decoded.vwap(), Some(Decimal::from(5)));
+    assert_eq!(decoded.trade_count(), Some(8));
+    assert_eq!(decoded.buy_volume(), Some(Decimal::from(1)));
+    assert_eq!(decoded.sell_volume(), Some(Decimal::from(2)));
+}
+
+#[test]
+fn test_encode_decode_exact_prices() {
+    // Neither a high price nor a tiny price survives a round trip through f32.
+    let open = Decimal::from_str("68123.45678901").unwrap();
+    let high = Decimal::from_str("68999.99999999").unwrap();
+    let low = Decimal::from_str("0.00000123").unwrap();
+    let close = Decimal::from_str("0.000000012345").unwrap();
+    let volume = Decimal::from_str("1234567.891").unwrap();
+    let vwap = Decimal::from(1) / Decimal::from(3);
+    let bar = OHLCVBar::new(1, Utc::now(), open, high, low, close, volume)
+        .with_vwap(vwap)
+        .with_buy_sell_volume(Decimal::from_str("0.1").unwrap(), Decimal::ZERO);
+
+    let (_, encoded) = SbeOHLCVBar::encode(bar).unwrap();
+
+    let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.open(), open);
+    assert_eq!(decoded.high(), high);
+    assert_eq!(decoded.low(), low);
+    assert_eq!(decoded.close(), close);
+    assert_eq!(decoded.volume(), volume);
+    // The vwap of a division is rounded to the digits of an i64 mantissa.
+    assert_eq!(
+        decoded.vwap(),
+        Some(Decimal::from_str("0.3333333333333333333").unwrap())
+    );
+    assert_eq!(
+        decoded.buy_volume(),
+        Some(Decimal::from_str("0.1").unwrap())
+    );
+    assert_eq!(decoded.sell_volume(), Some(Decimal::ZERO));
+}
+
+#[test]
+fn test_decode_nan_floats_with_exact_decimals() {
+    let bar = OHLCVBar::default()
+        .with_vwap(Decimal::from(5))
+        .with_buy_sell_volume(Decimal::from(1), Decimal::from(2));
+    let (_, mut encoded) = SbeOHLCVBar::encode(bar.clone()).unwrap();
+
+    // The f32 prices and volume are NaN, but the exact decimals carry the values.
+    for offset in (20..40).step_by(4) {
+        encoded[offset..offset + 4].copy_from_slice(&f32::NAN.to_le_bytes());
+    }
+
+    let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.open(), bar.open());
+    assert_eq!(decoded.high(), bar.high());
+    assert_eq!(decoded.low(), bar.low());
+    assert_eq!(decoded.close(), bar.close());
+    assert_eq!(decoded.volume(), bar.volume());
+    assert_eq!(decoded.vwap(), Some(Decimal::from(5)));
+}
+
+#[test]
+fn test_decode_version_2_nan_price() {
+    let (_, mut encoded) = SbeOHLCVBar::encode(OHLCVBar::default()).unwrap();
+
+    encoded[0] = 56;
+    encoded[6] = 2;
+    encoded.truncate(64);
+    encoded[32..36].copy_from_slice(&f32::INFINITY.to_le_bytes());
+
+    assert!(SbeOHLCVBar::decode(&encoded).is_err());
+}
+
+proptest! {
+    #[test]
+    fn test_encode_decode_random_prices(
+        prices in proptest::array::uniform5((0i64..=i64::MAX, 0u32..=28)),
+        vwap in proptest::option::of((0i64..=i64::MAX, 0u32..=28)),
+    ) {
+        let [open, high, low, close, volume] = prices.map(|(m, s)| Decimal::new(m, s));
+        let vwap = vwap.map(|(m, s)| Decimal::new(m, s));
+
+        let mut bar = OHLCVBar::new(1, Utc::now(), open, high, low, close, volume);
+        if let Some(vwap) = vwap {
+            bar = bar.with_vwap(vwap);
+        }
+
+        let (_, encoded) = SbeOHLCVBar::encode(bar).unwrap();
+
+        let decoded = SbeOHLCVBar::decode(&encoded).unwrap();
+        prop_assert_eq!(decoded.open(), open);
+        prop_assert_eq!(decoded.high(), high);
+        prop_assert_eq!(decoded.low(), low);
+        prop_assert_eq!(decoded.close(), close);
+        prop_assert_eq!(decoded.volume(), volume);
+        prop_assert_eq!(decoded.vwap(), vwap);
+    }
+}
@@ -25,7 +25,7 @@ fn test_encode() {
     let (limit, buffer) = enc.unwrap();
     assert_eq!(limit, 12);
 
-    let expected: Vec<u8> = vec![4, 0, 205, 0, 1, 0, 1, 0, 205, 0, 42, 0];
+    let expected: Vec<u8> = vec![4, 0, 205, 0, 1, 0, 6, 0, 205, 0, 42, 0];
     let actual = buffer;
 
     assert_eq!(expected, actual);
@@ -33,7 +33,7 @@ fn test_encode() {
 
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![4, 0, 205, 0, 1, 0, 1, 0, 205, 0, 42, 0];
+    let encoded: Vec<u8> = vec![4, 0, 205, 0, 1, 0, 6, 0, 205, 0, 42, 0];
     let buffer = encoded.as_slice();
 
     let message = FirstOHLCVBar::from(buffer);
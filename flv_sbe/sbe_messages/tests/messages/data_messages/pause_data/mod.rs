@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod pause_data_message_tests;
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod resume_data_message_tests;
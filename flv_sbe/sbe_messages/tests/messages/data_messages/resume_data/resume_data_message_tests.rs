@@ -0,0 +1,153 @@
+use chrono::{TimeZone, Utc};
+use common::prelude::{ExchangeID, TimeResolution};
+use sbe_messages::prelude::{DataType, MessageType, ReplayMode, ResumeDataMessage, SbeDecodeError};
+
+fn get_message() -> ResumeDataMessage {
+    ResumeDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::NoValue,
+        DataType::TradeData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+        Some(258),
+        None,
+    )
+}
+
+#[test]
+fn test_new() {
+    let message = get_message();
+
+    assert_eq!(message.message_type(), &MessageType::ResumeData);
+    assert_eq!(message.client_id(), &1);
+    assert_eq!(message.exchange_id(), &ExchangeID::Kraken);
+    assert_eq!(message.symbol_id(), &1);
+    assert_eq!(message.time_resolution(), &TimeResolution::NoValue);
+    assert_eq!(message.data_type_id(), &DataType::TradeData);
+    assert_eq!(message.start_time(), None);
+    assert_eq!(message.end_time(), None);
+    assert_eq!(message.replay_mode(), &ReplayMode::Unthrottled);
+    assert_eq!(message.replay_speed(), &0);
+    assert_eq!(message.resume_sequence_number(), Some(258));
+    assert_eq!(message.resume_time(), None);
+    assert_eq!(message.threshold(), 0.0);
+}
+
+#[test]
+fn test_encode() {
+    let message = get_message();
+
+    let enc = message.encode();
+    assert!(enc.is_ok());
+
+    let (limit, buffer) = enc.unwrap();
+    assert_eq!(limit, 64);
+
+    let expected: Vec<u8> = vec![
+        56, 0, 213, 0, 1, 0, 6, 0, 213, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 255, 255, 255, 255,
+        255, 255, 255, 127, 255, 255, 255, 255, 255, 255, 255, 127, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+        0, 255, 255, 255, 255, 255, 255, 255, 127, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let actual = buffer;
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_decode() {
+    let encoded: Vec<u8> = vec![
+        56, 0, 213, 0, 1, 0, 6, 0, 213, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 255, 255, 255, 255,
+        255, 255, 255, 127, 255, 255, 255, 255, 255, 255, 255, 127, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+        0, 255, 255, 255, 255, 255, 255, 255, 127, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let buffer = encoded.as_slice();
+
+    let message = ResumeDataMessage::try_from(buffer).expect("Failed to decode message");
+
+    assert_eq!(message, get_message());
+}
+
+#[test]
+fn test_encode_decode_resume_time() {
+    let start_time = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+    let end_time = Utc.with_ymd_and_hms(2022, 2, 1, 0, 0, 0).unwrap();
+    let resume_time = Utc.with_ymd_and_hms(2022, 1, 15, 12, 0, 0).unwrap();
+
+    let message = ResumeDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::OneMin,
+        DataType::OHLCVData,
+        Some(start_time),
+        Some(end_time),
+        ReplayMode::Scaled,
+        10,
+        None,
+        Some(resume_time),
+    );
+
+    let (limit, buffer) = message.encode().expect("Failed to encode message");
+    assert_eq!(limit, 64);
+
+    let decoded = ResumeDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.resume_sequence_number(), None);
+    assert_eq!(decoded.resume_time(), Some(resume_time));
+}
+
+#[test]
+fn test_encode_decode_threshold() {
+    let message = ResumeDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::NoValue,
+        DataType::TickBarData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+        Some(12),
+        None,
+    )
+    .with_threshold(500.0);
+
+    let (_, buffer) = message.encode().expect("Failed to encode message");
+
+    let decoded = ResumeDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.threshold(), 500.0);
+}
+
+#[test]
+fn test_decode_resume_time_out_of_range() {
+    let (_, mut buffer) = get_message().encode().expect("Failed to encode message");
+    // The resume time follows the header and the first 40 bytes of the block.
+    buffer[48..56].copy_from_slice(&(i64::MAX - 1).to_le_bytes());
+
+    let result = ResumeDataMessage::try_from(buffer.as_slice());
+    assert!(matches!(result, Err(SbeDecodeError::DecodeError(_))));
+}
+
+#[test]
+fn test_decode_truncated_block() {
+    let (_, buffer) = get_message().encode().expect("Failed to encode message");
+
+    let result = ResumeDataMessage::try_from(&buffer[..20]);
+    assert!(matches!(result, Err(SbeDecodeError::BufferTooShort { .. })));
+}
+
+#[test]
+fn test_display() {
+    let message = get_message();
+
+    let expected = "ResumeDataMessage[message_type: ResumeData, client_id: 1, exchange_id: Kraken, symbol_id: 1 time_resolution: NoValue data_type: TradeData start_time: None end_time: None replay_mode: Unthrottled replay_speed: 0 resume_sequence_number: Some(258) resume_time: None threshold: 0]";
+    let actual = format!("{}", message);
+
+    assert_eq!(expected, actual);
+}
@@ -1,5 +1,7 @@
-use common::prelude::{ExchangeID, TimeResolution};
-use sbe_messages::prelude::{DataType, MessageType, StartDataMessage};
+use chrono::{TimeZone, Utc};
+use common::prelude::{BarThreshold, ExchangeID, TimeResolution, TimeScale};
+use rust_decimal::Decimal;
+use sbe_messages::prelude::{DataType, MessageType, ReplayMode, SbeDecodeError, StartDataMessage};
 
 fn get_message() -> StartDataMessage {
     let client_id = 1;
@@ -13,6 +15,10 @@ fn get_message() -> StartDataMessage {
         symbol_id,
         time_resolution,
         data_type,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
     )
 }
 
@@ -53,9 +59,13 @@ fn test_encode() {
     assert!(enc.is_ok());
 
     let (limit, buffer) = enc.unwrap();
-    assert_eq!(limit, 17);
+    assert_eq!(limit, 48);
 
-    let expected: Vec<u8> = vec![9, 0, 201, 0, 1, 0, 1, 0, 201, 0, 1, 0, 1, 1, 0, 0, 1];
+    let expected: Vec<u8> = vec![
+        40, 0, 201, 0, 1, 0, 6, 0, 201, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 255, 255, 255, 255,
+        255, 255, 255, 127, 255, 255, 255, 255, 255, 255, 255, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0,
+    ];
     let actual = buffer;
 
     assert_eq!(expected, actual);
@@ -63,10 +73,14 @@ fn test_encode() {
 
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![9, 0, 201, 0, 1, 0, 1, 0, 201, 0, 1, 0, 1, 1, 0, 0, 1];
+    let encoded: Vec<u8> = vec![
+        40, 0, 201, 0, 1, 0, 6, 0, 201, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 255, 255, 255, 255,
+        255, 255, 255, 127, 255, 255, 255, 255, 255, 255, 255, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0,
+    ];
     let buffer = encoded.as_slice();
 
-    let message = StartDataMessage::from(buffer);
+    let message = StartDataMessage::try_from(buffer).expect("Failed to decode message");
 
     let exchange_id = ExchangeID::Kraken;
     let symbol_id = 1;
@@ -79,6 +93,286 @@ fn test_decode() {
     assert_eq!(message.symbol_id(), &symbol_id);
     assert_eq!(message.time_resolution(), &time_resolution);
     assert_eq!(message.data_type_id(), &data_type);
+    assert_eq!(message.start_time(), None);
+    assert_eq!(message.end_time(), None);
+    assert_eq!(message.replay_mode(), &ReplayMode::Unthrottled);
+    assert_eq!(message.replay_speed(), &0);
+    assert_eq!(message.threshold(), 0.0);
+}
+
+#[test]
+fn test_encode_decode_replay_mode() {
+    let message = StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::NoValue,
+        DataType::TradeData,
+        None,
+        None,
+        ReplayMode::Scaled,
+        100,
+    );
+
+    let (limit, buffer) = message.encode().expect("Failed to encode message");
+    assert_eq!(limit, 48);
+
+    let decoded = StartDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.replay_mode(), &ReplayMode::Scaled);
+    assert_eq!(decoded.replay_speed(), &100);
+}
+
+#[test]
+fn test_encode_decode_time_range() {
+    let start_time = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+    let end_time = Utc.with_ymd_and_hms(2022, 2, 1, 0, 0, 0).unwrap();
+
+    let message = StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::OneMin,
+        DataType::OHLCVData,
+        Some(start_time),
+        Some(end_time),
+        ReplayMode::Unthrottled,
+        0,
+    );
+
+    let (limit, buffer) = message.encode().expect("Failed to encode message");
+    assert_eq!(limit, 48);
+
+    let decoded = StartDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.start_time(), Some(start_time));
+    assert_eq!(decoded.end_time(), Some(end_time));
+}
+
+#[test]
+fn test_encode_decode_custom_time_resolution() {
+    let time_resolution = TimeResolution::new(3, TimeScale::Minute).unwrap();
+
+    let message = StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        time_resolution,
+        DataType::OHLCVData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    );
+
+    let (limit, buffer) = message.encode().expect("Failed to encode message");
+    assert_eq!(limit, 48);
+
+    // The time resolution is encoded as count and time scale.
+    assert_eq!(&buffer[15..20], &[3, 0, 0, 0, TimeScale::Minute as u8]);
+
+    let decoded = StartDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.time_resolution(), &time_resolution);
+}
+
+#[test]
+fn test_encode_decode_sub_second_time_resolution() {
+    let time_resolution = TimeResolution::new(250, TimeScale::Millisecond).unwrap();
+
+    let message = StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        time_resolution,
+        DataType::OHLCVData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    );
+
+    let (_, buffer) = message.encode().expect("Failed to encode message");
+
+    let decoded = StartDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded.time_resolution().count(), 250);
+    assert_eq!(decoded.time_resolution().scale(), TimeScale::Millisecond);
+}
+
+#[test]
+fn test_encode_decode_threshold() {
+    let message = StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::NoValue,
+        DataType::DollarBarData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    )
+    .with_threshold(250_000.5);
+
+    let (limit, buffer) = message.encode().expect("Failed to encode message");
+    assert_eq!(limit, 48);
+
+    // The threshold is the last field of the message block.
+    assert_eq!(&buffer[40..48], &250_000.5_f64.to_le_bytes());
+
+    let decoded = StartDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.data_type_id(), &DataType::DollarBarData);
+    assert_eq!(decoded.threshold(), 250_000.5);
+}
+
+fn get_bar_message(data_type: DataType, threshold: f64) -> StartDataMessage {
+    StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::NoValue,
+        data_type,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    )
+    .with_threshold(threshold)
+}
+
+#[test]
+fn test_bar_threshold() {
+    let message = get_bar_message(DataType::TickBarData, 100.0);
+    assert_eq!(message.bar_threshold(), Some(BarThreshold::Ticks(100)));
+
+    let message = get_bar_message(DataType::VolumeBarData, 2.5);
+    assert_eq!(
+        message.bar_threshold(),
+        Some(BarThreshold::Volume(Decimal::new(25, 1)))
+    );
+
+    let message = get_bar_message(DataType::DollarBarData, 1_000_000.0);
+    assert_eq!(
+        message.bar_threshold(),
+        Some(BarThreshold::Dollar(Decimal::from(1_000_000)))
+    );
+}
+
+#[test]
+fn test_bar_threshold_not_representable() {
+    // Tick thresholds must be whole, non-negative numbers
+    assert_eq!(
+        get_bar_message(DataType::TickBarData, 2.5).bar_threshold(),
+        None
+    );
+    assert_eq!(
+        get_bar_message(DataType::TickBarData, -1.0).bar_threshold(),
+        None
+    );
+    assert_eq!(
+        get_bar_message(DataType::VolumeBarData, f64::NAN).bar_threshold(),
+        None
+    );
+
+    // Only information bar data types have a bar threshold
+    assert_eq!(
+        get_bar_message(DataType::OHLCVData, 100.0).bar_threshold(),
+        None
+    );
+    assert_eq!(get_message().bar_threshold(), None);
+}
+
+#[test]
+fn test_open_ended_time_range() {
+    let start_time = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+
+    let message = StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::NoValue,
+        DataType::TradeData,
+        Some(start_time),
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    );
+
+    let (_, buffer) = message.encode().expect("Failed to encode message");
+
+    let decoded = StartDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded.start_time(), Some(start_time));
+    assert_eq!(decoded.end_time(), None);
+}
+
+#[test]
+fn test_decode_start_time_out_of_range() {
+    let message = get_message();
+    let (_, mut buffer) = message.encode().expect("Failed to encode message");
+
+    // The start time follows the header and the first 13 bytes of the block.
+    let start_time = i64::MAX - 1;
+    buffer[21..29].copy_from_slice(&start_time.to_le_bytes());
+
+    let result = StartDataMessage::try_from(buffer.as_slice());
+    assert!(matches!(result, Err(SbeDecodeError::DecodeError(_))));
+}
+
+#[test]
+fn test_decode_end_time_out_of_range() {
+    let message = get_message();
+    let (_, mut buffer) = message.encode().expect("Failed to encode message");
+
+    let end_time = i64::MIN;
+    buffer[29..37].copy_from_slice(&end_time.to_le_bytes());
+
+    let result = StartDataMessage::try_from(buffer.as_slice());
+    assert!(matches!(result, Err(SbeDecodeError::DecodeError(_))));
+}
+
+#[test]
+fn test_decode_version_1() {
+    // A version 1 message has no time range, replay mode and speed, and threshold.
+    let message = StartDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        1,
+        TimeResolution::OneMin,
+        DataType::OHLCVData,
+        None,
+        None,
+        ReplayMode::Scaled,
+        10,
+    )
+    .with_threshold(5.0);
+    let (_, mut buffer) = message.encode().expect("Failed to encode message");
+    buffer[0] = 13;
+    buffer[6] = 1;
+    buffer.truncate(8 + 13);
+
+    let decoded = StartDataMessage::try_from(buffer.as_slice()).expect("Failed to decode message");
+    assert_eq!(decoded.time_resolution(), &TimeResolution::OneMin);
+    assert_eq!(decoded.data_type_id(), &DataType::OHLCVData);
+    assert_eq!(decoded.start_time(), None);
+    assert_eq!(decoded.replay_mode(), &ReplayMode::Unthrottled);
+    assert_eq!(decoded.replay_speed(), &0);
+    assert_eq!(decoded.threshold(), 0.0);
+}
+
+#[test]
+fn test_decode_short_block() {
+    let message = get_message();
+    let (_, mut buffer) = message.encode().expect("Failed to encode message");
+    buffer[0] = 2;
+    buffer[6] = 1;
+    buffer.truncate(10);
+
+    let result = StartDataMessage::try_from(buffer.as_slice());
+    assert!(matches!(
+        result,
+        Err(SbeDecodeError::InvalidBlockLength { actual: 2, .. })
+    ));
 }
 
 #[test]
@@ -124,13 +418,18 @@ fn test_display() {
     let message = get_message();
 
     let expected = format!(
-        "StartDataMessage[message_type: {}, client_id: {}, exchange_id: {}, symbol_id: {} time_resolution: {} data_type: {}]",
+        "StartDataMessage[message_type: {}, client_id: {}, exchange_id: {}, symbol_id: {} time_resolution: {} data_type: {} start_time: {:?} end_time: {:?} replay_mode: {} replay_speed: {} threshold: {}]",
         message.message_type(),
         message.client_id(),
         message.exchange_id(),
         message.symbol_id(),
         message.time_resolution(),
-        message.data_type_id()
+        message.data_type_id(),
+        message.start_time(),
+        message.end_time(),
+        message.replay_mode(),
+        message.replay_speed(),
+        message.threshold()
     );
 
     let actual = format!("{}", message);
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod start_multi_data_message_tests;
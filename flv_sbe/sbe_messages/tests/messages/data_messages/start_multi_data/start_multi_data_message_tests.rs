@@ -0,0 +1,204 @@
+use chrono::{TimeZone, Utc};
+use common::prelude::{ExchangeID, TimeResolution};
+use sbe_messages::prelude::{
+    DataType, MessageType, ReplayMode, SbeDecodeError, StartMultiDataMessage,
+};
+
+fn get_message() -> StartMultiDataMessage {
+    let client_id = 1;
+    let exchange_id = ExchangeID::Kraken;
+    let symbol_ids = vec![1, 2];
+    let data_type = DataType::TradeData;
+    let time_resolution = TimeResolution::NoValue; // TimeResolution only applies to OHLCV data type
+    StartMultiDataMessage::new(
+        client_id,
+        exchange_id,
+        symbol_ids,
+        time_resolution,
+        data_type,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    )
+}
+
+fn get_encoded() -> Vec<u8> {
+    vec![
+        30, 0, 212, 0, 1, 0, 6, 0, 212, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 255, 255, 255, 255, 255, 255,
+        255, 127, 255, 255, 255, 255, 255, 255, 255, 127, 0, 0, 0, 2, 0, 2, 0, 1, 0, 2, 0,
+    ]
+}
+
+#[test]
+fn test_new() {
+    let message = get_message();
+
+    assert_eq!(message.message_type(), &MessageType::StartMultiData);
+    assert_eq!(message.client_id(), &1);
+    assert_eq!(message.exchange_id(), &ExchangeID::Kraken);
+    assert_eq!(message.symbol_ids(), &[1, 2]);
+    assert_eq!(message.time_resolution(), &TimeResolution::NoValue);
+    assert_eq!(message.data_type_id(), &DataType::TradeData);
+    assert_eq!(message.start_time(), None);
+    assert_eq!(message.end_time(), None);
+    assert_eq!(message.replay_mode(), &ReplayMode::Unthrottled);
+    assert_eq!(message.replay_speed(), &0);
+}
+
+#[test]
+fn test_encode() {
+    let message = get_message();
+
+    let enc = message.encode();
+    assert!(enc.is_ok());
+
+    let (limit, buffer) = enc.unwrap();
+    assert_eq!(limit, 46);
+
+    let expected = get_encoded();
+    let actual = buffer;
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_decode() {
+    let encoded = get_encoded();
+    let buffer = encoded.as_slice();
+
+    let message = StartMultiDataMessage::try_from(buffer).expect("Failed to decode message");
+
+    assert_eq!(message.message_type(), &MessageType::StartMultiData);
+    assert_eq!(message.client_id(), &1);
+    assert_eq!(message.exchange_id(), &ExchangeID::Kraken);
+    assert_eq!(message.symbol_ids(), &[1, 2]);
+    assert_eq!(message.time_resolution(), &TimeResolution::NoValue);
+    assert_eq!(message.data_type_id(), &DataType::TradeData);
+    assert_eq!(message.start_time(), None);
+    assert_eq!(message.end_time(), None);
+    assert_eq!(message.replay_mode(), &ReplayMode::Unthrottled);
+    assert_eq!(message.replay_speed(), &0);
+}
+
+#[test]
+fn test_encode_decode_time_range() {
+    let start_time = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+    let end_time = Utc.with_ymd_and_hms(2022, 2, 1, 0, 0, 0).unwrap();
+
+    let message = StartMultiDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        vec![3, 1, 2, 7],
+        TimeResolution::OneMin,
+        DataType::OHLCVData,
+        Some(start_time),
+        Some(end_time),
+        ReplayMode::Scaled,
+        100,
+    );
+
+    let (limit, buffer) = message.encode().expect("Failed to encode message");
+    assert_eq!(limit, 50);
+
+    let decoded = StartMultiDataMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.symbol_ids(), &[3, 1, 2, 7]);
+}
+
+#[test]
+fn test_encode_decode_no_symbols() {
+    let message = StartMultiDataMessage::new(
+        1,
+        ExchangeID::Kraken,
+        vec![],
+        TimeResolution::NoValue,
+        DataType::TradeData,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    );
+
+    let (limit, buffer) = message.encode().expect("Failed to encode message");
+    assert_eq!(limit, 42);
+
+    let decoded = StartMultiDataMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded, message);
+    assert!(decoded.symbol_ids().is_empty());
+}
+
+#[test]
+fn test_decode_missing_symbols() {
+    let mut encoded = get_encoded();
+    // Drop the last symbol entry
+    encoded.truncate(44);
+
+    let expected = SbeDecodeError::BufferTooShort {
+        expected: 46,
+        actual: 44,
+    };
+    assert_eq!(
+        StartMultiDataMessage::try_from(encoded.as_slice()),
+        Err(expected)
+    );
+}
+
+#[test]
+fn test_decode_missing_group_header() {
+    let mut encoded = get_encoded();
+    encoded.truncate(38);
+
+    let expected = SbeDecodeError::BufferTooShort {
+        expected: 42,
+        actual: 38,
+    };
+    assert_eq!(
+        StartMultiDataMessage::try_from(encoded.as_slice()),
+        Err(expected)
+    );
+}
+
+#[test]
+fn test_decode_invalid_group_block_length() {
+    let mut encoded = get_encoded();
+    encoded[38] = 4;
+
+    let expected = SbeDecodeError::InvalidBlockLength {
+        expected: 2,
+        actual: 4,
+    };
+    assert_eq!(
+        StartMultiDataMessage::try_from(encoded.as_slice()),
+        Err(expected)
+    );
+}
+
+#[test]
+fn test_decode_start_time_out_of_range() {
+    let mut encoded = get_encoded();
+    // The start time follows the header and the first 11 bytes of the block.
+    encoded[19..27].copy_from_slice(&(i64::MAX - 1).to_le_bytes());
+
+    let result = StartMultiDataMessage::try_from(encoded.as_slice());
+    assert!(matches!(result, Err(SbeDecodeError::DecodeError(_))));
+}
+
+#[test]
+fn test_decode_end_time_out_of_range() {
+    let mut encoded = get_encoded();
+    encoded[27..35].copy_from_slice(&i64::MIN.to_le_bytes());
+
+    let result = StartMultiDataMessage::try_from(encoded.as_slice());
+    assert!(matches!(result, Err(SbeDecodeError::DecodeError(_))));
+}
+
+#[test]
+fn test_display() {
+    let message = get_message();
+
+    let expected = "StartMultiDataMessage[message_type: StartMultiData, client_id: 1, exchange_id: Kraken, symbol_ids: [1, 2] time_resolution: NoValue data_type: TradeData start_time: None end_time: None replay_mode: Unthrottled replay_speed: 0]";
+    let actual = format!("{}", message);
+
+    assert_eq!(expected, actual);
+}
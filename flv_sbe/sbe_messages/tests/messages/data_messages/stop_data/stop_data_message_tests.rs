@@ -45,14 +45,14 @@ fn test_encode() {
     let (limit, buffer) = enc.unwrap();
     assert_eq!(limit, 16);
 
-    let expected: Vec<u8> = vec![8, 0, 202, 0, 1, 0, 1, 0, 202, 0, 1, 0, 1, 1, 0, 1];
+    let expected: Vec<u8> = vec![8, 0, 202, 0, 1, 0, 6, 0, 202, 0, 1, 0, 1, 1, 0, 1];
     let actual = buffer;
     assert_eq!(expected, actual);
 }
 
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![8, 0, 202, 0, 1, 0, 1, 0, 202, 0, 1, 0, 1, 1, 0, 1];
+    let encoded: Vec<u8> = vec![8, 0, 202, 0, 1, 0, 6, 0, 202, 0, 1, 0, 1, 1, 0, 1];
     let buffer = encoded.as_slice();
 
     let message = StopDataMessage::from(buffer);
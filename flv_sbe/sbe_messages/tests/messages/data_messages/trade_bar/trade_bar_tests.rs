@@ -1,7 +1,9 @@
 use chrono::{DateTime, Utc};
 use common::prelude::TradeBar;
+use proptest::prelude::*;
 use rust_decimal::Decimal;
 use sbe_messages::prelude::SbeTradeBar;
+use std::str::FromStr;
 
 // Default uses utc::now() for date_time, which is not deterministic,
 // and that would cause the encode test to fail. Therefore we use a fixed date.
@@ -33,13 +35,14 @@ fn test_encode_data_bar_message() {
     assert!(result.is_ok()); // Assert encode passes
 
     let (size, encoded) = result.unwrap();
-    assert_eq!(size, 28); // Assert encoded message size matches expected
+    assert_eq!(size, 54); // Assert encoded message size matches expected
     assert!(!encoded.is_empty()); // Assert non-empty encoded message
 
     let actual = encoded;
     let expected: Vec<u8> = vec![
-        20, 0, 207, 0, 1, 0, 1, 0, 207, 0, 1, 0, 64, 22, 164, 168, 122, 220, 5, 0, 0, 0, 200, 66,
-        0, 0, 200, 66,
+        46, 0, 207, 0, 1, 0, 6, 0, 207, 0, 1, 0, 64, 22, 164, 168, 122, 220, 5, 0, 0, 0, 200, 66,
+        0, 0, 200, 66, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0,
+        0, 0,
     ];
 
     assert_eq!(expected, actual);
@@ -48,8 +51,9 @@ fn test_encode_data_bar_message() {
 #[test]
 fn test_decode_trade_bar_message() {
     let encoded: Vec<u8> = vec![
-        20, 0, 207, 0, 1, 0, 1, 0, 207, 0, 1, 0, 64, 22, 164, 168, 122, 220, 5, 0, 0, 0, 200, 66,
-        0, 0, 200, 66,
+        46, 0, 207, 0, 1, 0, 6, 0, 207, 0, 1, 0, 64, 22, 164, 168, 122, 220, 5, 0, 0, 0, 200, 66,
+        0, 0, 200, 66, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0,
+        0, 0,
     ];
 
     let message = SbeTradeBar::decode(&encoded).unwrap();
@@ -58,3 +62,100 @@ fn test_decode_trade_bar_message() {
     assert_eq!(message.price(), Decimal::from(100));
     assert_eq!(message.volume(), Decimal::from(100));
 }
+
+#[test]
+fn test_encode_decode_sequence_number() {
+    let bar = get_trade_bar().with_sequence_number(258);
+
+    let (size, encoded) = SbeTradeBar::encode(bar.clone()).unwrap();
+    assert_eq!(size, 54);
+    assert_eq!(&encoded[28..36], &[2, 1, 0, 0, 0, 0, 0, 0]);
+
+    let decoded = SbeTradeBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.sequence_number(), 258);
+    assert_eq!(decoded, bar);
+}
+
+#[test]
+fn test_decode_version_2_trade_bar_message() {
+    // A version 2 message has no exact decimals and decodes the f32 values.
+    let encoded: Vec<u8> = vec![
+        28, 0, 207, 0, 1, 0, 2, 0, 207, 0, 1, 0, 64, 22, 164, 168, 122, 220, 5, 0, 0, 0, 200, 66,
+        0, 0, 200, 66, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+
+    let message = SbeTradeBar::decode(&encoded).unwrap();
+
+    assert_eq!(message.symbol_id(), 1);
+    assert_eq!(message.price(), Decimal::from(100));
+    assert_eq!(message.volume(), Decimal::from(100));
+}
+
+#[test]
+fn test_encode_decode_exact_price_and_volume() {
+    // Neither a high price nor a tiny price survives a round trip through f32.
+    let price = Decimal::from_str("68123.45678901").unwrap();
+    let volume = Decimal::from_str("0.00000123").unwrap();
+    let bar = TradeBar::new(1, get_trade_bar().date_time(), price, volume);
+
+    let (_, encoded) = SbeTradeBar::encode(bar).unwrap();
+
+    let decoded = SbeTradeBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.price(), price);
+    assert_eq!(decoded.volume(), volume);
+}
+
+#[test]
+fn test_decode_nan_floats_with_exact_decimals() {
+    let price = Decimal::from_str("68123.45678901").unwrap();
+    let volume = Decimal::from_str("0.5").unwrap();
+    let bar = TradeBar::new(1, get_trade_bar().date_time(), price, volume);
+    let (_, mut encoded) = SbeTradeBar::encode(bar).unwrap();
+
+    // The f32 price and volume are NaN, but the exact decimals carry the values.
+    encoded[20..24].copy_from_slice(&f32::NAN.to_le_bytes());
+    encoded[24..28].copy_from_slice(&f32::INFINITY.to_le_bytes());
+
+    let decoded = SbeTradeBar::decode(&encoded).unwrap();
+    assert_eq!(decoded.price(), price);
+    assert_eq!(decoded.volume(), volume);
+}
+
+#[test]
+fn test_decode_version_2_nan_price() {
+    let mut encoded: Vec<u8> = vec![
+        28, 0, 207, 0, 1, 0, 2, 0, 207, 0, 1, 0, 64, 22, 164, 168, 122, 220, 5, 0, 0, 0, 200, 66,
+        0, 0, 200, 66, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    encoded[20..24].copy_from_slice(&f32::NAN.to_le_bytes());
+
+    assert!(SbeTradeBar::decode(&encoded).is_err());
+}
+
+#[test]
+fn test_decode_timestamp_out_of_range() {
+    let (_, mut encoded) = SbeTradeBar::encode(get_trade_bar()).unwrap();
+    encoded[12..20].copy_from_slice(&i64::MAX.to_le_bytes());
+
+    assert!(SbeTradeBar::decode(&encoded).is_err());
+}
+
+proptest! {
+    #[test]
+    fn test_encode_decode_random_price_and_volume(
+        price in 0i64..=i64::MAX,
+        price_scale in 0u32..=28,
+        volume in 0i64..=i64::MAX,
+        volume_scale in 0u32..=28,
+    ) {
+        let price = Decimal::new(price, price_scale);
+        let volume = Decimal::new(volume, volume_scale);
+        let bar = TradeBar::new(1, get_trade_bar().date_time(), price, volume);
+
+        let (_, encoded) = SbeTradeBar::encode(bar).unwrap();
+
+        let decoded = SbeTradeBar::decode(&encoded).unwrap();
+        prop_assert_eq!(decoded.price(), price);
+        prop_assert_eq!(decoded.volume(), volume);
+    }
+}
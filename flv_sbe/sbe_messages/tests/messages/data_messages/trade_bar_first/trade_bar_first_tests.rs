@@ -31,7 +31,7 @@ fn test_encode() {
     let (limit, buffer) = enc.unwrap();
     assert_eq!(limit, 12);
 
-    let expected: Vec<u8> = vec![4, 0, 208, 0, 1, 0, 1, 0, 208, 0, 123, 0];
+    let expected: Vec<u8> = vec![4, 0, 208, 0, 1, 0, 6, 0, 208, 0, 123, 0];
     let actual = buffer;
 
     assert_eq!(expected, actual);
@@ -39,7 +39,7 @@ fn test_encode() {
 
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![4, 0, 208, 0, 1, 0, 1, 0, 208, 0, 123, 0];
+    let encoded: Vec<u8> = vec![4, 0, 208, 0, 1, 0, 6, 0, 208, 0, 123, 0];
     let buffer = encoded.as_slice();
 
     let message = FirstTradeBar::from(buffer);
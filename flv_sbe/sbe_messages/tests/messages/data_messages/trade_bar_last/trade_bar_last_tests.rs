@@ -29,7 +29,7 @@ fn test_encode() {
     let (limit, buffer) = enc.unwrap();
     assert_eq!(limit, 12);
 
-    let expected: Vec<u8> = vec![4, 0, 209, 0, 1, 0, 1, 0, 209, 0, 23, 0];
+    let expected: Vec<u8> = vec![4, 0, 209, 0, 1, 0, 6, 0, 209, 0, 23, 0];
     let actual = buffer;
 
     assert_eq!(expected, actual);
@@ -37,7 +37,7 @@ fn test_encode() {
 
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![4, 0, 209, 0, 1, 0, 1, 0, 209, 0, 23, 0];
+    let encoded: Vec<u8> = vec![4, 0, 209, 0, 1, 0, 6, 0, 209, 0, 23, 0];
     let buffer = encoded.as_slice();
 
     let message = LastTradeBar::from(buffer);
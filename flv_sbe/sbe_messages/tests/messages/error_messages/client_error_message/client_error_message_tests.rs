@@ -27,14 +27,14 @@ fn test_encode() {
     let (limit, buffer) = enc.unwrap();
     assert_eq!(limit, 13);
 
-    let expected: Vec<u8> = vec![5, 0, 33, 3, 1, 0, 1, 0, 33, 3, 1, 0, 1];
+    let expected: Vec<u8> = vec![5, 0, 33, 3, 1, 0, 6, 0, 33, 3, 1, 0, 1];
     let actual = buffer;
     assert_eq!(expected, actual);
 }
 
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![5, 0, 33, 3, 1, 0, 1, 0, 33, 3, 1, 0, 1];
+    let encoded: Vec<u8> = vec![5, 0, 33, 3, 1, 0, 6, 0, 33, 3, 1, 0, 1];
     let buffer = encoded.as_slice();
 
     let message = ClientErrorMessage::from(buffer);
@@ -57,3 +57,12 @@ fn test_display() {
     let actual = format!("{}", message);
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_error() {
+    let message = ClientErrorMessage::new(1, ClientErrorType::ClientAuthenticationError);
+
+    // A rejected request returns the message as error
+    let err: Box<dyn std::error::Error + Send> = Box::new(message.clone());
+    assert_eq!(err.downcast_ref::<ClientErrorMessage>(), Some(&message));
+}
@@ -25,16 +25,30 @@ fn test_encode() {
     assert!(enc.is_ok());
 
     let (limit, buffer) = enc.unwrap();
-    assert_eq!(limit, 13);
+    assert_eq!(limit, 17);
 
-    let expected: Vec<u8> = vec![5, 0, 34, 3, 1, 0, 1, 0, 34, 3, 1, 0, 1];
+    // Without retry-after hint, the field holds the null value
+    let expected: Vec<u8> = vec![9, 0, 34, 3, 1, 0, 6, 0, 34, 3, 1, 0, 1, 255, 255, 255, 255];
     let actual = buffer;
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn test_encode_retry_after() {
+    let message =
+        DataErrorMessage::new(1, DataErrorType::RateLimited).with_retry_after_millis(1500);
+    assert_eq!(message.retry_after_millis(), Some(1500));
+
+    let (limit, buffer) = message.encode().unwrap();
+    assert_eq!(limit, 17);
+
+    let expected: Vec<u8> = vec![9, 0, 34, 3, 1, 0, 6, 0, 34, 3, 1, 0, 10, 220, 5, 0, 0];
+    assert_eq!(expected, buffer);
+}
+
 #[test]
 fn test_decode() {
-    let encoded: Vec<u8> = vec![5, 0, 34, 3, 1, 0, 1, 0, 34, 3, 1, 0, 1];
+    let encoded: Vec<u8> = vec![9, 0, 34, 3, 1, 0, 6, 0, 34, 3, 1, 0, 1, 255, 255, 255, 255];
     let buffer = encoded.as_slice();
     let message = DataErrorMessage::from(buffer);
 
@@ -43,6 +57,30 @@ fn test_decode() {
     assert_eq!(message.message_type(), MessageType::DataError);
     assert_eq!(message.client_id(), client_id);
     assert_eq!(message.data_error_type(), error_type);
+    assert_eq!(message.retry_after_millis(), None);
+}
+
+#[test]
+fn test_decode_retry_after() {
+    let encoded: Vec<u8> = vec![9, 0, 34, 3, 1, 0, 6, 0, 34, 3, 1, 0, 10, 220, 5, 0, 0];
+    let message = DataErrorMessage::from(encoded.as_slice());
+
+    assert_eq!(message.data_error_type(), DataErrorType::RateLimited);
+    assert_eq!(message.retry_after_millis(), Some(1500));
+}
+
+#[test]
+fn test_decode_version_5() {
+    // Messages before schema version 6 have no retry-after hint
+    let encoded: Vec<u8> = vec![5, 0, 34, 3, 1, 0, 5, 0, 34, 3, 1, 0, 1];
+    let message = DataErrorMessage::from(encoded.as_slice());
+
+    assert_eq!(message.client_id(), 1);
+    assert_eq!(
+        message.data_error_type(),
+        DataErrorType::DataTypeNotKnownError
+    );
+    assert_eq!(message.retry_after_millis(), None);
 }
 
 #[test]
@@ -51,8 +89,17 @@ fn test_display() {
     let error_type = DataErrorType::DataTypeNotKnownError;
     let message = DataErrorMessage::new(client_id, error_type);
 
-    let expected = "DataErrorMessage { message_type: DataError, client_id: 1, data_error_type: DataTypeNotKnownError }";
+    let expected = "DataErrorMessage { message_type: DataError, client_id: 1, data_error_type: DataTypeNotKnownError, retry_after_millis: None }";
     let actual = format!("{}", message);
 
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_error() {
+    let message = DataErrorMessage::new(1, DataErrorType::RateLimited).with_retry_after_millis(250);
+
+    // A rejected request returns the message as error
+    let err: Box<dyn std::error::Error + Send> = Box::new(message.clone());
+    assert_eq!(err.downcast_ref::<DataErrorMessage>(), Some(&message));
+}
@@ -1,3 +1,5 @@
+mod decimal;
 mod errors;
+mod frame;
 mod messages;
 mod types;
@@ -15,6 +15,14 @@ fn test_from_u8() {
     assert_eq!(ClientErrorType::from(4), ClientErrorType::ClientLogOutError);
     assert_eq!(
         ClientErrorType::from(5),
+        ClientErrorType::ProtocolVersionMismatch
+    );
+    assert_eq!(
+        ClientErrorType::from(6),
+        ClientErrorType::ClientAuthenticationError
+    );
+    assert_eq!(
+        ClientErrorType::from(7),
         ClientErrorType::UnknownClientError
     );
 }
@@ -9,7 +9,17 @@ fn test_from_u8() {
     assert_eq!(DataErrorType::from(4), DataErrorType::DataTableNotFound);
     assert_eq!(DataErrorType::from(5), DataErrorType::DataSendError);
     assert_eq!(DataErrorType::from(6), DataErrorType::DataChannelError);
-    assert_eq!(DataErrorType::from(7), DataErrorType::UnknownDataError);
+    assert_eq!(
+        DataErrorType::from(7),
+        DataErrorType::TimeResolutionNotValidError
+    );
+    assert_eq!(
+        DataErrorType::from(8),
+        DataErrorType::ThresholdNotValidError
+    );
+    assert_eq!(DataErrorType::from(9), DataErrorType::NotEntitled);
+    assert_eq!(DataErrorType::from(10), DataErrorType::RateLimited);
+    assert_eq!(DataErrorType::from(11), DataErrorType::UnknownDataError);
 }
 
 #[test]
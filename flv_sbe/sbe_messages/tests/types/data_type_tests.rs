@@ -7,7 +7,28 @@ fn test_from_u8() {
     assert_eq!(DataType::from(2), DataType::OHLCVData);
     // assert_eq!(DataType::from(3), DataType::OrderBookData);
     // assert_eq!(DataType::from(4), DataType::QuoteData);
-    assert_eq!(DataType::from(5), DataType::UnknownDataType);
+    assert_eq!(DataType::from(5), DataType::TickBarData);
+    assert_eq!(DataType::from(6), DataType::VolumeBarData);
+    assert_eq!(DataType::from(7), DataType::DollarBarData);
+    assert_eq!(DataType::from(8), DataType::UnknownDataType);
+}
+
+#[test]
+fn test_is_information_bar() {
+    assert!(!DataType::TradeData.is_information_bar());
+    assert!(!DataType::OHLCVData.is_information_bar());
+    assert!(DataType::TickBarData.is_information_bar());
+    assert!(DataType::VolumeBarData.is_information_bar());
+    assert!(DataType::DollarBarData.is_information_bar());
+}
+
+#[test]
+fn test_is_ohlcv_bar() {
+    assert!(!DataType::UnknownDataType.is_ohlcv_bar());
+    assert!(!DataType::TradeData.is_ohlcv_bar());
+    assert!(DataType::OHLCVData.is_ohlcv_bar());
+    assert!(DataType::TickBarData.is_ohlcv_bar());
+    assert!(DataType::DollarBarData.is_ohlcv_bar());
 }
 
 #[test]
@@ -15,6 +36,7 @@ fn test_display() {
     assert_eq!(format!("{}", DataType::UnknownDataType), "UnknownDataType");
     assert_eq!(format!("{}", DataType::TradeData), "TradeData");
     assert_eq!(format!("{}", DataType::OHLCVData), "OHLCVData");
+    assert_eq!(format!("{}", DataType::TickBarData), "TickBarData");
     // assert_eq!(format!("{}", DataType::OrderBookData), "OrderBookData");
     // assert_eq!(format!("{}", DataType::QuoteData), "QuoteData");
 }
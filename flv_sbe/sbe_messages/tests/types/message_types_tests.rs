@@ -5,12 +5,19 @@ fn test_from_u16() {
     assert_eq!(MessageType::from(0_u16), MessageType::UnknownMessageType);
     assert_eq!(MessageType::from(101_u16), MessageType::ClientLogin);
     assert_eq!(MessageType::from(102_u16), MessageType::ClientLogout);
+    assert_eq!(MessageType::from(103_u16), MessageType::ClientLoginAck);
+    assert_eq!(MessageType::from(104_u16), MessageType::ClientLogoutAck);
+    assert_eq!(MessageType::from(105_u16), MessageType::ClientHeartbeat);
     assert_eq!(MessageType::from(201_u16), MessageType::StartData);
     assert_eq!(MessageType::from(202_u16), MessageType::StopData);
     assert_eq!(MessageType::from(203_u16), MessageType::StopAllData);
     assert_eq!(MessageType::from(204_u16), MessageType::OHLCVBar);
     assert_eq!(MessageType::from(205_u16), MessageType::FirstOHLCVBar);
     assert_eq!(MessageType::from(206_u16), MessageType::LastOHLCVBar);
+    assert_eq!(MessageType::from(210_u16), MessageType::PauseData);
+    assert_eq!(MessageType::from(211_u16), MessageType::ContinueData);
+    assert_eq!(MessageType::from(212_u16), MessageType::StartMultiData);
+    assert_eq!(MessageType::from(213_u16), MessageType::ResumeData);
     assert_eq!(MessageType::from(801_u16), MessageType::ClientError);
     assert_eq!(MessageType::from(999_u16), MessageType::UnknownMessageType);
 }
@@ -22,6 +29,15 @@ fn test_display() {
     let message_type = MessageType::ClientLogout;
     assert_eq!(format!("{}", message_type), "ClientLogout");
 
+    let message_type = MessageType::ClientLoginAck;
+    assert_eq!(format!("{}", message_type), "ClientLoginAck");
+
+    let message_type = MessageType::ClientLogoutAck;
+    assert_eq!(format!("{}", message_type), "ClientLogoutAck");
+
+    let message_type = MessageType::ClientHeartbeat;
+    assert_eq!(format!("{}", message_type), "ClientHeartbeat");
+
     let message_type = MessageType::StartData;
     assert_eq!(format!("{}", message_type), "StartData");
 
@@ -31,6 +47,18 @@ fn test_display() {
     let message_type = MessageType::StopAllData;
     assert_eq!(format!("{}", message_type), "StopAllData");
 
+    let message_type = MessageType::PauseData;
+    assert_eq!(format!("{}", message_type), "PauseData");
+
+    let message_type = MessageType::ContinueData;
+    assert_eq!(format!("{}", message_type), "ContinueData");
+
+    let message_type = MessageType::StartMultiData;
+    assert_eq!(format!("{}", message_type), "StartMultiData");
+
+    let message_type = MessageType::ResumeData;
+    assert_eq!(format!("{}", message_type), "ResumeData");
+
     let message_type = MessageType::UnknownMessageType;
     assert_eq!(format!("{}", message_type), "UnknownMessageType");
 }
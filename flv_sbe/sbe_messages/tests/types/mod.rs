@@ -6,3 +6,7 @@ mod data_error_types_tests;
 mod data_type_tests;
 #[cfg(test)]
 mod message_types_tests;
+#[cfg(test)]
+mod protocol_tests;
+#[cfg(test)]
+mod replay_mode_tests;
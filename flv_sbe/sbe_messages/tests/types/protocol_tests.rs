@@ -0,0 +1,70 @@
+use sbe_messages::prelude::{
+    negotiate_protocol_version, Capabilities, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION,
+};
+
+#[test]
+fn test_negotiate_protocol_version() {
+    assert_eq!(
+        negotiate_protocol_version(PROTOCOL_VERSION),
+        Some(PROTOCOL_VERSION)
+    );
+    assert_eq!(
+        negotiate_protocol_version(MIN_PROTOCOL_VERSION),
+        Some(MIN_PROTOCOL_VERSION)
+    );
+}
+
+#[test]
+fn test_negotiate_newer_protocol_version() {
+    assert_eq!(
+        negotiate_protocol_version(PROTOCOL_VERSION + 1),
+        Some(PROTOCOL_VERSION)
+    );
+}
+
+#[test]
+fn test_negotiate_unsupported_protocol_version() {
+    assert_eq!(negotiate_protocol_version(MIN_PROTOCOL_VERSION - 1), None);
+    assert_eq!(negotiate_protocol_version(0), None);
+}
+
+#[test]
+fn test_capabilities() {
+    let capabilities = Capabilities::RESUME_DATA.with(Capabilities::EXACT_DECIMALS);
+
+    assert!(capabilities.contains(Capabilities::RESUME_DATA));
+    assert!(capabilities.contains(Capabilities::EXACT_DECIMALS));
+    assert!(!capabilities.contains(Capabilities::MULTI_DATA));
+    assert!(!capabilities.is_empty());
+    assert_eq!(capabilities.bits(), 0b10001);
+}
+
+#[test]
+fn test_capabilities_intersection() {
+    // Unknown capabilities of a newer client are dropped.
+    let client = Capabilities::from(0b100101);
+
+    let negotiated = client.intersection(Capabilities::supported());
+    assert_eq!(
+        negotiated,
+        Capabilities::RESUME_DATA.with(Capabilities::INFORMATION_BARS)
+    );
+}
+
+#[test]
+fn test_capabilities_default() {
+    assert_eq!(Capabilities::default(), Capabilities::NONE);
+    assert!(Capabilities::NONE.is_empty());
+}
+
+#[test]
+fn test_capabilities_display() {
+    assert_eq!(format!("{}", Capabilities::NONE), "None");
+    assert_eq!(
+        format!(
+            "{}",
+            Capabilities::MULTI_DATA.with(Capabilities::BAR_STATISTICS)
+        ),
+        "MultiData|BarStatistics"
+    );
+}
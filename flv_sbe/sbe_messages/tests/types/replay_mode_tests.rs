@@ -0,0 +1,21 @@
+use sbe_messages::prelude::ReplayMode;
+
+#[test]
+fn test_from_u8() {
+    assert_eq!(ReplayMode::from(0), ReplayMode::Unthrottled);
+    assert_eq!(ReplayMode::from(1), ReplayMode::RealTime);
+    assert_eq!(ReplayMode::from(2), ReplayMode::Scaled);
+    assert_eq!(ReplayMode::from(3), ReplayMode::Unthrottled);
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(ReplayMode::default(), ReplayMode::Unthrottled);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", ReplayMode::Unthrottled), "Unthrottled");
+    assert_eq!(format!("{}", ReplayMode::RealTime), "RealTime");
+    assert_eq!(format!("{}", ReplayMode::Scaled), "Scaled");
+}
@@ -0,0 +1,65 @@
+/// Configuration of the admin endpoints.
+///
+/// Without an admin token, the admin endpoints reject every request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AdminConfig {
+    admin_token: Option<String>,
+    symbol_table: String,
+    exchanges: Vec<(u16, String)>,
+}
+
+impl AdminConfig {
+    /// Creates a new AdminConfig.
+    ///
+    /// # Parameters
+    ///
+    /// * `admin_token` - The shared token each admin request must carry, if any
+    /// * `symbol_table` - The database table to reload the symbols from
+    /// * `exchanges` - The (exchange_id, exchange_name) pairs of the SymbolManager
+    ///
+    pub(crate) fn new(
+        admin_token: Option<String>,
+        symbol_table: String,
+        exchanges: Vec<(u16, String)>,
+    ) -> Self {
+        Self {
+            admin_token,
+            symbol_table,
+            exchanges,
+        }
+    }
+}
+
+impl AdminConfig {
+    /// Returns true if the authorization header carries the admin token as bearer token.
+    ///
+    /// Compares in constant time, so the response time reveals nothing about the token.
+    pub(crate) fn authorizes(&self, authorization: Option<&str>) -> bool {
+        let (admin_token, authorization) = match (&self.admin_token, authorization) {
+            (Some(admin_token), Some(authorization)) => (admin_token, authorization),
+            _ => return false,
+        };
+
+        let token = match authorization.strip_prefix("Bearer ") {
+            Some(token) => token,
+            None => return false,
+        };
+
+        token.len() == admin_token.len()
+            && token
+                .bytes()
+                .zip(admin_token.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.admin_token.is_some()
+    }
+    pub(crate) fn symbol_table(&self) -> &str {
+        &self.symbol_table
+    }
+    pub(crate) fn exchanges(&self) -> &[(u16, String)] {
+        &self.exchanges
+    }
+}
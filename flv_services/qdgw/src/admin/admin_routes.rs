@@ -0,0 +1,189 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use warp::http::StatusCode;
+use warp::reject::Reject;
+use warp::reply::{json, with_status};
+use warp::{Filter, Rejection, Reply};
+
+use sbe_messages::prelude::DataType;
+
+use crate::admin::admin_types::{AdminError, AdminMessage};
+use crate::admin::AdminConfig;
+use crate::service::Server;
+
+// Rejects a request without the admin token.
+#[derive(Debug)]
+struct Unauthorized;
+
+impl Reject for Unauthorized {}
+
+/// Returns the filter of all admin endpoints.
+///
+/// * `GET /admin/sessions` - Lists the sessions of all logged in clients
+/// * `GET /admin/streams` - Lists all active data streams with their progress
+/// * `POST /admin/sessions/{client_id}/logout` - Logs out a client
+/// * `DELETE /admin/streams/{client_id}/{exchange_id}/{symbol_id}/{data_type_id}` - Cancels a data stream
+/// * `POST /admin/symbols/reload` - Reloads the symbols from the database
+///
+/// Each request must carry the admin token as bearer token in the authorization header,
+/// otherwise the endpoint responds with 401 Unauthorized.
+///
+/// # Parameters
+///
+/// * `server` - The server whose sessions and streams the endpoints manage
+/// * `config` - The admin token and the symbol source to reload from
+///
+pub(crate) fn admin_routes(
+    server: Server,
+    config: AdminConfig,
+) -> impl Filter<Extract = (impl Reply,), Error = Infallible> + Clone {
+    let config = Arc::new(config);
+
+    let authorized = {
+        let config = config.clone();
+        warp::header::optional::<String>("authorization")
+            .and_then(move |authorization: Option<String>| {
+                let config = config.clone();
+                async move {
+                    if config.authorizes(authorization.as_deref()) {
+                        Ok(())
+                    } else {
+                        Err(warp::reject::custom(Unauthorized))
+                    }
+                }
+            })
+            .untuple_one()
+    };
+
+    let with_server = warp::any().map(move || server.clone());
+    let with_config = warp::any().map(move || config.clone());
+
+    let list_sessions = warp::get()
+        .and(warp::path!("admin" / "sessions"))
+        .and(authorized.clone())
+        .and(with_server.clone())
+        .and_then(list_sessions);
+
+    let list_streams = warp::get()
+        .and(warp::path!("admin" / "streams"))
+        .and(authorized.clone())
+        .and(with_server.clone())
+        .and_then(list_streams);
+
+    let force_logout = warp::post()
+        .and(warp::path!("admin" / "sessions" / u16 / "logout"))
+        .and(authorized.clone())
+        .and(with_server.clone())
+        .and_then(force_logout);
+
+    let cancel_stream = warp::delete()
+        .and(warp::path!("admin" / "streams" / u16 / u16 / u16 / u8))
+        .and(authorized.clone())
+        .and(with_server.clone())
+        .and_then(cancel_stream);
+
+    let reload_symbols = warp::post()
+        .and(warp::path!("admin" / "symbols" / "reload"))
+        .and(authorized)
+        .and(with_server)
+        .and(with_config)
+        .and_then(reload_symbols);
+
+    list_sessions
+        .or(list_streams)
+        .or(force_logout)
+        .or(cancel_stream)
+        .or(reload_symbols)
+        .recover(handle_rejection)
+}
+
+async fn list_sessions(server: Server) -> Result<impl Reply, Rejection> {
+    Ok(json(&server.list_sessions().await))
+}
+
+async fn list_streams(server: Server) -> Result<impl Reply, Rejection> {
+    Ok(json(&server.list_streams().await))
+}
+
+async fn force_logout(client_id: u16, server: Server) -> Result<impl Reply, Rejection> {
+    if server.force_logout(client_id).await {
+        Ok(message_reply(
+            StatusCode::OK,
+            format!("Client {} logged out", client_id),
+        ))
+    } else {
+        Ok(error_reply(
+            StatusCode::NOT_FOUND,
+            format!("Client {} is not logged in", client_id),
+        ))
+    }
+}
+
+async fn cancel_stream(
+    client_id: u16,
+    exchange_id: u16,
+    symbol_id: u16,
+    data_type_id: u8,
+    server: Server,
+) -> Result<impl Reply, Rejection> {
+    let data_type = DataType::from(data_type_id);
+    if data_type == DataType::UnknownDataType {
+        return Ok(error_reply(
+            StatusCode::BAD_REQUEST,
+            format!("Unknown data type {}", data_type_id),
+        ));
+    }
+
+    let stream_key = (client_id, exchange_id, symbol_id, data_type);
+    if server.admin_cancel_stream(stream_key).await {
+        Ok(message_reply(
+            StatusCode::OK,
+            format!("Stream {:?} cancelled", stream_key),
+        ))
+    } else {
+        Ok(error_reply(
+            StatusCode::NOT_FOUND,
+            format!("Stream {:?} is not active", stream_key),
+        ))
+    }
+}
+
+async fn reload_symbols(server: Server, config: Arc<AdminConfig>) -> Result<impl Reply, Rejection> {
+    match server
+        .reload_symbols(config.symbol_table(), config.exchanges())
+        .await
+    {
+        Ok(number_of_symbols) => Ok(message_reply(
+            StatusCode::OK,
+            format!("Reloaded {} symbols", number_of_symbols),
+        )),
+        Err(e) => Ok(error_reply(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to reload symbols: {}", e),
+        )),
+    }
+}
+
+/// Turns a rejected admin request into a JSON error response.
+async fn handle_rejection(rejection: Rejection) -> Result<impl Reply, Infallible> {
+    let (status, error) = if rejection.is_not_found() {
+        (StatusCode::NOT_FOUND, "Not found")
+    } else if rejection.find::<Unauthorized>().is_some() {
+        (StatusCode::UNAUTHORIZED, "Missing or invalid admin token")
+    } else if rejection.find::<warp::reject::MethodNotAllowed>().is_some() {
+        (StatusCode::METHOD_NOT_ALLOWED, "Method not allowed")
+    } else {
+        (StatusCode::BAD_REQUEST, "Bad request")
+    };
+
+    Ok(error_reply(status, error.to_string()))
+}
+
+fn message_reply(status: StatusCode, message: String) -> warp::reply::Response {
+    with_status(json(&AdminMessage { message }), status).into_response()
+}
+
+fn error_reply(status: StatusCode, error: String) -> warp::reply::Response {
+    with_status(json(&AdminError { error }), status).into_response()
+}
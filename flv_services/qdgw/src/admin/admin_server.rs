@@ -0,0 +1,191 @@
+use std::time::Instant;
+
+use common::prelude::MessageProcessingError;
+use symbol_manager::SymbolManager;
+
+use crate::admin::admin_types::{SessionInfo, StreamInfo};
+use crate::service::{Server, StreamKey};
+
+impl Server {
+    /// Lists the sessions of all logged in clients, ordered by client ID.
+    pub(crate) async fn list_sessions(&self) -> Vec<SessionInfo> {
+        // Lock the client_configs hashmap to collect all logged in clients
+        let client_configs = self.client_configs().read().await;
+
+        let mut clients: Vec<(u16, String)> = client_configs
+            .iter()
+            .map(|(client_id, iggy_config)| (*client_id, iggy_config.topic_name().to_string()))
+            .collect();
+
+        // Unlock the client_configs hashmap
+        drop(client_configs);
+
+        clients.sort_by_key(|(client_id, _)| *client_id);
+
+        let mut sessions = Vec::with_capacity(clients.len());
+        let now = Instant::now();
+
+        for (client_id, data_topic) in clients {
+            let client_heartbeats = self.client_heartbeats().read().await;
+            let millis_since_heartbeat = client_heartbeats
+                .get(&client_id)
+                .map(|last_heartbeat| last_heartbeat.elapsed().as_millis() as u64);
+            drop(client_heartbeats);
+
+            let streams = self.count_client_streams(client_id, &[]).await;
+            let usage = self.rate_limiter().usage(client_id, now);
+
+            sessions.push(SessionInfo {
+                client_id,
+                data_topic,
+                millis_since_heartbeat,
+                streams,
+                rows_in_window: usage.rows_in_window(),
+                bytes_in_window: usage.bytes_in_window(),
+            });
+        }
+
+        sessions
+    }
+
+    /// Lists all active data streams with their progress, ordered by stream key.
+    pub(crate) async fn list_streams(&self) -> Vec<StreamInfo> {
+        // Lock the client_streams hashmap
+        let client_streams = self.client_streams().read().await;
+
+        let mut streams: Vec<StreamInfo> = client_streams
+            .iter()
+            .map(
+                |((client_id, exchange_id, symbol_id, data_type), control)| StreamInfo {
+                    client_id: *client_id,
+                    exchange_id: *exchange_id,
+                    symbol_id: *symbol_id,
+                    data_type: *data_type,
+                    paused: control.is_paused(),
+                    started_at: control.started_at(),
+                    bars_streamed: control.bars_streamed(),
+                    last_bar_time: control.last_bar_time(),
+                },
+            )
+            .collect();
+
+        // Unlock the client_streams hashmap
+        drop(client_streams);
+
+        streams.sort_by_key(|stream| {
+            (
+                stream.client_id,
+                stream.exchange_id,
+                stream.symbol_id,
+                stream.data_type,
+            )
+        });
+
+        streams
+    }
+
+    /// Logs out a client as if it had missed its heartbeats.
+    ///
+    /// Cancels all data streams of the client, deletes its data channel,
+    /// and deletes its iggy user, which revokes its access token.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client to log out
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the client was logged in, `false` otherwise.
+    ///
+    pub(crate) async fn force_logout(&self, client_id: u16) -> bool {
+        match self.check_client_login(client_id).await {
+            Ok(true) => {}
+            _ => return false,
+        }
+
+        println!(
+            "[QDGW/force_logout]: Admin logs out client {}, evict session",
+            client_id
+        );
+
+        self.evict_client_session(client_id).await;
+        true
+    }
+
+    /// Cancels the data stream with the given key on behalf of an admin.
+    ///
+    /// The client receives the last bar message, as if it had stopped the stream itself.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if an active stream was cancelled, `false` otherwise.
+    ///
+    pub(crate) async fn admin_cancel_stream(&self, stream_key: StreamKey) -> bool {
+        let cancelled = self.cancel_data_stream(&stream_key).await;
+
+        if cancelled {
+            println!(
+                "[QDGW/admin_cancel_stream]: Admin cancelled stream {:?}",
+                stream_key
+            );
+        }
+
+        cancelled
+    }
+
+    /// Reloads all symbols from the symbol table in the database and replaces the SymbolManager.
+    ///
+    /// Running streams keep streaming; new requests use the reloaded symbols
+    /// and validate their trade tables against the database again.
+    ///
+    /// # Parameters
+    ///
+    /// * `symbol_table` - The name of the symbol table of the default exchange
+    /// * `exchanges` - The (exchange_id, exchange_name) pairs of the SymbolManager
+    ///
+    /// # Returns
+    ///
+    /// A Result with the number of loaded symbols, or a MessageProcessingError
+    /// if the query fails or the symbols are not valid.
+    ///
+    pub(crate) async fn reload_symbols(
+        &self,
+        symbol_table: &str,
+        exchanges: &[(u16, String)],
+    ) -> Result<usize, MessageProcessingError> {
+        // Streams hold a read lock on the query manager for as long as they run,
+        // so the reload must not wait for a write lock.
+        let q_manager = self.query_manager().read().await;
+        let res = q_manager
+            .get_all_symbols_with_ids(symbol_table)
+            .await
+            .map_err(|e| e.to_string());
+        // Look up the trade tables of the reloaded symbols in the database again
+        q_manager.clear_catalog();
+        drop(q_manager);
+
+        let symbols = match res {
+            Ok(symbols) => symbols,
+            Err(e) => return Err(MessageProcessingError(e)),
+        };
+
+        let number_of_symbols = symbols.len();
+
+        let symbol_manager = match SymbolManager::new(symbols, exchanges.to_vec()) {
+            Ok(symbol_manager) => symbol_manager,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        // Lock the SymbolManager only to swap in the reloaded symbols
+        let mut symbol_db = self.symbol_manager().write().await;
+        *symbol_db = symbol_manager;
+        drop(symbol_db);
+
+        println!(
+            "[QDGW/reload_symbols]: Reloaded {} symbols from {}",
+            number_of_symbols, symbol_table
+        );
+
+        Ok(number_of_symbols)
+    }
+}
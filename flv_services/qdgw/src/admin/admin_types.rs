@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use sbe_messages::prelude::DataType;
+
+/// A logged in client, as listed by `GET /admin/sessions`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct SessionInfo {
+    pub(crate) client_id: u16,
+    // Name of the iggy topic of the client data channel.
+    pub(crate) data_topic: String,
+    pub(crate) millis_since_heartbeat: Option<u64>,
+    pub(crate) streams: usize,
+    pub(crate) rows_in_window: u64,
+    pub(crate) bytes_in_window: u64,
+}
+
+/// An active data stream, as listed by `GET /admin/streams`.
+///
+/// A stream of several symbols is listed once per symbol,
+/// and all of its entries share the same progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct StreamInfo {
+    pub(crate) client_id: u16,
+    pub(crate) exchange_id: u16,
+    pub(crate) symbol_id: u16,
+    pub(crate) data_type: DataType,
+    pub(crate) paused: bool,
+    pub(crate) started_at: DateTime<Utc>,
+    pub(crate) bars_streamed: u64,
+    pub(crate) last_bar_time: Option<DateTime<Utc>>,
+}
+
+/// The response of an admin action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct AdminMessage {
+    pub(crate) message: String,
+}
+
+/// The response of a failed admin request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct AdminError {
+    pub(crate) error: String,
+}
@@ -0,0 +1,7 @@
+mod admin_config;
+mod admin_routes;
+mod admin_server;
+mod admin_types;
+
+pub(crate) use admin_config::AdminConfig;
+pub(crate) use admin_routes::admin_routes;
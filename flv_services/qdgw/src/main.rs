@@ -1,26 +1,52 @@
+use std::env;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use autometrics::prometheus_exporter;
+use autometrics::settings::AutometricsSettings;
+use prometheus_client::registry::Registry;
 use tokio::sync::RwLock;
 use warp::Filter;
 
+use auth_manager::{FileCredentialStore, FileEntitlementStore};
 use common::prelude::ServiceID;
 use config_manager::ConfigManager;
 use db_query_manager::QueryDBManager;
 use service_utils::{print_utils, shutdown_utils};
 use symbol_manager::SymbolManager;
 
-use crate::service::Server;
+use crate::admin::AdminConfig;
+use crate::service::{Server, UsageMetrics};
 
+mod admin;
 mod service;
 
+#[cfg(test)]
+#[path = "../tests/mod.rs"]
+mod tests;
+
 const SVC_ID: ServiceID = ServiceID::QDGW;
 
+// TOML file with the auth tokens of all clients allowed to log in.
+const CREDENTIALS_FILE: &str = "qdgw_credentials.toml";
+
+// TOML file with the data each client is entitled to stream.
+const ENTITLEMENTS_FILE: &str = "qdgw_entitlements.toml";
+
+// Environment variable with the shared token of the admin endpoints.
+const ADMIN_TOKEN_ENV: &str = "QDGW_ADMIN_TOKEN";
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize the metrics exporter.
-    prometheus_exporter::init();
+    // Register the usage gauges of all clients next to the autometrics metrics.
+    let usage_metrics = UsageMetrics::new();
+    let mut registry = Registry::default();
+    usage_metrics.register(&mut registry);
+
+    // Initialize the metrics exporter with the registry.
+    AutometricsSettings::builder()
+        .prometheus_client_registry(registry)
+        .init();
 
     // Setup ConfigManager instance for contextual autoconfiguration.
     let cfg_manager = async { ConfigManager::new(SVC_ID) }.await;
@@ -37,18 +63,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .parse()
         .expect("[QDGW]/main: Failed to parse metric host to address");
 
-    //Creates a new Warp filter for the metrics endpoint.
-    let routes = warp::get()
-        .and(warp::path(metrics_uri.clone()))
-        .map(prometheus_exporter::encode_http_response);
-
-    //Creates a new Warp filter for the metrics endpoint with a graceful shutdown handlers.
-    let signal = shutdown_utils::signal_handler("Http web server");
-    let (_, web_server) = warp::serve(routes).bind_with_graceful_shutdown(web_addr, signal);
-
-    //Creates a new Tokio task for the HTTP web server.
-    let web_handle = tokio::spawn(web_server);
-
     // Get the symbol table for the default exchange.
     let default_exchange = cfg_manager.default_exchange();
     let exchanges = cfg_manager.exchanges_id_names().to_owned();
@@ -56,11 +70,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .get_symbol_table(default_exchange)
         .expect("[QDGW]/main: Failed to get symbol table for default exchange.");
 
-    // Create a new QueryDBManager instance.
-    let db_config = cfg_manager.db_config();
-    let mut q_manager = QueryDBManager::new(db_config.clone())
-        .await
-        .expect("[QDGW]/main: Failed to create QueryDBManager instance.");
+    // Create a new QueryDBManager instance for the configured DB backend.
+    let q_manager = QueryDBManager::with_db_backend(
+        cfg_manager.db_backend(),
+        cfg_manager.db_config(),
+        cfg_manager.pg_db_config(),
+    )
+    .await
+    .expect("[QDGW]/main: Failed to create QueryDBManager instance.");
 
     // Get all symbols for the default exchange.
     let symbols = q_manager
@@ -71,7 +88,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create a new SymbolManager instance.
     let symbol_manager = async {
         Arc::new(RwLock::new(
-            SymbolManager::new(symbols, exchanges)
+            SymbolManager::new(symbols, exchanges.clone())
                 .expect("[QDGW]/main: Failed to create SymbolManager instance."),
         ))
     }
@@ -92,8 +109,59 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let iggy_config = cfg_manager.iggy_config();
     let service_topic = iggy_config.topic_name().to_string();
 
+    // Load the auth tokens of all clients.
+    let credential_store = Arc::new(
+        FileCredentialStore::from_file(CREDENTIALS_FILE)
+            .expect("[QDGW]/main: Failed to load client credentials."),
+    );
+
+    // Load the entitlements of all clients.
+    let entitlement_store = Arc::new(
+        FileEntitlementStore::from_file(ENTITLEMENTS_FILE)
+            .expect("[QDGW]/main: Failed to load client entitlements."),
+    );
+
+    // Number of workers for concurrent data streams.
+    let worker_count = cfg_manager.svc_config().worker_count();
+
     //Creates a new server
-    let server = Server::new(iggy_config, query_manager.clone(), symbol_manager).await;
+    let server = Server::new(
+        iggy_config,
+        query_manager.clone(),
+        symbol_manager,
+        credential_store,
+        entitlement_store,
+        usage_metrics,
+        worker_count,
+    )
+    .await;
+
+    // The admin endpoints reject every request unless an admin token is set.
+    let admin_token = env::var(ADMIN_TOKEN_ENV)
+        .ok()
+        .filter(|token| !token.is_empty());
+    let admin_config = AdminConfig::new(admin_token, exchange_symbol_table, exchanges);
+    if !admin_config.is_enabled() {
+        println!(
+            "[QDGW]/main: {} is not set, admin endpoints are disabled.",
+            ADMIN_TOKEN_ENV
+        );
+    }
+
+    //Creates a new Warp filter for the metrics endpoint.
+    let metrics_route = warp::get()
+        .and(warp::path(metrics_uri.clone()))
+        .map(prometheus_exporter::encode_http_response);
+
+    //Serves the admin endpoints next to the metrics endpoint.
+    let routes = metrics_route.or(admin::admin_routes(server.clone(), admin_config));
+
+    //Creates a new Warp filter for the metrics endpoint with a graceful shutdown handlers.
+    let signal = shutdown_utils::signal_handler("Http web server");
+    let (_, web_server) = warp::serve(routes).bind_with_graceful_shutdown(web_addr, signal);
+
+    //Creates a new Tokio task for the HTTP web server.
+    let web_handle = tokio::spawn(web_server);
 
     //Creates a new Tokio task for the server.
     let signal = shutdown_utils::signal_handler("Message Bus connector");
@@ -108,7 +176,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Free up some memory before starting the service,
-    drop(db_config);
     drop(cfg_manager);
     drop(metrics_host);
     drop(metrics_uri);
@@ -0,0 +1,66 @@
+use std::error::Error;
+use std::fmt;
+
+use common::prelude::MessageProcessingError;
+use sbe_messages::prelude::{ClientErrorType, DataErrorType};
+
+/// GatewayError enum definition.
+///
+/// Used to represent errors of the gateway message handlers.
+///
+/// # Variants
+///
+/// * `ClientError` - A client request the client can fix, i.e. a request without login.
+///   Sent back to the client as ClientErrorMessage.
+/// * `DataError` - A data request the client can fix, i.e. an unknown symbol.
+///   Sent back to the client as DataErrorMessage.
+/// * `RateLimited` - A request of a client that exceeded its rate limits or quotas,
+///   with the number of milliseconds after which the client can retry, if known.
+///   Sent back to the client as DataErrorMessage with `RateLimited`.
+/// * `InvalidMessage` - A message that cannot be processed, i.e. of unknown type.
+///   Logged only, as the sender is unknown.
+/// * `InternalError` - A failure inside the gateway, i.e. a message bus error.
+///   Logged only.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum GatewayError {
+    ClientError(u16, ClientErrorType),
+    DataError(u16, DataErrorType),
+    RateLimited(u16, Option<u32>),
+    InvalidMessage(String),
+    InternalError(String),
+}
+
+impl fmt::Display for GatewayError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GatewayError::ClientError(client_id, client_error_type) => write!(
+                f,
+                "GatewayError: ClientError {} for client {}",
+                client_error_type, client_id
+            ),
+            GatewayError::DataError(client_id, data_error_type) => write!(
+                f,
+                "GatewayError: DataError {} for client {}",
+                data_error_type, client_id
+            ),
+            GatewayError::RateLimited(client_id, retry_after_millis) => write!(
+                f,
+                "GatewayError: RateLimited for client {}, retry after millis {:?}",
+                client_id, retry_after_millis
+            ),
+            GatewayError::InvalidMessage(e) => write!(f, "GatewayError: InvalidMessage {}", e),
+            GatewayError::InternalError(e) => write!(f, "GatewayError: InternalError {}", e),
+        }
+    }
+}
+
+impl Error for GatewayError {}
+
+impl From<MessageProcessingError> for GatewayError {
+    #[inline]
+    fn from(e: MessageProcessingError) -> Self {
+        GatewayError::InternalError(e.0)
+    }
+}
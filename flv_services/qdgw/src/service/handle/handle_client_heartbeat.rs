@@ -0,0 +1,39 @@
+use tokio::time::Instant;
+
+use sbe_messages::prelude::ClientHeartbeatMessage;
+
+use crate::service::errors::GatewayError;
+use crate::service::Server;
+
+impl Server {
+    /// Handles a client heartbeat message by recording the time it was received.
+    ///
+    /// Heartbeats of clients that are not logged in are ignored. A client whose
+    /// session got evicted learns about it from the ClientNotLoggedIn error
+    /// returned on its next request.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_heartbeat_msg`: The incoming ClientHeartbeatMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Result with no value. Recording a heartbeat does not fail.
+    ///
+    pub(crate) async fn handle_client_heartbeat(
+        &self,
+        client_heartbeat_msg: &ClientHeartbeatMessage,
+    ) -> Result<(), GatewayError> {
+        let client_id = client_heartbeat_msg.client_id();
+
+        // Lock the client_heartbeats hashmap
+        let mut client_heartbeats = self.client_heartbeats().write().await;
+
+        // Only update the heartbeat of logged in clients
+        if let Some(last_heartbeat) = client_heartbeats.get_mut(&client_id) {
+            *last_heartbeat = Instant::now();
+        }
+
+        Ok(())
+    }
+}
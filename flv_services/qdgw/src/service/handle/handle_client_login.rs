@@ -1,15 +1,22 @@
 use autometrics::autometrics;
+use tokio::time::Instant;
 
-use common::prelude::{IggyConfig, IggyUser, MessageProcessingError};
-use sbe_messages::prelude::{ClientErrorType, ClientLoginMessage};
+use common::prelude::{IggyConfig, MessageProcessingError};
+use sbe_messages::prelude::{
+    negotiate_protocol_version, Capabilities, ClientErrorType, ClientLoginMessage,
+};
 
+use crate::service::errors::GatewayError;
 use crate::service::Server;
 
 impl Server {
     /// Handles a client login message by validating the client ID and logging them in.
     ///
-    /// Gets the client's control channel, checks if they are already logged in, and logs them in if not.
-    /// Sends back any errors over the control channel.
+    /// Negotiates the protocol version and capabilities with the client, authenticates the client
+    /// with its auth token, checks if the client is already logged in, and logs them in if not.
+    /// Confirms a successful login with a ClientLoginAck that carries the client data channel,
+    /// the negotiated protocol version, the capabilities both sides support, and the personal
+    /// access token of the iggy user created for the client.
     ///
     /// # Parameters
     ///
@@ -17,86 +24,120 @@ impl Server {
     ///
     /// # Returns
     ///
-    /// Result with no value if successful, or a MessageProcessingError if an error occurs.
+    /// Result with no value if successful, or a GatewayError if an error occurs.
     ///
     /// # Errors
     ///
-    /// - MessageProcessingError if there is an issue getting the client's control channel, checking their login status,
-    /// or logging them in.
+    /// - `GatewayError::ClientError` with ProtocolVersionMismatch if the protocol version of the client is not supported.
+    /// - `GatewayError::RateLimited` if the client attempted to log in too often with the same auth token.
+    /// - `GatewayError::ClientError` with ClientAuthenticationError if the auth token is not valid for the client.
+    /// - `GatewayError::ClientError` with ClientAlreadyLoggedIn if the client is already logged in.
+    /// - `GatewayError::ClientError` with ClientLogInError if the login failed.
+    /// - `GatewayError::ClientError` with UnknownClientError if the login status cannot be checked.
+    /// - `GatewayError::InternalError` if the ClientLoginAck cannot be sent.
     ///
-    /// ```
     #[autometrics]
     pub(crate) async fn handle_client_login(
         &self,
         client_login_msg: &ClientLoginMessage,
-    ) -> Result<(), MessageProcessingError> {
+    ) -> Result<(), GatewayError> {
         // println!("[QDGW/handle_client::handle_client_login]:");
 
         // println!("::handle_client_login]: Extract the client ID from the message");
         let client_id = client_login_msg.client_id();
 
+        // println!("::handle_client_login]: Negotiate the protocol version");
+        let client_version = client_login_msg.protocol_version();
+        let protocol_version = match negotiate_protocol_version(client_version) {
+            Some(protocol_version) => protocol_version,
+            None => {
+                println!(
+                    "[QDGW/handle_client_login] ProtocolVersionMismatch: Unsupported protocol version: {}",
+                    client_version
+                );
+                return Err(GatewayError::ClientError(
+                    client_id,
+                    ClientErrorType::ProtocolVersionMismatch,
+                ));
+            }
+        };
+
+        // Confirm only the capabilities both sides support
+        let capabilities = client_login_msg
+            .capabilities()
+            .intersection(Capabilities::supported());
+
+        // Limit login attempts per credential, so that attempts with a wrong
+        // auth token cannot lock the client out of its login.
+        match self.ensure_login_rate(client_id, client_login_msg.auth_token()) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("::handle_client_login]: Authenticate the client");
+        if !self
+            .credential_store()
+            .authenticate(client_id, client_login_msg.auth_token())
+        {
+            println!(
+                "[QDGW/handle_client_login] ClientAuthenticationError: Invalid auth token for client: {}",
+                client_id
+            );
+            return Err(GatewayError::ClientError(
+                client_id,
+                ClientErrorType::ClientAuthenticationError,
+            ));
+        }
+
         // println!("::handle_client_login]: Check if the client is already logged in");
-        let exists = self.check_client_login(client_id).await;
-
-        // If the client is already logged in, return an error
-        // If not, proceed with client login
-        match exists {
-            Ok(exists) => match exists {
-                true => {
-                    // println!("::handle_client_login]: Client already logged in, return an error back to the client");
-                    let client_error_type = ClientErrorType::ClientAlreadyLoggedIn;
-                    match self.send_client_error(client_id, client_error_type).await {
-                        Ok(_) => {}
-                        Err(err) => {
-                            println!(
-                                "[QDGW/handle_client_login] ClientAlreadyLoggedIn: {:?}",
-                                err
-                            );
-                        }
-                    }
-                }
-                //
-                false => {
-                    // println!("::handle_client_login]: Client not logged in, proceed with login");
-                    let res = self.client_login(client_id).await;
-
-                    match res {
-                        Ok(_) => {}
-                        Err(err) => {
-                            println!(
-                                "[QDGW/handle_client_login] ClientLogInError: {:?}",
-                                err.to_string()
-                            );
-
-                            let client_error_type = ClientErrorType::ClientLogInError;
-                            match self.send_client_error(client_id, client_error_type).await {
-                                Ok(_) => {}
-                                Err(err) => {
-                                    println!(
-                                        "[QDGW/handle_client_login] ClientLogInError: {:?}",
-                                        err.to_string()
-                                    );
-                                }
-                            }
-                        }
-                    }
-                }
-            },
+        match self.check_client_login(client_id).await {
+            Ok(false) => {}
+            Ok(true) => {
+                return Err(GatewayError::ClientError(
+                    client_id,
+                    ClientErrorType::ClientAlreadyLoggedIn,
+                ))
+            }
             // Something went horribly wrong, log the message, and return an unknown error
             Err(err) => {
                 println!("[QDGW/handle_client_login] UnknownClientError: {:?}", err);
+                return Err(GatewayError::ClientError(
+                    client_id,
+                    ClientErrorType::UnknownClientError,
+                ));
+            }
+        }
 
-                let client_error_type = ClientErrorType::UnknownClientError;
-                match self.send_client_error(client_id, client_error_type).await {
-                    Ok(_) => {}
-                    Err(err) => {
-                        println!(
-                            "[QDGW/handle_client_login] UnknownClientError: {:?}",
-                            err.to_string()
-                        );
-                    }
-                }
+        // println!("::handle_client_login]: Client not logged in, proceed with login");
+        let (iggy_config, access_token) = match self
+            .client_login(client_id, client_login_msg.auth_token())
+            .await
+        {
+            Ok(login) => login,
+            Err(err) => {
+                println!(
+                    "[QDGW/handle_client_login] ClientLogInError: {:?}",
+                    err.to_string()
+                );
+                return Err(GatewayError::ClientError(
+                    client_id,
+                    ClientErrorType::ClientLogInError,
+                ));
             }
+        };
+
+        match self
+            .send_client_login_ack(
+                client_id,
+                &iggy_config,
+                protocol_version,
+                capabilities,
+                &access_token,
+            )
+            .await
+        {
+            Ok(_) => {}
+            Err(err) => return Err(GatewayError::from(err)),
         }
 
         Ok(())
@@ -105,26 +146,60 @@ impl Server {
     /// Login a client by adding them to the client database.
     ///
     /// Locks the client manager, creates a config for the client,
-    /// and attempts to add them to the database.
+    /// creates the iggy user of the client, and attempts to add them to the database.
+    ///
+    /// The gateway creates the data channel and the control topic of the client with its own
+    /// iggy user; apart from the gateway, only the iggy user of the client can use them.
     ///
     /// # Parameters
     ///
     /// - `client_id`: The ID of the client to log in
+    /// - `auth_token`: The auth token the client authenticated with
     ///
     /// # Returns
     ///
-    /// A Result with no value if the client was logged in successfully,
-    /// or a MessageProcessingError if there was an issue.
+    /// A Result with the iggy config of the client data channel and the personal access token
+    /// of the client if the client was logged in successfully, or a MessageProcessingError if there was an issue.
     ///
     /// # Errors
     ///
-    /// - MessageProcessingError if there was an issue adding the client to the database.
+    /// - MessageProcessingError if the producer for the client data channel cannot be created.
+    /// - MessageProcessingError if the control topic of the client cannot be created.
+    /// - MessageProcessingError if the iggy user or the access token of the client cannot be created.
     ///
-    pub(crate) async fn client_login(&self, client_id: u16) -> Result<(), MessageProcessingError> {
-        // Create an iggy config for the client
-        let user = IggyUser::default();
+    pub(crate) async fn client_login(
+        &self,
+        client_id: u16,
+        auth_token: &str,
+    ) -> Result<(IggyConfig, String), MessageProcessingError> {
+        // Create an iggy config for the client data channel owned by the gateway user
+        let user = self.iggy_config().user().clone();
         let iggy_config = IggyConfig::from_client_id(user, client_id as u32, 50000, false);
 
+        // Create an iggy client and initialize it as producer
+        let producer = match iggy_utils::get_producer(&iggy_config)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(producer) => producer,
+            Err(e) => return Err(MessageProcessingError(e)),
+        };
+
+        // Create the topic the client sends its requests on, next to its data channel
+        let control_config = iggy_utils::client_control_config(&iggy_config);
+        match iggy_utils::create_topic(&producer, &control_config)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(_) => {}
+            Err(e) => return Err(MessageProcessingError(e)),
+        }
+
+        // Create the iggy user the client uses to connect to its data channel
+        let access_token = self
+            .create_client_user(client_id, auth_token, &iggy_config)
+            .await?;
+
         // Lock the client_configs hashmap
         let mut client_configs = self.client_configs().write().await;
 
@@ -134,11 +209,6 @@ impl Server {
         // Unlock the client_configs hashmap
         drop(client_configs);
 
-        // Create an iggy client and initialize it as producer
-        let producer = iggy_utils::get_producer(&iggy_config)
-            .await
-            .expect("Failed to create producer client");
-
         // lock the client_data_producers hashmap
         let mut client_data_producers = self.client_producers().write().await;
 
@@ -148,7 +218,12 @@ impl Server {
         // Unlock the client_data_producers hashmap
         drop(client_data_producers);
 
+        // Count the login as the first heartbeat of the client
+        let mut client_heartbeats = self.client_heartbeats().write().await;
+        client_heartbeats.insert(client_id, Instant::now());
+        drop(client_heartbeats);
+
         // println!("[client_login]: Client {:?} logged in successfully", client_id);
-        Ok(())
+        Ok((iggy_config, access_token))
     }
 }
@@ -3,13 +3,15 @@ use autometrics::autometrics;
 use common::prelude::MessageProcessingError;
 use sbe_messages::prelude::{ClientErrorType, ClientLogoutMessage};
 
+use crate::service::errors::GatewayError;
 use crate::service::Server;
 
 impl Server {
     /// Handles a client logout message by validating the client ID and logging them out.
     ///
-    /// Gets the client's control channel, checks if they are logged in, and logs them out if so.
-    /// Sends back any errors over the control channel.
+    /// Checks if the client is logged in, and logs them out if so.
+    /// Confirms the logout with a ClientLogoutAck on the data channel of the client,
+    /// which is sent before the client is logged out, as the channel goes away with the client.
     ///
     /// # Parameters
     ///
@@ -17,80 +19,49 @@ impl Server {
     ///
     /// # Returns
     ///
-    /// Result with no value if successful, or a MessageProcessingError if an error occurs.
+    /// Result with no value if successful, or a GatewayError if an error occurs.
     ///
     /// # Errors
     ///
-    /// - MessageProcessingError if there is an issue getting the client's control channel, checking their login status,
-    /// or logging them out.
+    /// - `GatewayError::ClientError` with ClientNotLoggedIn if the client is not logged in.
+    /// - `GatewayError::ClientError` with ClientLogOutError if the logout failed.
+    /// - `GatewayError::ClientError` with UnknownClientError if the login status cannot be checked.
+    /// - `GatewayError::InternalError` if the ClientLogoutAck cannot be sent.
     ///
     #[autometrics]
     pub(crate) async fn handle_client_logout(
         &self,
         client_logout_msg: &ClientLogoutMessage,
-    ) -> Result<(), MessageProcessingError> {
+    ) -> Result<(), GatewayError> {
         // println!("[QDGW/handle_client::client_logout]");
 
         // println!("::handle_client_logout]: Extract the client ID from the message");
         let client_id = client_logout_msg.client_id();
 
         // println!("::handle_client_logout]: Check if the client is logged in");
-        let exists = self.check_client_login(client_id).await;
-
-        match exists {
-            Ok(exists) => match exists {
-                true => {
-                    // println!("[::handle_client_logout]: Client is logged in, proceed with logout");
-
-                    let res = self.client_logout(client_id).await;
-                    match res {
-                        Ok(_) => {}
-                        Err(err) => {
-                            // println!("[QDGW/handle_client_logout::handle_client_logout] ClientLogOutError: {:?}", err);
-
-                            let client_error_type = ClientErrorType::ClientLogOutError;
-                            match self.send_client_error(client_id, client_error_type).await {
-                                Ok(_) => {}
-                                Err(err) => println!(
-                                    "[QDGW/handle_client_logout]: ClientLogInError: {:?}",
-                                    err.to_string()
-                                ),
-                            }
-                        }
-                    }
-                }
-                // client does not exist, return an ClientNotLoggedIn error to the client
-                false => {
-                    // println!("[::handle_client_logout]: Client is not logged in, return an ClientNotLoggedIn error to the client");
-                    let client_error_type = ClientErrorType::ClientNotLoggedIn;
-                    match self.send_client_error(client_id, client_error_type).await {
-                        Ok(_) => {}
-                        Err(err) => {
-                            println!(
-                                "[QDGW/handle_client_logout]: ClientAlreadyLoggedIn: {:?}",
-                                err
-                            );
-                        }
-                    }
-                }
-            },
-            // Something went horribly wrong, log the message, and return an unknown error
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Confirm the logout on the data channel of the client while it still exists
+        match self.send_client_logout_ack(client_id).await {
+            Ok(_) => {}
+            Err(err) => return Err(GatewayError::from(err)),
+        }
+
+        // println!("[::handle_client_logout]: Client is logged in, proceed with logout");
+        match self.client_logout(client_id).await {
+            Ok(_) => {}
             Err(err) => {
                 println!(
-                    "[QDGW/handle_client_logout] UnknownClientError: {:?}",
+                    "[QDGW/handle_client_logout]: ClientLogOutError: {:?}",
                     err.to_string()
                 );
-
-                let client_error_type = ClientErrorType::UnknownClientError;
-                match self.send_client_error(client_id, client_error_type).await {
-                    Ok(_) => {}
-                    Err(err) => {
-                        println!(
-                            "[QDGW/handle_client_logout]: UnknownClientError: {:?}",
-                            err.to_string()
-                        );
-                    }
-                }
+                return Err(GatewayError::ClientError(
+                    client_id,
+                    ClientErrorType::ClientLogOutError,
+                ));
             }
         }
 
@@ -99,7 +70,8 @@ impl Server {
 
     /// Logs out a client by removing them from the client database.
     ///
-    /// Locks the client manager and removes the client with the given ID.
+    /// Cancels all active data streams of the client,
+    /// then locks the client manager and removes the client with the given ID.
     ///
     /// # Parameters
     ///
@@ -115,6 +87,9 @@ impl Server {
     /// - MessageProcessingError if there was an issue removing the client from the database.
     ///
     pub(crate) async fn client_logout(&self, client_id: u16) -> Result<(), MessageProcessingError> {
+        // Cancel all active data streams of the client
+        self.cancel_client_data_streams(client_id).await;
+
         // lock the client_data_producers hashmap
         let mut client_data_producers = self.client_producers().write().await;
 
@@ -133,6 +108,15 @@ impl Server {
         // Unlock the client_configs hashmap
         drop(client_configs);
 
+        // Stop tracking the heartbeats of the client
+        let mut client_heartbeats = self.client_heartbeats().write().await;
+        client_heartbeats.remove(&client_id);
+        drop(client_heartbeats);
+
+        // Stop exporting the usage of the client. The rate limits of the client are kept,
+        // so that logging out and in again does not reset them.
+        self.usage_metrics().remove(client_id);
+
         // println!("[client_logout]: Client {:?} logged out successfully", client_id);
         Ok(())
     }
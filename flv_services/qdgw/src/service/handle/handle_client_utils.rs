@@ -1,5 +1,7 @@
 use common::prelude::MessageProcessingError;
+use sbe_messages::prelude::ClientErrorType;
 
+use crate::service::errors::GatewayError;
 use crate::service::Server;
 
 impl Server {
@@ -24,4 +26,32 @@ impl Server {
 
         Ok(client_db.contains_key(&client_id))
     }
+
+    /// Ensures that the client with the given ID is logged in.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the client to check
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the client is logged in. Otherwise, a `GatewayError::ClientError`
+    /// with `ClientNotLoggedIn`, or with `UnknownClientError` if the check failed.
+    ///
+    pub(crate) async fn ensure_client_login(&self, client_id: u16) -> Result<(), GatewayError> {
+        match self.check_client_login(client_id).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(GatewayError::ClientError(
+                client_id,
+                ClientErrorType::ClientNotLoggedIn,
+            )),
+            Err(err) => {
+                println!("[QDGW/ensure_client_login]: UnknownClientError: {}", err);
+                Err(GatewayError::ClientError(
+                    client_id,
+                    ClientErrorType::UnknownClientError,
+                ))
+            }
+        }
+    }
 }
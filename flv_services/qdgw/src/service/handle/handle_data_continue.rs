@@ -0,0 +1,50 @@
+use crate::service::errors::GatewayError;
+use crate::service::Server;
+use autometrics::autometrics;
+use sbe_messages::prelude::ContinueDataMessage;
+
+impl Server {
+    /// Handles a ContinueDataMessage from a client.
+    ///
+    /// Continues the paused data stream matching the client id, exchange id, symbol id and
+    /// data type of the message. A paced stream continues at its replay speed from the
+    /// next bar on, without catching up on the time it was paused.
+    ///
+    /// # Parameters
+    ///
+    /// * `continue_data_msg` - The ContinueDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `GatewayError::ClientError` with ClientNotLoggedIn if the client is not logged in.
+    ///
+    #[autometrics]
+    pub(crate) async fn handle_continue_data(
+        &self,
+        continue_data_msg: &ContinueDataMessage,
+    ) -> Result<(), GatewayError> {
+        // println!("::handle_continue_data]: Extract fields from message");
+        let client_id = *continue_data_msg.client_id();
+        let exchange_id = *continue_data_msg.exchange_id() as u16;
+        let symbol_id = *continue_data_msg.symbol_id();
+        let data_type = *continue_data_msg.data_type_id();
+
+        // println!("::handle_continue_data]: Check if the client is logged in");
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("::handle_continue_data]: Continue the data stream");
+        let stream_key = (client_id, exchange_id, symbol_id, data_type);
+        if !self.continue_data_stream(&stream_key).await {
+            println!(
+                "[QDGW/handle_continue_data]: No paused data stream found for: {:?}",
+                stream_key
+            );
+        }
+
+        Ok(())
+    }
+}
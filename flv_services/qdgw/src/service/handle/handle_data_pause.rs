@@ -0,0 +1,50 @@
+use crate::service::errors::GatewayError;
+use crate::service::Server;
+use autometrics::autometrics;
+use sbe_messages::prelude::PauseDataMessage;
+
+impl Server {
+    /// Handles a PauseDataMessage from a client.
+    ///
+    /// Pauses the data stream matching the client id, exchange id, symbol id and data type
+    /// of the message. The paused stream holds back the next bar until it gets continued
+    /// with a ContinueDataMessage or cancelled with a StopDataMessage.
+    ///
+    /// # Parameters
+    ///
+    /// * `pause_data_msg` - The PauseDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `GatewayError::ClientError` with ClientNotLoggedIn if the client is not logged in.
+    ///
+    #[autometrics]
+    pub(crate) async fn handle_pause_data(
+        &self,
+        pause_data_msg: &PauseDataMessage,
+    ) -> Result<(), GatewayError> {
+        // println!("::handle_pause_data]: Extract fields from message");
+        let client_id = *pause_data_msg.client_id();
+        let exchange_id = *pause_data_msg.exchange_id() as u16;
+        let symbol_id = *pause_data_msg.symbol_id();
+        let data_type = *pause_data_msg.data_type_id();
+
+        // println!("::handle_pause_data]: Check if the client is logged in");
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("::handle_pause_data]: Pause the data stream");
+        let stream_key = (client_id, exchange_id, symbol_id, data_type);
+        if !self.pause_data_stream(&stream_key).await {
+            println!(
+                "[QDGW/handle_pause_data]: No running data stream found for: {:?}",
+                stream_key
+            );
+        }
+
+        Ok(())
+    }
+}
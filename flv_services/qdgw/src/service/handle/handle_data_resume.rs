@@ -0,0 +1,160 @@
+use crate::service::errors::GatewayError;
+use crate::service::scheduler::{DataStreamJob, ResumePoint};
+use crate::service::Server;
+use autometrics::autometrics;
+use sbe_messages::prelude::{DataErrorType, DataType, ResumeDataMessage, StartDataMessage};
+
+impl Server {
+    /// Handles a ResumeDataMessage from a client.
+    ///
+    /// Restarts a data stream from a given sequence number or timestamp,
+    /// typically after the client detected a gap in the sequence numbers of the bars.
+    /// The resumed stream replays the original request, skips all bars before the
+    /// resume point, and replaces a running stream with the same key.
+    /// If the message carries both, the sequence number takes precedence;
+    /// if it carries neither, the stream resumes from the first bar.
+    ///
+    /// # Parameters
+    ///
+    /// * `resume_data_msg` - The ResumeDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `GatewayError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// Can fail with a `GatewayError` if:
+    ///
+    /// - The client is not logged in (ClientNotLoggedIn)
+    /// - The symbol has no data table (DataTableNotFound)
+    /// - The data type is unknown (DataTypeNotKnownError)
+    /// - The time resolution of an OHLCV request is invalid (TimeResolutionNotValidError)
+    /// - The threshold of a tick, volume, or dollar bar request is invalid (ThresholdNotValidError)
+    /// - The data table does not exist in the database (DataTableNotFound)
+    /// - The data table has an unexpected schema, or the database cannot be queried (DataUnavailableError)
+    /// - The client is not entitled to the exchange, symbol, or data type, or already runs
+    ///   its maximum number of streams (NotEntitled)
+    /// - The client runs too many streams, or used up the rows or bytes of its quota window
+    ///   (RateLimited)
+    /// - The symbol is part of a running stream of several symbols (DataUnavailableError)
+    ///
+    #[autometrics]
+    pub(crate) async fn handle_resume_data_message(
+        &self,
+        resume_data_msg: &ResumeDataMessage,
+    ) -> Result<(), GatewayError> {
+        // println!("::handle_resume_data_message]: Extract fields from message");
+        let client_id = *resume_data_msg.client_id();
+        let exchange_id = *resume_data_msg.exchange_id() as u16;
+        let symbol_id = *resume_data_msg.symbol_id();
+        let data_type = *resume_data_msg.data_type_id();
+
+        // println!("::handle_resume_data_message]: Check if the client is logged in");
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_resume_data_message]: Client is logged in, proceed.");
+        let trade_table = match self.get_trade_table_name(exchange_id, symbol_id).await {
+            Ok(table) => table,
+            Err(err) => {
+                println!("[QDGW/handle_resume_data_message]: Failed to get Data Table For exchange error: {}", err);
+                return Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::DataTableNotFound,
+                ));
+            }
+        };
+
+        if data_type == DataType::UnknownDataType {
+            return Err(GatewayError::DataError(
+                client_id,
+                DataErrorType::DataTypeNotKnownError,
+            ));
+        }
+
+        // println!("[::handle_resume_data_message]: Validate the time resolution before it reaches the query.");
+        match self.ensure_valid_time_resolution(
+            client_id,
+            data_type,
+            resume_data_msg.time_resolution(),
+        ) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // The resumed stream replays the request of the original stream
+        let start_data_msg = StartDataMessage::new(
+            client_id,
+            *resume_data_msg.exchange_id(),
+            symbol_id,
+            *resume_data_msg.time_resolution(),
+            data_type,
+            resume_data_msg.start_time(),
+            resume_data_msg.end_time(),
+            *resume_data_msg.replay_mode(),
+            *resume_data_msg.replay_speed(),
+        )
+        .with_threshold(resume_data_msg.threshold());
+
+        // println!("[::handle_resume_data_message]: Validate the threshold of information bars.");
+        match self.ensure_valid_threshold(client_id, &start_data_msg) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_resume_data_message]: Validate the trade table in the database.");
+        match self.ensure_valid_trade_table(client_id, &trade_table).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_resume_data_message]: Determine the resume point.");
+        let resume = match (
+            resume_data_msg.resume_sequence_number(),
+            resume_data_msg.resume_time(),
+        ) {
+            (Some(sequence_number), _) => ResumePoint::SequenceNumber(sequence_number),
+            (None, Some(date_time)) => ResumePoint::DateTime(date_time),
+            (None, None) => ResumePoint::SequenceNumber(1),
+        };
+
+        // println!("[::handle_resume_data_message]: Check the entitlements of the client.");
+        let stream_key = (client_id, exchange_id, symbol_id, data_type);
+        match self.ensure_entitled(client_id, &[stream_key]).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_resume_data_message]: Check the stream quota of the client.");
+        match self.ensure_stream_quota(client_id) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_resume_data_message]: Register the resumed data stream.");
+        let control = match self.register_resumed_data_stream(stream_key).await {
+            Some(control) => control,
+            None => {
+                println!(
+                    "[QDGW/handle_resume_data_message]: Data stream is part of a multi symbol stream: {:?}",
+                    stream_key
+                );
+                return Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::DataUnavailableError,
+                ));
+            }
+        };
+
+        // println!("[::handle_resume_data_message]: Schedule the resumed data stream on the worker pool.");
+        let job = DataStreamJob::new(stream_key, trade_table, start_data_msg, control)
+            .with_resume(resume);
+        self.scheduler().schedule(job).await;
+
+        Ok(())
+    }
+}
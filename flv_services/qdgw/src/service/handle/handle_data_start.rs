@@ -1,12 +1,16 @@
+use crate::service::errors::GatewayError;
+use crate::service::scheduler::DataStreamJob;
 use crate::service::Server;
 use autometrics::autometrics;
 use common::prelude::MessageProcessingError;
-use sbe_messages::prelude::{ClientErrorType, DataErrorType, DataType, StartDataMessage};
+use sbe_messages::prelude::{DataErrorType, DataType, StartDataMessage};
 
 impl Server {
     /// Handles a StartDataMessage from a client.
     ///
-    /// Validates the request, gets the requested data, and sends it to the client.
+    /// Validates the request, registers a new data stream, and schedules it
+    /// on the worker pool that streams the requested data to the client.
+    /// The stream can be cancelled with a StopData or StopAllData message.
     ///
     /// # Parameters
     ///
@@ -15,24 +19,32 @@ impl Server {
     /// # Returns
     ///
     /// Returns a `Result` with `()` if successful, otherwise returns a
-    /// `MessageProcessingError` on failure.
+    /// `GatewayError` on failure.
     ///
     /// # Errors
     ///
-    /// Can fail with a `MessageProcessingError` if:
+    /// Can fail with a `GatewayError` if:
     ///
-    /// - Fails to get the client control or data channel
-    /// - Fails when the client is not logged in
-    /// - Fails when an unknown data type is requested
-    /// - Fails to get the first or last bar
-    /// - Fails to get the requested data bars
-    /// - Fails to send any error messages back to the client
+    /// - The client is not logged in (ClientNotLoggedIn)
+    /// - The symbol has no data table (DataTableNotFound)
+    /// - The data type is unknown (DataTypeNotKnownError)
+    /// - The time resolution of an OHLCV request is invalid (TimeResolutionNotValidError)
+    /// - The threshold of a tick, volume, or dollar bar request is invalid (ThresholdNotValidError)
+    /// - The data table does not exist in the database (DataTableNotFound)
+    /// - The data table has an unexpected schema, or the database cannot be queried (DataUnavailableError)
+    /// - The client is not entitled to the exchange, symbol, or data type, or already runs
+    ///   its maximum number of streams (NotEntitled)
+    /// - The client runs too many streams, or used up the rows or bytes of its quota window
+    ///   (RateLimited)
+    /// - The data stream is already active (DataUnavailableError)
+    ///
+    /// Errors that occur while streaming are sent back to the client from the stream task.
     ///
     #[autometrics]
     pub(crate) async fn handle_start_data_message(
         &self,
         start_data_msg: &StartDataMessage,
-    ) -> Result<(), MessageProcessingError> {
+    ) -> Result<(), GatewayError> {
         // Remove debug print
         // println!("[QDGW/handle_start_data_message]");
 
@@ -40,26 +52,12 @@ impl Server {
         let client_id = *start_data_msg.client_id();
         let exchange_id = *start_data_msg.exchange_id() as u16;
         let symbol_id = *start_data_msg.symbol_id();
-        let data_type = start_data_msg.data_type_id();
+        let data_type = *start_data_msg.data_type_id();
 
         // println!("::handle_start_data_message]: Check if the client is already logged in");
-        let exists = self
-            .check_client_login(client_id)
-            .await
-            .expect("[QDGW/handle_start_data_message]: Failed to check if client is logged in");
-
-        // Send a ClientNotLoggedIn Error, if not logged in.
-        if !exists {
-            // println!("[::handle_start_data_message]: Client is not logged in, return an ClientNotLoggedIn error to the client");
-            let client_error_type = ClientErrorType::ClientNotLoggedIn;
-            match self.send_client_error(client_id, client_error_type).await {
-                Ok(_) => {}
-                Err(err) => {
-                    println!("[QDGW/handle_start_data_message]: Failed to send ClientNotLoggedIn error: {}", err);
-                }
-            }
-
-            return Err(MessageProcessingError("Client not logged in.".into()));
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
         }
 
         // println!("[::handle_start_data_message]: Client is logged in, proceed.");
@@ -67,95 +65,76 @@ impl Server {
             Ok(table) => table,
             Err(err) => {
                 println!("[QDGW/handle_start_data_message]: Failed to get Data Table For exchange error: {}", err);
-                let data_err = DataErrorType::DataTableNotFound;
-                match self.send_data_error(client_id, data_err).await {
-                    Ok(_) => {}
-                    Err(err) => {
-                        println!("[QDGW/handle_start_data_message]: Failed to send DataTableNotFound error: {}", err);
-                    }
-                }
-                return Err(MessageProcessingError(format!(
-                    "[QDGW/handle_start_data_message]: Failed to get data table: {}",
-                    err
-                )));
+                return Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::DataTableNotFound,
+                ));
             }
         };
 
         // println!("[::handle_start_data_message]: Get trade bars for data type.");
-        match data_type {
-            DataType::UnknownDataType => {
-                let data_err = DataErrorType::DataTypeNotKnownError;
-                match self.send_data_error(client_id, data_err).await {
-                    Ok(_) => {}
-                    Err(err) => {
-                        println!(
-                            "[QDGW/handle_start_data_message]: Failed to get last bar: {}",
-                            err
-                        );
-                    }
-                }
-            }
-            //
-            DataType::TradeData => {
-                // println!("[::handle_start_data_message]: Symbol: {}, trade table: {}", symbol_id, trade_table);
-                match self
-                    .start_trade_data_stream(client_id, symbol_id, &trade_table)
-                    .await
-                {
-                    Ok(_) => {
-                        // println!("[::handle_start_data_message]: send all trade bars to the client.");
-                    }
-                    Err((data_err, err)) => {
-                        // println!("[::handle_start_data_message]: send error back to the client.");
-                        match self.send_data_error(client_id, data_err).await {
-                            Ok(_) => {}
-                            Err(err) => {
-                                println!(
-                                    "[QDGW/handle_start_data_message]: Failed to get last bar: {}",
-                                    err
-                                );
-                            }
-                        }
-
-                        return Err(MessageProcessingError(format!(
-                            "[QDGW/handle_start_data_message]: Failed to get trade bars: {}",
-                            err
-                        )));
-                    }
-                }
-            }
-            DataType::OHLCVData => {
-                // println!("[::handle_start_data_message]: Get all OHLCV bars.");
-                let time_resolution = &start_data_msg.time_resolution();
-
-                match self
-                    .start_ohlcv_data(client_id, symbol_id, &trade_table, &time_resolution)
-                    .await
-                {
-                    Ok(_) => {
-                        // println!("[::handle_start_data_message]: stream OHLCV bars to the client.");
-                    }
-                    Err((data_err, err)) => {
-                        match self.send_data_error(client_id, data_err).await {
-                            Ok(_) => {}
-                            Err(err) => {
-                                println!(
-                                    "[QDGW/handle_start_data_message]: Failed to get last bar: {}",
-                                    err
-                                );
-                            }
-                        }
-
-                        return Err(MessageProcessingError(format!(
-                            "[QDGW/handle_start_data_message]: Failed to get trade bars: {}",
-                            err
-                        )));
-                    }
-                }
+        if data_type == DataType::UnknownDataType {
+            return Err(GatewayError::DataError(
+                client_id,
+                DataErrorType::DataTypeNotKnownError,
+            ));
+        }
+
+        // println!("[::handle_start_data_message]: Validate the time resolution before it reaches the query.");
+        match self.ensure_valid_time_resolution(
+            client_id,
+            data_type,
+            start_data_msg.time_resolution(),
+        ) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_start_data_message]: Validate the threshold of information bars.");
+        match self.ensure_valid_threshold(client_id, start_data_msg) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_start_data_message]: Validate the trade table in the database.");
+        match self.ensure_valid_trade_table(client_id, &trade_table).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_start_data_message]: Check the entitlements of the client.");
+        let stream_key = (client_id, exchange_id, symbol_id, data_type);
+        match self.ensure_entitled(client_id, &[stream_key]).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_start_data_message]: Check the stream quota of the client.");
+        match self.ensure_stream_quota(client_id) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_start_data_message]: Register the data stream.");
+        let control = match self.register_data_stream(stream_key).await {
+            Some(control) => control,
+            None => {
+                println!(
+                    "[QDGW/handle_start_data_message]: Data stream already active: {:?}",
+                    stream_key
+                );
+                return Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::DataUnavailableError,
+                ));
             }
         };
 
-        // println!("[handle_start_data_message]: Date send successfully to client: {}", client_id);
+        // println!("[::handle_start_data_message]: Schedule the data stream on the worker pool.");
+        let job = DataStreamJob::new(stream_key, trade_table, start_data_msg.clone(), control);
+        self.scheduler().schedule(job).await;
+
+        // println!("[handle_start_data_message]: Data stream scheduled for client: {}", client_id);
         Ok(())
     }
 
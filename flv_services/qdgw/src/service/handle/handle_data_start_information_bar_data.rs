@@ -0,0 +1,209 @@
+use crate::service::scheduler::{DataStreamJob, MessageBatch, ReplayPacer};
+use crate::service::{Server, DATA_BATCH_DELAY_MICROS, DATA_BATCH_SIZE};
+use common::prelude::{InformationBarBuilder, MessageProcessingError};
+use futures::StreamExt;
+use sbe_messages::prelude::{DataErrorType, DataType};
+use std::time::Duration;
+use tokio::select;
+use tokio::time::sleep_until;
+
+impl Server {
+    /// Sends a stream of tick, volume, or dollar bars to the client.
+    ///
+    /// This will:
+    ///
+    /// 1. Send a first OHLCV bar message to indicate the start of the stream, unless the stream is resumed.
+    /// 2. Stream trade bars from the database for the given symbol and optional time range.
+    /// 3. Sample the trades into bars that close once they reach the threshold of the request.
+    /// 4. Number each closed bar and encode it into an SBE OHLCV bar message.
+    /// 5. Send the encoded bar messages to the client in batches.
+    /// 6. Send the partial bar of the remaining trades once all trades are streamed.
+    /// 7. Send a last OHLCV bar message to indicate the end of the stream.
+    ///
+    /// Information bars are sent as regular OHLCV bar messages, so existing OHLCV
+    /// consumers can process them without changes. The date time of a bar is the
+    /// date time of its first trade, whereas each bar is paced by its closing trade.
+    ///
+    /// When the cancellation token gets cancelled, streaming stops at the next trade,
+    /// the open bar is dropped, and the last bar message is sent to the client.
+    /// When the query fails while streaming, the open bar is dropped as well,
+    /// but the stream ends with an error instead of the last bar message.
+    ///
+    /// Each bar carries its sequence number in the stream, starting at 1.
+    /// A resumed stream skips all bars before its resume point and keeps
+    /// the sequence numbers of the original stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `job` - The data stream job with the client id, symbol id, trade table, bar threshold,
+    ///   time range, replay mode, and control handle of the data stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns a Result with the error variants:
+    ///
+    /// - `(DataErrorType::ThresholdNotValidError, MessageProcessingError)` - The job has no bar threshold.
+    /// - `(DataErrorType, MessageProcessingError)` - Error streaming bars.
+    ///
+    pub(crate) async fn start_information_bar_data(
+        &self,
+        job: &DataStreamJob,
+    ) -> Result<(), (DataErrorType, MessageProcessingError)> {
+        let (client_id, _, symbol_id, _) = job.stream_key();
+        let token = job.token();
+        let mut pacer = ReplayPacer::new(job);
+
+        // Information bars are sent as OHLCV data
+        let data_type = DataType::OHLCVData;
+
+        // The threshold has been validated before the stream was registered
+        let threshold = match job.bar_threshold() {
+            Some(threshold) => threshold,
+            None => {
+                return Err((
+                    DataErrorType::ThresholdNotValidError,
+                    MessageProcessingError("Missing bar threshold".to_string()),
+                ))
+            }
+        };
+
+        let mut builder = InformationBarBuilder::new(symbol_id, threshold);
+
+        // Send the first bar message to inform the client that the data stream starts;
+        // a resumed stream continues a stream that has already started
+        if !job.is_resumed() {
+            match self.send_first_bar(client_id, symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        // Lock the query manager
+        let q_manager = self.query_manager().read().await;
+
+        // Create a stream of trade bars from the database
+        let mut stream = q_manager
+            .stream_trades(job.trade_table(), job.start_time(), job.end_time())
+            .await;
+
+        // Collect bars into batches to send many bars per message bus round trip
+        let max_delay = Duration::from_micros(DATA_BATCH_DELAY_MICROS);
+        let mut batch = MessageBatch::new(DATA_BATCH_SIZE, max_delay);
+
+        // Sequence number of the last bar of the stream
+        let mut sequence_number: u64 = 0;
+
+        // Only a completed stream sends its partial last bar
+        let mut completed = false;
+
+        // Process trade bars from the stream as they come in until the stream ends or gets cancelled
+        loop {
+            select! {
+                _ = token.cancelled() => break,
+
+                // Send a partial batch once its oldest bar has waited long enough
+                _ = sleep_until(batch.deadline()), if !batch.is_empty() => {
+                    match self.send_batch(client_id, &mut batch).await {
+                        Ok(_) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                record = stream.next() => match record {
+                    Some(Ok(record)) => {
+                        // Add the trade to the open bar; continue until the bar reaches the threshold
+                        let bar = match builder.update(record.date_time(), record.price(), record.volume()) {
+                            Some(bar) => bar,
+                            None => continue,
+                        };
+
+                        // Number every bar, so a resumed stream numbers its bars like the original stream
+                        sequence_number += 1;
+
+                        // Skip the bars before the resume point of a resumed stream
+                        if job.skips(sequence_number, bar.date_time()) {
+                            continue;
+                        }
+
+                        // Send the pending bars before waiting for a paced or paused bar
+                        if !pacer.is_ready(record.date_time()) {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+
+                        // Wait until the closing trade of the bar is due; stop if cancelled while waiting
+                        if !pacer.wait(record.date_time()).await {
+                            break;
+                        }
+
+                        // Record the progress of the stream for the admin endpoints
+                        job.control().record_bar(bar.date_time());
+
+                        match self.encode_information_bar_message(sequence_number, bar) {
+                            Ok(message) => batch.push(message),
+                            Err(e) => return Err(e),
+                        }
+
+                        if batch.is_full() {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+                    }
+                    // A failed query leaves the open bar incomplete, so it is dropped
+                    // and the stream ends without the last bar message
+                    Some(Err(e)) => {
+                        match self.send_batch(client_id, &mut batch).await {
+                            Ok(_) => {}
+                            Err(e) => return Err(e),
+                        }
+
+                        return Err(self.map_stream_error(e));
+                    }
+                    None => {
+                        completed = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Send the partial bar of the trades after the last closed bar
+        if completed {
+            if let Some(bar) = builder.flush() {
+                sequence_number += 1;
+
+                if !job.skips(sequence_number, bar.date_time()) {
+                    // Record the progress of the stream for the admin endpoints
+                    job.control().record_bar(bar.date_time());
+
+                    match self.encode_information_bar_message(sequence_number, bar) {
+                        Ok(message) => batch.push(message),
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+        }
+
+        // Send the remaining bars before the last bar message
+        match self.send_batch(client_id, &mut batch).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Send the last bar message to inform the client that the data stream has ended,
+        // regardless of whether the stream completed or was cancelled,
+        // unless a resumed stream has replaced the stream
+        if !job.control().is_replaced() {
+            match self.send_last_bar(client_id, symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::service::errors::GatewayError;
+use crate::service::scheduler::DataStreamJob;
+use crate::service::{Server, StreamKey};
+use autometrics::autometrics;
+use sbe_messages::prelude::{DataErrorType, DataType, StartDataMessage, StartMultiDataMessage};
+
+impl Server {
+    /// Handles a StartMultiDataMessage from a client.
+    ///
+    /// Validates the request, registers one data stream for all requested symbols, and
+    /// schedules it on the worker pool. The worker merges the data of all symbols by
+    /// timestamp into one stream, where each bar is tagged with the id of its symbol.
+    ///
+    /// The stream is registered under the stream key of each symbol, so a StopData,
+    /// PauseData, or ContinueData message for any of the symbols applies to the whole stream.
+    /// Duplicate symbol ids are streamed once.
+    ///
+    /// # Parameters
+    ///
+    /// * `start_multi_data_msg` - The StartMultiDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `GatewayError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// Can fail with a `GatewayError` if:
+    ///
+    /// - The client is not logged in (ClientNotLoggedIn)
+    /// - The request holds no symbols (DataUnavailableError)
+    /// - Any of the symbols has no data table (DataTableNotFound)
+    /// - The data type is unknown or a tick, volume, or dollar bar (DataTypeNotKnownError)
+    /// - The time resolution of an OHLCV request is invalid (TimeResolutionNotValidError)
+    /// - Any of the data tables does not exist in the database (DataTableNotFound)
+    /// - Any of the data tables has an unexpected schema, or the database cannot be queried
+    ///   (DataUnavailableError)
+    /// - The client is not entitled to the exchange, any of the symbols, or the data type,
+    ///   or the stream exceeds its maximum number of streams (NotEntitled)
+    /// - The client runs too many streams, or used up the rows or bytes of its quota window
+    ///   (RateLimited)
+    /// - A data stream of any of the symbols is already active (DataUnavailableError)
+    ///
+    /// Errors that occur while streaming are sent back to the client from the stream task.
+    ///
+    #[autometrics]
+    pub(crate) async fn handle_start_multi_data_message(
+        &self,
+        start_multi_data_msg: &StartMultiDataMessage,
+    ) -> Result<(), GatewayError> {
+        // println!("::handle_start_multi_data_message]: Extract fields from message");
+        let client_id = *start_multi_data_msg.client_id();
+        let exchange_id = *start_multi_data_msg.exchange_id() as u16;
+        let data_type = *start_multi_data_msg.data_type_id();
+
+        // println!("::handle_start_multi_data_message]: Check if the client is already logged in");
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Keep the requested order of the symbols, without duplicates
+        let mut symbol_ids: Vec<u16> = Vec::with_capacity(start_multi_data_msg.symbol_ids().len());
+        for symbol_id in start_multi_data_msg.symbol_ids() {
+            if !symbol_ids.contains(symbol_id) {
+                symbol_ids.push(*symbol_id);
+            }
+        }
+
+        if symbol_ids.is_empty() {
+            return Err(GatewayError::DataError(
+                client_id,
+                DataErrorType::DataUnavailableError,
+            ));
+        }
+
+        // println!("[::handle_start_multi_data_message]: Look up the data table of each symbol.");
+        let mut symbols = Vec::with_capacity(symbol_ids.len());
+        for symbol_id in symbol_ids {
+            match self.get_trade_table_name(exchange_id, symbol_id).await {
+                Ok(table) => symbols.push((symbol_id, table)),
+                Err(err) => {
+                    println!("[QDGW/handle_start_multi_data_message]: Failed to get Data Table For symbol {} error: {}", symbol_id, err);
+                    return Err(GatewayError::DataError(
+                        client_id,
+                        DataErrorType::DataTableNotFound,
+                    ));
+                }
+            }
+        }
+
+        // Information bars need a threshold, which a multi symbol request does not carry
+        if data_type == DataType::UnknownDataType || data_type.is_information_bar() {
+            return Err(GatewayError::DataError(
+                client_id,
+                DataErrorType::DataTypeNotKnownError,
+            ));
+        }
+
+        // println!("[::handle_start_multi_data_message]: Validate the time resolution before it reaches the query.");
+        match self.ensure_valid_time_resolution(
+            client_id,
+            data_type,
+            start_multi_data_msg.time_resolution(),
+        ) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_start_multi_data_message]: Validate the trade table of each symbol in the database.");
+        for (_, trade_table) in &symbols {
+            match self.ensure_valid_trade_table(client_id, trade_table).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        // println!("[::handle_start_multi_data_message]: Register the data stream for all symbols.");
+        let stream_keys: Vec<StreamKey> = symbols
+            .iter()
+            .map(|(symbol_id, _)| (client_id, exchange_id, *symbol_id, data_type))
+            .collect();
+
+        // println!("[::handle_start_multi_data_message]: Check the entitlements of the client.");
+        match self.ensure_entitled(client_id, &stream_keys).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("[::handle_start_multi_data_message]: Check the stream quota of the client.");
+        match self.ensure_stream_quota(client_id) {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        let control = match self.register_data_streams(&stream_keys).await {
+            Some(control) => control,
+            None => {
+                println!(
+                    "[QDGW/handle_start_multi_data_message]: Data stream already active for any of: {:?}",
+                    stream_keys
+                );
+                return Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::DataUnavailableError,
+                ));
+            }
+        };
+
+        // The stream parameters shared by all symbols; the job is keyed by its first symbol.
+        let stream_key = stream_keys[0];
+        let request = StartDataMessage::new(
+            client_id,
+            *start_multi_data_msg.exchange_id(),
+            stream_key.2,
+            *start_multi_data_msg.time_resolution(),
+            data_type,
+            start_multi_data_msg.start_time(),
+            start_multi_data_msg.end_time(),
+            *start_multi_data_msg.replay_mode(),
+            *start_multi_data_msg.replay_speed(),
+        );
+
+        // println!("[::handle_start_multi_data_message]: Schedule the data stream on the worker pool.");
+        let job = DataStreamJob::new_multi(stream_key, symbols, request, control);
+        self.scheduler().schedule(job).await;
+
+        Ok(())
+    }
+}
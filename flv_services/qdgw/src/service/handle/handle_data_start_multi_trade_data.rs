@@ -0,0 +1,153 @@
+use crate::service::scheduler::{DataStreamJob, MessageBatch, ReplayPacer};
+use crate::service::{Server, DATA_BATCH_DELAY_MICROS, DATA_BATCH_SIZE};
+use common::prelude::MessageProcessingError;
+use futures::StreamExt;
+use sbe_messages::prelude::{DataErrorType, DataType};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::select;
+use tokio::time::sleep_until;
+
+impl Server {
+    /// Sends a merged stream of trade bar data of several symbols to the client.
+    ///
+    /// This will:
+    ///
+    /// 1. Send a first trade bar message for each symbol to indicate the start of the stream.
+    /// 2. Stream trade bars from the database for each symbol and optional time range.
+    /// 3. Merge the trade bars of all symbols into one stream ordered by timestamp.
+    /// 4. Number the trade bars of each symbol and encode each trade bar with the symbol id
+    ///    and sequence number of its symbol into an SBE message.
+    /// 5. Send the encoded trade bar messages to the client in batches.
+    /// 6. Send a last trade bar message for each symbol to indicate the end of the stream.
+    ///
+    /// Batching, pacing, pausing, cancellation, and query failures work the same as for
+    /// a single symbol stream, see `start_trade_data_stream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `job` - The data stream job with the client id, symbols and trade tables,
+    ///   time range, replay mode, and control handle of the data stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns a Result with the error variants:
+    ///
+    /// - `(DataErrorType, MessageProcessingError)` - Error streaming trade bars.
+    ///
+    pub(crate) async fn start_multi_trade_data_stream(
+        &self,
+        job: &DataStreamJob,
+    ) -> Result<(), (DataErrorType, MessageProcessingError)> {
+        let client_id = job.client_id();
+        let token = job.token();
+        let mut pacer = ReplayPacer::new(job);
+
+        // Set the data type to trade data
+        let data_type = DataType::TradeData;
+
+        // Send the first bar messages to inform the client that the data streams start
+        for (symbol_id, _) in job.symbols() {
+            match self.send_first_bar(client_id, *symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        // Lock the query manager
+        let q_manager = self.query_manager().read().await;
+
+        // Create a merged stream of trade bars of all symbols from the database
+        let mut stream = q_manager
+            .stream_trades_merged(job.symbols(), job.start_time(), job.end_time())
+            .await;
+
+        // Collect trade bars into batches to send many bars per message bus round trip
+        let max_delay = Duration::from_micros(DATA_BATCH_DELAY_MICROS);
+        let mut batch = MessageBatch::new(DATA_BATCH_SIZE, max_delay);
+
+        // Sequence number of the last bar of each symbol
+        let mut sequence_numbers: HashMap<u16, u64> = HashMap::new();
+
+        // Process trade bars from the stream as they come in until the stream ends or gets cancelled
+        loop {
+            select! {
+                _ = token.cancelled() => break,
+
+                // Send a partial batch once its oldest bar has waited long enough
+                _ = sleep_until(batch.deadline()), if !batch.is_empty() => {
+                    match self.send_batch(client_id, &mut batch).await {
+                        Ok(_) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                record = stream.next() => match record {
+                    Some(Ok((symbol_id, record))) => {
+                        // Number the bars of each symbol on their own,
+                        // so the client can detect gaps per symbol
+                        let sequence_number = sequence_numbers.entry(symbol_id).or_insert(0);
+                        *sequence_number += 1;
+                        let sequence_number = *sequence_number;
+
+                        // Send the pending bars before waiting for a paced or paused bar
+                        if !pacer.is_ready(record.date_time()) {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+
+                        // Wait until the bar is due; stop if cancelled while waiting
+                        if !pacer.wait(record.date_time()).await {
+                            break;
+                        }
+
+                        // Record the progress of the stream for the admin endpoints
+                        job.control().record_bar(record.date_time());
+
+                        match self.encode_trade_bar_message(symbol_id, sequence_number, &record) {
+                            Ok(message) => batch.push(message),
+                            Err(e) => return Err(e),
+                        }
+
+                        if batch.is_full() {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+                    }
+                    Some(Err(e)) => {
+                        // Send the bars before the failure, but no last bar message,
+                        // so the client does not take the stream for complete
+                        match self.send_batch(client_id, &mut batch).await {
+                            Ok(_) => {}
+                            Err(e) => return Err(e),
+                        }
+
+                        return Err(self.map_stream_error(e));
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        // Send the remaining bars before the last bar messages
+        match self.send_batch(client_id, &mut batch).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Send the last bar messages to inform the client that the data streams have ended,
+        // regardless of whether the stream completed or was cancelled
+        for (symbol_id, _) in job.symbols() {
+            match self.send_last_bar(client_id, *symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,25 +1,44 @@
-use crate::service::Server;
-use common::prelude::{MessageProcessingError, TimeResolution};
+use crate::service::scheduler::{DataStreamJob, MessageBatch, ReplayPacer};
+use crate::service::{Server, DATA_BATCH_DELAY_MICROS, DATA_BATCH_SIZE};
+use common::prelude::MessageProcessingError;
 use futures::StreamExt;
 use sbe_messages::prelude::{DataErrorType, DataType};
+use std::time::Duration;
+use tokio::select;
+use tokio::time::sleep_until;
 
 impl Server {
     /// Sends a stream of OHLCV bar data to the client.
     ///
     /// This will:
     ///
-    /// 1. Send a first OHLCV bar message to indicate the start of the stream.
-    /// 2. Stream OHLCV bars from the database for the given symbol and time resolution.
-    /// 3. Encode each OHLCV bar into an SBE message.
-    /// 4. Send the encoded OHLCV bar messages to the client.
+    /// 1. Send a first OHLCV bar message to indicate the start of the stream, unless the stream is resumed.
+    /// 2. Stream OHLCV bars from the database for the given symbol, time resolution, and optional time range.
+    /// 3. Number each OHLCV bar and encode it into an SBE message.
+    /// 4. Send the encoded OHLCV bar messages to the client in batches.
     /// 5. Send a last OHLCV bar message to indicate the end of the stream.
     ///
+    /// A batch is sent once it holds `DATA_BATCH_SIZE` bars, or once its oldest bar has
+    /// waited for `DATA_BATCH_DELAY_MICROS`, or before waiting for a paced or paused bar.
+    ///
+    /// Each OHLCV bar is paced according to the replay mode of the request, and
+    /// held back for as long as the stream is paused.
+    ///
+    /// When the cancellation token gets cancelled, streaming stops at the next OHLCV bar
+    /// and the last OHLCV bar message is sent to the client.
+    ///
+    /// When the query fails while streaming, e.g. after the connection to the database
+    /// could not be restored, the pending bars are sent and the stream ends with an error
+    /// without the last OHLCV bar message, so the client does not take the stream for complete.
+    ///
+    /// Each OHLCV bar carries its sequence number in the stream, starting at 1.
+    /// A resumed stream skips all bars before its resume point and keeps
+    /// the sequence numbers of the original stream.
+    ///
     /// # Arguments
     ///
-    /// * `client_id` - The id of the client to stream OHLCV bars to.
-    /// * `symbol_id` - The symbol id to stream OHLCV bars for.
-    /// * `trade_table` - The database table to stream OHLCV bars from.
-    /// * `time_resolution` - The time resolution of the OHLCV bars.
+    /// * `job` - The data stream job with the client id, symbol id, trade table,
+    ///   time resolution, time range, replay mode, and control handle of the data stream.
     ///
     /// # Errors
     ///
@@ -35,14 +54,11 @@ impl Server {
     /// async fn example(server: &Server)
     ///     -> Result<(), (DataErrorType, MessageProcessingError)> {
     ///
-    ///     let client_id = 1;
-    ///     let symbol_id = 2;
-    ///     let trade_table = "trades";
-    ///     let time_resolution = TimeResolution::OneMinute;
+    ///     let stream_key = (1, 1, 2, DataType::OHLCVData);
+    ///     let request = StartDataMessage::default();
+    ///     let job = DataStreamJob::new(stream_key, "trades".to_string(), request, DataStreamControl::new());
     ///
-    ///     server
-    ///     .start_ohlcv_data(client_id, symbol_id, trade_table, &time_resolution)
-    ///     .await?;
+    ///     server.start_ohlcv_data(&job).await?;
     ///
     /// Ok(())
     /// }
@@ -50,39 +66,127 @@ impl Server {
     ///
     pub(crate) async fn start_ohlcv_data(
         &self,
-        client_id: u16,
-        symbol_id: u16,
-        trade_table: &str,
-        time_resolution: &TimeResolution,
+        job: &DataStreamJob,
     ) -> Result<(), (DataErrorType, MessageProcessingError)> {
+        let (client_id, _, symbol_id, _) = job.stream_key();
+        let time_resolution = job.time_resolution();
+        let token = job.token();
+        let mut pacer = ReplayPacer::new(job);
+
         // Set the data type to OHLCV
         let data_type = DataType::OHLCVData;
 
-        // Send the first bar message to inform the client that the data stream starts
-        match self.send_first_bar(client_id, symbol_id, &data_type).await {
-            Ok(_) => {}
-            Err(e) => return Err(e),
+        // Send the first bar message to inform the client that the data stream starts;
+        // a resumed stream continues a stream that has already started
+        if !job.is_resumed() {
+            match self.send_first_bar(client_id, symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
         }
 
         // Lock the query manager
         let q_manager = self.query_manager().read().await;
 
         // Create a stream of trade bars from the database
-        let mut stream = q_manager.stream_ohlcv(trade_table, time_resolution).await;
+        let mut stream = q_manager
+            .stream_ohlcv(
+                job.trade_table(),
+                &time_resolution,
+                job.start_time(),
+                job.end_time(),
+            )
+            .await;
+
+        // Collect OHLCV bars into batches to send many bars per message bus round trip
+        let max_delay = Duration::from_micros(DATA_BATCH_DELAY_MICROS);
+        let mut batch = MessageBatch::new(DATA_BATCH_SIZE, max_delay);
+
+        // Sequence number of the last bar of the stream
+        let mut sequence_number: u64 = 0;
+
+        // Process OHLCV bars from the stream as they come in until the stream ends or gets cancelled
+        loop {
+            select! {
+                _ = token.cancelled() => break,
 
-        // Process OHLCV bars from the stream as they come in
-        while let Some(Ok(record)) = stream.next().await {
-            self.send_ohlcv_bar(client_id, symbol_id, &record)
-                .await
-                .expect("Failed to send OHLCV bar");
+                // Send a partial batch once its oldest bar has waited long enough
+                _ = sleep_until(batch.deadline()), if !batch.is_empty() => {
+                    match self.send_batch(client_id, &mut batch).await {
+                        Ok(_) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                record = stream.next() => match record {
+                    Some(Ok(record)) => {
+                        // Number every bar, so a resumed stream numbers its bars like the original stream
+                        sequence_number += 1;
+
+                        // Skip the bars before the resume point of a resumed stream
+                        if job.skips(sequence_number, record.date_time()) {
+                            continue;
+                        }
+
+                        // Send the pending bars before waiting for a paced or paused bar
+                        if !pacer.is_ready(record.date_time()) {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+
+                        // Wait until the bar is due; stop if cancelled while waiting
+                        if !pacer.wait(record.date_time()).await {
+                            break;
+                        }
+
+                        // Record the progress of the stream for the admin endpoints
+                        job.control().record_bar(record.date_time());
+
+                        match self.encode_ohlcv_bar_message(symbol_id, sequence_number, &record) {
+                            Ok(message) => batch.push(message),
+                            Err(e) => return Err(e),
+                        }
+
+                        if batch.is_full() {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+                    }
+                    Some(Err(e)) => {
+                        // Send the bars before the failure, but no last bar message,
+                        // so the client does not take the stream for complete
+                        match self.send_batch(client_id, &mut batch).await {
+                            Ok(_) => {}
+                            Err(e) => return Err(e),
+                        }
+
+                        return Err(self.map_stream_error(e));
+                    }
+                    None => break,
+                }
+            }
         }
 
-        // Send the last bar message to inform the client that the data stream has ended
-        match self.send_last_bar(client_id, symbol_id, &data_type).await {
+        // Send the remaining bars before the last bar message
+        match self.send_batch(client_id, &mut batch).await {
             Ok(_) => {}
             Err(e) => return Err(e),
         }
 
+        // Send the last bar message to inform the client that the data stream has ended,
+        // regardless of whether the stream completed or was cancelled,
+        // unless a resumed stream has replaced the stream
+        if !job.control().is_replaced() {
+            match self.send_last_bar(client_id, symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
         Ok(())
     }
 }
@@ -1,24 +1,44 @@
-use crate::service::Server;
+use crate::service::scheduler::{DataStreamJob, MessageBatch, ReplayPacer};
+use crate::service::{Server, DATA_BATCH_DELAY_MICROS, DATA_BATCH_SIZE};
 use common::prelude::MessageProcessingError;
 use futures::StreamExt;
 use sbe_messages::prelude::{DataErrorType, DataType};
+use std::time::Duration;
+use tokio::select;
+use tokio::time::sleep_until;
 
 impl Server {
     /// Sends a stream of trade bar data to the client.
     ///
     /// This will:
     ///
-    /// 1. Send a first trade bar message to indicate the start of the stream.
-    /// 2. Stream trade bars from the database for the given symbol.
-    /// 3. Encode each trade bar into an SBE message.
-    /// 4. Send the encoded trade bar messages to the client.
+    /// 1. Send a first trade bar message to indicate the start of the stream, unless the stream is resumed.
+    /// 2. Stream trade bars from the database for the given symbol and optional time range.
+    /// 3. Number each trade bar and encode it into an SBE message.
+    /// 4. Send the encoded trade bar messages to the client in batches.
     /// 5. Send a last trade bar message to indicate the end of the stream.
     ///
+    /// A batch is sent once it holds `DATA_BATCH_SIZE` bars, or once its oldest bar has
+    /// waited for `DATA_BATCH_DELAY_MICROS`, or before waiting for a paced or paused bar.
+    ///
+    /// Each trade bar is paced according to the replay mode of the request, and
+    /// held back for as long as the stream is paused.
+    ///
+    /// When the cancellation token gets cancelled, streaming stops at the next trade bar
+    /// and the last trade bar message is sent to the client.
+    ///
+    /// When the query fails while streaming, e.g. after the connection to the database
+    /// could not be restored, the pending bars are sent and the stream ends with an error
+    /// without the last trade bar message, so the client does not take the stream for complete.
+    ///
+    /// Each trade bar carries its sequence number in the stream, starting at 1.
+    /// A resumed stream skips all bars before its resume point and keeps
+    /// the sequence numbers of the original stream.
+    ///
     /// # Arguments
     ///
-    /// * `client_id` - The id of the client to stream trade bars to.
-    /// * `symbol_id` - The symbol id to stream trade bars for.
-    /// * `trade_table` - The database table to stream trade bars from.
+    /// * `job` - The data stream job with the client id, symbol id, trade table,
+    ///   time range, replay mode, and control handle of the data stream.
     ///
     /// # Errors
     ///
@@ -33,49 +53,132 @@ impl Server {
     /// async fn example(server: &Server)
     ///         -> Result<(), (DataErrorType, MessageProcessingError)> {
     ///
-    ///     let client_id = 1;
-    ///     let symbol_id = 2;
-    ///     let trade_table = "trades";
+    ///     let stream_key = (1, 1, 2, DataType::TradeData);
+    ///     let request = StartDataMessage::default();
+    ///     let job = DataStreamJob::new(stream_key, "trades".to_string(), request, DataStreamControl::new());
     ///
-    ///     server.start_trade_data(client_id, symbol_id, trade_table).await?;
+    ///     server.start_trade_data_stream(&job).await?;
     ///     Ok(())
     /// }
     /// ```
     ///
     pub(crate) async fn start_trade_data_stream(
         &self,
-        client_id: u16,
-        symbol_id: u16,
-        trade_table: &str,
+        job: &DataStreamJob,
     ) -> Result<(), (DataErrorType, MessageProcessingError)> {
+        let (client_id, _, symbol_id, _) = job.stream_key();
+        let token = job.token();
+        let mut pacer = ReplayPacer::new(job);
+
         // Set the data type to trade data
         let data_type = DataType::TradeData;
 
-        // Send the first bar message to inform the client that the data stream starts
-        match self.send_first_bar(client_id, symbol_id, &data_type).await {
-            Ok(_) => {}
-            Err(e) => return Err(e),
+        // Send the first bar message to inform the client that the data stream starts;
+        // a resumed stream continues a stream that has already started
+        if !job.is_resumed() {
+            match self.send_first_bar(client_id, symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
         }
 
         // Lock the query manager
         let q_manager = self.query_manager().read().await;
 
         // Create a stream of trade bars from the database
-        let mut stream = q_manager.stream_trades(&trade_table).await;
+        let mut stream = q_manager
+            .stream_trades(job.trade_table(), job.start_time(), job.end_time())
+            .await;
+
+        // Collect trade bars into batches to send many bars per message bus round trip
+        let max_delay = Duration::from_micros(DATA_BATCH_DELAY_MICROS);
+        let mut batch = MessageBatch::new(DATA_BATCH_SIZE, max_delay);
+
+        // Sequence number of the last bar of the stream
+        let mut sequence_number: u64 = 0;
+
+        // Process trade bars from the stream as they come in until the stream ends or gets cancelled
+        loop {
+            select! {
+                _ = token.cancelled() => break,
 
-        // Process trade bars from the stream as they come in
-        while let Some(Ok(record)) = stream.next().await {
-            self.send_trade_bar(client_id, symbol_id, &record)
-                .await
-                .expect("Failed to send trade bar");
+                // Send a partial batch once its oldest bar has waited long enough
+                _ = sleep_until(batch.deadline()), if !batch.is_empty() => {
+                    match self.send_batch(client_id, &mut batch).await {
+                        Ok(_) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                record = stream.next() => match record {
+                    Some(Ok(record)) => {
+                        // Number every bar, so a resumed stream numbers its bars like the original stream
+                        sequence_number += 1;
+
+                        // Skip the bars before the resume point of a resumed stream
+                        if job.skips(sequence_number, record.date_time()) {
+                            continue;
+                        }
+
+                        // Send the pending bars before waiting for a paced or paused bar
+                        if !pacer.is_ready(record.date_time()) {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+
+                        // Wait until the bar is due; stop if cancelled while waiting
+                        if !pacer.wait(record.date_time()).await {
+                            break;
+                        }
+
+                        // Record the progress of the stream for the admin endpoints
+                        job.control().record_bar(record.date_time());
+
+                        match self.encode_trade_bar_message(symbol_id, sequence_number, &record) {
+                            Ok(message) => batch.push(message),
+                            Err(e) => return Err(e),
+                        }
+
+                        if batch.is_full() {
+                            match self.send_batch(client_id, &mut batch).await {
+                                Ok(_) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+                    }
+                    Some(Err(e)) => {
+                        // Send the bars before the failure, but no last bar message,
+                        // so the client does not take the stream for complete
+                        match self.send_batch(client_id, &mut batch).await {
+                            Ok(_) => {}
+                            Err(e) => return Err(e),
+                        }
+
+                        return Err(self.map_stream_error(e));
+                    }
+                    None => break,
+                }
+            }
         }
 
-        // Send the last bar message to inform the client that the data stream has ended
-        match self.send_last_bar(client_id, symbol_id, &data_type).await {
+        // Send the remaining bars before the last bar message
+        match self.send_batch(client_id, &mut batch).await {
             Ok(_) => {}
             Err(e) => return Err(e),
         }
 
+        // Send the last bar message to inform the client that the data stream has ended,
+        // regardless of whether the stream completed or was cancelled,
+        // unless a resumed stream has replaced the stream
+        if !job.control().is_replaced() {
+            match self.send_last_bar(client_id, symbol_id, &data_type).await {
+                Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
         Ok(())
     }
 }
@@ -1,17 +1,49 @@
+use crate::service::errors::GatewayError;
 use crate::service::Server;
 use autometrics::autometrics;
-use common::prelude::MessageProcessingError;
 use sbe_messages::prelude::StopDataMessage;
 
 impl Server {
+    /// Handles a StopDataMessage from a client.
+    ///
+    /// Cancels the data stream matching the client id, exchange id, symbol id and data type
+    /// of the message. The cancelled stream stops at the next bar and sends the last bar
+    /// message to inform the client that the data stream has ended.
+    ///
+    /// # Parameters
+    ///
+    /// * `stop_data_msg` - The StopDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `GatewayError::ClientError` with ClientNotLoggedIn if the client is not logged in.
+    ///
     #[autometrics]
     pub(crate) async fn handle_stop_date(
         &self,
         stop_data_msg: &StopDataMessage,
-    ) -> Result<(), MessageProcessingError> {
-        // Remove debug print
-        println!("[QDGW/handle_stop_date]: stop_data: {:?}", stop_data_msg);
-        println!("[QDGW/handle_stop_date]: NOT IMPLEMENTED");
+    ) -> Result<(), GatewayError> {
+        // println!("::handle_stop_date]: Extract fields from message");
+        let client_id = *stop_data_msg.client_id();
+        let exchange_id = *stop_data_msg.exchange_id() as u16;
+        let symbol_id = *stop_data_msg.symbol_id();
+        let data_type = *stop_data_msg.data_type_id();
+
+        // println!("::handle_stop_date]: Check if the client is logged in");
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("::handle_stop_date]: Cancel the data stream");
+        let stream_key = (client_id, exchange_id, symbol_id, data_type);
+        if !self.cancel_data_stream(&stream_key).await {
+            println!(
+                "[QDGW/handle_stop_date]: No active data stream found for: {:?}",
+                stream_key
+            );
+        }
 
         Ok(())
     }
@@ -1,21 +1,40 @@
+use crate::service::errors::GatewayError;
 use crate::service::Server;
 use autometrics::autometrics;
-use common::prelude::MessageProcessingError;
 use sbe_messages::prelude::StopAllDataMessage;
 
 impl Server {
+    /// Handles a StopAllDataMessage from a client.
+    ///
+    /// Cancels all data streams of the client.
+    /// Each cancelled stream stops at the next bar and sends the last bar
+    /// message to inform the client that the data stream has ended.
+    ///
+    /// # Parameters
+    ///
+    /// * `stop_all_data_msg` - The StopAllDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `GatewayError::ClientError` with ClientNotLoggedIn if the client is not logged in.
+    ///
     #[autometrics]
     pub(crate) async fn handle_stop_all_data(
         &self,
         stop_all_data_msg: &StopAllDataMessage,
-    ) -> Result<(), MessageProcessingError> {
-        // Remove debug print
-        println!(
-            "[QDGW/handle_stop_date]: stop_all_data: {:?}",
-            stop_all_data_msg
-        );
+    ) -> Result<(), GatewayError> {
+        // println!("::handle_stop_all_data]: Extract fields from message");
+        let client_id = *stop_all_data_msg.client_id();
 
-        println!("[QDGW/handle_stop_date]: NOT IMPLEMENTED",);
+        // println!("::handle_stop_all_data]: Check if the client is logged in");
+        match self.ensure_client_login(client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        // println!("::handle_stop_all_data]: Cancel all data streams of the client");
+        self.cancel_client_data_streams(client_id).await;
 
         Ok(())
     }
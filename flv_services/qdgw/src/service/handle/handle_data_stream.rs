@@ -0,0 +1,62 @@
+use sbe_messages::prelude::{DataErrorType, DataType};
+
+use crate::service::errors::GatewayError;
+use crate::service::scheduler::DataStreamJob;
+use crate::service::Server;
+
+impl Server {
+    /// Runs a registered data stream until it completes or gets cancelled.
+    ///
+    /// This method is called by a data stream worker for each scheduled job.
+    /// Errors are sent back to the client as DataErrorMessage and the stream
+    /// gets removed from the stream registry once it has finished.
+    /// A stream stopped by the quota of the client sends `RateLimited`
+    /// with the time until the quota window ends.
+    ///
+    /// # Parameters
+    ///
+    /// * `job` - The data stream job to run
+    ///
+    pub(crate) async fn run_data_stream(&self, job: DataStreamJob) {
+        let stream_key = job.stream_key();
+        let token = job.token();
+        let (client_id, _, _, data_type) = stream_key;
+
+        let res = match (data_type, job.is_multi()) {
+            (DataType::TradeData, false) => self.start_trade_data_stream(&job).await,
+            (DataType::TradeData, true) => self.start_multi_trade_data_stream(&job).await,
+            (DataType::OHLCVData, false) => self.start_ohlcv_data(&job).await,
+            (DataType::OHLCVData, true) => self.start_multi_ohlcv_data(&job).await,
+            (DataType::TickBarData | DataType::VolumeBarData | DataType::DollarBarData, false) => {
+                self.start_information_bar_data(&job).await
+            }
+            // Multi symbol requests carry no bar threshold and reject information bars
+            (DataType::TickBarData | DataType::VolumeBarData | DataType::DollarBarData, true) => {
+                Ok(())
+            }
+            // Unknown data types are rejected before the stream gets registered
+            (DataType::UnknownDataType, _) => Ok(()),
+        };
+
+        if let Err((data_err, err)) = res {
+            println!(
+                "[QDGW/run_data_stream]: Failed to stream data {:?}: {}",
+                stream_key, err
+            );
+
+            // A stream stopped by the quota tells the client when the quota window ends
+            let err = match data_err {
+                DataErrorType::RateLimited => match self.ensure_stream_quota(client_id) {
+                    Err(err) => err,
+                    Ok(_) => GatewayError::RateLimited(client_id, None),
+                },
+                _ => GatewayError::DataError(client_id, data_err),
+            };
+
+            self.handle_error(err).await;
+        }
+
+        self.deregister_data_streams(&job.stream_keys(), token)
+            .await;
+    }
+}
@@ -0,0 +1,273 @@
+use common::prelude::{MessageProcessingError, TimeResolution};
+use db_query_manager::error::QueryError;
+use sbe_messages::prelude::{DataErrorType, DataType, StartDataMessage};
+
+use crate::service::errors::GatewayError;
+use crate::service::{Server, StreamKey};
+
+impl Server {
+    /// Ensures that a request for OHLCV data has a valid time resolution.
+    ///
+    /// The time resolution is only used to resample OHLCV data, so requests
+    /// of other data types pass regardless of their time resolution.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the requesting client
+    /// - `data_type`: The requested data type
+    /// - `time_resolution`: The requested time resolution
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the time resolution is valid. Otherwise, a
+    /// `GatewayError::DataError` with `TimeResolutionNotValidError`.
+    ///
+    pub(crate) fn ensure_valid_time_resolution(
+        &self,
+        client_id: u16,
+        data_type: DataType,
+        time_resolution: &TimeResolution,
+    ) -> Result<(), GatewayError> {
+        if data_type != DataType::OHLCVData {
+            return Ok(());
+        }
+
+        match time_resolution.validate() {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                println!("[QDGW/ensure_valid_time_resolution]: {}", err);
+                Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::TimeResolutionNotValidError,
+                ))
+            }
+        }
+    }
+
+    /// Ensures that the trade table of a requested stream exists in the database
+    /// and has the columns the stream queries.
+    ///
+    /// The QueryDBManager caches the catalog of the database, so most requests
+    /// do not reach the database.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the requesting client
+    /// - `trade_table`: The trade table of the requested symbol
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the trade table is valid. Otherwise, a
+    /// `GatewayError::DataError` with `DataTableNotFound` if the table does not exist,
+    /// or with `DataUnavailableError` if its schema is not valid or the lookup failed.
+    ///
+    pub(crate) async fn ensure_valid_trade_table(
+        &self,
+        client_id: u16,
+        trade_table: &str,
+    ) -> Result<(), GatewayError> {
+        let q_manager = self.query_manager().read().await;
+        let res = q_manager.validate_trade_table(trade_table).await;
+        drop(q_manager);
+
+        match res {
+            Ok(_) => Ok(()),
+            Err(QueryError::TableDoesNotExist(_, _)) => {
+                println!(
+                    "[QDGW/ensure_valid_trade_table]: Trade table {} does not exist",
+                    trade_table
+                );
+                Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::DataTableNotFound,
+                ))
+            }
+            Err(err) => {
+                println!("[QDGW/ensure_valid_trade_table]: {}", err);
+                Err(GatewayError::DataError(
+                    client_id,
+                    DataErrorType::DataUnavailableError,
+                ))
+            }
+        }
+    }
+
+    /// Ensures that a request for tick, volume, or dollar bars has a valid bar threshold.
+    ///
+    /// A tick threshold must be a whole number of trades, and all thresholds must be positive.
+    /// Requests of other data types pass regardless of their threshold.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the requesting client
+    /// - `start_data_msg`: The requested data stream
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the threshold is valid. Otherwise, a
+    /// `GatewayError::DataError` with `ThresholdNotValidError`.
+    ///
+    pub(crate) fn ensure_valid_threshold(
+        &self,
+        client_id: u16,
+        start_data_msg: &StartDataMessage,
+    ) -> Result<(), GatewayError> {
+        if !start_data_msg.data_type_id().is_information_bar() {
+            return Ok(());
+        }
+
+        let valid = match start_data_msg.bar_threshold() {
+            Some(threshold) => match threshold.validate() {
+                Ok(_) => true,
+                Err(err) => {
+                    println!("[QDGW/ensure_valid_threshold]: {}", err);
+                    false
+                }
+            },
+            None => {
+                println!(
+                    "[QDGW/ensure_valid_threshold]: Threshold {} is not valid for {}",
+                    start_data_msg.threshold(),
+                    start_data_msg.data_type_id()
+                );
+                false
+            }
+        };
+
+        if !valid {
+            return Err(GatewayError::DataError(
+                client_id,
+                DataErrorType::ThresholdNotValidError,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that a client is entitled to start a data stream with the given stream keys.
+    ///
+    /// Checks the data type, the exchange, and each symbol of the stream against the
+    /// entitlements of the client, and that the client stays within its maximum number
+    /// of concurrent streams, counted by `count_client_streams`.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the requesting client
+    /// - `stream_keys`: The (client_id, exchange_id, symbol_id, data_type) keys of the stream
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the client is entitled to the stream. Otherwise, a
+    /// `GatewayError::DataError` with `NotEntitled`.
+    ///
+    pub(crate) async fn ensure_entitled(
+        &self,
+        client_id: u16,
+        stream_keys: &[StreamKey],
+    ) -> Result<(), GatewayError> {
+        let not_entitled = |reason: String| {
+            println!(
+                "[QDGW/ensure_entitled]: Client {} not entitled: {}",
+                client_id, reason
+            );
+            Err(GatewayError::DataError(
+                client_id,
+                DataErrorType::NotEntitled,
+            ))
+        };
+
+        let entitlements = match self.entitlement_store().entitlements(client_id) {
+            Some(entitlements) => entitlements,
+            None => return not_entitled("No entitlements configured".to_string()),
+        };
+
+        // Lock the SymbolManager to look up the names of the exchange and the symbols
+        let mut symbol_db = self.symbol_manager().write().await;
+
+        for (_, exchange_id, symbol_id, data_type) in stream_keys {
+            if !entitlements.allows_data_type(*data_type) {
+                return not_entitled(format!("Data type {}", data_type));
+            }
+
+            match symbol_db.get_exchange_name(*exchange_id) {
+                Ok(exchange) if entitlements.allows_exchange(&exchange) => {}
+                Ok(exchange) => return not_entitled(format!("Exchange {}", exchange)),
+                Err(err) => return not_entitled(err.to_string()),
+            }
+
+            match symbol_db.get_symbol(*symbol_id) {
+                Ok(symbol) if entitlements.allows_symbol(&symbol) => {}
+                Ok(symbol) => return not_entitled(format!("Symbol {}", symbol)),
+                Err(err) => return not_entitled(err.to_string()),
+            }
+        }
+
+        // Unlock the SymbolManager
+        drop(symbol_db);
+
+        let stream_count = self.count_client_streams(client_id, stream_keys).await;
+
+        if !entitlements.allows_stream_count(stream_count) {
+            return not_entitled(format!(
+                "More than {:?} concurrent streams",
+                entitlements.max_streams()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Counts the data streams a client runs once a stream with the given keys starts.
+    ///
+    /// A stream of several symbols counts once per symbol, and a stream that
+    /// replaces a running stream with the same key is not counted twice.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the client
+    /// - `stream_keys`: The keys of the new stream, or none to count the running streams only
+    ///
+    pub(crate) async fn count_client_streams(
+        &self,
+        client_id: u16,
+        stream_keys: &[StreamKey],
+    ) -> usize {
+        // Lock the client_streams hashmap to count the streams of the client
+        let client_streams = self.client_streams().read().await;
+
+        let running_streams = client_streams
+            .keys()
+            .filter(|stream_key| stream_key.0 == client_id)
+            .count();
+
+        let new_streams = stream_keys
+            .iter()
+            .filter(|stream_key| !client_streams.contains_key(stream_key))
+            .count();
+
+        running_streams + new_streams
+    }
+
+    /// Maps an error that a running data stream yields to the data error sent to the client.
+    ///
+    /// # Parameters
+    ///
+    /// - `err`: The error of the stream of the query manager
+    ///
+    /// # Returns
+    ///
+    /// A `DataTableNotFound` data error if the table of the stream does not exist, or a
+    /// `DataUnavailableError` data error otherwise, e.g. after a failed query or reconnect.
+    ///
+    pub(crate) fn map_stream_error(
+        &self,
+        err: QueryError,
+    ) -> (DataErrorType, MessageProcessingError) {
+        let data_error_type = match err {
+            QueryError::TableDoesNotExist(_, _) => DataErrorType::DataTableNotFound,
+            _ => DataErrorType::DataUnavailableError,
+        };
+
+        (data_error_type, MessageProcessingError(err.to_string()))
+    }
+}
@@ -0,0 +1,163 @@
+use autometrics::autometrics;
+
+use common::prelude::MessageProcessingError;
+use sbe_messages::prelude::{ClientErrorType, DataErrorType};
+
+use crate::service::errors::GatewayError;
+use crate::service::Server;
+
+impl Server {
+    /// Handles an error returned by a message handler so that the message loop keeps running.
+    ///
+    /// Client and data errors go back to the client as ClientErrorMessage or DataErrorMessage.
+    /// Invalid messages and internal errors are logged.
+    ///
+    /// Each error type is reported through its own function, so the autometrics
+    /// `function_calls_total` counter of that function counts the errors of that type.
+    ///
+    /// # Parameters
+    ///
+    /// * `err` - The error returned by the message handler
+    ///
+    pub(crate) async fn handle_error(&self, err: GatewayError) {
+        let res = match err {
+            GatewayError::ClientError(client_id, client_error_type) => {
+                self.report_client_error(client_id, client_error_type).await
+            }
+            GatewayError::DataError(client_id, data_error_type) => {
+                self.report_data_error(client_id, data_error_type).await
+            }
+            GatewayError::RateLimited(client_id, retry_after_millis) => {
+                self.report_rate_limited(client_id, retry_after_millis)
+                    .await
+            }
+            GatewayError::InvalidMessage(e) => self.report_invalid_message(&e),
+            GatewayError::InternalError(e) => self.report_internal_error(&e),
+        };
+
+        if let Err(e) = res {
+            println!("[QDGW/handle_error]: Failed to send error to client: {}", e);
+        }
+    }
+
+    /// Handles an error returned by the login handler so that the message loop keeps running.
+    ///
+    /// A client that is not logged in cannot read a data channel, and neither can a client
+    /// whose login was rejected because the client ID is already logged in. So client errors
+    /// and rate limits of a login go to the login reject topic, which the QD client polls
+    /// while it waits for its login. All other errors are handled by `handle_error()`.
+    ///
+    /// # Parameters
+    ///
+    /// * `err` - The error returned by the login handler
+    ///
+    pub(crate) async fn handle_login_error(&self, err: GatewayError) {
+        let res = match err {
+            GatewayError::ClientError(client_id, client_error_type) => {
+                self.report_login_client_error(client_id, client_error_type)
+                    .await
+            }
+            GatewayError::RateLimited(client_id, retry_after_millis) => {
+                self.report_login_rate_limited(client_id, retry_after_millis)
+                    .await
+            }
+            _ => return self.handle_error(err).await,
+        };
+
+        if let Err(e) = res {
+            println!("[QDGW/handle_error]: Failed to reject login: {}", e);
+        }
+    }
+
+    /// Sends a client error back to the client.
+    #[autometrics]
+    async fn report_client_error(
+        &self,
+        client_id: u16,
+        client_error_type: ClientErrorType,
+    ) -> Result<(), MessageProcessingError> {
+        println!(
+            "[QDGW/handle_error]: ClientError {} for client {}",
+            client_error_type, client_id
+        );
+
+        self.send_client_error(client_id, client_error_type).await
+    }
+
+    /// Sends a data error back to the client.
+    #[autometrics]
+    async fn report_data_error(
+        &self,
+        client_id: u16,
+        data_error_type: DataErrorType,
+    ) -> Result<(), MessageProcessingError> {
+        println!(
+            "[QDGW/handle_error]: DataError {} for client {}",
+            data_error_type, client_id
+        );
+
+        self.send_data_error(client_id, data_error_type).await
+    }
+
+    /// Sends a RateLimited data error with the retry-after hint back to the client.
+    #[autometrics]
+    async fn report_rate_limited(
+        &self,
+        client_id: u16,
+        retry_after_millis: Option<u32>,
+    ) -> Result<(), MessageProcessingError> {
+        println!(
+            "[QDGW/handle_error]: RateLimited for client {}, retry after millis {:?}",
+            client_id, retry_after_millis
+        );
+
+        self.send_rate_limited_error(client_id, retry_after_millis)
+            .await
+    }
+
+    /// Sends the client error of a rejected login to the login reject topic.
+    #[autometrics]
+    async fn report_login_client_error(
+        &self,
+        client_id: u16,
+        client_error_type: ClientErrorType,
+    ) -> Result<(), MessageProcessingError> {
+        println!(
+            "[QDGW/handle_error]: Login rejected with ClientError {} for client {}",
+            client_error_type, client_id
+        );
+
+        self.send_login_client_error(client_id, client_error_type)
+            .await
+    }
+
+    /// Sends a RateLimited data error with the retry-after hint of a rejected login to the login reject topic.
+    #[autometrics]
+    async fn report_login_rate_limited(
+        &self,
+        client_id: u16,
+        retry_after_millis: Option<u32>,
+    ) -> Result<(), MessageProcessingError> {
+        println!(
+            "[QDGW/handle_error]: Login rejected with RateLimited for client {}, retry after millis {:?}",
+            client_id, retry_after_millis
+        );
+
+        self.send_login_rate_limited_error(client_id, retry_after_millis)
+            .await
+    }
+
+    /// Logs a message that cannot be processed.
+    #[autometrics]
+    fn report_invalid_message(&self, e: &str) -> Result<(), MessageProcessingError> {
+        println!("[QDGW/handle_error]: InvalidMessage: {}", e);
+        Ok(())
+    }
+
+    /// Logs an internal error of the gateway.
+    #[autometrics]
+    fn report_internal_error(&self, e: &str) -> Result<(), MessageProcessingError> {
+        println!("[QDGW/handle_error]: InternalError: {}", e);
+        Ok(())
+    }
+}
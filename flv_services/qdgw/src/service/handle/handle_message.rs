@@ -1,16 +1,20 @@
+use crate::service::errors::GatewayError;
 use crate::service::Server;
-use common::prelude::MessageProcessingError;
 use sbe_messages::prelude::{
-    ClientLoginMessage, ClientLogoutMessage, MessageType, StartDataMessage, StopAllDataMessage,
-    StopDataMessage,
+    validate_frame, ClientHeartbeatMessage, ClientLoginMessage, ClientLogoutMessage,
+    ContinueDataMessage, MessageType, PauseDataMessage, ResumeDataMessage, StartDataMessage,
+    StartMultiDataMessage, StopAllDataMessage, StopDataMessage,
 };
 
+// The client ID follows the message header and the message type in every control message.
+const CLIENT_ID_OFFSET: usize = 10;
+
 impl Server {
-    /// Handles a single message by processing it and sending it to the appropriate
-    /// manager for further processing.
+    /// Handles a single message of the gateway login topic.
     ///
-    /// This method takes a message payload, processes it by calling the `process_message`
-    /// method, and sends it to the appropriate handler for further processing.
+    /// Clients send their login with the bootstrap user, whose credentials are public,
+    /// so the topic only accepts ClientLoginMessages. All other requests arrive on the control
+    /// topic of a logged in client, see `handle_client_message`.
     ///
     /// # Parameters
     ///
@@ -19,31 +23,138 @@ impl Server {
     ///
     /// # Returns
     /// * Ok on success,
-    /// * Err on any processing error
+    /// * Err with a `GatewayError` on any processing error
     ///
-    pub(crate) async fn handle_message(
-        &self,
-        raw_message: &[u8],
-    ) -> Result<(), MessageProcessingError> {
-        //
-        let message_type = MessageType::from(raw_message[2] as u16);
+    pub(crate) async fn handle_message(&self, raw_message: &[u8]) -> Result<(), GatewayError> {
+        // Reject truncated or foreign payloads before any decoder touches them.
+        let message_type = match validate_frame(raw_message) {
+            Ok(message_type) => message_type,
+            Err(e) => {
+                return Err(GatewayError::InvalidMessage(format!(
+                    "[QDGW/handle_message]: Rejected message: {}",
+                    e
+                )))
+            }
+        };
 
         match message_type {
             MessageType::ClientLogin => {
-                let client_login_msg = ClientLoginMessage::from(raw_message);
+                // The auth token makes the message length variable, so decoding can fail.
+                let client_login_msg = match ClientLoginMessage::try_from(raw_message) {
+                    Ok(msg) => msg,
+                    Err(e) => {
+                        return Err(GatewayError::InvalidMessage(format!(
+                            "[QDGW/handle_message]: Rejected message: {}",
+                            e
+                        )))
+                    }
+                };
                 self.handle_client_login(&client_login_msg).await
             }
+            // Anyone can write to the login topic, so it cannot tell which client sent a request.
+            _ => Err(GatewayError::InvalidMessage(format!(
+                "[QDGW/handle_message]: Rejected {} on the login topic",
+                message_type
+            ))),
+        }
+    }
+
+    /// Handles a single message of the control topic of a logged in client.
+    ///
+    /// Only the iggy user of the client can write to its control topic, so the topic
+    /// identifies the client. A message that names another client is rejected before
+    /// any handler runs, and so is a login, which belongs on the gateway login topic.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Server instance
+    /// * `client_id` - The ID of the client that owns the control topic
+    /// * `message` - The message payload to be processed
+    ///
+    /// # Returns
+    /// * Ok on success,
+    /// * Err with a `GatewayError` on any processing error
+    ///
+    pub(crate) async fn handle_client_message(
+        &self,
+        client_id: u16,
+        raw_message: &[u8],
+    ) -> Result<(), GatewayError> {
+        // Reject truncated or foreign payloads before any decoder touches them.
+        let message_type = match validate_frame(raw_message) {
+            Ok(message_type) => message_type,
+            Err(e) => {
+                return Err(GatewayError::InvalidMessage(format!(
+                    "[QDGW/handle_client_message]: Rejected message: {}",
+                    e
+                )))
+            }
+        };
 
+        // The message must name the client that owns the control topic.
+        let sender_id = match raw_message.get(CLIENT_ID_OFFSET..CLIENT_ID_OFFSET + 2) {
+            Some(bytes) => u16::from_le_bytes([bytes[0], bytes[1]]),
+            None => {
+                return Err(GatewayError::InvalidMessage(format!(
+                    "[QDGW/handle_client_message]: Rejected {} without client ID",
+                    message_type
+                )))
+            }
+        };
+
+        if sender_id != client_id {
+            return Err(GatewayError::InvalidMessage(format!(
+                "[QDGW/handle_client_message]: Client {} sent {} for client {}",
+                client_id, message_type, sender_id
+            )));
+        }
+
+        // Reject control messages of clients that exceed their rate before any handler runs.
+        match self.ensure_control_rate(message_type, client_id).await {
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        match message_type {
             MessageType::ClientLogout => {
                 let client_logout_msg = ClientLogoutMessage::from(raw_message);
                 self.handle_client_logout(&client_logout_msg).await
             }
 
+            MessageType::ClientHeartbeat => {
+                let client_heartbeat_msg = ClientHeartbeatMessage::from(raw_message);
+                self.handle_client_heartbeat(&client_heartbeat_msg).await
+            }
+
             MessageType::StartData => {
-                let start_data_msg = StartDataMessage::from(raw_message);
+                // The time range arrives from the network and may be out of range, so decoding can fail.
+                let start_data_msg = match StartDataMessage::try_from(raw_message) {
+                    Ok(msg) => msg,
+                    Err(e) => {
+                        return Err(GatewayError::InvalidMessage(format!(
+                            "[QDGW/handle_client_message]: Rejected message: {}",
+                            e
+                        )))
+                    }
+                };
                 self.handle_start_data_message(&start_data_msg).await
             }
 
+            MessageType::StartMultiData => {
+                // The symbols group makes the message length variable, so decoding can fail.
+                let start_multi_data_msg = match StartMultiDataMessage::try_from(raw_message) {
+                    Ok(msg) => msg,
+                    Err(e) => {
+                        return Err(GatewayError::InvalidMessage(format!(
+                            "[QDGW/handle_client_message]: Rejected message: {}",
+                            e
+                        )))
+                    }
+                };
+                self.handle_start_multi_data_message(&start_multi_data_msg)
+                    .await
+            }
+
             MessageType::StopData => {
                 let stop_data_msg = StopDataMessage::from(raw_message);
                 self.handle_stop_date(&stop_data_msg).await
@@ -52,9 +163,34 @@ impl Server {
                 let stop_all_data_msg = StopAllDataMessage::from(raw_message);
                 self.handle_stop_all_data(&stop_all_data_msg).await
             }
-            _ => Err(MessageProcessingError(
-                "[QDGW/handle::handle_record]: Unknown message type. Abort processing".to_string(),
-            )),
+
+            MessageType::PauseData => {
+                let pause_data_msg = PauseDataMessage::from(raw_message);
+                self.handle_pause_data(&pause_data_msg).await
+            }
+
+            MessageType::ContinueData => {
+                let continue_data_msg = ContinueDataMessage::from(raw_message);
+                self.handle_continue_data(&continue_data_msg).await
+            }
+            MessageType::ResumeData => {
+                // The timestamps arrive from the network and may be out of range, so decoding can fail.
+                let resume_data_msg = match ResumeDataMessage::try_from(raw_message) {
+                    Ok(msg) => msg,
+                    Err(e) => {
+                        return Err(GatewayError::InvalidMessage(format!(
+                            "[QDGW/handle_client_message]: Rejected message: {}",
+                            e
+                        )))
+                    }
+                };
+                self.handle_resume_data_message(&resume_data_msg).await
+            }
+            // A client logs in on the gateway login topic, before it has a control topic.
+            _ => Err(GatewayError::InvalidMessage(format!(
+                "[QDGW/handle_client_message]: Unexpected message type: {}",
+                message_type
+            ))),
         }
     }
 }
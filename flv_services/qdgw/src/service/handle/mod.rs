@@ -1,9 +1,20 @@
+mod handle_client_heartbeat;
 mod handle_client_login;
 mod handle_client_logout;
+mod handle_client_utils;
+mod handle_data_continue;
+mod handle_data_pause;
+mod handle_data_resume;
 mod handle_data_start;
+mod handle_data_start_information_bar_data;
+mod handle_data_start_multi;
+mod handle_data_start_multi_ohlcv_data;
+mod handle_data_start_multi_trade_data;
 mod handle_data_start_ohlcv_data;
 mod handle_data_start_trade_data;
 mod handle_data_stop;
 mod handle_data_stop_all;
+mod handle_data_stream;
+mod handle_data_utils;
+mod handle_error;
 mod handle_message;
-mod handle_client_utils;
@@ -1,29 +1,85 @@
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 use iggy::clients::client::IggyClient;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use tokio::time::Instant;
 
+use auth_manager::{CredentialStore, EntitlementStore};
 use common::prelude::IggyConfig;
 use db_query_manager::QueryDBManager;
+use rate_limiter::{RateLimiter, RateLimits};
+use sbe_messages::prelude::DataType;
 use symbol_manager::SymbolManager;
 
+use crate::service::scheduler::{DataStreamControl, DataStreamScheduler};
+
+pub(crate) mod errors;
 mod handle;
 mod run;
+pub(crate) mod scheduler;
+mod usage_metrics;
 mod utils;
 
+pub use crate::service::usage_metrics::UsageMetrics;
+
 // tokio RwLock implements sync + send and works well with tokio async
 // https://stackoverflow.com/questions/67277282/async-function-the-trait-stdmarkersend-is-not-implemented-for-stdsync
 type Guarded<T> = std::sync::Arc<tokio::sync::RwLock<T>>;
 
+// Maximum number of bars sent to a client in one message batch.
+pub(crate) const DATA_BATCH_SIZE: usize = 1000;
+
+// Maximum time in microseconds a bar waits in a message batch before the batch is sent.
+pub(crate) const DATA_BATCH_DELAY_MICROS: u64 = 500;
+
+// Interval in milliseconds at which clients send a heartbeat, and at which stale sessions are evicted.
+pub(crate) const HEARTBEAT_INTERVAL_MILLIS: u64 = 1000;
+
+// Interval in milliseconds at which the control topics of logged in clients are polled.
+pub(crate) const CONTROL_POLL_INTERVAL_MILLIS: u64 = 10;
+
+// Number of missed heartbeats after which the session of a client gets evicted.
+pub(crate) const MAX_MISSED_HEARTBEATS: u32 = 3;
+
+// Largest burst of control messages a client can send, i.e. start data, and of login attempts per credential.
+pub(crate) const CONTROL_MESSAGE_BURST: u32 = 20;
+
+// Sustained number of control messages per second a client can send after a burst.
+pub(crate) const CONTROL_MESSAGES_PER_SECOND: u32 = 5;
+
+// Length in seconds of the window in which the rows and bytes streamed to a client are counted.
+pub(crate) const QUOTA_WINDOW_SECS: u64 = 60;
+
+// Maximum number of rows streamed to a client per quota window.
+pub(crate) const MAX_ROWS_PER_WINDOW: u64 = 10_000_000;
+
+// Maximum number of bytes streamed to a client per quota window.
+pub(crate) const MAX_BYTES_PER_WINDOW: u64 = 1024 * 1024 * 1024;
+
+// Identifies a running data stream by (client_id, exchange_id, symbol_id, data_type).
+pub(crate) type StreamKey = (u16, u16, u16, DataType);
+
+// The server is cloned into each data stream task; all fields are shared behind an Arc.
+#[derive(Clone)]
 pub struct Server {
-    consumer: IggyClient,
-    producer: IggyClient,
+    consumer: Arc<IggyClient>,
+    producer: Arc<IggyClient>,
     iggy_config: IggyConfig,
-    poll_command: PollMessages,
+    poll_command: Arc<PollMessages>,
     query_manager: Guarded<QueryDBManager>,
     symbol_manager: Guarded<SymbolManager>,
+    credential_store: Arc<dyn CredentialStore>,
+    entitlement_store: Arc<dyn EntitlementStore>,
+    rate_limiter: Arc<RateLimiter>,
+    usage_metrics: UsageMetrics,
     client_configs: Guarded<HashMap<u16, IggyConfig>>,
     client_producers: Guarded<HashMap<u16, IggyClient>>,
+    client_streams: Guarded<HashMap<StreamKey, DataStreamControl>>,
+    client_heartbeats: Guarded<HashMap<u16, Instant>>,
+    scheduler: Arc<DataStreamScheduler>,
+    worker_count: u16,
 }
 
 impl Server {
@@ -31,6 +87,10 @@ impl Server {
         iggy_config: IggyConfig,
         query_manager: Guarded<QueryDBManager>,
         symbol_manager: Guarded<SymbolManager>,
+        credential_store: Arc<dyn CredentialStore>,
+        entitlement_store: Arc<dyn EntitlementStore>,
+        usage_metrics: UsageMetrics,
+        worker_count: u16,
     ) -> Self {
         // Create an iggy client and initialize it as consumer
         let consumer = iggy_utils::get_consumer(&iggy_config)
@@ -42,6 +102,35 @@ impl Server {
             .await
             .expect("Failed to create producer client");
 
+        Self::from_iggy_clients(
+            consumer,
+            producer,
+            iggy_config,
+            query_manager,
+            symbol_manager,
+            credential_store,
+            entitlement_store,
+            usage_metrics,
+            worker_count,
+        )
+    }
+
+    /// Creates a server that uses the given iggy clients to consume and produce control messages.
+    ///
+    /// The clients are used as they are, so the handlers can be tested without a running iggy server.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_iggy_clients(
+        consumer: IggyClient,
+        producer: IggyClient,
+        iggy_config: IggyConfig,
+        query_manager: Guarded<QueryDBManager>,
+        symbol_manager: Guarded<SymbolManager>,
+        credential_store: Arc<dyn CredentialStore>,
+        entitlement_store: Arc<dyn EntitlementStore>,
+        usage_metrics: UsageMetrics,
+        worker_count: u16,
+    ) -> Self {
         // Preconfigure the poll message command for the consumer client
         let poll_command = PollMessages {
             consumer: Default::default(),
@@ -59,15 +148,42 @@ impl Server {
         // Create a new HashMap to store data producers for each client
         let client_producers = std::sync::Arc::new(tokio::sync::RwLock::new(HashMap::new()));
 
+        // Create a new HashMap to store the control handle of each active data stream
+        let client_streams = std::sync::Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+
+        // Create a new HashMap to store the time of the last heartbeat of each client
+        let client_heartbeats = std::sync::Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+
+        // Create a rate limiter that applies the same limits to each client
+        let rate_limits = RateLimits::new(CONTROL_MESSAGE_BURST, CONTROL_MESSAGES_PER_SECOND)
+            .with_window(Duration::from_secs(QUOTA_WINDOW_SECS))
+            .with_max_rows_per_window(MAX_ROWS_PER_WINDOW)
+            .with_max_bytes_per_window(MAX_BYTES_PER_WINDOW);
+        let rate_limiter = Arc::new(RateLimiter::new(rate_limits));
+
+        // At least one worker is required to process data streams
+        let worker_count = worker_count.max(1);
+
+        // Create a scheduler that distributes the workers fairly across clients
+        let scheduler = Arc::new(DataStreamScheduler::new(worker_count));
+
         Self {
-            consumer,
-            producer,
+            consumer: Arc::new(consumer),
+            producer: Arc::new(producer),
             iggy_config,
-            poll_command,
+            poll_command: Arc::new(poll_command),
             query_manager,
             symbol_manager,
+            credential_store,
+            entitlement_store,
+            rate_limiter,
+            usage_metrics,
             client_configs,
             client_producers,
+            client_streams,
+            client_heartbeats,
+            scheduler,
+            worker_count,
         }
     }
 }
@@ -79,6 +195,12 @@ impl Server {
     pub fn client_producers(&self) -> &Guarded<HashMap<u16, IggyClient>> {
         &self.client_producers
     }
+    pub(crate) fn client_streams(&self) -> &Guarded<HashMap<StreamKey, DataStreamControl>> {
+        &self.client_streams
+    }
+    pub(crate) fn client_heartbeats(&self) -> &Guarded<HashMap<u16, Instant>> {
+        &self.client_heartbeats
+    }
     pub fn consumer(&self) -> &IggyClient {
         &self.consumer
     }
@@ -97,4 +219,22 @@ impl Server {
     pub fn symbol_manager(&self) -> &Guarded<SymbolManager> {
         &self.symbol_manager
     }
+    pub(crate) fn credential_store(&self) -> &Arc<dyn CredentialStore> {
+        &self.credential_store
+    }
+    pub(crate) fn entitlement_store(&self) -> &Arc<dyn EntitlementStore> {
+        &self.entitlement_store
+    }
+    pub(crate) fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        &self.rate_limiter
+    }
+    pub(crate) fn usage_metrics(&self) -> &UsageMetrics {
+        &self.usage_metrics
+    }
+    pub(crate) fn scheduler(&self) -> &Arc<DataStreamScheduler> {
+        &self.scheduler
+    }
+    pub fn worker_count(&self) -> u16 {
+        self.worker_count
+    }
 }
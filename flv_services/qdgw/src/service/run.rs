@@ -1,21 +1,36 @@
 use std::error::Error;
 use std::future::Future;
+use std::time::Duration;
 
 use iggy::client::MessageClient;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use tokio::task::JoinHandle;
+use tokio::time::interval;
 use tokio::{pin, select};
+use tokio_util::sync::CancellationToken;
 
-use common::prelude::MessageProcessingError;
+use common::prelude::{IggyConfig, MessageProcessingError};
 
-use crate::service::Server;
+use crate::service::{Server, CONTROL_POLL_INTERVAL_MILLIS, HEARTBEAT_INTERVAL_MILLIS};
 
 impl Server {
     /// Runs the server, listening for signals and incoming messages.
     ///
-    /// This method will create a consumer for the channel topic to receive messages,
-    /// create a stream of messages from the consumer, and enter a loop selecting on
-    /// the shutdown signal future and stream.
+    /// This method will start the data stream workers, create a consumer for the channel topic
+    /// to receive messages, create a stream of messages from the consumer, and enter a loop
+    /// selecting on the shutdown signal future, the stream, and the control poll interval.
     /// If the signal arrives, the loop will break and shutdown.
-    /// If the stream has a message, the `handle_record()` method will be called to process it.
+    /// If the stream has a message, i.e. a login, the `handle_message()` method will be called to process it,
+    /// and a rejected login is reported to the client by `handle_login_error()`.
+    /// On each tick of the interval, the control topics of all logged in clients are polled,
+    /// and their requests are processed by `handle_client_message()`.
+    /// Errors of a message are reported by `handle_error()` and the loop keeps running.
+    ///
+    /// Control messages i.e. login, logout or stop data are handled right away.
+    /// Data streams are scheduled on a bounded pool of `worker_count` workers
+    /// so that a large data stream never blocks the message loop.
+    /// A session reaper evicts clients that stopped sending heartbeats
+    /// and updates the usage gauges of all clients.
     ///
     /// # Parameters
     ///
@@ -34,16 +49,43 @@ impl Server {
         let signal_future = signal;
         pin!(signal_future);
 
+        // Allow clients to send their login; the bootstrap user remains from an earlier run.
+        if let Err(e) = self.create_bootstrap_user().await {
+            println!("[QDGW/run]: Bootstrap user not created: {}", e);
+        }
+
+        // Allow clients to read rejected logins, as they have no data channel yet.
+        let reject_config = iggy_utils::login_reject_config(self.iggy_config());
+        if let Err(e) = iggy_utils::create_topic(self.producer(), &reject_config).await {
+            println!("[QDGW/run]: Login reject topic not created: {}", e);
+        }
+
+        // Start the workers that process scheduled data streams
+        let shutdown = CancellationToken::new();
+        let workers = self.spawn_data_stream_workers(&shutdown);
+
+        // Start the reaper that evicts the sessions of unresponsive clients
+        let reaper = self.spawn_session_reaper(&shutdown);
+
+        // Poll the control topics of logged in clients at a fixed interval
+        let mut control_ticker = interval(Duration::from_millis(CONTROL_POLL_INTERVAL_MILLIS));
+
         loop {
             select! {
                     _ = &mut signal_future => {break;}
 
+                _ = control_ticker.tick() => {
+                    self.poll_client_messages().await;
+                }
+
                 polled_messages = self.consumer().poll_messages(self.poll_command()) => {
                     match polled_messages {
                         Ok(polled_messages) => {
                             for polled_message in polled_messages.messages {
-                                self.handle_message(polled_message.payload.as_ref())
-                                   .await.expect("Failed to process message");
+                                // Errors are reported per message so that one bad login never stops the gateway
+                                if let Err(e) = self.handle_message(polled_message.payload.as_ref()).await {
+                                    self.handle_login_error(e).await;
+                                }
                             }
                         },
                         Err(e) => {
@@ -55,6 +97,18 @@ impl Server {
             } // end select
         } // end loop
 
+        // Cancel all data streams, and wait until each worker has sent its last bar
+        self.cancel_all_data_streams().await;
+        shutdown.cancel();
+        if let Err(e) = reaper.await {
+            println!("[QDGW/run]: Session reaper failed: {}", e);
+        }
+        for worker in workers {
+            if let Err(e) = worker.await {
+                println!("[QDGW/run]: Data stream worker failed: {}", e);
+            }
+        }
+
         self.shutdown_iggy().await.expect("Failed to shutdown iggy");
 
         Ok(())
@@ -62,6 +116,123 @@ impl Server {
 }
 
 impl Server {
+    /// Spawns `worker_count` workers that take data stream jobs from the scheduler.
+    ///
+    /// Each worker runs one data stream at a time until the shutdown token gets cancelled,
+    /// and returns to the scheduler once the stream has finished.
+    ///
+    /// # Parameters
+    ///
+    /// * `shutdown` - The token that stops the workers
+    ///
+    /// # Returns
+    ///
+    /// The join handles of all workers.
+    ///
+    fn spawn_data_stream_workers(&self, shutdown: &CancellationToken) -> Vec<JoinHandle<()>> {
+        (0..self.worker_count())
+            .map(|_| {
+                let server = self.clone();
+                let shutdown = shutdown.clone();
+
+                tokio::spawn(async move {
+                    loop {
+                        select! {
+                            _ = shutdown.cancelled() => break,
+
+                            job = server.scheduler().next_job() => {
+                                let client_id = job.client_id();
+                                server.run_data_stream(job).await;
+                                server.scheduler().complete(client_id).await;
+                            }
+                        }
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Spawns the reaper that checks for stale client sessions once per heartbeat interval,
+    /// and then updates the usage gauges of the remaining clients.
+    ///
+    /// # Parameters
+    ///
+    /// * `shutdown` - The token that stops the reaper
+    ///
+    /// # Returns
+    ///
+    /// The join handle of the reaper.
+    ///
+    fn spawn_session_reaper(&self, shutdown: &CancellationToken) -> JoinHandle<()> {
+        let server = self.clone();
+        let shutdown = shutdown.clone();
+
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_millis(HEARTBEAT_INTERVAL_MILLIS));
+
+            loop {
+                select! {
+                    _ = shutdown.cancelled() => break,
+
+                    _ = ticker.tick() => {
+                        server.evict_stale_sessions().await;
+                        server.update_usage_metrics().await;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Polls the control topic of each logged in client and handles its requests in order.
+    ///
+    /// The offset of each polled batch is committed, so every request is handled once.
+    /// A failed poll is logged and the control topics of the other clients are still polled.
+    ///
+    async fn poll_client_messages(&self) {
+        // Copy the configs, so that the handlers can log clients in and out
+        let client_configs: Vec<(u16, IggyConfig)> = self
+            .client_configs()
+            .read()
+            .await
+            .iter()
+            .map(|(client_id, iggy_config)| (*client_id, iggy_config.clone()))
+            .collect();
+
+        for (client_id, client_config) in client_configs {
+            let control_config = iggy_utils::client_control_config(&client_config);
+            let poll_command = PollMessages {
+                consumer: Default::default(),
+                stream_id: control_config.stream_id(),
+                topic_id: control_config.topic_id(),
+                partition_id: Option::from(control_config.partition_id()),
+                strategy: PollingStrategy::next(),
+                count: control_config.messages_per_batch(),
+                auto_commit: true,
+            };
+
+            let polled_messages = match self.consumer().poll_messages(&poll_command).await {
+                Ok(polled_messages) => polled_messages,
+                Err(e) => {
+                    println!(
+                        "[QDGW/run]: Error polling messages of client {}: {}",
+                        client_id, e
+                    );
+                    continue;
+                }
+            };
+
+            for polled_message in polled_messages.messages {
+                // Errors are reported per message so that one bad request never stops the gateway
+                if let Err(e) = self
+                    .handle_client_message(client_id, polled_message.payload.as_ref())
+                    .await
+                {
+                    self.handle_error(e).await;
+                }
+            }
+        }
+    }
+
     pub(super) async fn shutdown_iggy(&self) -> Result<(), Box<dyn Error>> {
         // Delete consumer stream and topic before shutting down.
         iggy_utils::cleanup(&self.consumer(), &self.iggy_config())
@@ -0,0 +1,123 @@
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+
+/// Shared control handle of a registered data stream.
+///
+/// The message loop uses it to cancel, pause, and continue a stream,
+/// while the stream task uses a clone to observe these signals.
+/// The stream task also records its progress, which the admin endpoints report.
+#[derive(Debug, Clone)]
+pub(crate) struct DataStreamControl {
+    token: CancellationToken,
+    paused: watch::Sender<bool>,
+    // Set when a resumed stream replaces this stream.
+    replaced: Arc<AtomicBool>,
+    started_at: DateTime<Utc>,
+    bars_streamed: Arc<AtomicU64>,
+    // Timestamp in milliseconds of the last streamed bar, or `NO_BAR` before the first bar.
+    last_bar_millis: Arc<AtomicI64>,
+}
+
+const NO_BAR: i64 = i64::MIN;
+
+impl DataStreamControl {
+    pub(crate) fn new() -> Self {
+        let (paused, _) = watch::channel(false);
+
+        Self {
+            token: CancellationToken::new(),
+            paused,
+            replaced: Arc::new(AtomicBool::new(false)),
+            started_at: Utc::now(),
+            bars_streamed: Arc::new(AtomicU64::new(0)),
+            last_bar_millis: Arc::new(AtomicI64::new(NO_BAR)),
+        }
+    }
+}
+
+impl DataStreamControl {
+    /// Cancels the data stream.
+    pub(crate) fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Cancels the data stream because a resumed stream replaces it.
+    ///
+    /// A replaced stream stops without sending the last bar message,
+    /// since the resumed stream continues where the client lost track.
+    pub(crate) fn replace(&self) {
+        self.replaced.store(true, Ordering::SeqCst);
+        self.token.cancel();
+    }
+
+    /// Returns `true` if a resumed stream has replaced the data stream.
+    pub(crate) fn is_replaced(&self) -> bool {
+        self.replaced.load(Ordering::SeqCst)
+    }
+
+    /// Pauses the data stream.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the stream was running, `false` if it was already paused.
+    ///
+    pub(crate) fn pause(&self) -> bool {
+        !self.paused.send_replace(true)
+    }
+
+    /// Continues a paused data stream.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the stream was paused, `false` if it was already running.
+    ///
+    pub(crate) fn resume(&self) -> bool {
+        self.paused.send_replace(false)
+    }
+
+    /// Returns `true` if the data stream is paused.
+    pub(crate) fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Records that the stream task has streamed a bar with the given timestamp.
+    pub(crate) fn record_bar(&self, date_time: DateTime<Utc>) {
+        self.bars_streamed.fetch_add(1, Ordering::Relaxed);
+        self.last_bar_millis
+            .store(date_time.timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Returns a receiver to observe the paused state of the data stream.
+    pub(crate) fn subscribe_paused(&self) -> watch::Receiver<bool> {
+        self.paused.subscribe()
+    }
+
+    /// Returns `true` if both handles control the same data stream.
+    pub(crate) fn same_stream(&self, other: &DataStreamControl) -> bool {
+        self.paused.same_channel(&other.paused)
+    }
+}
+
+impl DataStreamControl {
+    pub(crate) fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+    pub(crate) fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+    /// Returns the number of bars streamed so far.
+    pub(crate) fn bars_streamed(&self) -> u64 {
+        self.bars_streamed.load(Ordering::Relaxed)
+    }
+    /// Returns the timestamp of the last streamed bar, or None before the first bar.
+    pub(crate) fn last_bar_time(&self) -> Option<DateTime<Utc>> {
+        match self.last_bar_millis.load(Ordering::Relaxed) {
+            NO_BAR => None,
+            millis => DateTime::from_timestamp_millis(millis),
+        }
+    }
+}
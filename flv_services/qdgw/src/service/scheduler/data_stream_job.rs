@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use tokio_util::sync::CancellationToken;
+
+use common::prelude::{BarThreshold, TimeResolution};
+use sbe_messages::prelude::{ReplayMode, StartDataMessage};
+
+use crate::service::scheduler::{DataStreamControl, ResumePoint};
+use crate::service::StreamKey;
+
+/// A registered data stream waiting for a free worker.
+///
+/// A job streams either a single symbol, or several symbols merged by timestamp
+/// into one stream. The stream key identifies the job by its first symbol.
+#[derive(Debug, Clone)]
+pub(crate) struct DataStreamJob {
+    stream_key: StreamKey,
+    // (symbol_id, trade_table) of each symbol in the stream.
+    symbols: Vec<(u16, String)>,
+    request: StartDataMessage,
+    control: DataStreamControl,
+    resume: Option<ResumePoint>,
+}
+
+impl DataStreamJob {
+    /// Creates a new DataStreamJob for a single symbol.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_key` - The (client_id, exchange_id, symbol_id, data_type) key of the stream
+    /// * `trade_table` - The database table to stream the data from
+    /// * `request` - The StartDataMessage that requested the stream
+    /// * `control` - The control handle to cancel, pause, and continue the stream
+    ///
+    pub(crate) fn new(
+        stream_key: StreamKey,
+        trade_table: String,
+        request: StartDataMessage,
+        control: DataStreamControl,
+    ) -> Self {
+        let symbols = vec![(stream_key.2, trade_table)];
+
+        Self {
+            stream_key,
+            symbols,
+            request,
+            control,
+            resume: None,
+        }
+    }
+
+    /// Creates a new DataStreamJob that merges the data of several symbols into one stream.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_key` - The (client_id, exchange_id, symbol_id, data_type) key of the first symbol
+    /// * `symbols` - The (symbol_id, trade_table) pairs to stream, starting with the first symbol
+    /// * `request` - The StartDataMessage with the stream parameters shared by all symbols
+    /// * `control` - The control handle to cancel, pause, and continue the stream
+    ///
+    pub(crate) fn new_multi(
+        stream_key: StreamKey,
+        symbols: Vec<(u16, String)>,
+        request: StartDataMessage,
+        control: DataStreamControl,
+    ) -> Self {
+        Self {
+            stream_key,
+            symbols,
+            request,
+            control,
+            resume: None,
+        }
+    }
+}
+
+impl DataStreamJob {
+    /// Returns the DataStreamJob resumed from the given resume point.
+    ///
+    /// A resumed stream continues a stream the client lost track of, so it
+    /// skips the first bar message and all bars before the resume point.
+    ///
+    pub(crate) fn with_resume(mut self, resume: ResumePoint) -> Self {
+        self.resume = Some(resume);
+        self
+    }
+}
+
+impl DataStreamJob {
+    pub(crate) fn client_id(&self) -> u16 {
+        self.stream_key.0
+    }
+    pub(crate) fn stream_key(&self) -> StreamKey {
+        self.stream_key
+    }
+    /// Returns the stream keys of all symbols in the stream.
+    pub(crate) fn stream_keys(&self) -> Vec<StreamKey> {
+        let (client_id, exchange_id, _, data_type) = self.stream_key;
+
+        self.symbols
+            .iter()
+            .map(|(symbol_id, _)| (client_id, exchange_id, *symbol_id, data_type))
+            .collect()
+    }
+    pub(crate) fn symbols(&self) -> &[(u16, String)] {
+        &self.symbols
+    }
+    pub(crate) fn is_multi(&self) -> bool {
+        self.symbols.len() > 1
+    }
+    pub(crate) fn trade_table(&self) -> &str {
+        &self.symbols[0].1
+    }
+    pub(crate) fn time_resolution(&self) -> TimeResolution {
+        *self.request.time_resolution()
+    }
+    /// Returns the bar threshold of a tick, volume, or dollar bar stream.
+    pub(crate) fn bar_threshold(&self) -> Option<BarThreshold> {
+        self.request.bar_threshold()
+    }
+    pub(crate) fn start_time(&self) -> Option<DateTime<Utc>> {
+        self.request.start_time()
+    }
+    pub(crate) fn end_time(&self) -> Option<DateTime<Utc>> {
+        self.request.end_time()
+    }
+    pub(crate) fn replay_mode(&self) -> ReplayMode {
+        *self.request.replay_mode()
+    }
+    pub(crate) fn replay_speed(&self) -> u16 {
+        *self.request.replay_speed()
+    }
+    pub(crate) fn control(&self) -> &DataStreamControl {
+        &self.control
+    }
+    pub(crate) fn token(&self) -> &CancellationToken {
+        self.control.token()
+    }
+    pub(crate) fn is_resumed(&self) -> bool {
+        self.resume.is_some()
+    }
+    /// Returns `true` if the bar lies before the resume point of a resumed stream.
+    pub(crate) fn skips(&self, sequence_number: u64, date_time: DateTime<Utc>) -> bool {
+        match &self.resume {
+            Some(resume) => resume.skips(sequence_number, date_time),
+            None => false,
+        }
+    }
+}
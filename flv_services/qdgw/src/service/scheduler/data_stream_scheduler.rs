@@ -0,0 +1,164 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use tokio::sync::{Mutex, Notify};
+
+use crate::service::scheduler::DataStreamJob;
+
+/// Queues data stream jobs per client and hands them out to workers in round-robin order.
+///
+/// Each client has its own FIFO queue of pending jobs. Workers take the next job
+/// from the first client in the ready ring that runs fewer jobs than its share of
+/// the workers, and that client moves to the back of the ring if it has more pending jobs.
+///
+/// A job runs on its worker until the stream ends, so the scheduler caps the number of
+/// workers per client: each client with running or pending jobs gets an equal share of
+/// the workers, and with more than one worker, no client gets all of them. A client may
+/// exceed its share only while that leaves another worker idle. That way, a client that
+/// requests many long replays at once cannot starve other clients of workers, and a
+/// client that shows up while all other streams run still gets a worker right away.
+#[derive(Debug)]
+pub(crate) struct DataStreamScheduler {
+    worker_count: u16,
+    queue: Mutex<ClientQueue>,
+    notify: Notify,
+}
+
+#[derive(Debug, Default)]
+struct ClientQueue {
+    // Pending jobs for each client.
+    jobs: HashMap<u16, VecDeque<DataStreamJob>>,
+    // Clients with pending jobs in round-robin order.
+    ready: VecDeque<u16>,
+    // Number of running jobs for each client.
+    running: HashMap<u16, u16>,
+}
+
+impl DataStreamScheduler {
+    /// Creates a new DataStreamScheduler for the given number of workers.
+    pub(crate) fn new(worker_count: u16) -> Self {
+        Self {
+            worker_count: worker_count.max(1),
+            queue: Mutex::new(ClientQueue::default()),
+            notify: Notify::new(),
+        }
+    }
+}
+
+impl DataStreamScheduler {
+    /// Adds a job to the queue of its client and wakes up an idle worker.
+    ///
+    /// # Parameters
+    ///
+    /// * `job` - The data stream job to schedule
+    ///
+    pub(crate) async fn schedule(&self, job: DataStreamJob) {
+        let client_id = job.client_id();
+
+        // Lock the client queue
+        let mut queue = self.queue.lock().await;
+
+        let jobs = queue.jobs.entry(client_id).or_default();
+        jobs.push_back(job);
+
+        // A client with more than one pending job is already in the ready ring.
+        if jobs.len() == 1 {
+            queue.ready.push_back(client_id);
+        }
+
+        // Unlock the client queue before waking up a worker
+        drop(queue);
+
+        self.notify.notify_one();
+    }
+
+    /// Releases the worker of a finished job of the given client and wakes up an idle worker,
+    /// which may now run a job of a client that reached its share of the workers.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client of the finished job
+    ///
+    pub(crate) async fn complete(&self, client_id: u16) {
+        // Lock the client queue
+        let mut queue = self.queue.lock().await;
+
+        if let Some(running) = queue.running.get_mut(&client_id) {
+            *running -= 1;
+            if *running == 0 {
+                queue.running.remove(&client_id);
+            }
+        }
+
+        // Unlock the client queue before waking up a worker
+        drop(queue);
+
+        self.notify.notify_one();
+    }
+
+    /// Waits for and returns the next job in round-robin order across clients.
+    pub(crate) async fn next_job(&self) -> DataStreamJob {
+        loop {
+            if let Some(job) = self.try_next_job().await {
+                return job;
+            }
+
+            self.notify.notified().await;
+        }
+    }
+
+    /// Returns the next job in round-robin order across clients
+    /// that run fewer jobs than their share of the workers, if any.
+    pub(crate) async fn try_next_job(&self) -> Option<DataStreamJob> {
+        // Lock the client queue
+        let mut queue = self.queue.lock().await;
+
+        let max_workers = self.max_workers_per_client(&queue);
+        let running_jobs: u16 = queue.running.values().sum();
+        let idle_workers = self.worker_count.saturating_sub(running_jobs);
+
+        // Skip the clients that reached their share, keeping their place in the ring,
+        // unless another worker stays idle.
+        let position = queue.ready.iter().position(|client_id| {
+            queue.running.get(client_id).copied().unwrap_or(0) < max_workers || idle_workers > 1
+        })?;
+        let client_id = queue.ready.remove(position)?;
+
+        let jobs = queue.jobs.get_mut(&client_id)?;
+        let job = jobs.pop_front();
+
+        // Move the client to the back of the ring if it has more pending jobs.
+        if jobs.is_empty() {
+            queue.jobs.remove(&client_id);
+        } else {
+            queue.ready.push_back(client_id);
+        }
+
+        *queue.running.entry(client_id).or_default() += 1;
+
+        // Wake up the next worker if there is more work left.
+        if !queue.ready.is_empty() {
+            self.notify.notify_one();
+        }
+
+        job
+    }
+
+    /// Returns the share of the workers of each client with running or pending jobs.
+    ///
+    /// The workers are split equally across these clients, but each client gets at least one
+    /// worker, and, with more than one worker, never all of them.
+    fn max_workers_per_client(&self, queue: &ClientQueue) -> u16 {
+        let active_clients = queue
+            .running
+            .keys()
+            .chain(queue.jobs.keys())
+            .collect::<HashSet<_>>()
+            .len()
+            .max(1);
+
+        let fair_share = (self.worker_count as usize / active_clients) as u16;
+        let max_share = (self.worker_count - 1).max(1);
+
+        fair_share.clamp(1, max_share)
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use iggy::messages::send_messages::Message;
+use tokio::time::Instant;
+
+/// Collects encoded bar messages of a data stream to send them in one batch.
+///
+/// Each bar remains a separate message on the client data channel, so clients decode
+/// bars exactly as before. The batch is due once it holds `max_size` messages, or once
+/// its oldest message has waited for `max_delay`, whichever comes first.
+#[derive(Debug)]
+pub(crate) struct MessageBatch {
+    messages: Vec<Message>,
+    max_size: usize,
+    max_delay: Duration,
+    // Time when the oldest message was added to the batch.
+    oldest: Option<Instant>,
+}
+
+impl MessageBatch {
+    /// Creates a new, empty MessageBatch.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_size` - The maximum number of messages in a batch; at least one
+    /// * `max_delay` - The maximum time a message waits in the batch before it is sent
+    ///
+    pub(crate) fn new(max_size: usize, max_delay: Duration) -> Self {
+        let max_size = max_size.max(1);
+
+        Self {
+            messages: Vec::with_capacity(max_size),
+            max_size,
+            max_delay,
+            oldest: None,
+        }
+    }
+}
+
+impl MessageBatch {
+    /// Adds a message to the batch.
+    pub(crate) fn push(&mut self, message: Message) {
+        if self.messages.is_empty() {
+            self.oldest = Some(Instant::now());
+        }
+
+        self.messages.push(message);
+    }
+
+    /// Removes and returns all messages from the batch.
+    pub(crate) fn take(&mut self) -> Vec<Message> {
+        self.oldest = None;
+        std::mem::replace(&mut self.messages, Vec::with_capacity(self.max_size))
+    }
+
+    /// Returns `true` if the batch holds `max_size` messages.
+    pub(crate) fn is_full(&self) -> bool {
+        self.messages.len() >= self.max_size
+    }
+
+    /// Returns `true` if the batch holds no messages.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Returns the time when the batch is due to be sent.
+    ///
+    /// For an empty batch, this is `max_delay` from now.
+    pub(crate) fn deadline(&self) -> Instant {
+        self.oldest.unwrap_or_else(Instant::now) + self.max_delay
+    }
+}
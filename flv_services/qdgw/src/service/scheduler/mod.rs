@@ -0,0 +1,13 @@
+mod data_stream_control;
+mod data_stream_job;
+mod data_stream_scheduler;
+mod message_batch;
+mod replay_pacer;
+mod resume_point;
+
+pub(crate) use data_stream_control::DataStreamControl;
+pub(crate) use data_stream_job::DataStreamJob;
+pub(crate) use data_stream_scheduler::DataStreamScheduler;
+pub(crate) use message_batch::MessageBatch;
+pub(crate) use replay_pacer::ReplayPacer;
+pub(crate) use resume_point::ResumePoint;
@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::select;
+use tokio::sync::watch;
+use tokio::time::{sleep_until, Instant};
+use tokio_util::sync::CancellationToken;
+
+use sbe_messages::prelude::ReplayMode;
+
+use crate::service::scheduler::DataStreamJob;
+
+/// Paces the bars of a data stream according to the requested replay mode.
+///
+/// The pacer anchors the timestamp of the first bar to the wall-clock time it
+/// was sent and releases each following bar once the time gap to the anchor,
+/// divided by the replay speed, has elapsed. After a pause, the anchor moves to
+/// the next bar so the stream continues at the same pace instead of catching up.
+#[derive(Debug)]
+pub(crate) struct ReplayPacer {
+    // None means unthrottled.
+    speed: Option<u32>,
+    anchor: Option<(DateTime<Utc>, Instant)>,
+    paused: watch::Receiver<bool>,
+    token: CancellationToken,
+}
+
+impl ReplayPacer {
+    /// Creates a new ReplayPacer for the given data stream job.
+    ///
+    /// A scaled replay with speed 0 or 1 runs at real-time pace.
+    ///
+    pub(crate) fn new(job: &DataStreamJob) -> Self {
+        let speed = match job.replay_mode() {
+            ReplayMode::Unthrottled => None,
+            ReplayMode::RealTime => Some(1),
+            ReplayMode::Scaled => Some(job.replay_speed().max(1) as u32),
+        };
+
+        Self {
+            speed,
+            anchor: None,
+            paused: job.control().subscribe_paused(),
+            token: job.token().clone(),
+        }
+    }
+}
+
+impl ReplayPacer {
+    /// Waits until the bar with the given timestamp is due to be sent.
+    ///
+    /// Also waits for as long as the stream is paused.
+    ///
+    /// # Parameters
+    ///
+    /// * `date_time` - The timestamp of the next bar
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` when the bar is due, or `false` if the stream got cancelled while waiting.
+    ///
+    pub(crate) async fn wait(&mut self, date_time: DateTime<Utc>) -> bool {
+        loop {
+            if *self.paused.borrow_and_update() {
+                select! {
+                    _ = self.token.cancelled() => return false,
+                    res = self.paused.wait_for(|paused| !*paused) => if res.is_err() {
+                        return false;
+                    },
+                }
+
+                // Restart the replay clock so that the pause is not caught up.
+                self.anchor = None;
+            }
+
+            let deadline = match self.deadline(date_time) {
+                Some(deadline) => deadline,
+                None => return true,
+            };
+
+            select! {
+                _ = self.token.cancelled() => return false,
+                _ = sleep_until(deadline) => return true,
+                // Pause requested while waiting for the deadline.
+                res = self.paused.changed() => if res.is_err() {
+                    return false;
+                },
+            }
+        }
+    }
+
+    /// Returns `true` if the bar with the given timestamp can be sent right away,
+    /// i.e. the stream is not paused and the bar is already due.
+    ///
+    /// # Parameters
+    ///
+    /// * `date_time` - The timestamp of the next bar
+    ///
+    pub(crate) fn is_ready(&mut self, date_time: DateTime<Utc>) -> bool {
+        if *self.paused.borrow() {
+            return false;
+        }
+
+        match self.deadline(date_time) {
+            Some(deadline) => deadline <= Instant::now(),
+            None => true,
+        }
+    }
+
+    /// Returns the instant at which the bar with the given timestamp is due,
+    /// or `None` if the stream is unthrottled.
+    fn deadline(&mut self, date_time: DateTime<Utc>) -> Option<Instant> {
+        let speed = self.speed?;
+
+        let (anchor_time, anchor_instant) = *self.anchor.get_or_insert((date_time, Instant::now()));
+
+        // Bars out of timestamp order are sent right away.
+        let gap = (date_time - anchor_time).to_std().unwrap_or(Duration::ZERO);
+
+        Some(anchor_instant + gap / speed)
+    }
+}
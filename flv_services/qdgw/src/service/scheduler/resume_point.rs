@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+
+/// The bar from which a resumed data stream sends bars to the client again.
+///
+/// A resumed stream replays the original stream and skips all bars before the
+/// resume point, so its bars keep the sequence numbers of the original stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResumePoint {
+    /// Resume from the bar with this sequence number.
+    SequenceNumber(u64),
+    /// Resume from the first bar at or after this timestamp.
+    DateTime(DateTime<Utc>),
+}
+
+impl ResumePoint {
+    /// Returns `true` if the bar lies before the resume point and must not be sent again.
+    ///
+    /// # Parameters
+    ///
+    /// * `sequence_number` - The sequence number of the bar
+    /// * `date_time` - The timestamp of the bar
+    ///
+    pub(crate) fn skips(&self, sequence_number: u64, date_time: DateTime<Utc>) -> bool {
+        match self {
+            ResumePoint::SequenceNumber(resume_sequence_number) => {
+                sequence_number < *resume_sequence_number
+            }
+            ResumePoint::DateTime(resume_time) => date_time < *resume_time,
+        }
+    }
+}
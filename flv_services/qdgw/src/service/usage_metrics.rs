@@ -0,0 +1,90 @@
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::registry::Registry;
+
+use rate_limiter::ClientUsage;
+
+type ClientLabels = Vec<(String, String)>;
+
+/// Gauges of the current usage of each client, labeled by client id.
+///
+/// The gauges get registered in the same registry as the autometrics metrics,
+/// so the metrics endpoint exports them together.
+/// Clones share the same gauges.
+#[derive(Debug, Clone, Default)]
+pub struct UsageMetrics {
+    control_tokens: Family<ClientLabels, Gauge>,
+    streams: Family<ClientLabels, Gauge>,
+    rows_in_window: Family<ClientLabels, Gauge>,
+    bytes_in_window: Family<ClientLabels, Gauge>,
+}
+
+impl UsageMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers all gauges in the given registry.
+    pub fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "qdgw_client_control_tokens",
+            "Number of control messages a client can send without being rate limited",
+            self.control_tokens.clone(),
+        );
+        registry.register(
+            "qdgw_client_streams",
+            "Number of data streams a client runs",
+            self.streams.clone(),
+        );
+        registry.register(
+            "qdgw_client_rows_in_window",
+            "Number of rows streamed to a client in the current quota window",
+            self.rows_in_window.clone(),
+        );
+        registry.register(
+            "qdgw_client_bytes_in_window",
+            "Number of bytes streamed to a client in the current quota window",
+            self.bytes_in_window.clone(),
+        );
+    }
+}
+
+impl UsageMetrics {
+    /// Sets all gauges of a client.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client
+    /// * `usage` - The current usage of the client
+    /// * `streams` - The number of data streams the client runs
+    ///
+    pub(crate) fn set(&self, client_id: u16, usage: &ClientUsage, streams: usize) {
+        let labels = client_labels(client_id);
+
+        self.control_tokens
+            .get_or_create(&labels)
+            .set(usage.control_tokens() as i64);
+        self.streams.get_or_create(&labels).set(streams as i64);
+        self.rows_in_window
+            .get_or_create(&labels)
+            .set(usage.rows_in_window() as i64);
+        self.bytes_in_window
+            .get_or_create(&labels)
+            .set(usage.bytes_in_window() as i64);
+    }
+
+    /// Removes all gauges of a client, so that the metrics endpoint
+    /// only exports clients that are logged in.
+    pub(crate) fn remove(&self, client_id: u16) {
+        let labels = client_labels(client_id);
+
+        self.control_tokens.remove(&labels);
+        self.streams.remove(&labels);
+        self.rows_in_window.remove(&labels);
+        self.bytes_in_window.remove(&labels);
+    }
+}
+
+fn client_labels(client_id: u16) -> ClientLabels {
+    vec![("client_id".to_string(), client_id.to_string())]
+}
@@ -1,3 +1,8 @@
-mod utils_send_data;
+mod utils_client_session;
+mod utils_client_user;
 mod utils_data_encoding;
+mod utils_data_stream;
+mod utils_rate_limit;
+mod utils_send_ack;
+mod utils_send_data;
 mod utils_send_error;
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::service::{Server, HEARTBEAT_INTERVAL_MILLIS, MAX_MISSED_HEARTBEATS};
+
+impl Server {
+    /// Evicts the sessions of all clients that missed `MAX_MISSED_HEARTBEATS` heartbeats.
+    ///
+    /// A client that crashes without logging out would otherwise stay logged in forever,
+    /// and the next login with the same client ID would fail.
+    ///
+    pub(crate) async fn evict_stale_sessions(&self) {
+        let max_silence = Duration::from_millis(HEARTBEAT_INTERVAL_MILLIS) * MAX_MISSED_HEARTBEATS;
+        let now = Instant::now();
+
+        // Lock the client_heartbeats hashmap to collect all stale clients
+        let client_heartbeats = self.client_heartbeats().read().await;
+
+        let stale_clients: Vec<u16> = client_heartbeats
+            .iter()
+            .filter(|(_, last_heartbeat)| now.duration_since(**last_heartbeat) > max_silence)
+            .map(|(client_id, _)| *client_id)
+            .collect();
+
+        // Unlock the client_heartbeats hashmap
+        drop(client_heartbeats);
+
+        for client_id in stale_clients {
+            println!(
+                "[QDGW/evict_stale_sessions]: Client {} missed {} heartbeats, evict session",
+                client_id, MAX_MISSED_HEARTBEATS
+            );
+
+            self.evict_client_session(client_id).await;
+        }
+    }
+
+    /// Evicts the session of a client.
+    ///
+    /// Deletes the iggy stream and topic of the client data channel,
+    /// then logs the client out, which cancels all of its data streams,
+    /// and deletes the iggy user of the client, which revokes its access token.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client to evict
+    ///
+    pub(crate) async fn evict_client_session(&self, client_id: u16) {
+        let client_configs = self.client_configs().read().await;
+        let client_producers = self.client_producers().read().await;
+
+        if let (Some(iggy_config), Some(producer)) = (
+            client_configs.get(&client_id),
+            client_producers.get(&client_id),
+        ) {
+            let res = iggy_utils::cleanup(producer, iggy_config)
+                .await
+                .map_err(|e| e.to_string());
+
+            if let Err(err) = res {
+                println!(
+                    "[QDGW/evict_client_session]: Failed to clean up iggy stream of client {}: {}",
+                    client_id, err
+                );
+            }
+        }
+
+        // Unlock both hashmaps before logging out the client
+        drop(client_producers);
+        drop(client_configs);
+
+        match self.client_logout(client_id).await {
+            Ok(_) => {}
+            Err(err) => println!(
+                "[QDGW/evict_client_session]: Failed to log out client {}: {}",
+                client_id, err
+            ),
+        }
+
+        match self.delete_client_user(client_id).await {
+            Ok(_) => {}
+            Err(err) => println!(
+                "[QDGW/evict_client_session]: Failed to delete iggy user of client {}: {}",
+                client_id, err
+            ),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use iggy::client::Client;
+
+use common::prelude::{IggyConfig, IggyUser, MessageProcessingError};
+
+use crate::service::Server;
+
+impl Server {
+    /// Creates the iggy user of a client and issues a personal access token for it.
+    ///
+    /// The user may only use the data stream of the client, i.e. its data channel and its
+    /// control topic, see `iggy_utils::client_permissions`. The user of an earlier session of the client
+    /// gets replaced, which also revokes the access token of that session.
+    ///
+    /// The password of the user is the auth token the client authenticated with, so that only
+    /// the client can log in to read the login ack with the access token from its data channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client
+    /// * `auth_token` - The auth token the client authenticated with
+    /// * `client_config` - The iggy config of the client data channel
+    ///
+    /// # Returns
+    ///
+    /// A Result with the personal access token of the user,
+    /// or a MessageProcessingError if the user or the token cannot be created.
+    ///
+    pub(crate) async fn create_client_user(
+        &self,
+        client_id: u16,
+        auth_token: &str,
+        client_config: &IggyConfig,
+    ) -> Result<String, MessageProcessingError> {
+        // The user only exists if an earlier session of the client ended without eviction,
+        // so failing to delete a user that does not exist is expected.
+        let _ = self.delete_client_user(client_id).await;
+
+        let client_stream_id = match client_config.stream_id().get_u32_value() {
+            Ok(stream_id) => stream_id,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        let username = iggy_utils::client_user_name(client_id);
+        let user = IggyUser::new(&username, auth_token);
+        let permissions = iggy_utils::client_permissions(client_stream_id);
+
+        match iggy_utils::create_user_with_permissions(self.producer(), &user, permissions)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(_) => {}
+            Err(e) => return Err(MessageProcessingError(e)),
+        }
+
+        // Log in as the new user on a separate connection to issue its access token
+        let client = match iggy_utils::get_iggy_client(self.iggy_config().tcp_server_addr()).await {
+            Ok(client) => client,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        match client.connect().await {
+            Ok(_) => {}
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        }
+
+        match iggy_utils::login_user(&client, &user)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(_) => {}
+            Err(e) => return Err(MessageProcessingError(e)),
+        }
+
+        let token_name = iggy_utils::client_token_name(client_id);
+        let access_token = match iggy_utils::create_token(&client, token_name)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(access_token) => access_token,
+            Err(e) => return Err(MessageProcessingError(e)),
+        };
+
+        // The token stays valid after the connection that issued it closes
+        match client.disconnect().await {
+            Ok(_) => {}
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        }
+
+        Ok(access_token)
+    }
+
+    /// Creates the bootstrap iggy user clients use to send their login to the gateway.
+    ///
+    /// The user may only send logins to the gateway and read rejected logins,
+    /// see `iggy_utils::bootstrap_permissions`.
+    ///
+    /// # Returns
+    ///
+    /// A Result with no value if the user was created,
+    /// or a MessageProcessingError if the user cannot be created, i.e. because it already exists.
+    ///
+    pub(crate) async fn create_bootstrap_user(&self) -> Result<(), MessageProcessingError> {
+        let gateway_stream_id = match self.iggy_config().stream_id().get_u32_value() {
+            Ok(stream_id) => stream_id,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        let gateway_topic_id = match self.iggy_config().topic_id().get_u32_value() {
+            Ok(topic_id) => topic_id,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        let user = iggy_utils::bootstrap_user();
+        let permissions = iggy_utils::bootstrap_permissions(gateway_stream_id, gateway_topic_id);
+
+        match iggy_utils::create_user_with_permissions(self.producer(), &user, permissions)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(MessageProcessingError(e)),
+        }
+    }
+
+    /// Deletes the iggy user of a client, which also revokes its personal access token.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client
+    ///
+    /// # Returns
+    ///
+    /// A Result with no value if the user was deleted,
+    /// or a MessageProcessingError if the user does not exist or cannot be deleted.
+    ///
+    pub(crate) async fn delete_client_user(
+        &self,
+        client_id: u16,
+    ) -> Result<(), MessageProcessingError> {
+        let username = iggy_utils::client_user_name(client_id);
+
+        match iggy_utils::delete_user(self.producer(), &username)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(MessageProcessingError(e)),
+        }
+    }
+}
@@ -1,15 +1,19 @@
 use crate::service::Server;
-use common::prelude::MessageProcessingError;
+use common::prelude::{MessageProcessingError, OHLCVBar, TradeBar};
+use db_query_manager::types::{OHLCVRow, TradeRow};
+use iggy::messages::send_messages::Message;
 use sbe_messages::prelude::{
-    DataErrorType, DataType, FirstOHLCVBar, FirstTradeBar, LastOHLCVBar, LastTradeBar,
+    DataErrorType, DataType, FirstOHLCVBar, FirstTradeBar, LastOHLCVBar, LastTradeBar, SbeOHLCVBar,
+    SbeTradeBar,
 };
+use warp::hyper::body::Bytes;
 
 impl Server {
     /// Encodes a FirstTradeBar or FirstOHLCVBar message for the given symbol id and data type.
     ///
     /// # Parameters
     ///
-    /// * `date_type` - The data type to encode, TradeData or OHLCVData
+    /// * `date_type` - The data type to encode, TradeData, OHLCVData, or an information bar data type
     /// * `symbol_id` - The numeric id of the symbol
     ///
     /// # Returns
@@ -41,7 +45,11 @@ impl Server {
                     )),
                 }
             }
-            DataType::OHLCVData => {
+            // Information bars are sent as OHLCV bars, so existing OHLCV consumers keep working
+            DataType::OHLCVData
+            | DataType::TickBarData
+            | DataType::VolumeBarData
+            | DataType::DollarBarData => {
                 let first_ohlcv_bar = FirstOHLCVBar::new(symbol_id);
                 match first_ohlcv_bar.encode() {
                     Ok((_, buf)) => Ok(buf),
@@ -58,7 +66,7 @@ impl Server {
     ///
     /// # Parameters
     ///
-    /// * `date_type` - The data type to encode, TradeData or OHLCVData
+    /// * `date_type` - The data type to encode, TradeData, OHLCVData, or an information bar data type
     /// * `symbol_id` - The numeric id of the symbol
     ///
     /// # Returns
@@ -91,7 +99,11 @@ impl Server {
                     )),
                 }
             }
-            DataType::OHLCVData => {
+            // Information bars are sent as OHLCV bars, so existing OHLCV consumers keep working
+            DataType::OHLCVData
+            | DataType::TickBarData
+            | DataType::VolumeBarData
+            | DataType::DollarBarData => {
                 // Encode last bar message
                 let last_ohlcv_bar = LastOHLCVBar::new(symbol_id);
                 match last_ohlcv_bar.encode() {
@@ -104,4 +116,120 @@ impl Server {
             }
         }
     }
+
+    /// Encodes a trade row from the database into a trade bar message for the client data channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `symbol_id` - The numeric id of the symbol
+    /// * `sequence_number` - The sequence number of the bar in its data stream
+    /// * `record` - The trade row to encode
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the trade bar wrapped in an iggy message if successful, otherwise returns a
+    /// `(DataErrorType::DataEncodingError, MessageProcessingError)` tuple.
+    ///
+    pub(crate) fn encode_trade_bar_message(
+        &self,
+        symbol_id: u16,
+        sequence_number: u64,
+        record: &TradeRow,
+    ) -> Result<Message, (DataErrorType, MessageProcessingError)> {
+        let bar = TradeBar::new(
+            symbol_id,
+            record.date_time(),
+            record.price(),
+            record.volume(),
+        )
+        .with_sequence_number(sequence_number);
+
+        // Encode the trade bar message
+        match SbeTradeBar::encode(bar) {
+            Ok((_, buf)) => Ok(Message::new(None, Bytes::from(buf), None)),
+            Err(e) => Err((
+                DataErrorType::DataEncodingError,
+                MessageProcessingError(e.to_string()),
+            )),
+        }
+    }
+
+    /// Encodes an OHLCV row from the database into an OHLCV bar message for the client data channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `symbol_id` - The numeric id of the symbol
+    /// * `sequence_number` - The sequence number of the bar in its data stream
+    /// * `record` - The OHLCV row to encode
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the OHLCV bar wrapped in an iggy message if successful, otherwise returns a
+    /// `(DataErrorType::DataEncodingError, MessageProcessingError)` tuple.
+    ///
+    pub(crate) fn encode_ohlcv_bar_message(
+        &self,
+        symbol_id: u16,
+        sequence_number: u64,
+        record: &OHLCVRow,
+    ) -> Result<Message, (DataErrorType, MessageProcessingError)> {
+        let bar = OHLCVBar::new(
+            symbol_id,
+            record.date_time(),
+            record.open(),
+            record.high(),
+            record.low(),
+            record.close(),
+            record.volume(),
+        )
+        .with_sequence_number(sequence_number)
+        .with_vwap(record.vwap())
+        .with_trade_count(record.trade_count());
+
+        // Only trades with a side column have a buy and sell volume
+        let bar = match (record.buy_volume(), record.sell_volume()) {
+            (Some(buy_volume), Some(sell_volume)) => {
+                bar.with_buy_sell_volume(buy_volume, sell_volume)
+            }
+            _ => bar,
+        };
+
+        // Encode the OHLCV bar message
+        match SbeOHLCVBar::encode(bar) {
+            Ok((_, buf)) => Ok(Message::new(None, Bytes::from(buf), None)),
+            Err(e) => Err((
+                DataErrorType::DataEncodingError,
+                MessageProcessingError(e.to_string()),
+            )),
+        }
+    }
+
+    /// Encodes a tick, volume, or dollar bar into an OHLCV bar message for the client data channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `sequence_number` - The sequence number of the bar in its data stream
+    /// * `bar` - The information bar built from the trade stream
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the OHLCV bar wrapped in an iggy message if successful, otherwise returns a
+    /// `(DataErrorType::DataEncodingError, MessageProcessingError)` tuple.
+    ///
+    pub(crate) fn encode_information_bar_message(
+        &self,
+        sequence_number: u64,
+        bar: OHLCVBar,
+    ) -> Result<Message, (DataErrorType, MessageProcessingError)> {
+        let bar = bar.with_sequence_number(sequence_number);
+
+        // Encode the OHLCV bar message
+        match SbeOHLCVBar::encode(bar) {
+            Ok((_, buf)) => Ok(Message::new(None, Bytes::from(buf), None)),
+            Err(e) => Err((
+                DataErrorType::DataEncodingError,
+                MessageProcessingError(e.to_string()),
+            )),
+        }
+    }
 }
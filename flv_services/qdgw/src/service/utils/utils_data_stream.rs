@@ -0,0 +1,240 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::service::scheduler::DataStreamControl;
+use crate::service::{Server, StreamKey};
+
+impl Server {
+    /// Registers a new data stream for the given stream key.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_key` - The (client_id, exchange_id, symbol_id, data_type) key of the stream
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(DataStreamControl)` for the newly registered stream,
+    /// or `None` if a stream with the same key is already active.
+    ///
+    pub(crate) async fn register_data_stream(
+        &self,
+        stream_key: StreamKey,
+    ) -> Option<DataStreamControl> {
+        self.register_data_streams(&[stream_key]).await
+    }
+
+    /// Registers one data stream under all of the given stream keys.
+    ///
+    /// All keys share the same control handle, so the stream can be paused,
+    /// continued, or cancelled with the key of any of its symbols.
+    /// Either all keys get registered, or none.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_keys` - The (client_id, exchange_id, symbol_id, data_type) keys of the stream
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(DataStreamControl)` for the newly registered stream,
+    /// or `None` if a stream with any of the keys is already active.
+    ///
+    pub(crate) async fn register_data_streams(
+        &self,
+        stream_keys: &[StreamKey],
+    ) -> Option<DataStreamControl> {
+        // Lock the client_streams hashmap
+        let mut client_streams = self.client_streams().write().await;
+
+        if stream_keys
+            .iter()
+            .any(|stream_key| client_streams.contains_key(stream_key))
+        {
+            return None;
+        }
+
+        let control = DataStreamControl::new();
+        for stream_key in stream_keys {
+            client_streams.insert(*stream_key, control.clone());
+        }
+
+        Some(control)
+    }
+
+    /// Registers a resumed data stream for the given stream key.
+    ///
+    /// A running stream with the same key gets replaced, which stops it
+    /// without sending the last bar message, so the client receives only
+    /// the bars of the resumed stream from then on.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_key` - The (client_id, exchange_id, symbol_id, data_type) key of the stream
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(DataStreamControl)` for the newly registered stream,
+    /// or `None` if the key belongs to a running stream of several symbols,
+    /// which cannot be resumed for a single symbol.
+    ///
+    pub(crate) async fn register_resumed_data_stream(
+        &self,
+        stream_key: StreamKey,
+    ) -> Option<DataStreamControl> {
+        // Lock the client_streams hashmap
+        let mut client_streams = self.client_streams().write().await;
+
+        if let Some(running) = client_streams.get(&stream_key) {
+            let shared = client_streams
+                .iter()
+                .any(|(key, other)| *key != stream_key && other.same_stream(running));
+            if shared {
+                return None;
+            }
+
+            running.replace();
+        }
+
+        let control = DataStreamControl::new();
+        client_streams.insert(stream_key, control.clone());
+
+        Some(control)
+    }
+
+    /// Removes a finished data stream from the registry.
+    ///
+    /// A cancelled stream has already been removed by whoever cancelled it,
+    /// so the entries are only removed if the stream ran to completion.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_keys` - The keys of the finished stream
+    /// * `token` - The cancellation token of the finished stream
+    ///
+    pub(crate) async fn deregister_data_streams(
+        &self,
+        stream_keys: &[StreamKey],
+        token: &CancellationToken,
+    ) {
+        if token.is_cancelled() {
+            return;
+        }
+
+        // Lock the client_streams hashmap
+        let mut client_streams = self.client_streams().write().await;
+
+        for stream_key in stream_keys {
+            client_streams.remove(stream_key);
+        }
+    }
+
+    /// Cancels the data stream with the given key.
+    ///
+    /// The stream task stops streaming at the next bar and then sends the last bar message.
+    /// A stream of several symbols is cancelled as a whole and removed under all its keys.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_key` - The key of the stream to cancel
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if an active stream was cancelled, `false` otherwise.
+    ///
+    pub(crate) async fn cancel_data_stream(&self, stream_key: &StreamKey) -> bool {
+        // Lock the client_streams hashmap
+        let mut client_streams = self.client_streams().write().await;
+
+        match client_streams.remove(stream_key) {
+            Some(control) => {
+                // Remove the keys of the other symbols of the same stream
+                client_streams.retain(|_, other| !other.same_stream(&control));
+                control.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Pauses the data stream with the given key.
+    ///
+    /// The stream task holds back the next bar until the stream gets continued or cancelled.
+    /// A stream of several symbols is paused as a whole.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_key` - The key of the stream to pause
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if an active stream was paused, `false` otherwise.
+    ///
+    pub(crate) async fn pause_data_stream(&self, stream_key: &StreamKey) -> bool {
+        // Lock the client_streams hashmap
+        let client_streams = self.client_streams().read().await;
+
+        match client_streams.get(stream_key) {
+            Some(control) => control.pause(),
+            None => false,
+        }
+    }
+
+    /// Continues the paused data stream with the given key.
+    ///
+    /// A stream of several symbols is continued as a whole.
+    ///
+    /// # Parameters
+    ///
+    /// * `stream_key` - The key of the stream to continue
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a paused stream was continued, `false` otherwise.
+    ///
+    pub(crate) async fn continue_data_stream(&self, stream_key: &StreamKey) -> bool {
+        // Lock the client_streams hashmap
+        let client_streams = self.client_streams().read().await;
+
+        match client_streams.get(stream_key) {
+            Some(control) => control.resume(),
+            None => false,
+        }
+    }
+
+    /// Cancels all data streams of a client.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of cancelled streams.
+    ///
+    pub(crate) async fn cancel_client_data_streams(&self, client_id: u16) -> usize {
+        // Lock the client_streams hashmap
+        let mut client_streams = self.client_streams().write().await;
+
+        let stream_keys: Vec<StreamKey> = client_streams
+            .keys()
+            .filter(|(c_id, _, _, _)| *c_id == client_id)
+            .copied()
+            .collect();
+
+        for stream_key in &stream_keys {
+            if let Some(control) = client_streams.remove(stream_key) {
+                control.cancel();
+            }
+        }
+
+        stream_keys.len()
+    }
+
+    /// Cancels all active data streams of all clients.
+    pub(crate) async fn cancel_all_data_streams(&self) {
+        // Lock the client_streams hashmap
+        let mut client_streams = self.client_streams().write().await;
+
+        for (_, control) in client_streams.drain() {
+            control.cancel();
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::time::{Duration, Instant};
+
+use iggy::messages::send_messages::Message;
+
+use sbe_messages::prelude::MessageType;
+
+use crate::service::errors::GatewayError;
+use crate::service::Server;
+
+impl Server {
+    /// Ensures that a client stays within the rate of its control messages.
+    ///
+    /// Data requests of logged in clients take a token from the bucket of the client.
+    /// Requests of clients that are not logged in are rejected by their handlers,
+    /// so they never use up the tokens of a client that logs in later; logins are limited
+    /// per credential by `ensure_login_rate` instead. Heartbeats and logouts are never limited,
+    /// so that a limited client keeps its session and can always end it.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_type` - The type of the message
+    /// * `client_id` - The ID of the client that sent the message
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the message is within the rate of the client. Otherwise, a
+    /// `GatewayError::RateLimited` with the time until the client can send the next control message.
+    ///
+    pub(crate) async fn ensure_control_rate(
+        &self,
+        message_type: MessageType,
+        client_id: u16,
+    ) -> Result<(), GatewayError> {
+        match message_type {
+            MessageType::StartData
+            | MessageType::StartMultiData
+            | MessageType::StopData
+            | MessageType::StopAllData
+            | MessageType::PauseData
+            | MessageType::ContinueData
+            | MessageType::ResumeData => {}
+            _ => return Ok(()),
+        }
+
+        // Only sessions that authenticated at login have a bucket.
+        match self.check_client_login(client_id).await {
+            Ok(true) => {}
+            _ => return Ok(()),
+        }
+
+        match self
+            .rate_limiter()
+            .check_control_message(client_id, Instant::now())
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                println!(
+                    "[QDGW/ensure_control_rate]: Client {} sent {}: {}",
+                    client_id, message_type, err
+                );
+                Err(GatewayError::RateLimited(
+                    client_id,
+                    retry_after_millis(err.retry_after()),
+                ))
+            }
+        }
+    }
+
+    /// Ensures that login attempts with the same credential stay within the rate of control messages.
+    ///
+    /// Attempts are keyed by the client ID and the auth token, so that attempts with a
+    /// wrong auth token cannot lock the client with the right one out of its login.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client that attempts to log in
+    /// * `auth_token` - The auth token of the login attempt
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the attempt is within the rate of the credential. Otherwise, a
+    /// `GatewayError::RateLimited` with the time until the next attempt with the credential.
+    ///
+    pub(crate) fn ensure_login_rate(
+        &self,
+        client_id: u16,
+        auth_token: &str,
+    ) -> Result<(), GatewayError> {
+        match self
+            .rate_limiter()
+            .check_login_attempt(client_id, auth_token, Instant::now())
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                println!(
+                    "[QDGW/ensure_login_rate]: Client {} attempted to log in: {}",
+                    client_id, err
+                );
+                Err(GatewayError::RateLimited(
+                    client_id,
+                    retry_after_millis(err.retry_after()),
+                ))
+            }
+        }
+    }
+
+    /// Ensures that a client can start or continue a data stream.
+    ///
+    /// The client must not have used up the rows or bytes of the current quota window.
+    /// The quota is checked before a stream starts and before each send of a running stream,
+    /// see `send_client_data`, so a client that exceeds its quota has its running streams
+    /// stopped and can only start new streams once the window ends. The number of concurrent
+    /// streams is limited by the entitlements of the client, see `ensure_entitled`.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result` if the client is within its quota. Otherwise, a
+    /// `GatewayError::RateLimited` with the time until the quota window ends.
+    ///
+    pub(crate) fn ensure_stream_quota(&self, client_id: u16) -> Result<(), GatewayError> {
+        match self
+            .rate_limiter()
+            .check_stream_start(client_id, Instant::now())
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                println!("[QDGW/ensure_stream_quota]: Client {}: {}", client_id, err);
+                Err(GatewayError::RateLimited(
+                    client_id,
+                    retry_after_millis(err.retry_after()),
+                ))
+            }
+        }
+    }
+
+    /// Counts the rows and bytes of data messages sent to a client against its quota.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client the messages were sent to
+    /// * `messages` - The sent messages, each of which holds one row
+    ///
+    pub(crate) fn record_client_data(&self, client_id: u16, messages: &[Message]) {
+        let rows = messages.len() as u64;
+        let bytes = messages
+            .iter()
+            .map(|message| message.payload.len() as u64)
+            .sum();
+
+        self.rate_limiter()
+            .record_data(client_id, rows, bytes, Instant::now());
+    }
+
+    /// Updates the usage gauges of all logged in clients.
+    pub(crate) async fn update_usage_metrics(&self) {
+        // Lock the client_heartbeats hashmap to collect all logged in clients
+        let client_heartbeats = self.client_heartbeats().read().await;
+        let client_ids: Vec<u16> = client_heartbeats.keys().copied().collect();
+        drop(client_heartbeats);
+
+        let now = Instant::now();
+        for client_id in client_ids {
+            let usage = self.rate_limiter().usage(client_id, now);
+            let streams = self.count_client_streams(client_id, &[]).await;
+            self.usage_metrics().set(client_id, &usage, streams);
+        }
+    }
+}
+
+/// Converts a retry time to whole milliseconds, rounded up so that
+/// a client that waits the given time does not get limited again.
+fn retry_after_millis(retry_after: Option<Duration>) -> Option<u32> {
+    retry_after.map(|retry_after| {
+        let millis = retry_after.as_nanos().div_ceil(1_000_000);
+        millis.min(u32::MAX as u128) as u32
+    })
+}
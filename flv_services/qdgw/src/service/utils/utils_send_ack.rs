@@ -0,0 +1,112 @@
+use iggy::messages::send_messages::Message;
+use warp::hyper::body::Bytes;
+
+use common::prelude::{IggyConfig, MessageProcessingError};
+use sbe_messages::prelude::{Capabilities, ClientLoginAckMessage, ClientLogoutAckMessage};
+
+use crate::service::Server;
+
+impl Server {
+    /// Sends a ClientLoginAck message to confirm a successful client login.
+    ///
+    /// The ack carries the data channel the gateway assigned to the client,
+    /// the protocol version and capabilities negotiated with the client,
+    /// and the personal access token the client uses to connect to its data channel.
+    ///
+    /// The ack goes on the data channel of the client, which only the iggy user of the client
+    /// can read, because clients can only send to the gateway control channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client that logged in
+    /// * `iggy_config` - The iggy config of the client data channel
+    /// * `protocol_version` - The negotiated protocol version
+    /// * `capabilities` - The capabilities both the client and the gateway support
+    /// * `access_token` - The personal access token of the client, or an empty string
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to encode or send.
+    ///
+    pub(crate) async fn send_client_login_ack(
+        &self,
+        client_id: u16,
+        iggy_config: &IggyConfig,
+        protocol_version: u16,
+        capabilities: Capabilities,
+        access_token: &str,
+    ) -> Result<(), MessageProcessingError> {
+        let stream_id = match iggy_config.stream_id().get_u32_value() {
+            Ok(stream_id) => stream_id,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        let topic_id = match iggy_config.topic_id().get_u32_value() {
+            Ok(topic_id) => topic_id,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        let message =
+            ClientLoginAckMessage::new(client_id, stream_id, topic_id, iggy_config.partition_id())
+                .with_protocol_version(protocol_version)
+                .with_capabilities(capabilities)
+                .with_access_token(access_token);
+
+        let (_, buffer) = match message.encode() {
+            Ok(enc) => enc,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        self.send_ack(client_id, message).await
+    }
+
+    /// Sends a ClientLogoutAck message to confirm a successful client logout.
+    ///
+    /// The ack must be sent before the client is logged out, as it goes on the data channel of the client.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client that logged out
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to encode or send.
+    ///
+    pub(crate) async fn send_client_logout_ack(
+        &self,
+        client_id: u16,
+    ) -> Result<(), MessageProcessingError> {
+        let message = ClientLogoutAckMessage::new(client_id);
+
+        let (_, buffer) = match message.encode() {
+            Ok(enc) => enc,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
+
+        // Build iggy message wrapper
+        let message = Message::new(None, Bytes::from(buffer), None);
+
+        self.send_ack(client_id, message).await
+    }
+
+    /// Sends an ack message on the data channel of the client.
+    ///
+    /// Acks go on the same channel as the data and the errors of the client,
+    /// which only the iggy user of the client can read.
+    ///
+    async fn send_ack(
+        &self,
+        client_id: u16,
+        message: Message,
+    ) -> Result<(), MessageProcessingError> {
+        match self.send_data_channel(client_id, vec![message]).await {
+            Ok(_) => Ok(()),
+            Err((_, e)) => Err(e),
+        }
+    }
+}
@@ -2,10 +2,10 @@ use iggy::client::MessageClient;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
 use warp::hyper::body::Bytes;
 
-use common::prelude::{MessageProcessingError, OHLCVBar, TradeBar};
-use db_query_manager::types::{OHLCVRow, TradeRow};
-use sbe_messages::prelude::{DataErrorType, DataType, SbeOHLCVBar, SbeTradeBar};
+use common::prelude::MessageProcessingError;
+use sbe_messages::prelude::{DataErrorType, DataType};
 
+use crate::service::scheduler::MessageBatch;
 use crate::service::Server;
 
 impl Server {
@@ -85,69 +85,78 @@ impl Server {
         Ok(())
     }
 
-    pub(crate) async fn send_trade_bar(
+    /// Sends all bar messages of a batch to the client's data channel in one call.
+    ///
+    /// Each bar remains a separate message, so the client decodes the bars one by one.
+    /// Does nothing if the batch is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The id of the client to send the messages to.
+    /// * `batch` - The batch of encoded bar messages; empty after the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns a Result with the error variants:
+    ///
+    /// - `(DataErrorType, MessageProcessingError)` - Error sending the batch.
+    ///
+    pub(crate) async fn send_batch(
         &self,
         client_id: u16,
-        symbol_id: u16,
-        record: &TradeRow,
+        batch: &mut MessageBatch,
     ) -> Result<(), (DataErrorType, MessageProcessingError)> {
-        let bar = TradeBar::new(
-            symbol_id,
-            record.date_time(),
-            record.price(),
-            record.volume(),
-        );
-        // Encode the trade bar message
-        let (_, enc_trade_bar) = SbeTradeBar::encode(bar).unwrap();
-
-        // Build iggy message wrapper
-        let message = Message::new(None, Bytes::from(enc_trade_bar), None);
-
-        // Send trade bar message to inform the client that the data stream starts
-        match self.send_client_data(client_id, vec![message]).await {
-            Ok(_) => {}
-            Err(e) => return Err(e),
+        if batch.is_empty() {
+            return Ok(());
         }
 
-        Ok(())
+        self.send_client_data(client_id, batch.take()).await
     }
 
-    pub(crate) async fn send_ohlcv_bar(
+    /// Sends client data messages to the client's data channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The ID of the client to send the data to
+    /// * `messages` - The vector of Message structs containing the data
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result with `()` on success, or a (DataErrorType, MessageProcessingError) on failure.
+    ///
+    /// This function:
+    ///
+    /// - Checks that the client has not used up the quota of the current window,
+    ///   otherwise fails with `DataErrorType::RateLimited`, which ends the data stream
+    /// - Counts the rows and bytes of the messages against the quota of the client
+    /// - Sends the messages to the client's data channel, see `send_data_channel`
+    ///
+    pub(crate) async fn send_client_data(
         &self,
         client_id: u16,
-        symbol_id: u16,
-        record: &OHLCVRow,
+        messages: Vec<Message>,
     ) -> Result<(), (DataErrorType, MessageProcessingError)> {
-        let bar = OHLCVBar::new(
-            symbol_id,
-            record.date_time(),
-            record.open(),
-            record.high(),
-            record.low(),
-            record.close(),
-            record.volume(),
-        );
-        // Encode the trade bar message
-        let (_, enc_ohlcv_bar) = SbeOHLCVBar::encode(bar).unwrap();
-
-        // Build iggy message wrapper
-        let message = Message::new(None, Bytes::from(enc_ohlcv_bar), None);
-
-        // Send the ohlcv bar message to inform the client that the data stream starts
-        match self.send_client_data(client_id, vec![message]).await {
-            Ok(_) => {}
-            Err(e) => return Err(e),
+        // Stop a running stream once the client has used up its quota
+        if let Err(e) = self.ensure_stream_quota(client_id) {
+            return Err((
+                DataErrorType::RateLimited,
+                MessageProcessingError(e.to_string()),
+            ));
         }
 
-        Ok(())
+        // Count the data against the quota of the client before the messages move into the send call
+        self.record_client_data(client_id, &messages);
+
+        self.send_data_channel(client_id, messages).await
     }
 
-    /// Sends client data messages to the client's data channel.
+    /// Sends messages to the client's data channel without counting them against the quota,
+    /// so that acks and errors, i.e. a rate limited error, always reach the client.
     ///
     /// # Arguments
     ///
-    /// * `client_id` - The ID of the client to send the data to
-    /// * `messages` - The vector of Message structs containing the data
+    /// * `client_id` - The ID of the client to send the messages to
+    /// * `messages` - The vector of Message structs to send
     ///
     /// # Returns
     ///
@@ -160,26 +169,47 @@ impl Server {
     /// - Sends the messages to the client's topic/partition using the producer
     /// - Unlocks the hashmaps
     ///
-    pub(crate) async fn send_client_data(
+    pub(crate) async fn send_data_channel(
         &self,
         client_id: u16,
         messages: Vec<Message>,
     ) -> Result<(), (DataErrorType, MessageProcessingError)> {
         // Lock the client_configs hashmap
-        let client_configs = self.client_configs().write().await;
-
-        // Get the client config for the client
-        let iggy_config = client_configs.get(&client_id).unwrap();
+        let client_configs = self.client_configs().read().await;
+
+        // Get the client config for the client.
+        // The config is missing if the client logged out while a data stream was still active.
+        let iggy_config = match client_configs.get(&client_id) {
+            Some(iggy_config) => iggy_config,
+            None => {
+                return Err((
+                    DataErrorType::DataChannelError,
+                    MessageProcessingError(format!(
+                        "[QDGW/send_data_channel]: No config found for client: {}",
+                        client_id
+                    )),
+                ))
+            }
+        };
 
         // lock the client_data_producers hashmap
         let client_data_producers = self.client_producers().read().await;
 
-        // Get the producer for the error channel
-        let producer = client_data_producers
-            .get(&client_id)
-            .expect("[QDGW/utils_message::send_client_data]: No producer found");
+        // Get the producer for the data channel
+        let producer = match client_data_producers.get(&client_id) {
+            Some(producer) => producer,
+            None => {
+                return Err((
+                    DataErrorType::DataChannelError,
+                    MessageProcessingError(format!(
+                        "[QDGW/send_data_channel]: No producer found for client: {}",
+                        client_id
+                    )),
+                ))
+            }
+        };
 
-        producer
+        match producer
             .send_messages(&mut SendMessages {
                 stream_id: iggy_config.stream_id(),
                 topic_id: iggy_config.topic_id(),
@@ -187,7 +217,15 @@ impl Server {
                 messages,
             })
             .await
-            .expect("Failed to send error");
+        {
+            Ok(_) => {}
+            Err(e) => {
+                return Err((
+                    DataErrorType::DataSendError,
+                    MessageProcessingError(e.to_string()),
+                ))
+            }
+        }
 
         // Unlock the client_configs hashmap
         drop(client_configs);
@@ -26,20 +26,12 @@ impl Server {
         client_id: u16,
         client_error: ClientErrorType,
     ) -> Result<(), MessageProcessingError> {
-        let message = ClientErrorMessage::new(client_id, client_error);
-        let enc = message.encode();
-        assert!(enc.is_ok());
+        let message = match client_error_message(client_id, client_error) {
+            Ok(message) => message,
+            Err(e) => return Err(e),
+        };
 
-        let (_, buffer) = enc.unwrap();
-
-        // Build iggy message wrapper
-        let message = Message::new(None, Bytes::from(buffer), None);
-
-        self.send_error(message)
-            .await
-            .expect("Failed to send client error message");
-
-        Ok(())
+        self.send_error(client_id, message).await
     }
 
     /// Sends a DataError message to the given producer.
@@ -61,46 +53,191 @@ impl Server {
         data_error: DataErrorType,
     ) -> Result<(), MessageProcessingError> {
         let message = DataErrorMessage::new(client_id, data_error);
-        let (_, buffer) = message
-            .encode()
-            .expect("Failed to encode data error message");
+        let (_, buffer) = match message.encode() {
+            Ok(enc) => enc,
+            Err(e) => return Err(MessageProcessingError(e.to_string())),
+        };
 
         // Build iggy message wrapper
         let message = Message::new(None, Bytes::from(buffer), None);
 
         // Send message
-        self.send_error(message)
-            .await
-            .expect("Failed to send error message");
+        self.send_error(client_id, message).await
+    }
+
+    /// Sends a DataError message with `RateLimited` to the given producer.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client the error is for
+    /// * `retry_after_millis` - The number of milliseconds after which the client can retry, if known
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to send.
+    ///
+    pub(crate) async fn send_rate_limited_error(
+        &self,
+        client_id: u16,
+        retry_after_millis: Option<u32>,
+    ) -> Result<(), MessageProcessingError> {
+        let message = match rate_limited_message(client_id, retry_after_millis) {
+            Ok(message) => message,
+            Err(e) => return Err(e),
+        };
 
-        Ok(())
+        // Send message
+        self.send_error(client_id, message).await
     }
 
-    /// Sends an error message to the given producer.
+    /// Sends a ClientError message for a rejected login to the login reject topic.
     ///
     /// # Parameters
     ///
-    /// * `producer` - The topic producer to send the message on
-    /// * `error_buffer` - The encoded error message bytes to send
+    /// * `client_id` - The id of the client that attempted to log in
+    /// * `client_error` - The ClientErrorType to send
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to send.
+    ///
+    pub(crate) async fn send_login_client_error(
+        &self,
+        client_id: u16,
+        client_error: ClientErrorType,
+    ) -> Result<(), MessageProcessingError> {
+        let message = match client_error_message(client_id, client_error) {
+            Ok(message) => message,
+            Err(e) => return Err(e),
+        };
+
+        self.send_login_reject(message).await
+    }
+
+    /// Sends a DataError message with `RateLimited` for a rejected login to the login reject topic.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client that attempted to log in
+    /// * `retry_after_millis` - The number of milliseconds after which the client can retry, if known
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to send.
+    ///
+    pub(crate) async fn send_login_rate_limited_error(
+        &self,
+        client_id: u16,
+        retry_after_millis: Option<u32>,
+    ) -> Result<(), MessageProcessingError> {
+        let message = match rate_limited_message(client_id, retry_after_millis) {
+            Ok(message) => message,
+            Err(e) => return Err(e),
+        };
+
+        self.send_login_reject(message).await
+    }
+
+    /// Sends an error message to a client.
+    ///
+    /// The error goes on the data channel of a logged in client, which only the iggy user
+    /// of the client can read. A client that is not logged in has no data channel,
+    /// so its error goes to the login reject topic instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client the error is for
+    /// * `message` - The encoded error message to send
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to send.
+    ///
+    pub(crate) async fn send_error(
+        &self,
+        client_id: u16,
+        message: Message,
+    ) -> Result<(), MessageProcessingError> {
+        let logged_in = matches!(self.check_client_login(client_id).await, Ok(true));
+        if logged_in {
+            return match self.send_data_channel(client_id, vec![message]).await {
+                Ok(_) => Ok(()),
+                Err((_, e)) => Err(e),
+            };
+        }
+
+        self.send_login_reject(message).await
+    }
+
+    /// Sends an error message to the login reject topic of the gateway.
+    ///
+    /// Clients poll the topic with the bootstrap user while they wait for their login,
+    /// see `iggy_utils::login_reject_config`.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - The encoded error message to send
     ///
     /// # Returns
     ///
     /// Returns a `Result` with `()` if successful, otherwise returns a
     /// `MessageProcessingError` on failure to send.
     ///
-    pub(crate) async fn send_error(&self, message: Message) -> Result<(), MessageProcessingError> {
-        self.producer()
+    async fn send_login_reject(&self, message: Message) -> Result<(), MessageProcessingError> {
+        let reject_config = iggy_utils::login_reject_config(self.iggy_config());
+
+        // Send the error message
+        match self
+            .producer()
             .send_messages(&mut SendMessages {
-                stream_id: self.iggy_config().stream_id(),
-                topic_id: self.iggy_config().topic_id(),
-                partitioning: Partitioning::partition_id(self.iggy_config().partition_id()),
+                stream_id: reject_config.stream_id(),
+                topic_id: reject_config.topic_id(),
+                partitioning: Partitioning::partition_id(reject_config.partition_id()),
                 messages: vec![message],
             })
             .await
-            .expect("Failed to send error");
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(MessageProcessingError(e.to_string())),
+        }
+    }
+}
 
-        // Send the error message
+/// Encodes a ClientError message into an iggy message.
+fn client_error_message(
+    client_id: u16,
+    client_error: ClientErrorType,
+) -> Result<Message, MessageProcessingError> {
+    let message = ClientErrorMessage::new(client_id, client_error);
+    let (_, buffer) = match message.encode() {
+        Ok(enc) => enc,
+        Err(e) => return Err(MessageProcessingError(e.to_string())),
+    };
 
-        Ok(())
-    }
+    // Build iggy message wrapper
+    Ok(Message::new(None, Bytes::from(buffer), None))
+}
+
+/// Encodes a DataError message with `RateLimited` and the retry-after hint, if known, into an iggy message.
+fn rate_limited_message(
+    client_id: u16,
+    retry_after_millis: Option<u32>,
+) -> Result<Message, MessageProcessingError> {
+    let message = match retry_after_millis {
+        Some(millis) => DataErrorMessage::new(client_id, DataErrorType::RateLimited)
+            .with_retry_after_millis(millis),
+        None => DataErrorMessage::new(client_id, DataErrorType::RateLimited),
+    };
+
+    let (_, buffer) = match message.encode() {
+        Ok(enc) => enc,
+        Err(e) => return Err(MessageProcessingError(e.to_string())),
+    };
+
+    // Build iggy message wrapper
+    Ok(Message::new(None, Bytes::from(buffer), None))
 }
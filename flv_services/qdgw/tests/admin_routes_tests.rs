@@ -0,0 +1,160 @@
+use warp::http::StatusCode;
+use warp::test::request;
+
+use auth_manager::Entitlements;
+use sbe_messages::prelude::DataType;
+
+use crate::admin::{admin_routes, AdminConfig};
+use crate::service::Server;
+use crate::tests::test_server::{
+    get_server, get_trade_request, login_client, CLIENT_ID, ETHAED, EXCHANGE_ID, SYMBOL_TABLE,
+};
+
+const ADMIN_TOKEN: &str = "qdgw-admin-test-token";
+
+fn get_config(admin_token: Option<&str>, symbol_table: &str) -> AdminConfig {
+    AdminConfig::new(
+        admin_token.map(|token| token.to_string()),
+        symbol_table.to_string(),
+        vec![(EXCHANGE_ID, "kraken".to_string())],
+    )
+}
+
+// Sends an authorized admin request and returns the status and the body of the response.
+async fn send(server: &Server, method: &str, path: &str) -> (StatusCode, String) {
+    let routes = admin_routes(server.clone(), get_config(Some(ADMIN_TOKEN), SYMBOL_TABLE));
+
+    let response = request()
+        .method(method)
+        .path(path)
+        .header("authorization", format!("Bearer {}", ADMIN_TOKEN))
+        .reply(&routes)
+        .await;
+
+    let body = String::from_utf8(response.body().to_vec()).expect("Body is not UTF-8");
+    (response.status(), body)
+}
+
+#[tokio::test]
+async fn test_admin_requires_token() {
+    let server = get_server(Entitlements::new()).await;
+
+    let routes = admin_routes(server.clone(), get_config(Some(ADMIN_TOKEN), SYMBOL_TABLE));
+    let response = request()
+        .method("GET")
+        .path("/admin/sessions")
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = request()
+        .method("GET")
+        .path("/admin/sessions")
+        .header("authorization", "Bearer wrong-token")
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Without an admin token, every request is rejected
+    let routes = admin_routes(server, get_config(None, SYMBOL_TABLE));
+    let response = request()
+        .method("GET")
+        .path("/admin/sessions")
+        .header("authorization", format!("Bearer {}", ADMIN_TOKEN))
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_admin_list_sessions_and_streams() {
+    let server = get_server(Entitlements::new()).await;
+
+    let (status, body) = send(&server, "GET", "/admin/sessions").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "[]");
+
+    login_client(&server, CLIENT_ID).await;
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+
+    let (status, body) = send(&server, "GET", "/admin/sessions").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains(&format!("\"client_id\":{}", CLIENT_ID)));
+    assert!(body.contains("\"streams\":1"));
+
+    let (status, body) = send(&server, "GET", "/admin/streams").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains(&format!("\"symbol_id\":{}", ETHAED)));
+    assert!(body.contains("\"paused\":false"));
+}
+
+#[tokio::test]
+async fn test_admin_cancel_stream() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+    let job = server
+        .scheduler()
+        .try_next_job()
+        .await
+        .expect("Failed to get scheduled job");
+
+    let path = format!(
+        "/admin/streams/{}/{}/{}/{}",
+        CLIENT_ID,
+        EXCHANGE_ID,
+        ETHAED,
+        DataType::TradeData as u8
+    );
+    let (status, _) = send(&server, "DELETE", &path).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(job.token().is_cancelled());
+    assert!(server.client_streams().read().await.is_empty());
+
+    let (status, _) = send(&server, "DELETE", &path).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let path = format!("/admin/streams/{}/{}/{}/99", CLIENT_ID, EXCHANGE_ID, ETHAED);
+    let (status, _) = send(&server, "DELETE", &path).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_admin_force_logout() {
+    let server = get_server(Entitlements::new()).await;
+
+    let path = format!("/admin/sessions/{}/logout", CLIENT_ID);
+    let (status, _) = send(&server, "POST", &path).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    login_client(&server, CLIENT_ID).await;
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+
+    // The session ends even though its data channel cannot be deleted without iggy
+    let (status, _) = send(&server, "POST", &path).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!server.check_client_login(CLIENT_ID).await.unwrap());
+    assert!(server.client_streams().read().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_admin_reload_symbols() {
+    let server = get_server(Entitlements::new()).await;
+
+    let (status, body) = send(&server, "POST", "/admin/symbols/reload").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("Reloaded 3 symbols"));
+
+    let routes = admin_routes(server, get_config(Some(ADMIN_TOKEN), "binance_symbols"));
+    let response = request()
+        .method("POST")
+        .path("/admin/symbols/reload")
+        .header("authorization", format!("Bearer {}", ADMIN_TOKEN))
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
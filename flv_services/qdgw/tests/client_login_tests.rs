@@ -0,0 +1,150 @@
+use auth_manager::Entitlements;
+use sbe_messages::prelude::{ClientErrorType, ClientLoginMessage, ClientLogoutMessage};
+
+use crate::service::errors::GatewayError;
+use crate::tests::test_server::{
+    get_server, login_client, AUTH_TOKEN, CLIENT_ID, OTHER_AUTH_TOKEN, OTHER_CLIENT_ID,
+};
+
+#[tokio::test]
+async fn test_login_unsupported_protocol_version() {
+    let server = get_server(Entitlements::new()).await;
+
+    let login = ClientLoginMessage::new(CLIENT_ID)
+        .with_protocol_version(1)
+        .with_auth_token(AUTH_TOKEN);
+
+    let res = server.handle_client_login(&login).await;
+    assert_eq!(
+        res,
+        Err(GatewayError::ClientError(
+            CLIENT_ID,
+            ClientErrorType::ProtocolVersionMismatch
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_login_wrong_auth_token() {
+    let server = get_server(Entitlements::new()).await;
+
+    // The auth token of another client
+    let login = ClientLoginMessage::new(CLIENT_ID).with_auth_token(OTHER_AUTH_TOKEN);
+    let res = server.handle_client_login(&login).await;
+    assert_eq!(
+        res,
+        Err(GatewayError::ClientError(
+            CLIENT_ID,
+            ClientErrorType::ClientAuthenticationError
+        ))
+    );
+
+    // No auth token
+    let login = ClientLoginMessage::new(CLIENT_ID);
+    let res = server.handle_client_login(&login).await;
+    assert_eq!(
+        res,
+        Err(GatewayError::ClientError(
+            CLIENT_ID,
+            ClientErrorType::ClientAuthenticationError
+        ))
+    );
+    assert!(!server.check_client_login(CLIENT_ID).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_login_unknown_client() {
+    let server = get_server(Entitlements::new()).await;
+
+    let login = ClientLoginMessage::new(99).with_auth_token(AUTH_TOKEN);
+    let res = server.handle_client_login(&login).await;
+    assert_eq!(
+        res,
+        Err(GatewayError::ClientError(
+            99,
+            ClientErrorType::ClientAuthenticationError
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_login_already_logged_in() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let login = ClientLoginMessage::new(CLIENT_ID).with_auth_token(AUTH_TOKEN);
+    let res = server.handle_client_login(&login).await;
+    assert_eq!(
+        res,
+        Err(GatewayError::ClientError(
+            CLIENT_ID,
+            ClientErrorType::ClientAlreadyLoggedIn
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_login_through_handle_message() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, OTHER_CLIENT_ID).await;
+
+    let login = ClientLoginMessage::new(OTHER_CLIENT_ID).with_auth_token(OTHER_AUTH_TOKEN);
+    let (_, buffer) = login.encode().expect("Failed to encode login");
+
+    let res = server.handle_message(&buffer).await;
+    assert_eq!(
+        res,
+        Err(GatewayError::ClientError(
+            OTHER_CLIENT_ID,
+            ClientErrorType::ClientAlreadyLoggedIn
+        ))
+    );
+
+    // A truncated login never reaches the handler
+    let res = server.handle_message(&buffer[..buffer.len() - 1]).await;
+    assert!(matches!(res, Err(GatewayError::InvalidMessage(_))));
+}
+
+#[tokio::test]
+async fn test_request_on_login_topic() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    // Anyone can write to the login topic, so a logout there never reaches the handler
+    let (_, buffer) = ClientLogoutMessage::new(CLIENT_ID)
+        .encode()
+        .expect("Failed to encode logout");
+
+    let res = server.handle_message(&buffer).await;
+    assert!(matches!(res, Err(GatewayError::InvalidMessage(_))));
+    assert!(matches!(
+        server.check_client_login(CLIENT_ID).await,
+        Ok(true)
+    ));
+}
+
+#[tokio::test]
+async fn test_request_for_other_client() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+    login_client(&server, OTHER_CLIENT_ID).await;
+
+    // A client cannot end the session of another client on its own control topic
+    let (_, buffer) = ClientLogoutMessage::new(CLIENT_ID)
+        .encode()
+        .expect("Failed to encode logout");
+
+    let res = server.handle_client_message(OTHER_CLIENT_ID, &buffer).await;
+    assert!(matches!(res, Err(GatewayError::InvalidMessage(_))));
+    assert!(matches!(
+        server.check_client_login(CLIENT_ID).await,
+        Ok(true)
+    ));
+
+    // A login belongs on the login topic
+    let login = ClientLoginMessage::new(CLIENT_ID).with_auth_token(AUTH_TOKEN);
+    let (_, buffer) = login.encode().expect("Failed to encode login");
+
+    let res = server.handle_client_message(CLIENT_ID, &buffer).await;
+    assert!(matches!(res, Err(GatewayError::InvalidMessage(_))));
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use iggy::messages::send_messages::Message;
+use tokio::time::{sleep, Instant};
+use warp::hyper::body::Bytes;
+
+use crate::service::scheduler::MessageBatch;
+use crate::service::{DATA_BATCH_DELAY_MICROS, DATA_BATCH_SIZE};
+
+fn get_message(payload: u8) -> Message {
+    Message::new(None, Bytes::from(vec![payload]), None)
+}
+
+#[test]
+fn test_new_batch() {
+    let batch = MessageBatch::new(
+        DATA_BATCH_SIZE,
+        Duration::from_micros(DATA_BATCH_DELAY_MICROS),
+    );
+
+    assert!(batch.is_empty());
+    assert!(!batch.is_full());
+}
+
+#[test]
+fn test_batch_is_full() {
+    let mut batch = MessageBatch::new(3, Duration::from_millis(1));
+
+    for payload in 0..3 {
+        assert!(!batch.is_full());
+        batch.push(get_message(payload));
+    }
+    assert!(batch.is_full());
+
+    let messages = batch.take();
+    let payloads: Vec<u8> = messages.iter().map(|message| message.payload[0]).collect();
+    assert_eq!(payloads, vec![0, 1, 2]);
+    assert!(batch.is_empty());
+    assert!(!batch.is_full());
+}
+
+#[test]
+fn test_batch_min_size() {
+    let mut batch = MessageBatch::new(0, Duration::from_millis(1));
+
+    batch.push(get_message(0));
+    assert!(batch.is_full());
+}
+
+#[tokio::test]
+async fn test_batch_deadline() {
+    let max_delay = Duration::from_millis(20);
+    let mut batch = MessageBatch::new(DATA_BATCH_SIZE, max_delay);
+
+    // The oldest message sets the deadline of the batch
+    let before = Instant::now();
+    batch.push(get_message(0));
+    let deadline = batch.deadline();
+    assert!(deadline >= before + max_delay);
+
+    sleep(Duration::from_millis(5)).await;
+    batch.push(get_message(1));
+    assert_eq!(batch.deadline(), deadline);
+
+    // Taking the messages resets the deadline
+    batch.take();
+    sleep(Duration::from_millis(5)).await;
+    assert!(batch.deadline() > deadline);
+}
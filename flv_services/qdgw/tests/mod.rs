@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod admin_routes_tests;
+#[cfg(test)]
+mod client_login_tests;
+#[cfg(test)]
+mod message_batch_tests;
+#[cfg(test)]
+mod pause_data_tests;
+#[cfg(test)]
+mod rate_limit_tests;
+#[cfg(test)]
+mod scheduler_tests;
+#[cfg(test)]
+mod start_data_tests;
+#[cfg(test)]
+mod test_server;
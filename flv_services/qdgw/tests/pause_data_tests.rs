@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use tokio::time::{timeout, Instant};
+
+use auth_manager::Entitlements;
+use common::prelude::ExchangeID;
+use sbe_messages::prelude::{
+    ClientErrorType, ContinueDataMessage, DataType, PauseDataMessage, ReplayMode, StartDataMessage,
+};
+
+use crate::service::errors::GatewayError;
+use crate::service::scheduler::{DataStreamJob, ReplayPacer};
+use crate::service::Server;
+use crate::tests::test_server::{get_server, get_trade_request, login_client, CLIENT_ID, ETHAED};
+
+fn time(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+}
+
+fn get_pause() -> PauseDataMessage {
+    PauseDataMessage::new(CLIENT_ID, ExchangeID::Kraken, ETHAED, DataType::TradeData)
+}
+
+fn get_continue() -> ContinueDataMessage {
+    ContinueDataMessage::new(CLIENT_ID, ExchangeID::Kraken, ETHAED, DataType::TradeData)
+}
+
+// Starts a stream through the handler and returns the job the worker would run.
+async fn start_stream(server: &Server, request: &StartDataMessage) -> DataStreamJob {
+    login_client(server, CLIENT_ID).await;
+
+    assert_eq!(server.handle_start_data_message(request).await, Ok(()));
+
+    server
+        .scheduler()
+        .try_next_job()
+        .await
+        .expect("Failed to get scheduled job")
+}
+
+#[tokio::test]
+async fn test_pause_and_continue_data() {
+    let server = get_server(Entitlements::new()).await;
+    let job = start_stream(&server, &get_trade_request(CLIENT_ID, ETHAED)).await;
+
+    assert_eq!(server.handle_pause_data(&get_pause()).await, Ok(()));
+    assert!(job.control().is_paused());
+    assert!(server.list_streams().await[0].paused);
+
+    assert_eq!(server.handle_continue_data(&get_continue()).await, Ok(()));
+    assert!(!job.control().is_paused());
+}
+
+#[tokio::test]
+async fn test_pause_data_not_logged_in() {
+    let server = get_server(Entitlements::new()).await;
+
+    assert_eq!(
+        server.handle_pause_data(&get_pause()).await,
+        Err(GatewayError::ClientError(
+            CLIENT_ID,
+            ClientErrorType::ClientNotLoggedIn
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_pacer_holds_bars_while_paused() {
+    let server = get_server(Entitlements::new()).await;
+    let job = start_stream(&server, &get_trade_request(CLIENT_ID, ETHAED)).await;
+    let mut pacer = ReplayPacer::new(&job);
+
+    // An unthrottled stream sends each bar right away
+    assert!(pacer.is_ready(time(0)));
+    assert!(pacer.wait(time(0)).await);
+
+    assert_eq!(server.handle_pause_data(&get_pause()).await, Ok(()));
+    assert!(!pacer.is_ready(time(1)));
+    assert!(timeout(Duration::from_millis(50), pacer.wait(time(1)))
+        .await
+        .is_err());
+
+    assert_eq!(server.handle_continue_data(&get_continue()).await, Ok(()));
+    assert!(pacer.is_ready(time(1)));
+    assert!(pacer.wait(time(1)).await);
+}
+
+#[tokio::test]
+async fn test_pacer_stops_when_cancelled_while_paused() {
+    let server = get_server(Entitlements::new()).await;
+    let job = start_stream(&server, &get_trade_request(CLIENT_ID, ETHAED)).await;
+    let mut pacer = ReplayPacer::new(&job);
+
+    assert_eq!(server.handle_pause_data(&get_pause()).await, Ok(()));
+
+    let stream_key = job.stream_key();
+    assert!(server.cancel_data_stream(&stream_key).await);
+    assert!(!pacer.wait(time(0)).await);
+}
+
+#[tokio::test]
+async fn test_pacer_scaled_replay() {
+    let server = get_server(Entitlements::new()).await;
+    let request = StartDataMessage::new(
+        CLIENT_ID,
+        ExchangeID::Kraken,
+        ETHAED,
+        Default::default(),
+        DataType::TradeData,
+        None,
+        None,
+        ReplayMode::Scaled,
+        100,
+    );
+    let job = start_stream(&server, &request).await;
+    let mut pacer = ReplayPacer::new(&job);
+
+    // The first bar anchors the replay clock no earlier than the start of the test
+    let start = Instant::now();
+    assert!(pacer.wait(time(0)).await);
+
+    // One second of trades at 100 times the speed takes 10 milliseconds,
+    // less the millisecond the tokio timer may round off
+    assert!(!pacer.is_ready(time(1)));
+    assert!(pacer.wait(time(1)).await);
+    assert!(start.elapsed() >= Duration::from_millis(9));
+
+    // A bar out of timestamp order is sent right away
+    assert!(pacer.is_ready(time(0)));
+}
@@ -0,0 +1,176 @@
+use std::time::Instant;
+
+use iggy::messages::send_messages::Message;
+use warp::hyper::body::Bytes;
+
+use auth_manager::Entitlements;
+use common::prelude::ExchangeID;
+use sbe_messages::prelude::{
+    ClientErrorType, ClientHeartbeatMessage, ClientLoginMessage, DataErrorType, DataType,
+    PauseDataMessage,
+};
+
+use crate::service::errors::GatewayError;
+use crate::service::{CONTROL_MESSAGE_BURST, MAX_ROWS_PER_WINDOW};
+use crate::tests::test_server::{
+    get_server, get_trade_request, login_client, AUTH_TOKEN, CLIENT_ID, ETHAED, OTHER_AUTH_TOKEN,
+};
+
+fn encode_pause(client_id: u16) -> Vec<u8> {
+    let pause = PauseDataMessage::new(client_id, ExchangeID::Kraken, ETHAED, DataType::TradeData);
+    let (_, buffer) = pause.encode().expect("Failed to encode pause data");
+    buffer
+}
+
+#[tokio::test]
+async fn test_control_rate() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let pause = encode_pause(CLIENT_ID);
+    for _ in 0..CONTROL_MESSAGE_BURST {
+        assert_eq!(
+            server.handle_client_message(CLIENT_ID, &pause).await,
+            Ok(())
+        );
+    }
+
+    match server.handle_client_message(CLIENT_ID, &pause).await {
+        Err(GatewayError::RateLimited(client_id, Some(retry_after_millis))) => {
+            assert_eq!(client_id, CLIENT_ID);
+            assert!(retry_after_millis > 0);
+        }
+        res => panic!("Expected RateLimited, got {:?}", res),
+    }
+}
+
+#[tokio::test]
+async fn test_control_rate_skips_heartbeats() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let (_, heartbeat) = ClientHeartbeatMessage::new(CLIENT_ID)
+        .encode()
+        .expect("Failed to encode heartbeat");
+    for _ in 0..2 * CONTROL_MESSAGE_BURST {
+        assert_eq!(
+            server.handle_client_message(CLIENT_ID, &heartbeat).await,
+            Ok(())
+        );
+    }
+
+    assert_eq!(
+        server
+            .handle_client_message(CLIENT_ID, &encode_pause(CLIENT_ID))
+            .await,
+        Ok(())
+    );
+}
+
+#[tokio::test]
+async fn test_control_rate_skips_clients_not_logged_in() {
+    let server = get_server(Entitlements::new()).await;
+
+    // Requests of a client that is not logged in never use up its tokens
+    let pause = encode_pause(CLIENT_ID);
+    for _ in 0..2 * CONTROL_MESSAGE_BURST {
+        assert_eq!(
+            server.handle_client_message(CLIENT_ID, &pause).await,
+            Err(GatewayError::ClientError(
+                CLIENT_ID,
+                ClientErrorType::ClientNotLoggedIn
+            ))
+        );
+    }
+
+    login_client(&server, CLIENT_ID).await;
+    assert_eq!(
+        server.handle_client_message(CLIENT_ID, &pause).await,
+        Ok(())
+    );
+}
+
+#[tokio::test]
+async fn test_login_rate() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    // Logins with a wrong auth token use up the tokens of that credential only
+    let wrong_login = ClientLoginMessage::new(CLIENT_ID).with_auth_token(OTHER_AUTH_TOKEN);
+    for _ in 0..CONTROL_MESSAGE_BURST {
+        assert_eq!(
+            server.handle_client_login(&wrong_login).await,
+            Err(GatewayError::ClientError(
+                CLIENT_ID,
+                ClientErrorType::ClientAuthenticationError
+            ))
+        );
+    }
+    assert!(matches!(
+        server.handle_client_login(&wrong_login).await,
+        Err(GatewayError::RateLimited(CLIENT_ID, Some(_)))
+    ));
+
+    let login = ClientLoginMessage::new(CLIENT_ID).with_auth_token(AUTH_TOKEN);
+    assert_eq!(
+        server.handle_client_login(&login).await,
+        Err(GatewayError::ClientError(
+            CLIENT_ID,
+            ClientErrorType::ClientAlreadyLoggedIn
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_stream_quota() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    // The client used up the rows of the current quota window
+    server
+        .rate_limiter()
+        .record_data(CLIENT_ID, MAX_ROWS_PER_WINDOW, 0, Instant::now());
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert!(matches!(
+        server.handle_start_data_message(&request).await,
+        Err(GatewayError::RateLimited(CLIENT_ID, Some(_)))
+    ));
+    assert!(server.client_streams().read().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_stream_quota_stops_running_stream() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+    let job = server
+        .scheduler()
+        .try_next_job()
+        .await
+        .expect("Failed to get scheduled job");
+
+    // The client uses up its quota while the stream is running
+    server
+        .rate_limiter()
+        .record_data(CLIENT_ID, MAX_ROWS_PER_WINDOW, 0, Instant::now());
+
+    let message = Message::new(None, Bytes::from(vec![0]), None);
+    match server.send_client_data(CLIENT_ID, vec![message]).await {
+        Err((data_error_type, _)) => assert_eq!(data_error_type, DataErrorType::RateLimited),
+        res => panic!("Expected RateLimited, got {:?}", res),
+    }
+
+    // Acks and errors bypass the quota, so only the send fails without iggy
+    let message = Message::new(None, Bytes::from(vec![0]), None);
+    match server.send_data_channel(CLIENT_ID, vec![message]).await {
+        Err((data_error_type, _)) => assert_eq!(data_error_type, DataErrorType::DataSendError),
+        res => panic!("Expected DataSendError, got {:?}", res),
+    }
+
+    // The stream ends before its first bar and leaves the stream registry
+    server.run_data_stream(job).await;
+    assert!(server.client_streams().read().await.is_empty());
+}
@@ -0,0 +1,116 @@
+use sbe_messages::prelude::{DataType, StartDataMessage};
+
+use crate::service::scheduler::{DataStreamControl, DataStreamJob, DataStreamScheduler};
+
+fn get_job(client_id: u16, symbol_id: u16) -> DataStreamJob {
+    let stream_key = (client_id, 1, symbol_id, DataType::TradeData);
+    DataStreamJob::new(
+        stream_key,
+        format!("trades_{}", symbol_id),
+        StartDataMessage::default(),
+        DataStreamControl::new(),
+    )
+}
+
+async fn next_client(scheduler: &DataStreamScheduler) -> Option<u16> {
+    scheduler.try_next_job().await.map(|job| job.client_id())
+}
+
+#[tokio::test]
+async fn test_round_robin() {
+    let scheduler = DataStreamScheduler::new(8);
+
+    for symbol_id in 1..=3 {
+        scheduler.schedule(get_job(1, symbol_id)).await;
+    }
+    scheduler.schedule(get_job(2, 1)).await;
+    scheduler.schedule(get_job(3, 1)).await;
+
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    assert_eq!(next_client(&scheduler).await, Some(2));
+    assert_eq!(next_client(&scheduler).await, Some(3));
+    // Client 1 exceeds its share of two workers, as other workers stay idle
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    assert_eq!(next_client(&scheduler).await, None);
+}
+
+#[tokio::test]
+async fn test_fifo_per_client() {
+    let scheduler = DataStreamScheduler::new(4);
+
+    scheduler.schedule(get_job(1, 1)).await;
+    scheduler.schedule(get_job(1, 2)).await;
+
+    let job = scheduler.try_next_job().await.expect("Failed to get job");
+    assert_eq!(job.stream_key().2, 1);
+    let job = scheduler.try_next_job().await.expect("Failed to get job");
+    assert_eq!(job.stream_key().2, 2);
+}
+
+#[tokio::test]
+async fn test_one_client_keeps_a_worker_free() {
+    let scheduler = DataStreamScheduler::new(4);
+
+    // One client with as many long replays as there are workers
+    for symbol_id in 1..=4 {
+        scheduler.schedule(get_job(1, symbol_id)).await;
+    }
+
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    // The last worker stays free for other clients
+    assert_eq!(next_client(&scheduler).await, None);
+
+    // Another client gets the free worker right away
+    scheduler.schedule(get_job(2, 1)).await;
+    assert_eq!(next_client(&scheduler).await, Some(2));
+}
+
+#[tokio::test]
+async fn test_fair_share_across_clients() {
+    let scheduler = DataStreamScheduler::new(4);
+
+    for symbol_id in 1..=4 {
+        scheduler.schedule(get_job(1, symbol_id)).await;
+    }
+    for _ in 0..3 {
+        assert_eq!(next_client(&scheduler).await, Some(1));
+    }
+
+    for symbol_id in 1..=2 {
+        scheduler.schedule(get_job(2, symbol_id)).await;
+    }
+    assert_eq!(next_client(&scheduler).await, Some(2));
+
+    // Client 1 runs more than its share of two workers,
+    // so the worker of its finished stream goes to client 2
+    scheduler.complete(1).await;
+    assert_eq!(next_client(&scheduler).await, Some(2));
+
+    // The worker of a finished stream of client 2 stays free for other clients,
+    // and client 1 gets a worker again once it runs fewer streams than its share
+    scheduler.complete(2).await;
+    assert_eq!(next_client(&scheduler).await, None);
+    scheduler.complete(1).await;
+    assert_eq!(next_client(&scheduler).await, Some(1));
+}
+
+#[tokio::test]
+async fn test_single_worker() {
+    let scheduler = DataStreamScheduler::new(1);
+
+    scheduler.schedule(get_job(1, 1)).await;
+    scheduler.schedule(get_job(1, 2)).await;
+    scheduler.schedule(get_job(2, 1)).await;
+
+    // The worker takes turns between the clients as their streams finish
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    scheduler.complete(1).await;
+    assert_eq!(next_client(&scheduler).await, Some(2));
+    scheduler.complete(2).await;
+    assert_eq!(next_client(&scheduler).await, Some(1));
+    scheduler.complete(1).await;
+    assert_eq!(next_client(&scheduler).await, None);
+}
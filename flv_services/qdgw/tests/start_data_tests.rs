@@ -0,0 +1,194 @@
+use auth_manager::Entitlements;
+use common::prelude::{TimeResolution, TimeScale};
+use sbe_messages::prelude::{ClientErrorType, DataErrorType, DataType};
+
+use crate::service::errors::GatewayError;
+use crate::tests::test_server::{
+    get_request, get_server, get_trade_request, login_client, CLIENT_ID, ETHAED, EXCHANGE_ID,
+    OTHER_CLIENT_ID, SOLUSD, XBTUSD,
+};
+
+fn data_error(data_error_type: DataErrorType) -> Result<(), GatewayError> {
+    Err(GatewayError::DataError(CLIENT_ID, data_error_type))
+}
+
+#[tokio::test]
+async fn test_start_data_schedules_stream() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+
+    let stream_key = (CLIENT_ID, EXCHANGE_ID, ETHAED, DataType::TradeData);
+    assert!(server
+        .client_streams()
+        .read()
+        .await
+        .contains_key(&stream_key));
+
+    let job = server
+        .scheduler()
+        .try_next_job()
+        .await
+        .expect("Failed to get scheduled job");
+    assert_eq!(job.stream_key(), stream_key);
+    assert_eq!(job.trade_table(), "kraken_ethaed");
+}
+
+#[tokio::test]
+async fn test_start_data_twice() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::DataUnavailableError)
+    );
+}
+
+#[tokio::test]
+async fn test_start_data_not_logged_in() {
+    let server = get_server(Entitlements::new()).await;
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        Err(GatewayError::ClientError(
+            CLIENT_ID,
+            ClientErrorType::ClientNotLoggedIn
+        ))
+    );
+    assert!(server.scheduler().try_next_job().await.is_none());
+}
+
+#[tokio::test]
+async fn test_start_data_unknown_symbol() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, 42);
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::DataTableNotFound)
+    );
+
+    // The symbol is known, but its trade table is missing in the database
+    let request = get_trade_request(CLIENT_ID, SOLUSD);
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::DataTableNotFound)
+    );
+}
+
+#[tokio::test]
+async fn test_start_data_invalid_request() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_request(
+        CLIENT_ID,
+        ETHAED,
+        DataType::UnknownDataType,
+        TimeResolution::NoValue,
+    );
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::DataTypeNotKnownError)
+    );
+
+    let request = get_request(
+        CLIENT_ID,
+        ETHAED,
+        DataType::OHLCVData,
+        TimeResolution::NoValue,
+    );
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::TimeResolutionNotValidError)
+    );
+
+    let time_resolution =
+        TimeResolution::new(1, TimeScale::Minute).expect("Failed to create time resolution");
+    let request = get_request(CLIENT_ID, ETHAED, DataType::OHLCVData, time_resolution);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+}
+
+#[tokio::test]
+async fn test_start_data_without_entitlements() {
+    let server = get_server(Entitlements::new()).await;
+    login_client(&server, OTHER_CLIENT_ID).await;
+
+    let request = get_trade_request(OTHER_CLIENT_ID, ETHAED);
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        Err(GatewayError::DataError(
+            OTHER_CLIENT_ID,
+            DataErrorType::NotEntitled
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_start_data_entitled_symbols() {
+    let entitlements = Entitlements::new()
+        .with_allow_symbols(vec!["eth*".to_string(), "xbt*".to_string()])
+        .with_deny_symbols(vec!["xbtusd".to_string()]);
+    let server = get_server(entitlements).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, XBTUSD);
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::NotEntitled)
+    );
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+}
+
+#[tokio::test]
+async fn test_start_data_entitled_exchanges_and_data_types() {
+    let entitlements = Entitlements::new()
+        .with_exchanges(vec!["Kraken".to_string()])
+        .with_data_types(vec![DataType::OHLCVData]);
+    let server = get_server(entitlements).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::NotEntitled)
+    );
+
+    let entitlements = Entitlements::new().with_exchanges(vec!["binance".to_string()]);
+    let server = get_server(entitlements).await;
+    login_client(&server, CLIENT_ID).await;
+
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::NotEntitled)
+    );
+}
+
+#[tokio::test]
+async fn test_start_data_max_streams() {
+    let server = get_server(Entitlements::new().with_max_streams(1)).await;
+    login_client(&server, CLIENT_ID).await;
+
+    let request = get_trade_request(CLIENT_ID, ETHAED);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+
+    let request = get_trade_request(CLIENT_ID, XBTUSD);
+    assert_eq!(
+        server.handle_start_data_message(&request).await,
+        data_error(DataErrorType::NotEntitled)
+    );
+
+    // Stopping the first stream frees its slot
+    let stream_key = (CLIENT_ID, EXCHANGE_ID, ETHAED, DataType::TradeData);
+    assert!(server.cancel_data_stream(&stream_key).await);
+    assert_eq!(server.handle_start_data_message(&request).await, Ok(()));
+}
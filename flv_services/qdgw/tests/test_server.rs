@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+
+use auth_manager::{Entitlements, FileCredentialStore, FileEntitlementStore};
+use common::prelude::{ExchangeID, IggyConfig, IggyUser, ServiceID, TimeResolution};
+use db_query_manager::{InMemoryBackend, QueryDBManager};
+use sbe_messages::prelude::{DataType, ReplayMode, StartDataMessage};
+use symbol_manager::SymbolManager;
+
+use crate::service::{Server, UsageMetrics};
+
+pub(crate) const CLIENT_ID: u16 = 7;
+pub(crate) const AUTH_TOKEN: &str = "qdgw-test-token";
+pub(crate) const OTHER_CLIENT_ID: u16 = 8;
+pub(crate) const OTHER_AUTH_TOKEN: &str = "qdgw-other-test-token";
+
+pub(crate) const SYMBOL_TABLE: &str = "kraken_symbols";
+pub(crate) const EXCHANGE_ID: u16 = 1;
+pub(crate) const ETHAED: u16 = 1;
+pub(crate) const XBTUSD: u16 = 2;
+// A symbol without trade table in the database.
+pub(crate) const SOLUSD: u16 = 3;
+
+// The address of an iggy server that is never connected, as the handlers under test do not send.
+const IGGY_ADDR: &str = "127.0.0.1:8090";
+
+// Three trades a second apart, in the format of the Kraken trade files.
+const TRADES_CSV: &str = "\
+1700000000,10.0,1.0
+1700000001,11.0,1.0
+1700000002,12.0,1.0
+";
+
+/// Returns a server that serves the trades of ethaed and xbtusd on Kraken from memory.
+///
+/// Both test clients have valid credentials; only the test client has the given entitlements.
+pub(crate) async fn get_server(entitlements: Entitlements) -> Server {
+    let symbols = vec![
+        (ETHAED, "ethaed".to_string()),
+        (XBTUSD, "xbtusd".to_string()),
+        (SOLUSD, "solusd".to_string()),
+    ];
+    let exchanges = vec![(EXCHANGE_ID, "kraken".to_string())];
+
+    let backend = InMemoryBackend::new()
+        .with_symbols(SYMBOL_TABLE, symbols.clone())
+        .with_trades_from_csv_reader("kraken_ethaed", TRADES_CSV.as_bytes())
+        .expect("Failed to add trades")
+        .with_trades_from_csv_reader("kraken_xbtusd", TRADES_CSV.as_bytes())
+        .expect("Failed to add trades");
+    let query_manager = Arc::new(RwLock::new(QueryDBManager::from_backend(backend)));
+
+    let symbol_manager =
+        SymbolManager::new(symbols, exchanges).expect("Failed to create SymbolManager");
+    let symbol_manager = Arc::new(RwLock::new(symbol_manager));
+
+    let credential_store = FileCredentialStore::new(HashMap::from([
+        (CLIENT_ID, AUTH_TOKEN.to_string()),
+        (OTHER_CLIENT_ID, OTHER_AUTH_TOKEN.to_string()),
+    ]))
+    .expect("Failed to create credential store");
+    let entitlement_store = FileEntitlementStore::new(HashMap::from([(CLIENT_ID, entitlements)]));
+
+    let iggy_config = IggyConfig::from_client_id(
+        IggyUser::default(),
+        ServiceID::QDGW.id() as u32,
+        50000,
+        false,
+    );
+
+    Server::from_iggy_clients(
+        get_iggy_client().await,
+        get_iggy_client().await,
+        iggy_config,
+        query_manager,
+        symbol_manager,
+        Arc::new(credential_store),
+        Arc::new(entitlement_store),
+        UsageMetrics::new(),
+        4,
+    )
+}
+
+async fn get_iggy_client() -> iggy::clients::client::IggyClient {
+    iggy_utils::get_iggy_client(IGGY_ADDR.to_string())
+        .await
+        .expect("Failed to create iggy client")
+}
+
+/// Registers a session for the client as the login handler does once the data channel exists.
+pub(crate) async fn login_client(server: &Server, client_id: u16) {
+    let iggy_config =
+        IggyConfig::from_client_id(IggyUser::default(), client_id as u32, 50000, false);
+
+    let producer = get_iggy_client().await;
+
+    server
+        .client_configs()
+        .write()
+        .await
+        .insert(client_id, iggy_config);
+    server
+        .client_producers()
+        .write()
+        .await
+        .insert(client_id, producer);
+    server
+        .client_heartbeats()
+        .write()
+        .await
+        .insert(client_id, Instant::now());
+}
+
+/// Returns an unthrottled request for the trades of the symbol.
+pub(crate) fn get_trade_request(client_id: u16, symbol_id: u16) -> StartDataMessage {
+    get_request(
+        client_id,
+        symbol_id,
+        DataType::TradeData,
+        TimeResolution::NoValue,
+    )
+}
+
+/// Returns an unthrottled request for the data type of the symbol.
+pub(crate) fn get_request(
+    client_id: u16,
+    symbol_id: u16,
+    data_type: DataType,
+    time_resolution: TimeResolution,
+) -> StartDataMessage {
+    StartDataMessage::new(
+        client_id,
+        ExchangeID::Kraken,
+        symbol_id,
+        time_resolution,
+        data_type,
+        None,
+        None,
+        ReplayMode::Unthrottled,
+        0,
+    )
+}
@@ -34,7 +34,7 @@ const SVC_ID: ServiceID = ServiceID::SYMDB;
 /// - Creates a `QueryDBManager` to fetch symbols from the database.
 /// - Fetches all symbols for the default exchange.
 /// - Creates a `SymbolManager` to manage the symbol data.
-/// - Closes `QueryDBManager` as it is no longer needed.
+/// - Hands the `QueryDBManager` to the service to query the coverage of the symbols.
 /// - Configures the gRPC service address from `ConfigManager`.
 /// - Creates the gRPC service with a `SYMDBServer`.
 /// - Adds a health service to the gRPC server.
@@ -87,11 +87,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .get_symbol_table(default_exchange)
         .expect("[SYMDB]/main: Failed to get symbol table for default exchange.");
 
-    // Create a new QueryDBManager instance.
-    let db_config = cfg_manager.db_config();
-    let mut q_manager = QueryDBManager::new(db_config)
-        .await
-        .expect("[SYMDB]/main: Failed to create QueryDBManager instance.");
+    // Create a new QueryDBManager instance for the configured DB backend.
+    let q_manager = QueryDBManager::with_db_backend(
+        cfg_manager.db_backend(),
+        cfg_manager.db_config(),
+        cfg_manager.pg_db_config(),
+    )
+    .await
+    .expect("[SYMDB]/main: Failed to create QueryDBManager instance.");
 
     // Get all symbols for the default exchange.
     let symbols = q_manager
@@ -116,7 +119,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .expect("[CMDB]: Failed to parse address");
 
     // Create new gRPC service
-    let grpc_svc = SymdbServiceServer::new(SYMDBServer::new(symbol_manager));
+    let grpc_svc = SymdbServiceServer::new(SYMDBServer::new(symbol_manager, q_manager));
 
     // Build health service for gRPC server
     let (mut health_reporter, health_svc) = tonic_health::server::health_reporter();
@@ -144,7 +147,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     drop(metrics_host);
     drop(metrics_uri);
     drop(metrics_addr);
-    drop(q_manager);
     drop(service_addr);
 
     //Creates a new Tokio task for each server.
@@ -1,6 +1,10 @@
 use autometrics::autometrics;
 use common::prelude::LookupError;
+use db_query_manager::error::QueryError;
+use db_query_manager::{types, QueryDBManager};
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tonic::{Request, Response, Status};
 
 use proto::binding::symdb_service_server::SymdbService;
@@ -9,14 +13,30 @@ use symbol_manager::SymbolManager;
 
 const FN_NAME: &str = "[SymdbClient/service]: ";
 
+/// Cached coverages of the trade tables, keyed by exchange ID and symbol ID.
+type CoverageCache = HashMap<(u16, u16), types::SymbolCoverage>;
+
+/// A symbol with the trade table to query its coverage from.
+struct SymbolTradeTable {
+    symbol_id: u16,
+    symbol: String,
+    trade_table: String,
+}
+
 #[derive(Clone)]
 pub struct SYMDBServer {
     symbol_manager: Arc<RwLock<SymbolManager>>,
+    q_manager: Arc<QueryDBManager>,
+    coverages: Arc<tokio::sync::RwLock<CoverageCache>>,
 }
 
 impl SYMDBServer {
-    pub fn new(symbol_manager: Arc<RwLock<SymbolManager>>) -> Self {
-        Self { symbol_manager }
+    pub fn new(symbol_manager: Arc<RwLock<SymbolManager>>, q_manager: QueryDBManager) -> Self {
+        Self {
+            symbol_manager,
+            q_manager: Arc::new(q_manager),
+            coverages: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+        }
     }
 }
 
@@ -107,6 +127,58 @@ impl SymdbService for SYMDBServer {
         };
     }
 
+    /// Gets the time coverage of the trades of the requested symbols.
+    ///
+    /// Coverages are computed once per symbol and gap threshold and then served from a cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The SymbolCoverageRequest containing the exchange ID, the symbol IDs,
+    ///   and the gap threshold in seconds. No symbol IDs request all symbols of the exchange.
+    ///
+    /// # Returns
+    ///
+    /// Returns a SymbolCoverageResponse containing the first and last trade, the row count,
+    /// and the gaps longer than the gap threshold of each symbol.
+    ///
+    /// # Errors
+    ///
+    /// May return an InvalidArgument error if the gap threshold is negative.
+    /// May return an internal error if a symbol does not exist or the DB query failed.
+    ///
+    async fn get_symbol_coverage(
+        &self,
+        request: Request<SymbolCoverageRequest>,
+    ) -> Result<Response<SymbolCoverageResponse>, Status> {
+        self.symbol_coverage(request.into_inner(), false).await
+    }
+
+    /// Refreshes the time coverage of the requested symbols with the trades imported
+    /// after their last trade. Call this after an import of new trades.
+    ///
+    /// Symbols without a cached coverage for the gap threshold get a full coverage.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The SymbolCoverageRequest containing the exchange ID, the symbol IDs,
+    ///   and the gap threshold in seconds. No symbol IDs request all symbols of the exchange.
+    ///
+    /// # Returns
+    ///
+    /// Returns a SymbolCoverageResponse containing the refreshed coverage of each symbol.
+    ///
+    /// # Errors
+    ///
+    /// May return an InvalidArgument error if the gap threshold is negative.
+    /// May return an internal error if a symbol does not exist or the DB query failed.
+    ///
+    async fn refresh_symbol_coverage(
+        &self,
+        request: Request<SymbolCoverageRequest>,
+    ) -> Result<Response<SymbolCoverageResponse>, Status> {
+        self.symbol_coverage(request.into_inner(), true).await
+    }
+
     /// Looks up the symbol ID for the given symbol name and exchange ID.
     ///
     /// # Arguments
@@ -162,6 +234,185 @@ impl SymdbService for SYMDBServer {
     }
 }
 
+impl SYMDBServer {
+    /// Gets or refreshes the coverage of the requested symbols.
+    async fn symbol_coverage(
+        &self,
+        request: SymbolCoverageRequest,
+        refresh: bool,
+    ) -> Result<Response<SymbolCoverageResponse>, Status> {
+        let exchange_id = request.exchange_id as u16;
+
+        if request.min_gap_secs < 0 {
+            let msg = format!("Negative gap threshold: {}", request.min_gap_secs);
+            return Err(Status::invalid_argument(format!("{FN_NAME} {msg}")));
+        }
+        let min_gap = Duration::from_secs(request.min_gap_secs as u64);
+
+        // Resolve the symbols and their trade tables before querying the DB,
+        // so that the symbol manager is not locked during the queries.
+        let (exchange_name, symbols) = match self.lookup_trade_tables(exchange_id, &request) {
+            Ok(symbols) => symbols,
+            Err(msg) => return Err(Status::internal(msg)),
+        };
+
+        let mut coverages = Vec::with_capacity(symbols.len());
+        for SymbolTradeTable {
+            symbol_id,
+            symbol,
+            trade_table,
+        } in symbols
+        {
+            let coverage = match self
+                .coverage(exchange_id, symbol_id, &trade_table, min_gap, refresh)
+                .await
+            {
+                Ok(coverage) => coverage,
+                Err(e) => {
+                    let msg = format!("Failed to get coverage for symbol: {}", symbol);
+                    return Err(Status::internal(get_error_msg(msg.as_str(), e)));
+                }
+            };
+
+            coverages.push(to_proto_coverage(symbol, &coverage));
+        }
+
+        Ok(Response::new(SymbolCoverageResponse {
+            exchange_name,
+            coverages,
+        }))
+    }
+
+    /// Looks up the exchange name and the symbol and trade table of each requested symbol ID.
+    ///
+    /// Returns the error message if the exchange or a symbol was not found.
+    fn lookup_trade_tables(
+        &self,
+        exchange_id: u16,
+        request: &SymbolCoverageRequest,
+    ) -> Result<(String, Vec<SymbolTradeTable>), String> {
+        // Lock symbol manager
+        let mut sym_manager = self
+            .symbol_manager
+            .write()
+            .expect("Failed To lock symbol manager");
+
+        let exchange_name = match sym_manager.get_exchange_name(exchange_id) {
+            Ok(exchange_name) => exchange_name,
+            Err(e) => {
+                let msg = format!("Exchange not found for ID: {}", exchange_id);
+                return Err(get_error_msg(msg.as_str(), e));
+            }
+        };
+
+        let symbol_ids = if request.symbol_ids.is_empty() {
+            match sym_manager.get_all_symbol_ids() {
+                Ok(symbol_ids) => symbol_ids,
+                Err(e) => return Err(get_error_msg("Failed to get all symbol IDs", e)),
+            }
+        } else {
+            request.symbol_ids.iter().map(|id| *id as u16).collect()
+        };
+
+        let mut symbols = Vec::with_capacity(symbol_ids.len());
+        for symbol_id in symbol_ids {
+            let symbol = match sym_manager.get_symbol(symbol_id) {
+                Ok(symbol) => symbol,
+                Err(e) => {
+                    let msg = format!("Symbol not found for ID: {}", symbol_id);
+                    return Err(get_error_msg(msg.as_str(), e));
+                }
+            };
+
+            let trade_table = match sym_manager.get_symbol_table_name(exchange_id, symbol_id) {
+                Ok(trade_table) => trade_table,
+                Err(e) => {
+                    let msg = format!("Trade table not found for symbol ID: {}", symbol_id);
+                    return Err(get_error_msg(msg.as_str(), e));
+                }
+            };
+
+            symbols.push(SymbolTradeTable {
+                symbol_id,
+                symbol,
+                trade_table,
+            });
+        }
+
+        Ok((exchange_name, symbols))
+    }
+
+    /// Gets the coverage of a symbol from the cache or the DB.
+    ///
+    /// A cached coverage for the same gap threshold is returned as is,
+    /// or refreshed with the trades after its last trade if `refresh` is set.
+    /// Symbols without a trade table are covered without trades and are not cached,
+    /// so that their coverage gets computed once their trades are imported.
+    async fn coverage(
+        &self,
+        exchange_id: u16,
+        symbol_id: u16,
+        trade_table: &str,
+        min_gap: Duration,
+        refresh: bool,
+    ) -> Result<types::SymbolCoverage, QueryError> {
+        let key = (exchange_id, symbol_id);
+
+        let cached = match self.coverages.read().await.get(&key) {
+            Some(coverage) if coverage.min_gap() == min_gap => Some(coverage.clone()),
+            _ => None,
+        };
+
+        let result = match cached {
+            Some(coverage) if !refresh => return Ok(coverage),
+            Some(coverage) => self.q_manager.refresh_symbol_coverage(coverage).await,
+            None => {
+                self.q_manager
+                    .get_symbol_coverage(symbol_id, trade_table, min_gap)
+                    .await
+            }
+        };
+
+        let coverage = match result {
+            Ok(coverage) => coverage,
+            Err(QueryError::TableDoesNotExist(_, _)) => {
+                self.coverages.write().await.remove(&key);
+                return Ok(types::SymbolCoverage::new(symbol_id, trade_table, min_gap));
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.coverages.write().await.insert(key, coverage.clone());
+
+        Ok(coverage)
+    }
+}
+
+/// Converts the coverage of a symbol into its proto message with timestamps in milliseconds.
+fn to_proto_coverage(symbol: String, coverage: &types::SymbolCoverage) -> SymbolCoverage {
+    let gaps = coverage
+        .gaps()
+        .iter()
+        .map(|gap| TradeGap {
+            start: gap.start().timestamp_millis(),
+            end: gap.end().timestamp_millis(),
+        })
+        .collect();
+
+    SymbolCoverage {
+        symbol_id: coverage.symbol_id() as i32,
+        symbol,
+        first_trade: coverage.first_trade().map_or(0, |t| t.timestamp_millis()),
+        last_trade: coverage.last_trade().map_or(0, |t| t.timestamp_millis()),
+        row_count: coverage.row_count(),
+        gaps,
+    }
+}
+
 fn get_status(msg: &str, e: LookupError) -> Status {
-    Status::internal(format!("{FN_NAME} {msg} because of error: {e}"))
+    Status::internal(get_error_msg(msg, e))
+}
+
+fn get_error_msg(msg: &str, e: impl std::fmt::Display) -> String {
+    format!("{FN_NAME} {msg} because of error: {e}")
 }
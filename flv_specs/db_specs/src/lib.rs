@@ -1,2 +1,3 @@
 mod clickhouse;
 pub mod prelude;
+mod questdb;
@@ -1,2 +1,4 @@
 pub use crate::clickhouse::get_cluster_db_config;
 pub use crate::clickhouse::get_local_db_config;
+pub use crate::questdb::get_cluster_pg_db_config;
+pub use crate::questdb::get_local_pg_db_config;
@@ -0,0 +1,9 @@
+use common::prelude::DBConfig;
+
+pub fn get_local_pg_db_config() -> DBConfig {
+    DBConfig::new(9009, "127.0.0.1".to_string())
+}
+
+pub fn get_cluster_pg_db_config() -> DBConfig {
+    DBConfig::new(9009, "questdb.default.svc.cluster.local".to_string())
+}
@@ -1,4 +1,6 @@
-use db_specs::prelude::{get_cluster_db_config, get_local_db_config};
+use db_specs::prelude::{
+    get_cluster_db_config, get_cluster_pg_db_config, get_local_db_config, get_local_pg_db_config,
+};
 
 #[test]
 fn test_get_local_db_config() {
@@ -23,3 +25,19 @@ fn test_get_cluster_db_config() {
     assert_eq!(cluster_config.password(), "password".to_string());
     assert_eq!(cluster_config.database(), "default".to_string());
 }
+
+#[test]
+fn test_get_local_pg_db_config() {
+    let local_config = get_local_pg_db_config();
+    assert_eq!(local_config.host(), "127.0.0.1");
+    assert_eq!(local_config.pg_port(), 8812);
+    assert_eq!(local_config.pg_user(), "admin");
+    assert_eq!(local_config.pg_database(), "qdb");
+}
+
+#[test]
+fn test_get_cluster_pg_db_config() {
+    let cluster_config = get_cluster_pg_db_config();
+    assert_eq!(cluster_config.host(), "questdb.default.svc.cluster.local");
+    assert_eq!(cluster_config.pg_port(), 8812);
+}
@@ -20,6 +20,7 @@ use common::prelude::{MetricConfig, ServiceConfig, ServiceID};
 /// - metric_uri: "metrics"
 /// - metric_host: "0.0.0.0"
 /// - metric_port: 8080 (default prometheus port)
+/// - worker_count: 8 workers for concurrent data streams
 ///
 /// # Remarks
 ///
@@ -54,6 +55,7 @@ pub fn get_qdgw_service_config() -> ServiceConfig {
         dependencies,
         metrics,
     )
+    .with_worker_count(8)
 }
 
 /// Returns a vector of u16 containing the ports used by the QDGW service.
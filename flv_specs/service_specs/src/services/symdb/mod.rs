@@ -17,6 +17,7 @@ use common::prelude::{MetricConfig, ServiceConfig, ServiceID};
 /// - cluster_port: [7070, 8081]
 /// - dependencies: None
 /// - metrics: MetricConfig with custom port 8081 to avoid port clashes on localhost.
+/// - worker_count: 1
 ///
 /// # Remarks
 ///
@@ -27,5 +27,6 @@ mod tests {
         assert_eq!(config.metrics().metric_uri(), "metrics");
         assert_eq!(config.metrics().metric_host(), "0.0.0.0");
         assert_eq!(config.metrics().metric_port(), 8080);
+        assert_eq!(config.worker_count(), 8);
     }
 }
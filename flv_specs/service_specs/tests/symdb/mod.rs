@@ -28,4 +28,5 @@ fn test_get_symdb_service_config() {
     assert_eq!(config.metrics().metric_uri(), "metrics");
     assert_eq!(config.metrics().metric_host(), "0.0.0.0");
     assert_eq!(config.metrics().metric_port(), 8081);
+    assert_eq!(config.worker_count(), 1);
 }
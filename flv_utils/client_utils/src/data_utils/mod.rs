@@ -61,7 +61,7 @@ pub async fn load_data(
 
     // println!("{FN_NAME}: Creating a new QueryDBManager.");
     let db_config = cfg_manager.db_config();
-    let mut db_query_manager = match QueryDBManager::new(db_config.clone()).await {
+    let db_query_manager = match QueryDBManager::new(db_config.clone()).await {
         Ok(db_query_manager) => db_query_manager,
         Err(err) => {
             println!("{FN_NAME}: Failed to create QueryDBManager instance. Error: {err}");
@@ -69,6 +69,14 @@ pub fn handle_error_message(message: Vec<u8>) -> Result<(), Box<dyn Error + Send
 ///   The gateway sends this error when the client failed to log out.
 ///   This is exceptional rare, and needs to be investigated.
 ///
+/// - ClientErrorType::ProtocolVersionMismatch:
+///   The gateway sends this error when it does not support the protocol version of the client.
+///   This is a fatal error, and the client must be updated to a supported protocol version.
+///
+/// - ClientErrorType::ClientAuthenticationError:
+///   The gateway sends this error when the auth token of the client is missing or invalid.
+///   This is a fatal error, and the client must log in again with a valid auth token.
+///
 /// - ClientErrorType::UnknownClientError:
 ///   The gateway sends this error in all other cases or when the origin of the error is unknown.
 ///   Assume something went totally wrong, restart the client,
@@ -105,6 +113,16 @@ fn handle_client_error(raw_event: Vec<u8>) -> Result<(), Box<dyn Error + Send>>
             // This is exceptional rare, and needs to be investigated.
             println!("ClientLogOutError");
         }
+        ClientErrorType::ProtocolVersionMismatch => {
+            // The gateway sends this error when it does not support the protocol version of the client.
+            println!("ProtocolVersionMismatch");
+            // This is a fatal error, and the client must be updated to a supported protocol version.
+        }
+        ClientErrorType::ClientAuthenticationError => {
+            // The gateway sends this error when the auth token of the client is missing or invalid.
+            println!("ClientAuthenticationError");
+            // This is a fatal error, and the client must log in again with a valid auth token.
+        }
         ClientErrorType::UnknownClientError => {
             // The gateway sends this error in all other cases or when the origin of the error is unknown.
             // Assume something went totally wrong, restart the client,
@@ -142,6 +160,19 @@ fn handle_client_error(raw_event: Vec<u8>) -> Result<(), Box<dyn Error + Send>>
 /// - DataErrorType::DataChannelError:
 ///   The gateway will send this error if no connection to the client data channel exists.
 ///
+/// - DataErrorType::TimeResolutionNotValidError:
+///   The gateway will send this error if the client requests OHLCV data with an invalid time resolution.
+///
+/// - DataErrorType::ThresholdNotValidError:
+///   The gateway will send this error if the client requests tick, volume, or dollar bars with an invalid threshold.
+///
+/// - DataErrorType::NotEntitled:
+///   The gateway will send this error if the client requests data it is not entitled to.
+///
+/// - DataErrorType::RateLimited:
+///   The gateway will send this error if the client exceeds its request rate or data quota.
+///   The error may carry the number of milliseconds after which the client should retry.
+///
 /// - DataErrorType::UnknownDataError:
 ///   The gateway will send this error in all other cases where it cannot determine the error cause.
 ///
@@ -177,6 +208,25 @@ fn handle_data_error(msg: Vec<u8>) -> Result<(), Box<dyn Error + Send>> {
             // The gateway will send this error if no connection to the client data channel exists
             println!("DataChannelError");
         }
+        DataErrorType::TimeResolutionNotValidError => {
+            // The gateway will send this error if the client requests OHLCV data with an invalid time resolution
+            println!("TimeResolutionNotValidError");
+        }
+        DataErrorType::ThresholdNotValidError => {
+            // The gateway will send this error if the client requests tick, volume, or dollar bars with an invalid threshold
+            println!("ThresholdNotValidError");
+        }
+        DataErrorType::NotEntitled => {
+            // The gateway will send this error if the client requests data it is not entitled to
+            println!("NotEntitled");
+        }
+        DataErrorType::RateLimited => {
+            // The gateway will send this error if the client exceeds its request rate or data quota
+            println!(
+                "RateLimited, retry after millis: {:?}",
+                data_error.retry_after_millis()
+            );
+        }
         DataErrorType::UnknownDataError => {
             // The gateway will send this error in all other cases where it cannot determine the error cause
             println!("UnknownDataError");
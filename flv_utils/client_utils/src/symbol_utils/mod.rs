@@ -63,7 +63,7 @@ pub async fn get_symbol_id(
 
     // println!("{FN_NAME}: Creating a new QueryDBManager.");
     let db_config = cfg_manager.db_config();
-    let mut db_query_manager = match QueryDBManager::new(db_config.clone()).await {
+    let db_query_manager = match QueryDBManager::new(db_config.clone()).await {
         Ok(dqm) => dqm,
         Err(err) => {
             println!("{FN_NAME}: Failed to create QueryDBManager.");
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::error::Error;
 
 use iggy::client::{Client, UserClient};
@@ -5,18 +6,30 @@ use iggy::client::{PersonalAccessTokenClient, StreamClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientBuilder};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
 use iggy::models::user_status::UserStatus;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
 
 use common::prelude::{IggyConfig, IggyUser};
 
+/// The ID of the topic the gateway adds next to the main topic of a stream, i.e. the control topic
+/// of a client data stream and the login reject topic of the gateway stream.
+/// The main topics have the ID of a client or service, which always fits into an u16.
+const SIDE_TOPIC_ID: u32 = u16::MAX as u32 + 1;
+
 /// Creates a new `IggyClient` instance and returns it.
 ///
 /// # Returns
@@ -370,3 +383,322 @@ pub async fn create_token(
 
     Ok(token)
 }
+
+/// Returns the name of the iggy user the gateway creates for a client.
+///
+/// # Arguments
+///
+/// * `client_id` - The ID of the client
+///
+pub fn client_user_name(client_id: u16) -> String {
+    format!("client_{}", client_id)
+}
+
+/// Returns the name of the personal access token the gateway issues for the iggy user of a client.
+///
+/// # Arguments
+///
+/// * `client_id` - The ID of the client
+///
+pub fn client_token_name(client_id: u16) -> String {
+    format!("client_{}_token", client_id)
+}
+
+/// Returns the iggy user clients use to send their login to the gateway.
+///
+/// The user may only send logins and read rejected logins, see `bootstrap_permissions`,
+/// so its password is no secret.
+///
+pub fn bootstrap_user() -> IggyUser {
+    IggyUser::new("qd_bootstrap", "qd_bootstrap")
+}
+
+/// Returns the permissions of the bootstrap user.
+///
+/// The user has no global permissions. On the gateway control stream, it may only send
+/// messages to the login topic and poll the login reject topic, see `login_reject_config`,
+/// so it can neither read the logins of other clients nor fake a rejected login,
+/// and cannot send requests for a logged in client.
+///
+/// # Arguments
+///
+/// * `gateway_stream_id` - The ID of the control stream of the gateway
+/// * `gateway_topic_id` - The ID of the login topic of the gateway
+///
+pub fn bootstrap_permissions(gateway_stream_id: u32, gateway_topic_id: u32) -> Permissions {
+    let login_topic = TopicPermissions {
+        manage_topic: false,
+        read_topic: false,
+        poll_messages: false,
+        send_messages: true,
+    };
+
+    let reject_topic = TopicPermissions {
+        manage_topic: false,
+        read_topic: false,
+        poll_messages: true,
+        send_messages: false,
+    };
+
+    let gateway_stream = StreamPermissions {
+        manage_stream: false,
+        read_stream: false,
+        manage_topics: false,
+        read_topics: false,
+        poll_messages: false,
+        send_messages: false,
+        topics: Some(HashMap::from([
+            (gateway_topic_id, login_topic),
+            (SIDE_TOPIC_ID, reject_topic),
+        ])),
+    };
+
+    Permissions {
+        global: GlobalPermissions::default(),
+        streams: Some(HashMap::from([(gateway_stream_id, gateway_stream)])),
+    }
+}
+
+/// Returns the permissions of the iggy user of a client.
+///
+/// The user has no global permissions. It may manage its own data stream, which the gateway
+/// creates at login and the client deletes at logout, and read and write its topics.
+/// It has no access to the gateway control stream: the client sends its requests to the
+/// control topic of its data stream, see `client_control_config`, which no other client
+/// can write to, and the gateway sends acks and errors on the data topic.
+///
+/// # Arguments
+///
+/// * `client_stream_id` - The ID of the data stream of the client
+///
+pub fn client_permissions(client_stream_id: u32) -> Permissions {
+    let client_stream = StreamPermissions {
+        manage_stream: true,
+        read_stream: true,
+        manage_topics: true,
+        read_topics: true,
+        poll_messages: true,
+        send_messages: true,
+        topics: None,
+    };
+
+    Permissions {
+        global: GlobalPermissions::default(),
+        streams: Some(HashMap::from([(client_stream_id, client_stream)])),
+    }
+}
+
+/// Returns the config of the control topic of a client, on which the client sends its requests
+/// to the gateway once it has logged in.
+///
+/// The topic belongs to the data stream of the client, so only the iggy user of the client and
+/// the gateway can use it, and the gateway knows the client of each request from its topic.
+///
+/// # Arguments
+///
+/// * `client_config` - The config of the data channel of the client
+///
+pub fn client_control_config(client_config: &IggyConfig) -> IggyConfig {
+    side_topic_config(client_config, "control")
+}
+
+/// Returns the config of the topic on which the gateway rejects logins.
+///
+/// A client that is not logged in has no data channel yet, so it polls this topic with the
+/// bootstrap user for the ClientErrorMessage or DataErrorMessage with its client ID.
+///
+/// # Arguments
+///
+/// * `gateway_config` - The config of the control channel of the gateway
+///
+pub fn login_reject_config(gateway_config: &IggyConfig) -> IggyConfig {
+    side_topic_config(gateway_config, "login_rejects")
+}
+
+fn side_topic_config(iggy_config: &IggyConfig, name: &str) -> IggyConfig {
+    IggyConfig::new(
+        iggy_config.user().clone(),
+        &iggy_config.tcp_server_addr(),
+        iggy_config.stream_id(),
+        iggy_config.stream_name().to_string(),
+        Identifier::numeric(SIDE_TOPIC_ID).expect("Topic ID is not zero"),
+        format!("{}_{}", iggy_config.stream_name(), name),
+        1,
+        iggy_config.messages_per_batch(),
+        true,
+    )
+}
+
+/// Creates the topic of the given config with a single partition in an existing stream.
+///
+/// # Arguments
+///
+/// * `client` - A reference to an `IggyClient` instance logged in with a user that may manage the stream.
+/// * `iggy_config` - The config with the stream and the topic to create.
+///
+/// # Returns
+///
+/// A `Result` with `()` if the topic was created, or a boxed error otherwise,
+/// i.e. if the topic already exists.
+///
+pub async fn create_topic(
+    client: &IggyClient,
+    iggy_config: &IggyConfig,
+) -> Result<(), Box<dyn Error>> {
+    let topic_id = match iggy_config.topic_id().get_u32_value() {
+        Ok(topic_id) => topic_id,
+        Err(err) => return Err(Box::from(err)),
+    };
+
+    match client
+        .create_topic(&CreateTopic {
+            stream_id: iggy_config.stream_id(),
+            topic_id: Some(topic_id),
+            partitions_count: 1,
+            name: iggy_config.topic_name().to_string(),
+            message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::None,
+            max_topic_size: None,
+            replication_factor: Some(1),
+        })
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Box::from(err)),
+    }
+}
+
+/// Creates a new user with the given permissions on the Iggy server.
+///
+/// Unlike `create_user`, this fails if the user already exists.
+///
+/// # Arguments
+///
+/// * `client` - A reference to an `IggyClient` instance logged in with a user that may manage users.
+/// * `user` - A reference to the `IggyUser` to create.
+/// * `permissions` - The permissions of the new user.
+///
+/// # Returns
+///
+/// A `Result` with `()` if the user was created, or a boxed error otherwise.
+///
+pub async fn create_user_with_permissions(
+    client: &IggyClient,
+    user: &IggyUser,
+    permissions: Permissions,
+) -> Result<(), Box<dyn Error>> {
+    match client
+        .create_user(&CreateUser {
+            username: user.username().to_string(),
+            password: user.password().to_string(),
+            status: UserStatus::Active,
+            permissions: Some(permissions),
+        })
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Box::from(err)),
+    }
+}
+
+/// Deletes a user from the Iggy server, which also deletes all personal access tokens of the user.
+///
+/// # Arguments
+///
+/// * `client` - A reference to an `IggyClient` instance logged in with a user that may manage users.
+/// * `username` - The name of the user to delete.
+///
+/// # Returns
+///
+/// A `Result` with `()` if the user was deleted, or a boxed error otherwise.
+///
+pub async fn delete_user(client: &IggyClient, username: &str) -> Result<(), Box<dyn Error>> {
+    let user_id = match Identifier::named(username) {
+        Ok(user_id) => user_id,
+        Err(err) => return Err(Box::from(err)),
+    };
+
+    match client.delete_user(&DeleteUser { user_id }).await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Box::from(err)),
+    }
+}
+
+/// Deletes a personal access token of the logged in user.
+///
+/// # Arguments
+///
+/// * `client` - The IggyClient instance
+/// * `token_name` - The name of the token to delete
+///
+/// # Returns
+///
+/// A `Result` with `()` if the token was deleted, or a boxed error otherwise.
+///
+pub async fn delete_token(client: &IggyClient, token_name: String) -> Result<(), Box<dyn Error>> {
+    match client
+        .delete_personal_access_token(&DeletePersonalAccessToken { name: token_name })
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Box::from(err)),
+    }
+}
+
+/// Logs in the user of a personal access token.
+///
+/// # Arguments
+///
+/// * `client` - A reference to a connected `IggyClient` instance.
+/// * `token` - The personal access token.
+///
+/// # Returns
+///
+/// A `Result` with `()` if the user was logged in, or a boxed error otherwise.
+///
+pub async fn login_with_token(client: &IggyClient, token: &str) -> Result<(), Box<dyn Error>> {
+    match client
+        .login_with_personal_access_token(&LoginWithPersonalAccessToken {
+            token: token.to_string(),
+        })
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Box::from(err)),
+    }
+}
+
+/// Creates a new `IggyClient`, connects it to the Iggy server,
+/// and logs in the user of the given personal access token.
+///
+/// # Arguments
+///
+/// * `tcp_server_addr` - The TCP address of the Iggy server.
+/// * `token` - The personal access token.
+///
+/// # Returns
+///
+/// A `Result` with the logged in `IggyClient`, or a boxed error
+/// if the client cannot connect or the token is not valid.
+///
+pub async fn get_token_client(
+    tcp_server_addr: String,
+    token: &str,
+) -> Result<IggyClient, Box<dyn Error>> {
+    let client = match get_iggy_client(tcp_server_addr).await {
+        Ok(client) => client,
+        Err(err) => return Err(Box::from(err)),
+    };
+
+    match client.connect().await {
+        Ok(_) => (),
+        Err(err) => return Err(Box::from(err)),
+    }
+
+    match login_with_token(&client, token).await {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    }
+
+    Ok(client)
+}
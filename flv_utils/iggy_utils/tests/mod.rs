@@ -1,5 +1,9 @@
 use common::prelude::{IggyConfig, IggyUser};
-use iggy_utils::{cleanup, get_consumer, get_iggy_client, get_producer, shutdown};
+use iggy_utils::{
+    bootstrap_permissions, bootstrap_user, cleanup, client_control_config, client_permissions,
+    client_token_name, client_user_name, get_consumer, get_iggy_client, get_producer,
+    login_reject_config, shutdown,
+};
 
 #[tokio::test]
 async fn test_get_iggy_client() {
@@ -76,3 +80,96 @@ async fn test_get_producer_init_error() {
     // Assert
     assert!(result.is_err());
 }
+
+#[test]
+fn test_client_user_and_token_name() {
+    assert_eq!(client_user_name(77), "client_77");
+    assert_eq!(client_token_name(77), "client_77_token");
+}
+
+#[test]
+fn test_client_permissions() {
+    let permissions = client_permissions(77);
+
+    // No global permissions
+    assert!(!permissions.global.manage_users);
+    assert!(!permissions.global.read_streams);
+    assert!(!permissions.global.poll_messages);
+    assert!(!permissions.global.send_messages);
+
+    // The client has no access to the gateway control stream
+    let streams = permissions.streams.expect("Missing stream permissions");
+    assert_eq!(streams.len(), 1);
+    assert!(!streams.contains_key(&1));
+
+    // The client manages its own data stream
+    let client_stream = streams.get(&77).expect("Missing client stream permissions");
+    assert!(client_stream.manage_stream);
+    assert!(client_stream.poll_messages);
+    assert!(client_stream.send_messages);
+}
+
+#[test]
+fn test_bootstrap_permissions() {
+    assert_eq!(bootstrap_user().username(), "qd_bootstrap");
+
+    let gateway_config = IggyConfig::from_client_id(bootstrap_user(), 1, 1, false);
+    let reject_config = login_reject_config(&gateway_config);
+    let reject_topic_id = reject_config.topic_id().get_u32_value().unwrap();
+
+    let permissions = bootstrap_permissions(1, 1);
+
+    // No global permissions
+    assert!(!permissions.global.manage_users);
+    assert!(!permissions.global.read_streams);
+    assert!(!permissions.global.poll_messages);
+    assert!(!permissions.global.send_messages);
+
+    // No stream wide permissions on the gateway control stream
+    let streams = permissions.streams.expect("Missing stream permissions");
+    assert_eq!(streams.len(), 1);
+
+    let gateway_stream = streams.get(&1).expect("Missing gateway stream permissions");
+    assert!(!gateway_stream.manage_stream);
+    assert!(!gateway_stream.read_stream);
+    assert!(!gateway_stream.read_topics);
+    assert!(!gateway_stream.poll_messages);
+    assert!(!gateway_stream.send_messages);
+
+    let topics = gateway_stream
+        .topics
+        .as_ref()
+        .expect("Missing topic permissions");
+    assert_eq!(topics.len(), 2);
+
+    // The bootstrap user may only send logins
+    let login_topic = topics.get(&1).expect("Missing login topic permissions");
+    assert!(!login_topic.poll_messages);
+    assert!(login_topic.send_messages);
+
+    // and poll rejected logins
+    let reject_topic = topics
+        .get(&reject_topic_id)
+        .expect("Missing reject topic permissions");
+    assert!(reject_topic.poll_messages);
+    assert!(!reject_topic.send_messages);
+}
+
+#[test]
+fn test_side_topic_configs() {
+    let client_config = IggyConfig::from_client_id(bootstrap_user(), 77, 1, false);
+    let control_config = client_control_config(&client_config);
+
+    // The control topic belongs to the data stream of the client
+    assert_eq!(control_config.stream_id(), client_config.stream_id());
+    assert_ne!(control_config.topic_id(), client_config.topic_id());
+    assert_eq!(control_config.partition_id(), 1);
+
+    let gateway_config = IggyConfig::from_client_id(bootstrap_user(), 1, 1, false);
+    let reject_config = login_reject_config(&gateway_config);
+
+    // The reject topic belongs to the gateway control stream
+    assert_eq!(reject_config.stream_id(), gateway_config.stream_id());
+    assert_ne!(reject_config.topic_id(), gateway_config.topic_id());
+    assert_eq!(reject_config.partition_id(), 1);
+}